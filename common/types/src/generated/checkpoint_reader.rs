@@ -125,6 +125,37 @@ impl CheckpointCellData {
     }
 }
 
+impl CheckpointCellData {
+    pub fn evidence_smt_root(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(9).unwrap();
+        cur.into()
+    }
+}
+
+pub struct ProposalVec {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for ProposalVec {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl ProposalVec {
+    pub fn len(&self) -> usize {
+        self.cursor.dynvec_length()
+    }
+}
+
+impl ProposalVec {
+    pub fn get(&self, index: usize) -> Vec<u8> {
+        let cur = self.cursor.dynvec_slice_by_index(index).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
 pub struct CheckpointWitness {
     pub cursor: Cursor,
 }
@@ -136,8 +167,15 @@ impl From<Cursor> for CheckpointWitness {
 }
 
 impl CheckpointWitness {
-    pub fn proposal(&self) -> Vec<u8> {
+    pub fn mode(&self) -> u8 {
         let cur = self.cursor.table_slice_by_index(0).unwrap();
+        cur.into()
+    }
+}
+
+impl CheckpointWitness {
+    pub fn proposal(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
         let cur2 = cur.convert_to_rawbytes().unwrap();
         cur2.into()
     }
@@ -145,12 +183,110 @@ impl CheckpointWitness {
 
 impl CheckpointWitness {
     pub fn proof(&self) -> Vec<u8> {
-        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        let cur = self.cursor.table_slice_by_index(2).unwrap();
         let cur2 = cur.convert_to_rawbytes().unwrap();
         cur2.into()
     }
 }
 
+impl CheckpointWitness {
+    pub fn conflict_proof(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(3).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+impl CheckpointWitness {
+    pub fn period_proposals(&self) -> ProposalVec {
+        let cur = self.cursor.table_slice_by_index(4).unwrap();
+        cur.into()
+    }
+}
+
+impl CheckpointWitness {
+    pub fn evidence_proof(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(5).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+pub struct Byte20Vec {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for Byte20Vec {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl Byte20Vec {
+    pub fn len(&self) -> usize {
+        self.cursor.fixvec_length()
+    }
+}
+
+impl Byte20Vec {
+    pub fn get(&self, index: usize) -> Vec<u8> {
+        let cur = self.cursor.fixvec_slice_by_index(20, index).unwrap();
+        cur.into()
+    }
+}
+
+pub struct CheckpointEvidenceCellData {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for CheckpointEvidenceCellData {
+    fn from(cursor: Cursor) -> Self {
+        CheckpointEvidenceCellData { cursor }
+    }
+}
+
+impl CheckpointEvidenceCellData {
+    pub fn version(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(0).unwrap();
+        cur.into()
+    }
+}
+
+impl CheckpointEvidenceCellData {
+    pub fn metadata_type_id(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        cur.into()
+    }
+}
+
+impl CheckpointEvidenceCellData {
+    pub fn epoch(&self) -> u64 {
+        let cur = self.cursor.table_slice_by_index(2).unwrap();
+        cur.into()
+    }
+}
+
+impl CheckpointEvidenceCellData {
+    pub fn height(&self) -> u64 {
+        let cur = self.cursor.table_slice_by_index(3).unwrap();
+        cur.into()
+    }
+}
+
+impl CheckpointEvidenceCellData {
+    pub fn round(&self) -> u64 {
+        let cur = self.cursor.table_slice_by_index(4).unwrap();
+        cur.into()
+    }
+}
+
+impl CheckpointEvidenceCellData {
+    pub fn offenders(&self) -> Byte20Vec {
+        let cur = self.cursor.table_slice_by_index(5).unwrap();
+        cur.into()
+    }
+}
+
 pub struct CheckpointArgs {
     pub cursor: Cursor,
 }
@@ -167,3 +303,34 @@ impl CheckpointArgs {
         cur.into()
     }
 }
+
+pub struct CheckpointEvidenceArgs {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for CheckpointEvidenceArgs {
+    fn from(cursor: Cursor) -> Self {
+        CheckpointEvidenceArgs { cursor }
+    }
+}
+
+impl CheckpointEvidenceArgs {
+    pub fn checkpoint_type_id(&self) -> Vec<u8> {
+        let cur = self.cursor.slice_by_offset(0, 32).unwrap();
+        cur.into()
+    }
+}
+
+impl CheckpointEvidenceArgs {
+    pub fn height(&self) -> u64 {
+        let cur = self.cursor.slice_by_offset(32, 8).unwrap();
+        cur.into()
+    }
+}
+
+impl CheckpointEvidenceArgs {
+    pub fn round(&self) -> u64 {
+        let cur = self.cursor.slice_by_offset(40, 8).unwrap();
+        cur.into()
+    }
+}
@@ -480,6 +480,270 @@ impl molecule::prelude::Builder for DelegateRequirementBuilder {
     }
 }
 #[derive(Clone)]
+pub struct CommissionChange(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for CommissionChange {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for CommissionChange {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for CommissionChange {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "previous_rate", self.previous_rate())?;
+        write!(f, ", {}: {}", "effective_epoch", self.effective_epoch())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for CommissionChange {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            21, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        CommissionChange::new_unchecked(v.into())
+    }
+}
+impl CommissionChange {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn previous_rate(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn effective_epoch(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Uint64::new_unchecked(self.0.slice(start..end))
+        } else {
+            Uint64::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> CommissionChangeReader<'r> {
+        CommissionChangeReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for CommissionChange {
+    type Builder = CommissionChangeBuilder;
+    const NAME: &'static str = "CommissionChange";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        CommissionChange(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CommissionChangeReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        CommissionChangeReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .previous_rate(self.previous_rate())
+            .effective_epoch(self.effective_epoch())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct CommissionChangeReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for CommissionChangeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for CommissionChangeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for CommissionChangeReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "previous_rate", self.previous_rate())?;
+        write!(f, ", {}: {}", "effective_epoch", self.effective_epoch())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> CommissionChangeReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn previous_rate(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn effective_epoch(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for CommissionChangeReader<'r> {
+    type Entity = CommissionChange;
+    const NAME: &'static str = "CommissionChangeReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        CommissionChangeReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct CommissionChangeBuilder {
+    pub(crate) previous_rate: Byte,
+    pub(crate) effective_epoch: Uint64,
+}
+impl CommissionChangeBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn previous_rate(mut self, v: Byte) -> Self {
+        self.previous_rate = v;
+        self
+    }
+    pub fn effective_epoch(mut self, v: Uint64) -> Self {
+        self.effective_epoch = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for CommissionChangeBuilder {
+    type Entity = CommissionChange;
+    const NAME: &'static str = "CommissionChangeBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.previous_rate.as_slice().len()
+            + self.effective_epoch.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.previous_rate.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.effective_epoch.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.previous_rate.as_slice())?;
+        writer.write_all(self.effective_epoch.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        CommissionChange::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
 pub struct DelegateCellData(molecule::bytes::Bytes);
 impl ::core::fmt::LowerHex for DelegateCellData {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
@@ -508,6 +772,7 @@ impl ::core::fmt::Display for DelegateCellData {
             self.delegate_requirement()
         )?;
         write!(f, ", {}: {}", "metadata_type_id", self.metadata_type_id())?;
+        write!(f, ", {}: {}", "commission_change", self.commission_change())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -518,17 +783,18 @@ impl ::core::fmt::Display for DelegateCellData {
 impl ::core::default::Default for DelegateCellData {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            134, 0, 0, 0, 24, 0, 0, 0, 25, 0, 0, 0, 45, 0, 0, 0, 65, 0, 0, 0, 102, 0, 0, 0, 0, 0,
+            159, 0, 0, 0, 28, 0, 0, 0, 29, 0, 0, 0, 49, 0, 0, 0, 69, 0, 0, 0, 106, 0, 0, 0, 138, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 16, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 16, 0, 0, 0, 32, 0, 0, 0, 36, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0,
+            0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
         DelegateCellData::new_unchecked(v.into())
     }
 }
 impl DelegateCellData {
-    pub const FIELD_COUNT: usize = 5;
+    pub const FIELD_COUNT: usize = 6;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -572,11 +838,17 @@ impl DelegateCellData {
     pub fn metadata_type_id(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn commission_change(&self) -> CommissionChange {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[24..]) as usize;
-            Byte32::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            CommissionChange::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte32::new_unchecked(self.0.slice(start..))
+            CommissionChange::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> DelegateCellDataReader<'r> {
@@ -611,6 +883,7 @@ impl molecule::prelude::Entity for DelegateCellData {
             .l2_address(self.l2_address())
             .delegate_requirement(self.delegate_requirement())
             .metadata_type_id(self.metadata_type_id())
+            .commission_change(self.commission_change())
     }
 }
 #[derive(Clone, Copy)]
@@ -642,6 +915,7 @@ impl<'r> ::core::fmt::Display for DelegateCellDataReader<'r> {
             self.delegate_requirement()
         )?;
         write!(f, ", {}: {}", "metadata_type_id", self.metadata_type_id())?;
+        write!(f, ", {}: {}", "commission_change", self.commission_change())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -650,7 +924,7 @@ impl<'r> ::core::fmt::Display for DelegateCellDataReader<'r> {
     }
 }
 impl<'r> DelegateCellDataReader<'r> {
-    pub const FIELD_COUNT: usize = 5;
+    pub const FIELD_COUNT: usize = 6;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -694,11 +968,17 @@ impl<'r> DelegateCellDataReader<'r> {
     pub fn metadata_type_id(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn commission_change(&self) -> CommissionChangeReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[24..]) as usize;
-            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            CommissionChangeReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Byte32Reader::new_unchecked(&self.as_slice()[start..])
+            CommissionChangeReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -756,6 +1036,7 @@ impl<'r> molecule::prelude::Reader<'r> for DelegateCellDataReader<'r> {
         IdentityReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         DelegateRequirementReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Byte32Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        CommissionChangeReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
         Ok(())
     }
 }
@@ -766,9 +1047,10 @@ pub struct DelegateCellDataBuilder {
     pub(crate) l2_address: Identity,
     pub(crate) delegate_requirement: DelegateRequirement,
     pub(crate) metadata_type_id: Byte32,
+    pub(crate) commission_change: CommissionChange,
 }
 impl DelegateCellDataBuilder {
-    pub const FIELD_COUNT: usize = 5;
+    pub const FIELD_COUNT: usize = 6;
     pub fn version(mut self, v: Byte) -> Self {
         self.version = v;
         self
@@ -789,6 +1071,10 @@ impl DelegateCellDataBuilder {
         self.metadata_type_id = v;
         self
     }
+    pub fn commission_change(mut self, v: CommissionChange) -> Self {
+        self.commission_change = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for DelegateCellDataBuilder {
     type Entity = DelegateCellData;
@@ -800,6 +1086,7 @@ impl molecule::prelude::Builder for DelegateCellDataBuilder {
             + self.l2_address.as_slice().len()
             + self.delegate_requirement.as_slice().len()
             + self.metadata_type_id.as_slice().len()
+            + self.commission_change.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -814,6 +1101,8 @@ impl molecule::prelude::Builder for DelegateCellDataBuilder {
         total_size += self.delegate_requirement.as_slice().len();
         offsets.push(total_size);
         total_size += self.metadata_type_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.commission_change.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -823,6 +1112,7 @@ impl molecule::prelude::Builder for DelegateCellDataBuilder {
         writer.write_all(self.l2_address.as_slice())?;
         writer.write_all(self.delegate_requirement.as_slice())?;
         writer.write_all(self.metadata_type_id.as_slice())?;
+        writer.write_all(self.commission_change.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -833,6 +1123,272 @@ impl molecule::prelude::Builder for DelegateCellDataBuilder {
     }
 }
 #[derive(Clone)]
+pub struct RequirementWitness(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RequirementWitness {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for RequirementWitness {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for RequirementWitness {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "eth_sig", self.eth_sig())?;
+        write!(f, ", {}: {}", "sig_mode", self.sig_mode())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for RequirementWitness {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            78, 0, 0, 0, 12, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        RequirementWitness::new_unchecked(v.into())
+    }
+}
+impl RequirementWitness {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn eth_sig(&self) -> Byte65 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte65::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sig_mode(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> RequirementWitnessReader<'r> {
+        RequirementWitnessReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for RequirementWitness {
+    type Builder = RequirementWitnessBuilder;
+    const NAME: &'static str = "RequirementWitness";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        RequirementWitness(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RequirementWitnessReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        RequirementWitnessReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .eth_sig(self.eth_sig())
+            .sig_mode(self.sig_mode())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct RequirementWitnessReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RequirementWitnessReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for RequirementWitnessReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for RequirementWitnessReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "eth_sig", self.eth_sig())?;
+        write!(f, ", {}: {}", "sig_mode", self.sig_mode())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> RequirementWitnessReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn eth_sig(&self) -> Byte65Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte65Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sig_mode(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            ByteReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            ByteReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for RequirementWitnessReader<'r> {
+    type Entity = RequirementWitness;
+    const NAME: &'static str = "RequirementWitnessReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        RequirementWitnessReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte65Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct RequirementWitnessBuilder {
+    pub(crate) eth_sig: Byte65,
+    pub(crate) sig_mode: Byte,
+}
+impl RequirementWitnessBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn eth_sig(mut self, v: Byte65) -> Self {
+        self.eth_sig = v;
+        self
+    }
+    pub fn sig_mode(mut self, v: Byte) -> Self {
+        self.sig_mode = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for RequirementWitnessBuilder {
+    type Entity = RequirementWitness;
+    const NAME: &'static str = "RequirementWitnessBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.eth_sig.as_slice().len()
+            + self.sig_mode.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.eth_sig.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sig_mode.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.eth_sig.as_slice())?;
+        writer.write_all(self.sig_mode.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        RequirementWitness::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
 pub struct DelegateArgs(molecule::bytes::Bytes);
 impl ::core::fmt::LowerHex for DelegateArgs {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
@@ -1026,6 +1582,7 @@ impl ::core::fmt::Display for DelegateInfoDelta {
             "inauguration_epoch",
             self.inauguration_epoch()
         )?;
+        write!(f, ", {}: {}", "from_staker", self.from_staker())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1036,15 +1593,16 @@ impl ::core::fmt::Display for DelegateInfoDelta {
 impl ::core::default::Default for DelegateInfoDelta {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            65, 0, 0, 0, 20, 0, 0, 0, 21, 0, 0, 0, 41, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            89, 0, 0, 0, 24, 0, 0, 0, 25, 0, 0, 0, 45, 0, 0, 0, 61, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
         ];
         DelegateInfoDelta::new_unchecked(v.into())
     }
 }
 impl DelegateInfoDelta {
-    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1082,11 +1640,17 @@ impl DelegateInfoDelta {
     pub fn inauguration_epoch(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn from_staker(&self) -> Identity {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            Uint64::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            Identity::new_unchecked(self.0.slice(start..end))
         } else {
-            Uint64::new_unchecked(self.0.slice(start..))
+            Identity::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> DelegateInfoDeltaReader<'r> {
@@ -1120,6 +1684,7 @@ impl molecule::prelude::Entity for DelegateInfoDelta {
             .staker(self.staker())
             .amount(self.amount())
             .inauguration_epoch(self.inauguration_epoch())
+            .from_staker(self.from_staker())
     }
 }
 #[derive(Clone, Copy)]
@@ -1150,6 +1715,7 @@ impl<'r> ::core::fmt::Display for DelegateInfoDeltaReader<'r> {
             "inauguration_epoch",
             self.inauguration_epoch()
         )?;
+        write!(f, ", {}: {}", "from_staker", self.from_staker())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1158,7 +1724,7 @@ impl<'r> ::core::fmt::Display for DelegateInfoDeltaReader<'r> {
     }
 }
 impl<'r> DelegateInfoDeltaReader<'r> {
-    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1196,11 +1762,17 @@ impl<'r> DelegateInfoDeltaReader<'r> {
     pub fn inauguration_epoch(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn from_staker(&self) -> IdentityReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            IdentityReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Uint64Reader::new_unchecked(&self.as_slice()[start..])
+            IdentityReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -1257,6 +1829,7 @@ impl<'r> molecule::prelude::Reader<'r> for DelegateInfoDeltaReader<'r> {
         IdentityReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Uint128Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        IdentityReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
         Ok(())
     }
 }
@@ -1266,9 +1839,10 @@ pub struct DelegateInfoDeltaBuilder {
     pub(crate) staker: Identity,
     pub(crate) amount: Uint128,
     pub(crate) inauguration_epoch: Uint64,
+    pub(crate) from_staker: Identity,
 }
 impl DelegateInfoDeltaBuilder {
-    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_COUNT: usize = 5;
     pub fn is_increase(mut self, v: Byte) -> Self {
         self.is_increase = v;
         self
@@ -1285,6 +1859,10 @@ impl DelegateInfoDeltaBuilder {
         self.inauguration_epoch = v;
         self
     }
+    pub fn from_staker(mut self, v: Identity) -> Self {
+        self.from_staker = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for DelegateInfoDeltaBuilder {
     type Entity = DelegateInfoDelta;
@@ -1295,6 +1873,7 @@ impl molecule::prelude::Builder for DelegateInfoDeltaBuilder {
             + self.staker.as_slice().len()
             + self.amount.as_slice().len()
             + self.inauguration_epoch.as_slice().len()
+            + self.from_staker.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -1307,6 +1886,8 @@ impl molecule::prelude::Builder for DelegateInfoDeltaBuilder {
         total_size += self.amount.as_slice().len();
         offsets.push(total_size);
         total_size += self.inauguration_epoch.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.from_staker.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -1315,6 +1896,7 @@ impl molecule::prelude::Builder for DelegateInfoDeltaBuilder {
         writer.write_all(self.staker.as_slice())?;
         writer.write_all(self.amount.as_slice())?;
         writer.write_all(self.inauguration_epoch.as_slice())?;
+        writer.write_all(self.from_staker.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -2633,6 +3215,8 @@ impl ::core::fmt::Display for DelegateAtWitness {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "mode", self.mode())?;
+        write!(f, ", {}: {}", "eth_sig", self.eth_sig())?;
+        write!(f, ", {}: {}", "sig_mode", self.sig_mode())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -2642,12 +3226,16 @@ impl ::core::fmt::Display for DelegateAtWitness {
 }
 impl ::core::default::Default for DelegateAtWitness {
     fn default() -> Self {
-        let v: Vec<u8> = vec![9, 0, 0, 0, 8, 0, 0, 0, 0];
+        let v: Vec<u8> = vec![
+            83, 0, 0, 0, 16, 0, 0, 0, 17, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
         DelegateAtWitness::new_unchecked(v.into())
     }
 }
 impl DelegateAtWitness {
-    pub const FIELD_COUNT: usize = 1;
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -2667,8 +3255,20 @@ impl DelegateAtWitness {
     pub fn mode(&self) -> Byte {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn eth_sig(&self) -> Byte65 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte65::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sig_mode(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[8..]) as usize;
+            let end = molecule::unpack_number(&slice[16..]) as usize;
             Byte::new_unchecked(self.0.slice(start..end))
         } else {
             Byte::new_unchecked(self.0.slice(start..))
@@ -2700,7 +3300,10 @@ impl molecule::prelude::Entity for DelegateAtWitness {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().mode(self.mode())
+        Self::new_builder()
+            .mode(self.mode())
+            .eth_sig(self.eth_sig())
+            .sig_mode(self.sig_mode())
     }
 }
 #[derive(Clone, Copy)]
@@ -2723,6 +3326,8 @@ impl<'r> ::core::fmt::Display for DelegateAtWitnessReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "mode", self.mode())?;
+        write!(f, ", {}: {}", "eth_sig", self.eth_sig())?;
+        write!(f, ", {}: {}", "sig_mode", self.sig_mode())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -2731,7 +3336,7 @@ impl<'r> ::core::fmt::Display for DelegateAtWitnessReader<'r> {
     }
 }
 impl<'r> DelegateAtWitnessReader<'r> {
-    pub const FIELD_COUNT: usize = 1;
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -2751,8 +3356,20 @@ impl<'r> DelegateAtWitnessReader<'r> {
     pub fn mode(&self) -> ByteReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn eth_sig(&self) -> Byte65Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte65Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sig_mode(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[8..]) as usize;
+            let end = molecule::unpack_number(&slice[16..]) as usize;
             ByteReader::new_unchecked(&self.as_slice()[start..end])
         } else {
             ByteReader::new_unchecked(&self.as_slice()[start..])
@@ -2809,36 +3426,57 @@ impl<'r> molecule::prelude::Reader<'r> for DelegateAtWitnessReader<'r> {
             return ve!(Self, OffsetsNotMatch);
         }
         ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte65Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        ByteReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
 pub struct DelegateAtWitnessBuilder {
     pub(crate) mode: Byte,
+    pub(crate) eth_sig: Byte65,
+    pub(crate) sig_mode: Byte,
 }
 impl DelegateAtWitnessBuilder {
-    pub const FIELD_COUNT: usize = 1;
+    pub const FIELD_COUNT: usize = 3;
     pub fn mode(mut self, v: Byte) -> Self {
         self.mode = v;
         self
     }
+    pub fn eth_sig(mut self, v: Byte65) -> Self {
+        self.eth_sig = v;
+        self
+    }
+    pub fn sig_mode(mut self, v: Byte) -> Self {
+        self.sig_mode = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for DelegateAtWitnessBuilder {
     type Entity = DelegateAtWitness;
     const NAME: &'static str = "DelegateAtWitnessBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.mode.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.mode.as_slice().len()
+            + self.eth_sig.as_slice().len()
+            + self.sig_mode.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
         total_size += self.mode.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.eth_sig.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sig_mode.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
         writer.write_all(self.mode.as_slice())?;
+        writer.write_all(self.eth_sig.as_slice())?;
+        writer.write_all(self.sig_mode.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
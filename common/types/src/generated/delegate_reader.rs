@@ -105,6 +105,61 @@ impl DelegateCellData {
     }
 }
 
+impl DelegateCellData {
+    pub fn commission_change(&self) -> CommissionChange {
+        let cur = self.cursor.table_slice_by_index(5).unwrap();
+        cur.into()
+    }
+}
+
+pub struct CommissionChange {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for CommissionChange {
+    fn from(cursor: Cursor) -> Self {
+        CommissionChange { cursor }
+    }
+}
+
+impl CommissionChange {
+    pub fn previous_rate(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(0).unwrap();
+        cur.into()
+    }
+}
+
+impl CommissionChange {
+    pub fn effective_epoch(&self) -> u64 {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        cur.into()
+    }
+}
+
+pub struct RequirementWitness {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for RequirementWitness {
+    fn from(cursor: Cursor) -> Self {
+        RequirementWitness { cursor }
+    }
+}
+
+impl RequirementWitness {
+    pub fn eth_sig(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(0).unwrap();
+        cur.into()
+    }
+}
+
+impl RequirementWitness {
+    pub fn sig_mode(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        cur.into()
+    }
+}
+
 pub struct DelegateArgs {
     pub cursor: Cursor,
 }
@@ -167,6 +222,13 @@ impl DelegateInfoDelta {
     }
 }
 
+impl DelegateInfoDelta {
+    pub fn from_staker(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(4).unwrap();
+        cur.into()
+    }
+}
+
 pub struct DelegateInfoDeltas {
     pub cursor: Cursor,
 }
@@ -300,6 +362,20 @@ impl DelegateAtWitness {
     }
 }
 
+impl DelegateAtWitness {
+    pub fn eth_sig(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        cur.into()
+    }
+}
+
+impl DelegateAtWitness {
+    pub fn sig_mode(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(2).unwrap();
+        cur.into()
+    }
+}
+
 pub struct StakerSmtRoot {
     pub cursor: Cursor,
 }
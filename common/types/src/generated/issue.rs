@@ -199,3 +199,196 @@ impl molecule::prelude::Builder for IssueCellDataBuilder {
         IssueCellData::new_unchecked(inner.into())
     }
 }
+#[derive(Clone)]
+pub struct IssueArgs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for IssueArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for IssueArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for IssueArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "type_id", self.type_id())?;
+        write!(
+            f,
+            ", {}: {}",
+            "reward_smt_type_id",
+            self.reward_smt_type_id()
+        )?;
+        write!(f, ", {}: {}", "admin_lock_hash", self.admin_lock_hash())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for IssueArgs {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        IssueArgs::new_unchecked(v.into())
+    }
+}
+impl IssueArgs {
+    pub const TOTAL_SIZE: usize = 96;
+    pub const FIELD_SIZES: [usize; 3] = [32, 32, 32];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn type_id(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn reward_smt_type_id(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(32..64))
+    }
+    pub fn admin_lock_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(64..96))
+    }
+    pub fn as_reader<'r>(&'r self) -> IssueArgsReader<'r> {
+        IssueArgsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for IssueArgs {
+    type Builder = IssueArgsBuilder;
+    const NAME: &'static str = "IssueArgs";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        IssueArgs(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        IssueArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        IssueArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .type_id(self.type_id())
+            .reward_smt_type_id(self.reward_smt_type_id())
+            .admin_lock_hash(self.admin_lock_hash())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct IssueArgsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for IssueArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for IssueArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for IssueArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "type_id", self.type_id())?;
+        write!(
+            f,
+            ", {}: {}",
+            "reward_smt_type_id",
+            self.reward_smt_type_id()
+        )?;
+        write!(f, ", {}: {}", "admin_lock_hash", self.admin_lock_hash())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> IssueArgsReader<'r> {
+    pub const TOTAL_SIZE: usize = 96;
+    pub const FIELD_SIZES: [usize; 3] = [32, 32, 32];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn type_id(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn reward_smt_type_id(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[32..64])
+    }
+    pub fn admin_lock_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[64..96])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for IssueArgsReader<'r> {
+    type Entity = IssueArgs;
+    const NAME: &'static str = "IssueArgsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        IssueArgsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct IssueArgsBuilder {
+    pub(crate) type_id: Byte32,
+    pub(crate) reward_smt_type_id: Byte32,
+    pub(crate) admin_lock_hash: Byte32,
+}
+impl IssueArgsBuilder {
+    pub const TOTAL_SIZE: usize = 96;
+    pub const FIELD_SIZES: [usize; 3] = [32, 32, 32];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn type_id(mut self, v: Byte32) -> Self {
+        self.type_id = v;
+        self
+    }
+    pub fn reward_smt_type_id(mut self, v: Byte32) -> Self {
+        self.reward_smt_type_id = v;
+        self
+    }
+    pub fn admin_lock_hash(mut self, v: Byte32) -> Self {
+        self.admin_lock_hash = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for IssueArgsBuilder {
+    type Entity = IssueArgs;
+    const NAME: &'static str = "IssueArgsBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.type_id.as_slice())?;
+        writer.write_all(self.reward_smt_type_id.as_slice())?;
+        writer.write_all(self.admin_lock_hash.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        IssueArgs::new_unchecked(inner.into())
+    }
+}
@@ -42,3 +42,34 @@ impl IssueCellData {
         cur.into()
     }
 }
+
+pub struct IssueArgs {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for IssueArgs {
+    fn from(cursor: Cursor) -> Self {
+        IssueArgs { cursor }
+    }
+}
+
+impl IssueArgs {
+    pub fn type_id(&self) -> Vec<u8> {
+        let cur = self.cursor.slice_by_offset(0, 32).unwrap();
+        cur.into()
+    }
+}
+
+impl IssueArgs {
+    pub fn reward_smt_type_id(&self) -> Vec<u8> {
+        let cur = self.cursor.slice_by_offset(32, 32).unwrap();
+        cur.into()
+    }
+}
+
+impl IssueArgs {
+    pub fn admin_lock_hash(&self) -> Vec<u8> {
+        let cur = self.cursor.slice_by_offset(64, 32).unwrap();
+        cur.into()
+    }
+}
@@ -1050,8 +1050,8 @@ impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for ValidatorListReaderIterator
     }
 }
 #[derive(Clone)]
-pub struct Metadata(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Metadata {
+pub struct IdentityVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for IdentityVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1060,162 +1060,65 @@ impl ::core::fmt::LowerHex for Metadata {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Metadata {
+impl ::core::fmt::Debug for IdentityVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Metadata {
+impl ::core::fmt::Display for IdentityVec {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "epoch_len", self.epoch_len())?;
-        write!(f, ", {}: {}", "period_len", self.period_len())?;
-        write!(f, ", {}: {}", "quorum", self.quorum())?;
-        write!(f, ", {}: {}", "gas_limit", self.gas_limit())?;
-        write!(f, ", {}: {}", "gas_price", self.gas_price())?;
-        write!(f, ", {}: {}", "interval", self.interval())?;
-        write!(f, ", {}: {}", "validators", self.validators())?;
-        write!(f, ", {}: {}", "propose_ratio", self.propose_ratio())?;
-        write!(f, ", {}: {}", "prevote_ratio", self.prevote_ratio())?;
-        write!(f, ", {}: {}", "precommit_ratio", self.precommit_ratio())?;
-        write!(f, ", {}: {}", "brake_ratio", self.brake_ratio())?;
-        write!(f, ", {}: {}", "tx_num_limit", self.tx_num_limit())?;
-        write!(f, ", {}: {}", "max_tx_size", self.max_tx_size())?;
-        write!(f, ", {}: {}", "block_height", self.block_height())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
         }
-        write!(f, " }}")
+        write!(f, "]")
     }
 }
-impl ::core::default::Default for Metadata {
+impl ::core::default::Default for IdentityVec {
     fn default() -> Self {
-        let v: Vec<u8> = vec![
-            126, 0, 0, 0, 60, 0, 0, 0, 64, 0, 0, 0, 68, 0, 0, 0, 70, 0, 0, 0, 78, 0, 0, 0, 86, 0,
-            0, 0, 90, 0, 0, 0, 94, 0, 0, 0, 98, 0, 0, 0, 102, 0, 0, 0, 106, 0, 0, 0, 110, 0, 0, 0,
-            114, 0, 0, 0, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        Metadata::new_unchecked(v.into())
+        let v: Vec<u8> = vec![0, 0, 0, 0];
+        IdentityVec::new_unchecked(v.into())
     }
 }
-impl Metadata {
-    pub const FIELD_COUNT: usize = 14;
+impl IdentityVec {
+    pub const ITEM_SIZE: usize = 20;
     pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn epoch_len(&self) -> Uint32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn period_len(&self) -> Uint32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn quorum(&self) -> Uint16 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Uint16::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn gas_limit(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn gas_price(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn interval(&self) -> Uint32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn validators(&self) -> ValidatorList {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
-        let end = molecule::unpack_number(&slice[32..]) as usize;
-        ValidatorList::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn propose_ratio(&self) -> Uint32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[32..]) as usize;
-        let end = molecule::unpack_number(&slice[36..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn prevote_ratio(&self) -> Uint32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[36..]) as usize;
-        let end = molecule::unpack_number(&slice[40..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn precommit_ratio(&self) -> Uint32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[40..]) as usize;
-        let end = molecule::unpack_number(&slice[44..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
     }
-    pub fn brake_ratio(&self) -> Uint32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[44..]) as usize;
-        let end = molecule::unpack_number(&slice[48..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn tx_num_limit(&self) -> Uint32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[48..]) as usize;
-        let end = molecule::unpack_number(&slice[52..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn max_tx_size(&self) -> Uint32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[52..]) as usize;
-        let end = molecule::unpack_number(&slice[56..]) as usize;
-        Uint32::new_unchecked(self.0.slice(start..end))
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn block_height(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[56..]) as usize;
-        if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[60..]) as usize;
-            Uint64::new_unchecked(self.0.slice(start..end))
+    pub fn get(&self, idx: usize) -> Option<Identity> {
+        if idx >= self.len() {
+            None
         } else {
-            Uint64::new_unchecked(self.0.slice(start..))
+            Some(self.get_unchecked(idx))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> MetadataReader<'r> {
-        MetadataReader::new_unchecked(self.as_slice())
+    pub fn get_unchecked(&self, idx: usize) -> Identity {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Identity::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn as_reader<'r>(&'r self) -> IdentityVecReader<'r> {
+        IdentityVecReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Metadata {
-    type Builder = MetadataBuilder;
-    const NAME: &'static str = "Metadata";
+impl molecule::prelude::Entity for IdentityVec {
+    type Builder = IdentityVecBuilder;
+    const NAME: &'static str = "IdentityVec";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Metadata(data)
+        IdentityVec(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -1224,35 +1127,21 @@ impl molecule::prelude::Entity for Metadata {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        MetadataReader::from_slice(slice).map(|reader| reader.to_entity())
+        IdentityVecReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        MetadataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        IdentityVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .epoch_len(self.epoch_len())
-            .period_len(self.period_len())
-            .quorum(self.quorum())
-            .gas_limit(self.gas_limit())
-            .gas_price(self.gas_price())
-            .interval(self.interval())
-            .validators(self.validators())
-            .propose_ratio(self.propose_ratio())
-            .prevote_ratio(self.prevote_ratio())
-            .precommit_ratio(self.precommit_ratio())
-            .brake_ratio(self.brake_ratio())
-            .tx_num_limit(self.tx_num_limit())
-            .max_tx_size(self.max_tx_size())
-            .block_height(self.block_height())
+        Self::new_builder().extend(self.into_iter())
     }
 }
 #[derive(Clone, Copy)]
-pub struct MetadataReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for MetadataReader<'r> {
+pub struct IdentityVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for IdentityVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1261,150 +1150,2389 @@ impl<'r> ::core::fmt::LowerHex for MetadataReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for MetadataReader<'r> {
+impl<'r> ::core::fmt::Debug for IdentityVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for MetadataReader<'r> {
+impl<'r> ::core::fmt::Display for IdentityVecReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "epoch_len", self.epoch_len())?;
-        write!(f, ", {}: {}", "period_len", self.period_len())?;
-        write!(f, ", {}: {}", "quorum", self.quorum())?;
-        write!(f, ", {}: {}", "gas_limit", self.gas_limit())?;
-        write!(f, ", {}: {}", "gas_price", self.gas_price())?;
-        write!(f, ", {}: {}", "interval", self.interval())?;
-        write!(f, ", {}: {}", "validators", self.validators())?;
-        write!(f, ", {}: {}", "propose_ratio", self.propose_ratio())?;
-        write!(f, ", {}: {}", "prevote_ratio", self.prevote_ratio())?;
-        write!(f, ", {}: {}", "precommit_ratio", self.precommit_ratio())?;
-        write!(f, ", {}: {}", "brake_ratio", self.brake_ratio())?;
-        write!(f, ", {}: {}", "tx_num_limit", self.tx_num_limit())?;
-        write!(f, ", {}: {}", "max_tx_size", self.max_tx_size())?;
-        write!(f, ", {}: {}", "block_height", self.block_height())?;
-        let extra_count = self.count_extra_fields();
-        if extra_count != 0 {
-            write!(f, ", .. ({} fields)", extra_count)?;
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
         }
-        write!(f, " }}")
+        write!(f, "]")
     }
 }
-impl<'r> MetadataReader<'r> {
-    pub const FIELD_COUNT: usize = 14;
+impl<'r> IdentityVecReader<'r> {
+    pub const ITEM_SIZE: usize = 20;
     pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
+    pub fn len(&self) -> usize {
+        self.item_count()
     }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
+    pub fn get(&self, idx: usize) -> Option<IdentityReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
     }
-    pub fn epoch_len(&self) -> Uint32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    pub fn get_unchecked(&self, idx: usize) -> IdentityReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        IdentityReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn period_len(&self) -> Uint32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+}
+impl<'r> molecule::prelude::Reader<'r> for IdentityVecReader<'r> {
+    type Entity = IdentityVec;
+    const NAME: &'static str = "IdentityVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
-    pub fn quorum(&self) -> Uint16Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Uint16Reader::new_unchecked(&self.as_slice()[start..end])
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        IdentityVecReader(slice)
     }
-    pub fn gas_limit(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
     }
-    pub fn gas_price(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct IdentityVecBuilder(pub(crate) Vec<Identity>);
+impl IdentityVecBuilder {
+    pub const ITEM_SIZE: usize = 20;
+    pub fn set(mut self, v: Vec<Identity>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: Identity) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Identity>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: Identity) -> Option<Identity> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for IdentityVecBuilder {
+    type Entity = IdentityVec;
+    const NAME: &'static str = "IdentityVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        IdentityVec::new_unchecked(inner.into())
+    }
+}
+pub struct IdentityVecIterator(IdentityVec, usize, usize);
+impl ::core::iter::Iterator for IdentityVecIterator {
+    type Item = Identity;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for IdentityVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for IdentityVec {
+    type Item = Identity;
+    type IntoIter = IdentityVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        IdentityVecIterator(self, 0, len)
+    }
+}
+impl<'r> IdentityVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> IdentityVecReaderIterator<'t, 'r> {
+        IdentityVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct IdentityVecReaderIterator<'t, 'r>(&'t IdentityVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for IdentityVecReaderIterator<'t, 'r> {
+    type Item = IdentityReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for IdentityVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct BytesVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BytesVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for BytesVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for BytesVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for BytesVec {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        BytesVec::new_unchecked(v.into())
+    }
+}
+impl BytesVec {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Bytes> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> Bytes {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            Bytes::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BytesVecReader<'r> {
+        BytesVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for BytesVec {
+    type Builder = BytesVecBuilder;
+    const NAME: &'static str = "BytesVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        BytesVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BytesVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BytesVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BytesVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BytesVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BytesVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BytesVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> BytesVecReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<BytesReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            BytesReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BytesVecReader<'r> {
+    type Entity = BytesVec;
+    const NAME: &'static str = "BytesVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BytesVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            BytesReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct BytesVecBuilder(pub(crate) Vec<Bytes>);
+impl BytesVecBuilder {
+    pub fn set(mut self, v: Vec<Bytes>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: Bytes) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Bytes>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: Bytes) -> Option<Bytes> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for BytesVecBuilder {
+    type Entity = BytesVec;
+    const NAME: &'static str = "BytesVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        BytesVec::new_unchecked(inner.into())
+    }
+}
+pub struct BytesVecIterator(BytesVec, usize, usize);
+impl ::core::iter::Iterator for BytesVecIterator {
+    type Item = Bytes;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for BytesVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for BytesVec {
+    type Item = Bytes;
+    type IntoIter = BytesVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        BytesVecIterator(self, 0, len)
+    }
+}
+impl<'r> BytesVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> BytesVecReaderIterator<'t, 'r> {
+        BytesVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct BytesVecReaderIterator<'t, 'r>(&'t BytesVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for BytesVecReaderIterator<'t, 'r> {
+    type Item = BytesReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for BytesVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct Metadata(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Metadata {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Metadata {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Metadata {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "epoch_len", self.epoch_len())?;
+        write!(f, ", {}: {}", "period_len", self.period_len())?;
+        write!(f, ", {}: {}", "quorum", self.quorum())?;
+        write!(f, ", {}: {}", "gas_limit", self.gas_limit())?;
+        write!(f, ", {}: {}", "gas_price", self.gas_price())?;
+        write!(f, ", {}: {}", "interval", self.interval())?;
+        write!(f, ", {}: {}", "validators", self.validators())?;
+        write!(f, ", {}: {}", "propose_ratio", self.propose_ratio())?;
+        write!(f, ", {}: {}", "prevote_ratio", self.prevote_ratio())?;
+        write!(f, ", {}: {}", "precommit_ratio", self.precommit_ratio())?;
+        write!(f, ", {}: {}", "brake_ratio", self.brake_ratio())?;
+        write!(f, ", {}: {}", "tx_num_limit", self.tx_num_limit())?;
+        write!(f, ", {}: {}", "max_tx_size", self.max_tx_size())?;
+        write!(f, ", {}: {}", "block_height", self.block_height())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for Metadata {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            126, 0, 0, 0, 60, 0, 0, 0, 64, 0, 0, 0, 68, 0, 0, 0, 70, 0, 0, 0, 78, 0, 0, 0, 86, 0,
+            0, 0, 90, 0, 0, 0, 94, 0, 0, 0, 98, 0, 0, 0, 102, 0, 0, 0, 106, 0, 0, 0, 110, 0, 0, 0,
+            114, 0, 0, 0, 118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        Metadata::new_unchecked(v.into())
+    }
+}
+impl Metadata {
+    pub const FIELD_COUNT: usize = 14;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn epoch_len(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn period_len(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn quorum(&self) -> Uint16 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint16::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn gas_limit(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn gas_price(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn interval(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn validators(&self) -> ValidatorList {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        ValidatorList::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn propose_ratio(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn prevote_ratio(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn precommit_ratio(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn brake_ratio(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn tx_num_limit(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        let end = molecule::unpack_number(&slice[52..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn max_tx_size(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[52..]) as usize;
+        let end = molecule::unpack_number(&slice[56..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn block_height(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[56..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[60..]) as usize;
+            Uint64::new_unchecked(self.0.slice(start..end))
+        } else {
+            Uint64::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> MetadataReader<'r> {
+        MetadataReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Metadata {
+    type Builder = MetadataBuilder;
+    const NAME: &'static str = "Metadata";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Metadata(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        MetadataReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        MetadataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .epoch_len(self.epoch_len())
+            .period_len(self.period_len())
+            .quorum(self.quorum())
+            .gas_limit(self.gas_limit())
+            .gas_price(self.gas_price())
+            .interval(self.interval())
+            .validators(self.validators())
+            .propose_ratio(self.propose_ratio())
+            .prevote_ratio(self.prevote_ratio())
+            .precommit_ratio(self.precommit_ratio())
+            .brake_ratio(self.brake_ratio())
+            .tx_num_limit(self.tx_num_limit())
+            .max_tx_size(self.max_tx_size())
+            .block_height(self.block_height())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct MetadataReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for MetadataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for MetadataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for MetadataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "epoch_len", self.epoch_len())?;
+        write!(f, ", {}: {}", "period_len", self.period_len())?;
+        write!(f, ", {}: {}", "quorum", self.quorum())?;
+        write!(f, ", {}: {}", "gas_limit", self.gas_limit())?;
+        write!(f, ", {}: {}", "gas_price", self.gas_price())?;
+        write!(f, ", {}: {}", "interval", self.interval())?;
+        write!(f, ", {}: {}", "validators", self.validators())?;
+        write!(f, ", {}: {}", "propose_ratio", self.propose_ratio())?;
+        write!(f, ", {}: {}", "prevote_ratio", self.prevote_ratio())?;
+        write!(f, ", {}: {}", "precommit_ratio", self.precommit_ratio())?;
+        write!(f, ", {}: {}", "brake_ratio", self.brake_ratio())?;
+        write!(f, ", {}: {}", "tx_num_limit", self.tx_num_limit())?;
+        write!(f, ", {}: {}", "max_tx_size", self.max_tx_size())?;
+        write!(f, ", {}: {}", "block_height", self.block_height())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> MetadataReader<'r> {
+    pub const FIELD_COUNT: usize = 14;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn epoch_len(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn period_len(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn quorum(&self) -> Uint16Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint16Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn gas_limit(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn gas_price(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn interval(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn validators(&self) -> ValidatorListReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        ValidatorListReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn propose_ratio(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn prevote_ratio(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn precommit_ratio(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn brake_ratio(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn tx_num_limit(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        let end = molecule::unpack_number(&slice[52..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn max_tx_size(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[52..]) as usize;
+        let end = molecule::unpack_number(&slice[56..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn block_height(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[56..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[60..]) as usize;
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for MetadataReader<'r> {
+    type Entity = Metadata;
+    const NAME: &'static str = "MetadataReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        MetadataReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint16Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        ValidatorListReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[12]..offsets[13]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[13]..offsets[14]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct MetadataBuilder {
+    pub(crate) epoch_len: Uint32,
+    pub(crate) period_len: Uint32,
+    pub(crate) quorum: Uint16,
+    pub(crate) gas_limit: Uint64,
+    pub(crate) gas_price: Uint64,
+    pub(crate) interval: Uint32,
+    pub(crate) validators: ValidatorList,
+    pub(crate) propose_ratio: Uint32,
+    pub(crate) prevote_ratio: Uint32,
+    pub(crate) precommit_ratio: Uint32,
+    pub(crate) brake_ratio: Uint32,
+    pub(crate) tx_num_limit: Uint32,
+    pub(crate) max_tx_size: Uint32,
+    pub(crate) block_height: Uint64,
+}
+impl MetadataBuilder {
+    pub const FIELD_COUNT: usize = 14;
+    pub fn epoch_len(mut self, v: Uint32) -> Self {
+        self.epoch_len = v;
+        self
+    }
+    pub fn period_len(mut self, v: Uint32) -> Self {
+        self.period_len = v;
+        self
+    }
+    pub fn quorum(mut self, v: Uint16) -> Self {
+        self.quorum = v;
+        self
+    }
+    pub fn gas_limit(mut self, v: Uint64) -> Self {
+        self.gas_limit = v;
+        self
+    }
+    pub fn gas_price(mut self, v: Uint64) -> Self {
+        self.gas_price = v;
+        self
+    }
+    pub fn interval(mut self, v: Uint32) -> Self {
+        self.interval = v;
+        self
+    }
+    pub fn validators(mut self, v: ValidatorList) -> Self {
+        self.validators = v;
+        self
+    }
+    pub fn propose_ratio(mut self, v: Uint32) -> Self {
+        self.propose_ratio = v;
+        self
+    }
+    pub fn prevote_ratio(mut self, v: Uint32) -> Self {
+        self.prevote_ratio = v;
+        self
+    }
+    pub fn precommit_ratio(mut self, v: Uint32) -> Self {
+        self.precommit_ratio = v;
+        self
+    }
+    pub fn brake_ratio(mut self, v: Uint32) -> Self {
+        self.brake_ratio = v;
+        self
+    }
+    pub fn tx_num_limit(mut self, v: Uint32) -> Self {
+        self.tx_num_limit = v;
+        self
+    }
+    pub fn max_tx_size(mut self, v: Uint32) -> Self {
+        self.max_tx_size = v;
+        self
+    }
+    pub fn block_height(mut self, v: Uint64) -> Self {
+        self.block_height = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for MetadataBuilder {
+    type Entity = Metadata;
+    const NAME: &'static str = "MetadataBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.epoch_len.as_slice().len()
+            + self.period_len.as_slice().len()
+            + self.quorum.as_slice().len()
+            + self.gas_limit.as_slice().len()
+            + self.gas_price.as_slice().len()
+            + self.interval.as_slice().len()
+            + self.validators.as_slice().len()
+            + self.propose_ratio.as_slice().len()
+            + self.prevote_ratio.as_slice().len()
+            + self.precommit_ratio.as_slice().len()
+            + self.brake_ratio.as_slice().len()
+            + self.tx_num_limit.as_slice().len()
+            + self.max_tx_size.as_slice().len()
+            + self.block_height.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.epoch_len.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.period_len.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.quorum.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.gas_limit.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.gas_price.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.interval.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.validators.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.propose_ratio.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.prevote_ratio.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.precommit_ratio.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.brake_ratio.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.tx_num_limit.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.max_tx_size.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.block_height.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.epoch_len.as_slice())?;
+        writer.write_all(self.period_len.as_slice())?;
+        writer.write_all(self.quorum.as_slice())?;
+        writer.write_all(self.gas_limit.as_slice())?;
+        writer.write_all(self.gas_price.as_slice())?;
+        writer.write_all(self.interval.as_slice())?;
+        writer.write_all(self.validators.as_slice())?;
+        writer.write_all(self.propose_ratio.as_slice())?;
+        writer.write_all(self.prevote_ratio.as_slice())?;
+        writer.write_all(self.precommit_ratio.as_slice())?;
+        writer.write_all(self.brake_ratio.as_slice())?;
+        writer.write_all(self.tx_num_limit.as_slice())?;
+        writer.write_all(self.max_tx_size.as_slice())?;
+        writer.write_all(self.block_height.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Metadata::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct TypeIds(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TypeIds {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for TypeIds {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for TypeIds {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "issue_type_id", self.issue_type_id())?;
+        write!(f, ", {}: {}", "selection_type_id", self.selection_type_id())?;
+        write!(
+            f,
+            ", {}: {}",
+            "xudt_owner_lock_hash",
+            self.xudt_owner_lock_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "metadata_code_hash",
+            self.metadata_code_hash()
+        )?;
+        write!(f, ", {}: {}", "metadata_type_id", self.metadata_type_id())?;
+        write!(
+            f,
+            ", {}: {}",
+            "checkpoint_code_hash",
+            self.checkpoint_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "checkpoint_type_id",
+            self.checkpoint_type_id()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "stake_smt_code_hash",
+            self.stake_smt_code_hash()
+        )?;
+        write!(f, ", {}: {}", "stake_smt_type_id", self.stake_smt_type_id())?;
+        write!(
+            f,
+            ", {}: {}",
+            "delegate_smt_code_hash",
+            self.delegate_smt_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "delegate_smt_type_id",
+            self.delegate_smt_type_id()
+        )?;
+        write!(f, ", {}: {}", "reward_code_hash", self.reward_code_hash())?;
+        write!(f, ", {}: {}", "reward_type_id", self.reward_type_id())?;
+        write!(f, ", {}: {}", "xudt_type_hash", self.xudt_type_hash())?;
+        write!(
+            f,
+            ", {}: {}",
+            "stake_at_code_hash",
+            self.stake_at_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "delegate_at_code_hash",
+            self.delegate_at_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "withdraw_code_hash",
+            self.withdraw_code_hash()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for TypeIds {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            104, 2, 0, 0, 72, 0, 0, 0, 104, 0, 0, 0, 136, 0, 0, 0, 168, 0, 0, 0, 200, 0, 0, 0, 232,
+            0, 0, 0, 8, 1, 0, 0, 40, 1, 0, 0, 72, 1, 0, 0, 104, 1, 0, 0, 136, 1, 0, 0, 168, 1, 0,
+            0, 200, 1, 0, 0, 232, 1, 0, 0, 8, 2, 0, 0, 40, 2, 0, 0, 72, 2, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        TypeIds::new_unchecked(v.into())
+    }
+}
+impl TypeIds {
+    pub const FIELD_COUNT: usize = 17;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn issue_type_id(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn selection_type_id(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn xudt_owner_lock_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn metadata_code_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn metadata_type_id(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn checkpoint_code_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn checkpoint_type_id(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn stake_smt_code_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn stake_smt_type_id(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn delegate_smt_code_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn delegate_smt_type_id(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn reward_code_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        let end = molecule::unpack_number(&slice[52..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn reward_type_id(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[52..]) as usize;
+        let end = molecule::unpack_number(&slice[56..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn xudt_type_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[56..]) as usize;
+        let end = molecule::unpack_number(&slice[60..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn stake_at_code_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[60..]) as usize;
+        let end = molecule::unpack_number(&slice[64..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn delegate_at_code_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[64..]) as usize;
+        let end = molecule::unpack_number(&slice[68..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn withdraw_code_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[68..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[72..]) as usize;
+            Byte32::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte32::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> TypeIdsReader<'r> {
+        TypeIdsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for TypeIds {
+    type Builder = TypeIdsBuilder;
+    const NAME: &'static str = "TypeIds";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        TypeIds(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TypeIdsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        TypeIdsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .issue_type_id(self.issue_type_id())
+            .selection_type_id(self.selection_type_id())
+            .xudt_owner_lock_hash(self.xudt_owner_lock_hash())
+            .metadata_code_hash(self.metadata_code_hash())
+            .metadata_type_id(self.metadata_type_id())
+            .checkpoint_code_hash(self.checkpoint_code_hash())
+            .checkpoint_type_id(self.checkpoint_type_id())
+            .stake_smt_code_hash(self.stake_smt_code_hash())
+            .stake_smt_type_id(self.stake_smt_type_id())
+            .delegate_smt_code_hash(self.delegate_smt_code_hash())
+            .delegate_smt_type_id(self.delegate_smt_type_id())
+            .reward_code_hash(self.reward_code_hash())
+            .reward_type_id(self.reward_type_id())
+            .xudt_type_hash(self.xudt_type_hash())
+            .stake_at_code_hash(self.stake_at_code_hash())
+            .delegate_at_code_hash(self.delegate_at_code_hash())
+            .withdraw_code_hash(self.withdraw_code_hash())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct TypeIdsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TypeIdsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for TypeIdsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for TypeIdsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "issue_type_id", self.issue_type_id())?;
+        write!(f, ", {}: {}", "selection_type_id", self.selection_type_id())?;
+        write!(
+            f,
+            ", {}: {}",
+            "xudt_owner_lock_hash",
+            self.xudt_owner_lock_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "metadata_code_hash",
+            self.metadata_code_hash()
+        )?;
+        write!(f, ", {}: {}", "metadata_type_id", self.metadata_type_id())?;
+        write!(
+            f,
+            ", {}: {}",
+            "checkpoint_code_hash",
+            self.checkpoint_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "checkpoint_type_id",
+            self.checkpoint_type_id()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "stake_smt_code_hash",
+            self.stake_smt_code_hash()
+        )?;
+        write!(f, ", {}: {}", "stake_smt_type_id", self.stake_smt_type_id())?;
+        write!(
+            f,
+            ", {}: {}",
+            "delegate_smt_code_hash",
+            self.delegate_smt_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "delegate_smt_type_id",
+            self.delegate_smt_type_id()
+        )?;
+        write!(f, ", {}: {}", "reward_code_hash", self.reward_code_hash())?;
+        write!(f, ", {}: {}", "reward_type_id", self.reward_type_id())?;
+        write!(f, ", {}: {}", "xudt_type_hash", self.xudt_type_hash())?;
+        write!(
+            f,
+            ", {}: {}",
+            "stake_at_code_hash",
+            self.stake_at_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "delegate_at_code_hash",
+            self.delegate_at_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "withdraw_code_hash",
+            self.withdraw_code_hash()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> TypeIdsReader<'r> {
+    pub const FIELD_COUNT: usize = 17;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn issue_type_id(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn selection_type_id(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn xudt_owner_lock_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn metadata_code_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn metadata_type_id(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn checkpoint_code_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn checkpoint_type_id(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn stake_smt_code_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn stake_smt_type_id(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn delegate_smt_code_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn delegate_smt_type_id(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn reward_code_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        let end = molecule::unpack_number(&slice[52..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn reward_type_id(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[52..]) as usize;
+        let end = molecule::unpack_number(&slice[56..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn xudt_type_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[56..]) as usize;
+        let end = molecule::unpack_number(&slice[60..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn stake_at_code_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[60..]) as usize;
+        let end = molecule::unpack_number(&slice[64..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn delegate_at_code_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[64..]) as usize;
+        let end = molecule::unpack_number(&slice[68..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn withdraw_code_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[68..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[72..]) as usize;
+            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Byte32Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for TypeIdsReader<'r> {
+    type Entity = TypeIds;
+    const NAME: &'static str = "TypeIdsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        TypeIdsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[12]..offsets[13]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[13]..offsets[14]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[14]..offsets[15]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[15]..offsets[16]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[16]..offsets[17]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct TypeIdsBuilder {
+    pub(crate) issue_type_id: Byte32,
+    pub(crate) selection_type_id: Byte32,
+    pub(crate) xudt_owner_lock_hash: Byte32,
+    pub(crate) metadata_code_hash: Byte32,
+    pub(crate) metadata_type_id: Byte32,
+    pub(crate) checkpoint_code_hash: Byte32,
+    pub(crate) checkpoint_type_id: Byte32,
+    pub(crate) stake_smt_code_hash: Byte32,
+    pub(crate) stake_smt_type_id: Byte32,
+    pub(crate) delegate_smt_code_hash: Byte32,
+    pub(crate) delegate_smt_type_id: Byte32,
+    pub(crate) reward_code_hash: Byte32,
+    pub(crate) reward_type_id: Byte32,
+    pub(crate) xudt_type_hash: Byte32,
+    pub(crate) stake_at_code_hash: Byte32,
+    pub(crate) delegate_at_code_hash: Byte32,
+    pub(crate) withdraw_code_hash: Byte32,
+}
+impl TypeIdsBuilder {
+    pub const FIELD_COUNT: usize = 17;
+    pub fn issue_type_id(mut self, v: Byte32) -> Self {
+        self.issue_type_id = v;
+        self
+    }
+    pub fn selection_type_id(mut self, v: Byte32) -> Self {
+        self.selection_type_id = v;
+        self
+    }
+    pub fn xudt_owner_lock_hash(mut self, v: Byte32) -> Self {
+        self.xudt_owner_lock_hash = v;
+        self
+    }
+    pub fn metadata_code_hash(mut self, v: Byte32) -> Self {
+        self.metadata_code_hash = v;
+        self
+    }
+    pub fn metadata_type_id(mut self, v: Byte32) -> Self {
+        self.metadata_type_id = v;
+        self
+    }
+    pub fn checkpoint_code_hash(mut self, v: Byte32) -> Self {
+        self.checkpoint_code_hash = v;
+        self
+    }
+    pub fn checkpoint_type_id(mut self, v: Byte32) -> Self {
+        self.checkpoint_type_id = v;
+        self
+    }
+    pub fn stake_smt_code_hash(mut self, v: Byte32) -> Self {
+        self.stake_smt_code_hash = v;
+        self
+    }
+    pub fn stake_smt_type_id(mut self, v: Byte32) -> Self {
+        self.stake_smt_type_id = v;
+        self
+    }
+    pub fn delegate_smt_code_hash(mut self, v: Byte32) -> Self {
+        self.delegate_smt_code_hash = v;
+        self
+    }
+    pub fn delegate_smt_type_id(mut self, v: Byte32) -> Self {
+        self.delegate_smt_type_id = v;
+        self
+    }
+    pub fn reward_code_hash(mut self, v: Byte32) -> Self {
+        self.reward_code_hash = v;
+        self
+    }
+    pub fn reward_type_id(mut self, v: Byte32) -> Self {
+        self.reward_type_id = v;
+        self
+    }
+    pub fn xudt_type_hash(mut self, v: Byte32) -> Self {
+        self.xudt_type_hash = v;
+        self
+    }
+    pub fn stake_at_code_hash(mut self, v: Byte32) -> Self {
+        self.stake_at_code_hash = v;
+        self
+    }
+    pub fn delegate_at_code_hash(mut self, v: Byte32) -> Self {
+        self.delegate_at_code_hash = v;
+        self
+    }
+    pub fn withdraw_code_hash(mut self, v: Byte32) -> Self {
+        self.withdraw_code_hash = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for TypeIdsBuilder {
+    type Entity = TypeIds;
+    const NAME: &'static str = "TypeIdsBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.issue_type_id.as_slice().len()
+            + self.selection_type_id.as_slice().len()
+            + self.xudt_owner_lock_hash.as_slice().len()
+            + self.metadata_code_hash.as_slice().len()
+            + self.metadata_type_id.as_slice().len()
+            + self.checkpoint_code_hash.as_slice().len()
+            + self.checkpoint_type_id.as_slice().len()
+            + self.stake_smt_code_hash.as_slice().len()
+            + self.stake_smt_type_id.as_slice().len()
+            + self.delegate_smt_code_hash.as_slice().len()
+            + self.delegate_smt_type_id.as_slice().len()
+            + self.reward_code_hash.as_slice().len()
+            + self.reward_type_id.as_slice().len()
+            + self.xudt_type_hash.as_slice().len()
+            + self.stake_at_code_hash.as_slice().len()
+            + self.delegate_at_code_hash.as_slice().len()
+            + self.withdraw_code_hash.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.issue_type_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.selection_type_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.xudt_owner_lock_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.metadata_code_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.metadata_type_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.checkpoint_code_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.checkpoint_type_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.stake_smt_code_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.stake_smt_type_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.delegate_smt_code_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.delegate_smt_type_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.reward_code_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.reward_type_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.xudt_type_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.stake_at_code_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.delegate_at_code_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.withdraw_code_hash.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.issue_type_id.as_slice())?;
+        writer.write_all(self.selection_type_id.as_slice())?;
+        writer.write_all(self.xudt_owner_lock_hash.as_slice())?;
+        writer.write_all(self.metadata_code_hash.as_slice())?;
+        writer.write_all(self.metadata_type_id.as_slice())?;
+        writer.write_all(self.checkpoint_code_hash.as_slice())?;
+        writer.write_all(self.checkpoint_type_id.as_slice())?;
+        writer.write_all(self.stake_smt_code_hash.as_slice())?;
+        writer.write_all(self.stake_smt_type_id.as_slice())?;
+        writer.write_all(self.delegate_smt_code_hash.as_slice())?;
+        writer.write_all(self.delegate_smt_type_id.as_slice())?;
+        writer.write_all(self.reward_code_hash.as_slice())?;
+        writer.write_all(self.reward_type_id.as_slice())?;
+        writer.write_all(self.xudt_type_hash.as_slice())?;
+        writer.write_all(self.stake_at_code_hash.as_slice())?;
+        writer.write_all(self.delegate_at_code_hash.as_slice())?;
+        writer.write_all(self.withdraw_code_hash.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        TypeIds::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct MetadataCellData(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for MetadataCellData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for MetadataCellData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for MetadataCellData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "version", self.version())?;
+        write!(f, ", {}: {}", "epoch", self.epoch())?;
+        write!(f, ", {}: {}", "base_reward", self.base_reward())?;
+        write!(f, ", {}: {}", "half_epoch", self.half_epoch())?;
+        write!(
+            f,
+            ", {}: {}",
+            "propose_minimum_rate",
+            self.propose_minimum_rate()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "propose_discount_rate",
+            self.propose_discount_rate()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "propose_count_smt_root",
+            self.propose_count_smt_root()
+        )?;
+        write!(f, ", {}: {}", "type_ids", self.type_ids())?;
+        write!(f, ", {}: {}", "metadata", self.metadata())?;
+        write!(f, ", {}: {}", "slash_rate", self.slash_rate())?;
+        write!(
+            f,
+            ", {}: {}",
+            "liveness_slash_rate",
+            self.liveness_slash_rate()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "treasury_lock_hash",
+            self.treasury_lock_hash()
+        )?;
+        write!(f, ", {}: {}", "governance", self.governance())?;
+        write!(f, ", {}: {}", "type_ids_upgrade", self.type_ids_upgrade())?;
+        write!(f, ", {}: {}", "unbonding_epochs", self.unbonding_epochs())?;
+        write!(
+            f,
+            ", {}: {}",
+            "inauguration_delay",
+            self.inauguration_delay()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "commission_smt_root",
+            self.commission_smt_root()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "reward_params_smt_root",
+            self.reward_params_smt_root()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for MetadataCellData {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            11, 6, 0, 0, 76, 0, 0, 0, 77, 0, 0, 0, 85, 0, 0, 0, 101, 0, 0, 0, 109, 0, 0, 0, 110, 0,
+            0, 0, 111, 0, 0, 0, 143, 0, 0, 0, 247, 2, 0, 0, 251, 2, 0, 0, 252, 2, 0, 0, 253, 2, 0,
+            0, 29, 3, 0, 0, 63, 3, 0, 0, 187, 5, 0, 0, 195, 5, 0, 0, 203, 5, 0, 0, 235, 5, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 104, 2, 0, 0, 72, 0, 0, 0, 104, 0, 0, 0, 136, 0, 0, 0, 168, 0,
+            0, 0, 200, 0, 0, 0, 232, 0, 0, 0, 8, 1, 0, 0, 40, 1, 0, 0, 72, 1, 0, 0, 104, 1, 0, 0,
+            136, 1, 0, 0, 168, 1, 0, 0, 200, 1, 0, 0, 232, 1, 0, 0, 8, 2, 0, 0, 40, 2, 0, 0, 72, 2,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 34, 0, 0, 0, 20, 0, 0, 0, 21, 0, 0, 0, 22, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 124, 2, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 104, 2, 0, 0, 72, 0, 0, 0, 104, 0, 0, 0, 136, 0, 0, 0, 168, 0, 0, 0, 200, 0,
+            0, 0, 232, 0, 0, 0, 8, 1, 0, 0, 40, 1, 0, 0, 72, 1, 0, 0, 104, 1, 0, 0, 136, 1, 0, 0,
+            168, 1, 0, 0, 200, 1, 0, 0, 232, 1, 0, 0, 8, 2, 0, 0, 40, 2, 0, 0, 72, 2, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        MetadataCellData::new_unchecked(v.into())
+    }
+}
+impl MetadataCellData {
+    pub const FIELD_COUNT: usize = 18;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn version(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn epoch(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn base_reward(&self) -> Uint128 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint128::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn half_epoch(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn propose_minimum_rate(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn propose_discount_rate(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn propose_count_smt_root(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn type_ids(&self) -> TypeIds {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        TypeIds::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn metadata(&self) -> MetadataList {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        MetadataList::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn slash_rate(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn liveness_slash_rate(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn treasury_lock_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        let end = molecule::unpack_number(&slice[52..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn governance(&self) -> GovernanceConfig {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[52..]) as usize;
+        let end = molecule::unpack_number(&slice[56..]) as usize;
+        GovernanceConfig::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn type_ids_upgrade(&self) -> TypeIdsUpgrade {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[56..]) as usize;
+        let end = molecule::unpack_number(&slice[60..]) as usize;
+        TypeIdsUpgrade::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn unbonding_epochs(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[60..]) as usize;
+        let end = molecule::unpack_number(&slice[64..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn inauguration_delay(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[64..]) as usize;
+        let end = molecule::unpack_number(&slice[68..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn commission_smt_root(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[68..]) as usize;
+        let end = molecule::unpack_number(&slice[72..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn reward_params_smt_root(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[72..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[76..]) as usize;
+            Byte32::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte32::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> MetadataCellDataReader<'r> {
+        MetadataCellDataReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for MetadataCellData {
+    type Builder = MetadataCellDataBuilder;
+    const NAME: &'static str = "MetadataCellData";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        MetadataCellData(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        MetadataCellDataReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        MetadataCellDataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .version(self.version())
+            .epoch(self.epoch())
+            .base_reward(self.base_reward())
+            .half_epoch(self.half_epoch())
+            .propose_minimum_rate(self.propose_minimum_rate())
+            .propose_discount_rate(self.propose_discount_rate())
+            .propose_count_smt_root(self.propose_count_smt_root())
+            .type_ids(self.type_ids())
+            .metadata(self.metadata())
+            .slash_rate(self.slash_rate())
+            .liveness_slash_rate(self.liveness_slash_rate())
+            .treasury_lock_hash(self.treasury_lock_hash())
+            .governance(self.governance())
+            .type_ids_upgrade(self.type_ids_upgrade())
+            .unbonding_epochs(self.unbonding_epochs())
+            .inauguration_delay(self.inauguration_delay())
+            .commission_smt_root(self.commission_smt_root())
+            .reward_params_smt_root(self.reward_params_smt_root())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct MetadataCellDataReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for MetadataCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for MetadataCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for MetadataCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "version", self.version())?;
+        write!(f, ", {}: {}", "epoch", self.epoch())?;
+        write!(f, ", {}: {}", "base_reward", self.base_reward())?;
+        write!(f, ", {}: {}", "half_epoch", self.half_epoch())?;
+        write!(
+            f,
+            ", {}: {}",
+            "propose_minimum_rate",
+            self.propose_minimum_rate()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "propose_discount_rate",
+            self.propose_discount_rate()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "propose_count_smt_root",
+            self.propose_count_smt_root()
+        )?;
+        write!(f, ", {}: {}", "type_ids", self.type_ids())?;
+        write!(f, ", {}: {}", "metadata", self.metadata())?;
+        write!(f, ", {}: {}", "slash_rate", self.slash_rate())?;
+        write!(
+            f,
+            ", {}: {}",
+            "liveness_slash_rate",
+            self.liveness_slash_rate()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "treasury_lock_hash",
+            self.treasury_lock_hash()
+        )?;
+        write!(f, ", {}: {}", "governance", self.governance())?;
+        write!(f, ", {}: {}", "type_ids_upgrade", self.type_ids_upgrade())?;
+        write!(f, ", {}: {}", "unbonding_epochs", self.unbonding_epochs())?;
+        write!(
+            f,
+            ", {}: {}",
+            "inauguration_delay",
+            self.inauguration_delay()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "commission_smt_root",
+            self.commission_smt_root()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "reward_params_smt_root",
+            self.reward_params_smt_root()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> MetadataCellDataReader<'r> {
+    pub const FIELD_COUNT: usize = 18;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn version(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn epoch(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn base_reward(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn half_epoch(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn propose_minimum_rate(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
         let end = molecule::unpack_number(&slice[24..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn interval(&self) -> Uint32Reader<'r> {
+    pub fn propose_discount_rate(&self) -> ByteReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[24..]) as usize;
         let end = molecule::unpack_number(&slice[28..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn validators(&self) -> ValidatorListReader<'r> {
+    pub fn propose_count_smt_root(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[28..]) as usize;
         let end = molecule::unpack_number(&slice[32..]) as usize;
-        ValidatorListReader::new_unchecked(&self.as_slice()[start..end])
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn propose_ratio(&self) -> Uint32Reader<'r> {
+    pub fn type_ids(&self) -> TypeIdsReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[32..]) as usize;
         let end = molecule::unpack_number(&slice[36..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        TypeIdsReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn prevote_ratio(&self) -> Uint32Reader<'r> {
+    pub fn metadata(&self) -> MetadataListReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[36..]) as usize;
         let end = molecule::unpack_number(&slice[40..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        MetadataListReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn precommit_ratio(&self) -> Uint32Reader<'r> {
+    pub fn slash_rate(&self) -> ByteReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[40..]) as usize;
         let end = molecule::unpack_number(&slice[44..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn brake_ratio(&self) -> Uint32Reader<'r> {
+    pub fn liveness_slash_rate(&self) -> ByteReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[44..]) as usize;
         let end = molecule::unpack_number(&slice[48..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn tx_num_limit(&self) -> Uint32Reader<'r> {
+    pub fn treasury_lock_hash(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[48..]) as usize;
         let end = molecule::unpack_number(&slice[52..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn max_tx_size(&self) -> Uint32Reader<'r> {
+    pub fn governance(&self) -> GovernanceConfigReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[52..]) as usize;
         let end = molecule::unpack_number(&slice[56..]) as usize;
-        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        GovernanceConfigReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn block_height(&self) -> Uint64Reader<'r> {
+    pub fn type_ids_upgrade(&self) -> TypeIdsUpgradeReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[56..]) as usize;
+        let end = molecule::unpack_number(&slice[60..]) as usize;
+        TypeIdsUpgradeReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn unbonding_epochs(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[60..]) as usize;
+        let end = molecule::unpack_number(&slice[64..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn inauguration_delay(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[64..]) as usize;
+        let end = molecule::unpack_number(&slice[68..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn commission_smt_root(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[68..]) as usize;
+        let end = molecule::unpack_number(&slice[72..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn reward_params_smt_root(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[72..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[60..]) as usize;
-            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[76..]) as usize;
+            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Uint64Reader::new_unchecked(&self.as_slice()[start..])
+            Byte32Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for MetadataReader<'r> {
-    type Entity = Metadata;
-    const NAME: &'static str = "MetadataReader";
+impl<'r> molecule::prelude::Reader<'r> for MetadataCellDataReader<'r> {
+    type Entity = MetadataCellData;
+    const NAME: &'static str = "MetadataCellDataReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        MetadataReader(slice)
+        MetadataCellDataReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -1446,180 +3574,220 @@ impl<'r> molecule::prelude::Reader<'r> for MetadataReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Uint32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Uint16Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        ValidatorListReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
-        Uint32Reader::verify(&slice[offsets[12]..offsets[13]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[13]..offsets[14]], compatible)?;
+        ByteReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        ByteReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        TypeIdsReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        MetadataListReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        ByteReader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        ByteReader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
+        GovernanceConfigReader::verify(&slice[offsets[12]..offsets[13]], compatible)?;
+        TypeIdsUpgradeReader::verify(&slice[offsets[13]..offsets[14]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[14]..offsets[15]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[15]..offsets[16]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[16]..offsets[17]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[17]..offsets[18]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct MetadataBuilder {
-    pub(crate) epoch_len: Uint32,
-    pub(crate) period_len: Uint32,
-    pub(crate) quorum: Uint16,
-    pub(crate) gas_limit: Uint64,
-    pub(crate) gas_price: Uint64,
-    pub(crate) interval: Uint32,
-    pub(crate) validators: ValidatorList,
-    pub(crate) propose_ratio: Uint32,
-    pub(crate) prevote_ratio: Uint32,
-    pub(crate) precommit_ratio: Uint32,
-    pub(crate) brake_ratio: Uint32,
-    pub(crate) tx_num_limit: Uint32,
-    pub(crate) max_tx_size: Uint32,
-    pub(crate) block_height: Uint64,
+pub struct MetadataCellDataBuilder {
+    pub(crate) version: Byte,
+    pub(crate) epoch: Uint64,
+    pub(crate) base_reward: Uint128,
+    pub(crate) half_epoch: Uint64,
+    pub(crate) propose_minimum_rate: Byte,
+    pub(crate) propose_discount_rate: Byte,
+    pub(crate) propose_count_smt_root: Byte32,
+    pub(crate) type_ids: TypeIds,
+    pub(crate) metadata: MetadataList,
+    pub(crate) slash_rate: Byte,
+    pub(crate) liveness_slash_rate: Byte,
+    pub(crate) treasury_lock_hash: Byte32,
+    pub(crate) governance: GovernanceConfig,
+    pub(crate) type_ids_upgrade: TypeIdsUpgrade,
+    pub(crate) unbonding_epochs: Uint64,
+    pub(crate) inauguration_delay: Uint64,
+    pub(crate) commission_smt_root: Byte32,
+    pub(crate) reward_params_smt_root: Byte32,
 }
-impl MetadataBuilder {
-    pub const FIELD_COUNT: usize = 14;
-    pub fn epoch_len(mut self, v: Uint32) -> Self {
-        self.epoch_len = v;
+impl MetadataCellDataBuilder {
+    pub const FIELD_COUNT: usize = 18;
+    pub fn version(mut self, v: Byte) -> Self {
+        self.version = v;
         self
     }
-    pub fn period_len(mut self, v: Uint32) -> Self {
-        self.period_len = v;
+    pub fn epoch(mut self, v: Uint64) -> Self {
+        self.epoch = v;
         self
     }
-    pub fn quorum(mut self, v: Uint16) -> Self {
-        self.quorum = v;
+    pub fn base_reward(mut self, v: Uint128) -> Self {
+        self.base_reward = v;
         self
     }
-    pub fn gas_limit(mut self, v: Uint64) -> Self {
-        self.gas_limit = v;
+    pub fn half_epoch(mut self, v: Uint64) -> Self {
+        self.half_epoch = v;
         self
     }
-    pub fn gas_price(mut self, v: Uint64) -> Self {
-        self.gas_price = v;
+    pub fn propose_minimum_rate(mut self, v: Byte) -> Self {
+        self.propose_minimum_rate = v;
         self
     }
-    pub fn interval(mut self, v: Uint32) -> Self {
-        self.interval = v;
+    pub fn propose_discount_rate(mut self, v: Byte) -> Self {
+        self.propose_discount_rate = v;
         self
     }
-    pub fn validators(mut self, v: ValidatorList) -> Self {
-        self.validators = v;
+    pub fn propose_count_smt_root(mut self, v: Byte32) -> Self {
+        self.propose_count_smt_root = v;
         self
     }
-    pub fn propose_ratio(mut self, v: Uint32) -> Self {
-        self.propose_ratio = v;
+    pub fn type_ids(mut self, v: TypeIds) -> Self {
+        self.type_ids = v;
         self
     }
-    pub fn prevote_ratio(mut self, v: Uint32) -> Self {
-        self.prevote_ratio = v;
+    pub fn metadata(mut self, v: MetadataList) -> Self {
+        self.metadata = v;
         self
     }
-    pub fn precommit_ratio(mut self, v: Uint32) -> Self {
-        self.precommit_ratio = v;
+    pub fn slash_rate(mut self, v: Byte) -> Self {
+        self.slash_rate = v;
         self
     }
-    pub fn brake_ratio(mut self, v: Uint32) -> Self {
-        self.brake_ratio = v;
+    pub fn liveness_slash_rate(mut self, v: Byte) -> Self {
+        self.liveness_slash_rate = v;
         self
     }
-    pub fn tx_num_limit(mut self, v: Uint32) -> Self {
-        self.tx_num_limit = v;
+    pub fn treasury_lock_hash(mut self, v: Byte32) -> Self {
+        self.treasury_lock_hash = v;
         self
     }
-    pub fn max_tx_size(mut self, v: Uint32) -> Self {
-        self.max_tx_size = v;
+    pub fn governance(mut self, v: GovernanceConfig) -> Self {
+        self.governance = v;
         self
     }
-    pub fn block_height(mut self, v: Uint64) -> Self {
-        self.block_height = v;
+    pub fn type_ids_upgrade(mut self, v: TypeIdsUpgrade) -> Self {
+        self.type_ids_upgrade = v;
         self
     }
-}
-impl molecule::prelude::Builder for MetadataBuilder {
-    type Entity = Metadata;
-    const NAME: &'static str = "MetadataBuilder";
-    fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.epoch_len.as_slice().len()
-            + self.period_len.as_slice().len()
-            + self.quorum.as_slice().len()
-            + self.gas_limit.as_slice().len()
-            + self.gas_price.as_slice().len()
-            + self.interval.as_slice().len()
-            + self.validators.as_slice().len()
-            + self.propose_ratio.as_slice().len()
-            + self.prevote_ratio.as_slice().len()
-            + self.precommit_ratio.as_slice().len()
-            + self.brake_ratio.as_slice().len()
-            + self.tx_num_limit.as_slice().len()
-            + self.max_tx_size.as_slice().len()
-            + self.block_height.as_slice().len()
+    pub fn unbonding_epochs(mut self, v: Uint64) -> Self {
+        self.unbonding_epochs = v;
+        self
+    }
+    pub fn inauguration_delay(mut self, v: Uint64) -> Self {
+        self.inauguration_delay = v;
+        self
+    }
+    pub fn commission_smt_root(mut self, v: Byte32) -> Self {
+        self.commission_smt_root = v;
+        self
+    }
+    pub fn reward_params_smt_root(mut self, v: Byte32) -> Self {
+        self.reward_params_smt_root = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for MetadataCellDataBuilder {
+    type Entity = MetadataCellData;
+    const NAME: &'static str = "MetadataCellDataBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.version.as_slice().len()
+            + self.epoch.as_slice().len()
+            + self.base_reward.as_slice().len()
+            + self.half_epoch.as_slice().len()
+            + self.propose_minimum_rate.as_slice().len()
+            + self.propose_discount_rate.as_slice().len()
+            + self.propose_count_smt_root.as_slice().len()
+            + self.type_ids.as_slice().len()
+            + self.metadata.as_slice().len()
+            + self.slash_rate.as_slice().len()
+            + self.liveness_slash_rate.as_slice().len()
+            + self.treasury_lock_hash.as_slice().len()
+            + self.governance.as_slice().len()
+            + self.type_ids_upgrade.as_slice().len()
+            + self.unbonding_epochs.as_slice().len()
+            + self.inauguration_delay.as_slice().len()
+            + self.commission_smt_root.as_slice().len()
+            + self.reward_params_smt_root.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.epoch_len.as_slice().len();
+        total_size += self.version.as_slice().len();
         offsets.push(total_size);
-        total_size += self.period_len.as_slice().len();
+        total_size += self.epoch.as_slice().len();
         offsets.push(total_size);
-        total_size += self.quorum.as_slice().len();
+        total_size += self.base_reward.as_slice().len();
         offsets.push(total_size);
-        total_size += self.gas_limit.as_slice().len();
+        total_size += self.half_epoch.as_slice().len();
         offsets.push(total_size);
-        total_size += self.gas_price.as_slice().len();
+        total_size += self.propose_minimum_rate.as_slice().len();
         offsets.push(total_size);
-        total_size += self.interval.as_slice().len();
+        total_size += self.propose_discount_rate.as_slice().len();
         offsets.push(total_size);
-        total_size += self.validators.as_slice().len();
+        total_size += self.propose_count_smt_root.as_slice().len();
         offsets.push(total_size);
-        total_size += self.propose_ratio.as_slice().len();
+        total_size += self.type_ids.as_slice().len();
         offsets.push(total_size);
-        total_size += self.prevote_ratio.as_slice().len();
+        total_size += self.metadata.as_slice().len();
         offsets.push(total_size);
-        total_size += self.precommit_ratio.as_slice().len();
+        total_size += self.slash_rate.as_slice().len();
         offsets.push(total_size);
-        total_size += self.brake_ratio.as_slice().len();
+        total_size += self.liveness_slash_rate.as_slice().len();
         offsets.push(total_size);
-        total_size += self.tx_num_limit.as_slice().len();
+        total_size += self.treasury_lock_hash.as_slice().len();
         offsets.push(total_size);
-        total_size += self.max_tx_size.as_slice().len();
+        total_size += self.governance.as_slice().len();
         offsets.push(total_size);
-        total_size += self.block_height.as_slice().len();
+        total_size += self.type_ids_upgrade.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.unbonding_epochs.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.inauguration_delay.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.commission_smt_root.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.reward_params_smt_root.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.epoch_len.as_slice())?;
-        writer.write_all(self.period_len.as_slice())?;
-        writer.write_all(self.quorum.as_slice())?;
-        writer.write_all(self.gas_limit.as_slice())?;
-        writer.write_all(self.gas_price.as_slice())?;
-        writer.write_all(self.interval.as_slice())?;
-        writer.write_all(self.validators.as_slice())?;
-        writer.write_all(self.propose_ratio.as_slice())?;
-        writer.write_all(self.prevote_ratio.as_slice())?;
-        writer.write_all(self.precommit_ratio.as_slice())?;
-        writer.write_all(self.brake_ratio.as_slice())?;
-        writer.write_all(self.tx_num_limit.as_slice())?;
-        writer.write_all(self.max_tx_size.as_slice())?;
-        writer.write_all(self.block_height.as_slice())?;
+        writer.write_all(self.version.as_slice())?;
+        writer.write_all(self.epoch.as_slice())?;
+        writer.write_all(self.base_reward.as_slice())?;
+        writer.write_all(self.half_epoch.as_slice())?;
+        writer.write_all(self.propose_minimum_rate.as_slice())?;
+        writer.write_all(self.propose_discount_rate.as_slice())?;
+        writer.write_all(self.propose_count_smt_root.as_slice())?;
+        writer.write_all(self.type_ids.as_slice())?;
+        writer.write_all(self.metadata.as_slice())?;
+        writer.write_all(self.slash_rate.as_slice())?;
+        writer.write_all(self.liveness_slash_rate.as_slice())?;
+        writer.write_all(self.treasury_lock_hash.as_slice())?;
+        writer.write_all(self.governance.as_slice())?;
+        writer.write_all(self.type_ids_upgrade.as_slice())?;
+        writer.write_all(self.unbonding_epochs.as_slice())?;
+        writer.write_all(self.inauguration_delay.as_slice())?;
+        writer.write_all(self.commission_smt_root.as_slice())?;
+        writer.write_all(self.reward_params_smt_root.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Metadata::new_unchecked(inner.into())
+        MetadataCellData::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct TypeIds(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for TypeIds {
+pub struct TypeIdsUpgrade(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for TypeIdsUpgrade {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1628,81 +3796,16 @@ impl ::core::fmt::LowerHex for TypeIds {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for TypeIds {
+impl ::core::fmt::Debug for TypeIdsUpgrade {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for TypeIds {
+impl ::core::fmt::Display for TypeIdsUpgrade {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "issue_type_id", self.issue_type_id())?;
-        write!(f, ", {}: {}", "selection_type_id", self.selection_type_id())?;
-        write!(
-            f,
-            ", {}: {}",
-            "xudt_owner_lock_hash",
-            self.xudt_owner_lock_hash()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "metadata_code_hash",
-            self.metadata_code_hash()
-        )?;
-        write!(f, ", {}: {}", "metadata_type_id", self.metadata_type_id())?;
-        write!(
-            f,
-            ", {}: {}",
-            "checkpoint_code_hash",
-            self.checkpoint_code_hash()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "checkpoint_type_id",
-            self.checkpoint_type_id()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "stake_smt_code_hash",
-            self.stake_smt_code_hash()
-        )?;
-        write!(f, ", {}: {}", "stake_smt_type_id", self.stake_smt_type_id())?;
-        write!(
-            f,
-            ", {}: {}",
-            "delegate_smt_code_hash",
-            self.delegate_smt_code_hash()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "delegate_smt_type_id",
-            self.delegate_smt_type_id()
-        )?;
-        write!(f, ", {}: {}", "reward_code_hash", self.reward_code_hash())?;
-        write!(f, ", {}: {}", "reward_type_id", self.reward_type_id())?;
-        write!(f, ", {}: {}", "xudt_type_hash", self.xudt_type_hash())?;
-        write!(
-            f,
-            ", {}: {}",
-            "stake_at_code_hash",
-            self.stake_at_code_hash()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "delegate_at_code_hash",
-            self.delegate_at_code_hash()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "withdraw_code_hash",
-            self.withdraw_code_hash()
-        )?;
+        write!(f, "{}: {}", "effective_epoch", self.effective_epoch())?;
+        write!(f, ", {}: {}", "type_ids", self.type_ids())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1710,168 +3813,79 @@ impl ::core::fmt::Display for TypeIds {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for TypeIds {
-    fn default() -> Self {
-        let v: Vec<u8> = vec![
-            104, 2, 0, 0, 72, 0, 0, 0, 104, 0, 0, 0, 136, 0, 0, 0, 168, 0, 0, 0, 200, 0, 0, 0, 232,
-            0, 0, 0, 8, 1, 0, 0, 40, 1, 0, 0, 72, 1, 0, 0, 104, 1, 0, 0, 136, 1, 0, 0, 168, 1, 0,
-            0, 200, 1, 0, 0, 232, 1, 0, 0, 8, 2, 0, 0, 40, 2, 0, 0, 72, 2, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        ];
-        TypeIds::new_unchecked(v.into())
-    }
-}
-impl TypeIds {
-    pub const FIELD_COUNT: usize = 17;
-    pub fn total_size(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn field_count(&self) -> usize {
-        if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn issue_type_id(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn selection_type_id(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn xudt_owner_lock_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn metadata_code_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn metadata_type_id(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn checkpoint_code_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn checkpoint_type_id(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
-        let end = molecule::unpack_number(&slice[32..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn stake_smt_code_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[32..]) as usize;
-        let end = molecule::unpack_number(&slice[36..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn stake_smt_type_id(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[36..]) as usize;
-        let end = molecule::unpack_number(&slice[40..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn delegate_smt_code_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[40..]) as usize;
-        let end = molecule::unpack_number(&slice[44..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn delegate_smt_type_id(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[44..]) as usize;
-        let end = molecule::unpack_number(&slice[48..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
+impl ::core::default::Default for TypeIdsUpgrade {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            124, 2, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 2, 0, 0, 72, 0, 0,
+            0, 104, 0, 0, 0, 136, 0, 0, 0, 168, 0, 0, 0, 200, 0, 0, 0, 232, 0, 0, 0, 8, 1, 0, 0,
+            40, 1, 0, 0, 72, 1, 0, 0, 104, 1, 0, 0, 136, 1, 0, 0, 168, 1, 0, 0, 200, 1, 0, 0, 232,
+            1, 0, 0, 8, 2, 0, 0, 40, 2, 0, 0, 72, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        TypeIdsUpgrade::new_unchecked(v.into())
     }
-    pub fn reward_code_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[48..]) as usize;
-        let end = molecule::unpack_number(&slice[52..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
+}
+impl TypeIdsUpgrade {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn reward_type_id(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[52..]) as usize;
-        let end = molecule::unpack_number(&slice[56..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn xudt_type_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[56..]) as usize;
-        let end = molecule::unpack_number(&slice[60..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn stake_at_code_hash(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[60..]) as usize;
-        let end = molecule::unpack_number(&slice[64..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn delegate_at_code_hash(&self) -> Byte32 {
+    pub fn effective_epoch(&self) -> Uint64 {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[64..]) as usize;
-        let end = molecule::unpack_number(&slice[68..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn withdraw_code_hash(&self) -> Byte32 {
+    pub fn type_ids(&self) -> TypeIds {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[68..]) as usize;
+        let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[72..]) as usize;
-            Byte32::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            TypeIds::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte32::new_unchecked(self.0.slice(start..))
+            TypeIds::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> TypeIdsReader<'r> {
-        TypeIdsReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> TypeIdsUpgradeReader<'r> {
+        TypeIdsUpgradeReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for TypeIds {
-    type Builder = TypeIdsBuilder;
-    const NAME: &'static str = "TypeIds";
+impl molecule::prelude::Entity for TypeIdsUpgrade {
+    type Builder = TypeIdsUpgradeBuilder;
+    const NAME: &'static str = "TypeIdsUpgrade";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        TypeIds(data)
+        TypeIdsUpgrade(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -1880,38 +3894,23 @@ impl molecule::prelude::Entity for TypeIds {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TypeIdsReader::from_slice(slice).map(|reader| reader.to_entity())
+        TypeIdsUpgradeReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        TypeIdsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        TypeIdsUpgradeReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .issue_type_id(self.issue_type_id())
-            .selection_type_id(self.selection_type_id())
-            .xudt_owner_lock_hash(self.xudt_owner_lock_hash())
-            .metadata_code_hash(self.metadata_code_hash())
-            .metadata_type_id(self.metadata_type_id())
-            .checkpoint_code_hash(self.checkpoint_code_hash())
-            .checkpoint_type_id(self.checkpoint_type_id())
-            .stake_smt_code_hash(self.stake_smt_code_hash())
-            .stake_smt_type_id(self.stake_smt_type_id())
-            .delegate_smt_code_hash(self.delegate_smt_code_hash())
-            .delegate_smt_type_id(self.delegate_smt_type_id())
-            .reward_code_hash(self.reward_code_hash())
-            .reward_type_id(self.reward_type_id())
-            .xudt_type_hash(self.xudt_type_hash())
-            .stake_at_code_hash(self.stake_at_code_hash())
-            .delegate_at_code_hash(self.delegate_at_code_hash())
-            .withdraw_code_hash(self.withdraw_code_hash())
+            .effective_epoch(self.effective_epoch())
+            .type_ids(self.type_ids())
     }
 }
 #[derive(Clone, Copy)]
-pub struct TypeIdsReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for TypeIdsReader<'r> {
+pub struct TypeIdsUpgradeReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for TypeIdsUpgradeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1920,81 +3919,16 @@ impl<'r> ::core::fmt::LowerHex for TypeIdsReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for TypeIdsReader<'r> {
+impl<'r> ::core::fmt::Debug for TypeIdsUpgradeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for TypeIdsReader<'r> {
+impl<'r> ::core::fmt::Display for TypeIdsUpgradeReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "issue_type_id", self.issue_type_id())?;
-        write!(f, ", {}: {}", "selection_type_id", self.selection_type_id())?;
-        write!(
-            f,
-            ", {}: {}",
-            "xudt_owner_lock_hash",
-            self.xudt_owner_lock_hash()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "metadata_code_hash",
-            self.metadata_code_hash()
-        )?;
-        write!(f, ", {}: {}", "metadata_type_id", self.metadata_type_id())?;
-        write!(
-            f,
-            ", {}: {}",
-            "checkpoint_code_hash",
-            self.checkpoint_code_hash()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "checkpoint_type_id",
-            self.checkpoint_type_id()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "stake_smt_code_hash",
-            self.stake_smt_code_hash()
-        )?;
-        write!(f, ", {}: {}", "stake_smt_type_id", self.stake_smt_type_id())?;
-        write!(
-            f,
-            ", {}: {}",
-            "delegate_smt_code_hash",
-            self.delegate_smt_code_hash()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "delegate_smt_type_id",
-            self.delegate_smt_type_id()
-        )?;
-        write!(f, ", {}: {}", "reward_code_hash", self.reward_code_hash())?;
-        write!(f, ", {}: {}", "reward_type_id", self.reward_type_id())?;
-        write!(f, ", {}: {}", "xudt_type_hash", self.xudt_type_hash())?;
-        write!(
-            f,
-            ", {}: {}",
-            "stake_at_code_hash",
-            self.stake_at_code_hash()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "delegate_at_code_hash",
-            self.delegate_at_code_hash()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "withdraw_code_hash",
-            self.withdraw_code_hash()
-        )?;
+        write!(f, "{}: {}", "effective_epoch", self.effective_epoch())?;
+        write!(f, ", {}: {}", "type_ids", self.type_ids())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -2002,8 +3936,8 @@ impl<'r> ::core::fmt::Display for TypeIdsReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> TypeIdsReader<'r> {
-    pub const FIELD_COUNT: usize = 17;
+impl<'r> TypeIdsUpgradeReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -2012,129 +3946,39 @@ impl<'r> TypeIdsReader<'r> {
             0
         } else {
             (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn issue_type_id(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn selection_type_id(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn xudt_owner_lock_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn metadata_code_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn metadata_type_id(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn checkpoint_code_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn checkpoint_type_id(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
-        let end = molecule::unpack_number(&slice[32..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn stake_smt_code_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[32..]) as usize;
-        let end = molecule::unpack_number(&slice[36..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn stake_smt_type_id(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[36..]) as usize;
-        let end = molecule::unpack_number(&slice[40..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn delegate_smt_code_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[40..]) as usize;
-        let end = molecule::unpack_number(&slice[44..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn delegate_smt_type_id(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[44..]) as usize;
-        let end = molecule::unpack_number(&slice[48..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn reward_code_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[48..]) as usize;
-        let end = molecule::unpack_number(&slice[52..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn reward_type_id(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[52..]) as usize;
-        let end = molecule::unpack_number(&slice[56..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+        }
     }
-    pub fn xudt_type_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[56..]) as usize;
-        let end = molecule::unpack_number(&slice[60..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn stake_at_code_hash(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[60..]) as usize;
-        let end = molecule::unpack_number(&slice[64..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn delegate_at_code_hash(&self) -> Byte32Reader<'r> {
+    pub fn effective_epoch(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[64..]) as usize;
-        let end = molecule::unpack_number(&slice[68..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn withdraw_code_hash(&self) -> Byte32Reader<'r> {
+    pub fn type_ids(&self) -> TypeIdsReader<'r> {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[68..]) as usize;
+        let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[72..]) as usize;
-            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            TypeIdsReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Byte32Reader::new_unchecked(&self.as_slice()[start..])
+            TypeIdsReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for TypeIdsReader<'r> {
-    type Entity = TypeIds;
-    const NAME: &'static str = "TypeIdsReader";
+impl<'r> molecule::prelude::Reader<'r> for TypeIdsUpgradeReader<'r> {
+    type Entity = TypeIdsUpgrade;
+    const NAME: &'static str = "TypeIdsUpgradeReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        TypeIdsReader(slice)
+        TypeIdsUpgradeReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -2176,210 +4020,375 @@ impl<'r> molecule::prelude::Reader<'r> for TypeIdsReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[12]..offsets[13]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[13]..offsets[14]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[14]..offsets[15]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[15]..offsets[16]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[16]..offsets[17]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        TypeIdsReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct TypeIdsBuilder {
-    pub(crate) issue_type_id: Byte32,
-    pub(crate) selection_type_id: Byte32,
-    pub(crate) xudt_owner_lock_hash: Byte32,
-    pub(crate) metadata_code_hash: Byte32,
-    pub(crate) metadata_type_id: Byte32,
-    pub(crate) checkpoint_code_hash: Byte32,
-    pub(crate) checkpoint_type_id: Byte32,
-    pub(crate) stake_smt_code_hash: Byte32,
-    pub(crate) stake_smt_type_id: Byte32,
-    pub(crate) delegate_smt_code_hash: Byte32,
-    pub(crate) delegate_smt_type_id: Byte32,
-    pub(crate) reward_code_hash: Byte32,
-    pub(crate) reward_type_id: Byte32,
-    pub(crate) xudt_type_hash: Byte32,
-    pub(crate) stake_at_code_hash: Byte32,
-    pub(crate) delegate_at_code_hash: Byte32,
-    pub(crate) withdraw_code_hash: Byte32,
+pub struct TypeIdsUpgradeBuilder {
+    pub(crate) effective_epoch: Uint64,
+    pub(crate) type_ids: TypeIds,
 }
-impl TypeIdsBuilder {
-    pub const FIELD_COUNT: usize = 17;
-    pub fn issue_type_id(mut self, v: Byte32) -> Self {
-        self.issue_type_id = v;
+impl TypeIdsUpgradeBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn effective_epoch(mut self, v: Uint64) -> Self {
+        self.effective_epoch = v;
         self
     }
-    pub fn selection_type_id(mut self, v: Byte32) -> Self {
-        self.selection_type_id = v;
+    pub fn type_ids(mut self, v: TypeIds) -> Self {
+        self.type_ids = v;
         self
     }
-    pub fn xudt_owner_lock_hash(mut self, v: Byte32) -> Self {
-        self.xudt_owner_lock_hash = v;
-        self
+}
+impl molecule::prelude::Builder for TypeIdsUpgradeBuilder {
+    type Entity = TypeIdsUpgrade;
+    const NAME: &'static str = "TypeIdsUpgradeBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.effective_epoch.as_slice().len()
+            + self.type_ids.as_slice().len()
     }
-    pub fn metadata_code_hash(mut self, v: Byte32) -> Self {
-        self.metadata_code_hash = v;
-        self
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.effective_epoch.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.type_ids.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.effective_epoch.as_slice())?;
+        writer.write_all(self.type_ids.as_slice())?;
+        Ok(())
     }
-    pub fn metadata_type_id(mut self, v: Byte32) -> Self {
-        self.metadata_type_id = v;
-        self
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        TypeIdsUpgrade::new_unchecked(inner.into())
     }
-    pub fn checkpoint_code_hash(mut self, v: Byte32) -> Self {
-        self.checkpoint_code_hash = v;
-        self
+}
+#[derive(Clone)]
+pub struct GovernanceConfig(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for GovernanceConfig {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
     }
-    pub fn checkpoint_type_id(mut self, v: Byte32) -> Self {
-        self.checkpoint_type_id = v;
-        self
+}
+impl ::core::fmt::Debug for GovernanceConfig {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
     }
-    pub fn stake_smt_code_hash(mut self, v: Byte32) -> Self {
-        self.stake_smt_code_hash = v;
-        self
+}
+impl ::core::fmt::Display for GovernanceConfig {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "mode", self.mode())?;
+        write!(f, ", {}: {}", "threshold", self.threshold())?;
+        write!(f, ", {}: {}", "members", self.members())?;
+        write!(f, ", {}: {}", "upgrade_delay", self.upgrade_delay())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for GovernanceConfig {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            34, 0, 0, 0, 20, 0, 0, 0, 21, 0, 0, 0, 22, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ];
+        GovernanceConfig::new_unchecked(v.into())
+    }
+}
+impl GovernanceConfig {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn mode(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn threshold(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn members(&self) -> IdentityVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        IdentityVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn upgrade_delay(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Uint64::new_unchecked(self.0.slice(start..end))
+        } else {
+            Uint64::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> GovernanceConfigReader<'r> {
+        GovernanceConfigReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for GovernanceConfig {
+    type Builder = GovernanceConfigBuilder;
+    const NAME: &'static str = "GovernanceConfig";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        GovernanceConfig(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        GovernanceConfigReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        GovernanceConfigReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .mode(self.mode())
+            .threshold(self.threshold())
+            .members(self.members())
+            .upgrade_delay(self.upgrade_delay())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct GovernanceConfigReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for GovernanceConfigReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for GovernanceConfigReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for GovernanceConfigReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "mode", self.mode())?;
+        write!(f, ", {}: {}", "threshold", self.threshold())?;
+        write!(f, ", {}: {}", "members", self.members())?;
+        write!(f, ", {}: {}", "upgrade_delay", self.upgrade_delay())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> GovernanceConfigReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn mode(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn threshold(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn members(&self) -> IdentityVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        IdentityVecReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn stake_smt_type_id(mut self, v: Byte32) -> Self {
-        self.stake_smt_type_id = v;
-        self
+    pub fn upgrade_delay(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
+        }
     }
-    pub fn delegate_smt_code_hash(mut self, v: Byte32) -> Self {
-        self.delegate_smt_code_hash = v;
-        self
+}
+impl<'r> molecule::prelude::Reader<'r> for GovernanceConfigReader<'r> {
+    type Entity = GovernanceConfig;
+    const NAME: &'static str = "GovernanceConfigReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
-    pub fn delegate_smt_type_id(mut self, v: Byte32) -> Self {
-        self.delegate_smt_type_id = v;
-        self
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        GovernanceConfigReader(slice)
     }
-    pub fn reward_code_hash(mut self, v: Byte32) -> Self {
-        self.reward_code_hash = v;
-        self
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
     }
-    pub fn reward_type_id(mut self, v: Byte32) -> Self {
-        self.reward_type_id = v;
-        self
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        IdentityVecReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Ok(())
     }
-    pub fn xudt_type_hash(mut self, v: Byte32) -> Self {
-        self.xudt_type_hash = v;
+}
+#[derive(Debug, Default)]
+pub struct GovernanceConfigBuilder {
+    pub(crate) mode: Byte,
+    pub(crate) threshold: Byte,
+    pub(crate) members: IdentityVec,
+    pub(crate) upgrade_delay: Uint64,
+}
+impl GovernanceConfigBuilder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn mode(mut self, v: Byte) -> Self {
+        self.mode = v;
         self
     }
-    pub fn stake_at_code_hash(mut self, v: Byte32) -> Self {
-        self.stake_at_code_hash = v;
+    pub fn threshold(mut self, v: Byte) -> Self {
+        self.threshold = v;
         self
     }
-    pub fn delegate_at_code_hash(mut self, v: Byte32) -> Self {
-        self.delegate_at_code_hash = v;
+    pub fn members(mut self, v: IdentityVec) -> Self {
+        self.members = v;
         self
     }
-    pub fn withdraw_code_hash(mut self, v: Byte32) -> Self {
-        self.withdraw_code_hash = v;
+    pub fn upgrade_delay(mut self, v: Uint64) -> Self {
+        self.upgrade_delay = v;
         self
     }
 }
-impl molecule::prelude::Builder for TypeIdsBuilder {
-    type Entity = TypeIds;
-    const NAME: &'static str = "TypeIdsBuilder";
+impl molecule::prelude::Builder for GovernanceConfigBuilder {
+    type Entity = GovernanceConfig;
+    const NAME: &'static str = "GovernanceConfigBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.issue_type_id.as_slice().len()
-            + self.selection_type_id.as_slice().len()
-            + self.xudt_owner_lock_hash.as_slice().len()
-            + self.metadata_code_hash.as_slice().len()
-            + self.metadata_type_id.as_slice().len()
-            + self.checkpoint_code_hash.as_slice().len()
-            + self.checkpoint_type_id.as_slice().len()
-            + self.stake_smt_code_hash.as_slice().len()
-            + self.stake_smt_type_id.as_slice().len()
-            + self.delegate_smt_code_hash.as_slice().len()
-            + self.delegate_smt_type_id.as_slice().len()
-            + self.reward_code_hash.as_slice().len()
-            + self.reward_type_id.as_slice().len()
-            + self.xudt_type_hash.as_slice().len()
-            + self.stake_at_code_hash.as_slice().len()
-            + self.delegate_at_code_hash.as_slice().len()
-            + self.withdraw_code_hash.as_slice().len()
+            + self.mode.as_slice().len()
+            + self.threshold.as_slice().len()
+            + self.members.as_slice().len()
+            + self.upgrade_delay.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.issue_type_id.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.selection_type_id.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.xudt_owner_lock_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.metadata_code_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.metadata_type_id.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.checkpoint_code_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.checkpoint_type_id.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.stake_smt_code_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.stake_smt_type_id.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.delegate_smt_code_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.delegate_smt_type_id.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.reward_code_hash.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.reward_type_id.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.xudt_type_hash.as_slice().len();
+        total_size += self.mode.as_slice().len();
         offsets.push(total_size);
-        total_size += self.stake_at_code_hash.as_slice().len();
+        total_size += self.threshold.as_slice().len();
         offsets.push(total_size);
-        total_size += self.delegate_at_code_hash.as_slice().len();
+        total_size += self.members.as_slice().len();
         offsets.push(total_size);
-        total_size += self.withdraw_code_hash.as_slice().len();
+        total_size += self.upgrade_delay.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.issue_type_id.as_slice())?;
-        writer.write_all(self.selection_type_id.as_slice())?;
-        writer.write_all(self.xudt_owner_lock_hash.as_slice())?;
-        writer.write_all(self.metadata_code_hash.as_slice())?;
-        writer.write_all(self.metadata_type_id.as_slice())?;
-        writer.write_all(self.checkpoint_code_hash.as_slice())?;
-        writer.write_all(self.checkpoint_type_id.as_slice())?;
-        writer.write_all(self.stake_smt_code_hash.as_slice())?;
-        writer.write_all(self.stake_smt_type_id.as_slice())?;
-        writer.write_all(self.delegate_smt_code_hash.as_slice())?;
-        writer.write_all(self.delegate_smt_type_id.as_slice())?;
-        writer.write_all(self.reward_code_hash.as_slice())?;
-        writer.write_all(self.reward_type_id.as_slice())?;
-        writer.write_all(self.xudt_type_hash.as_slice())?;
-        writer.write_all(self.stake_at_code_hash.as_slice())?;
-        writer.write_all(self.delegate_at_code_hash.as_slice())?;
-        writer.write_all(self.withdraw_code_hash.as_slice())?;
+        writer.write_all(self.mode.as_slice())?;
+        writer.write_all(self.threshold.as_slice())?;
+        writer.write_all(self.members.as_slice())?;
+        writer.write_all(self.upgrade_delay.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        TypeIds::new_unchecked(inner.into())
+        GovernanceConfig::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct MetadataCellData(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for MetadataCellData {
+pub struct MetadataArgs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for MetadataArgs {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2388,38 +4397,15 @@ impl ::core::fmt::LowerHex for MetadataCellData {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for MetadataCellData {
+impl ::core::fmt::Debug for MetadataArgs {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for MetadataCellData {
+impl ::core::fmt::Display for MetadataArgs {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "version", self.version())?;
-        write!(f, ", {}: {}", "epoch", self.epoch())?;
-        write!(f, ", {}: {}", "base_reward", self.base_reward())?;
-        write!(f, ", {}: {}", "half_epoch", self.half_epoch())?;
-        write!(
-            f,
-            ", {}: {}",
-            "propose_minimum_rate",
-            self.propose_minimum_rate()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "propose_discount_rate",
-            self.propose_discount_rate()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "propose_count_smt_root",
-            self.propose_count_smt_root()
-        )?;
-        write!(f, ", {}: {}", "type_ids", self.type_ids())?;
-        write!(f, ", {}: {}", "metadata", self.metadata())?;
+        write!(f, "{}: {}", "metadata_type_id", self.metadata_type_id())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -2427,124 +4413,52 @@ impl ::core::fmt::Display for MetadataCellData {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for MetadataCellData {
+impl ::core::default::Default for MetadataArgs {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            215, 2, 0, 0, 40, 0, 0, 0, 41, 0, 0, 0, 49, 0, 0, 0, 65, 0, 0, 0, 73, 0, 0, 0, 74, 0,
-            0, 0, 75, 0, 0, 0, 107, 0, 0, 0, 211, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 2, 0,
-            0, 72, 0, 0, 0, 104, 0, 0, 0, 136, 0, 0, 0, 168, 0, 0, 0, 200, 0, 0, 0, 232, 0, 0, 0,
-            8, 1, 0, 0, 40, 1, 0, 0, 72, 1, 0, 0, 104, 1, 0, 0, 136, 1, 0, 0, 168, 1, 0, 0, 200, 1,
-            0, 0, 232, 1, 0, 0, 8, 2, 0, 0, 40, 2, 0, 0, 72, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0,
+            40, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
-        MetadataCellData::new_unchecked(v.into())
+        MetadataArgs::new_unchecked(v.into())
     }
 }
-impl MetadataCellData {
-    pub const FIELD_COUNT: usize = 9;
+impl MetadataArgs {
+    pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
     pub fn field_count(&self) -> usize {
         if self.total_size() == molecule::NUMBER_SIZE {
-            0
-        } else {
-            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
-        }
-    }
-    pub fn count_extra_fields(&self) -> usize {
-        self.field_count() - Self::FIELD_COUNT
-    }
-    pub fn has_extra_fields(&self) -> bool {
-        Self::FIELD_COUNT != self.field_count()
-    }
-    pub fn version(&self) -> Byte {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        Byte::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn epoch(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn base_reward(&self) -> Uint128 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Uint128::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn half_epoch(&self) -> Uint64 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint64::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn propose_minimum_rate(&self) -> Byte {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Byte::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn propose_discount_rate(&self) -> Byte {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        Byte::new_unchecked(self.0.slice(start..end))
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn propose_count_smt_root(&self) -> Byte32 {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
-        let end = molecule::unpack_number(&slice[32..]) as usize;
-        Byte32::new_unchecked(self.0.slice(start..end))
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn type_ids(&self) -> TypeIds {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[32..]) as usize;
-        let end = molecule::unpack_number(&slice[36..]) as usize;
-        TypeIds::new_unchecked(self.0.slice(start..end))
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn metadata(&self) -> MetadataList {
+    pub fn metadata_type_id(&self) -> Byte32 {
         let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let start = molecule::unpack_number(&slice[4..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[40..]) as usize;
-            MetadataList::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            Byte32::new_unchecked(self.0.slice(start..end))
         } else {
-            MetadataList::new_unchecked(self.0.slice(start..))
+            Byte32::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> MetadataCellDataReader<'r> {
-        MetadataCellDataReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> MetadataArgsReader<'r> {
+        MetadataArgsReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for MetadataCellData {
-    type Builder = MetadataCellDataBuilder;
-    const NAME: &'static str = "MetadataCellData";
+impl molecule::prelude::Entity for MetadataArgs {
+    type Builder = MetadataArgsBuilder;
+    const NAME: &'static str = "MetadataArgs";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        MetadataCellData(data)
+        MetadataArgs(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -2553,30 +4467,21 @@ impl molecule::prelude::Entity for MetadataCellData {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        MetadataCellDataReader::from_slice(slice).map(|reader| reader.to_entity())
+        MetadataArgsReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        MetadataCellDataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        MetadataArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .version(self.version())
-            .epoch(self.epoch())
-            .base_reward(self.base_reward())
-            .half_epoch(self.half_epoch())
-            .propose_minimum_rate(self.propose_minimum_rate())
-            .propose_discount_rate(self.propose_discount_rate())
-            .propose_count_smt_root(self.propose_count_smt_root())
-            .type_ids(self.type_ids())
-            .metadata(self.metadata())
+        Self::new_builder().metadata_type_id(self.metadata_type_id())
     }
 }
 #[derive(Clone, Copy)]
-pub struct MetadataCellDataReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for MetadataCellDataReader<'r> {
+pub struct MetadataArgsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for MetadataArgsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2585,38 +4490,15 @@ impl<'r> ::core::fmt::LowerHex for MetadataCellDataReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for MetadataCellDataReader<'r> {
+impl<'r> ::core::fmt::Debug for MetadataArgsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for MetadataCellDataReader<'r> {
+impl<'r> ::core::fmt::Display for MetadataArgsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "version", self.version())?;
-        write!(f, ", {}: {}", "epoch", self.epoch())?;
-        write!(f, ", {}: {}", "base_reward", self.base_reward())?;
-        write!(f, ", {}: {}", "half_epoch", self.half_epoch())?;
-        write!(
-            f,
-            ", {}: {}",
-            "propose_minimum_rate",
-            self.propose_minimum_rate()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "propose_discount_rate",
-            self.propose_discount_rate()
-        )?;
-        write!(
-            f,
-            ", {}: {}",
-            "propose_count_smt_root",
-            self.propose_count_smt_root()
-        )?;
-        write!(f, ", {}: {}", "type_ids", self.type_ids())?;
-        write!(f, ", {}: {}", "metadata", self.metadata())?;
+        write!(f, "{}: {}", "metadata_type_id", self.metadata_type_id())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -2624,8 +4506,8 @@ impl<'r> ::core::fmt::Display for MetadataCellDataReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> MetadataCellDataReader<'r> {
-    pub const FIELD_COUNT: usize = 9;
+impl<'r> MetadataArgsReader<'r> {
+    pub const FIELD_COUNT: usize = 1;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -2642,73 +4524,25 @@ impl<'r> MetadataCellDataReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn version(&self) -> ByteReader<'r> {
+    pub fn metadata_type_id(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
-        let end = molecule::unpack_number(&slice[8..]) as usize;
-        ByteReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn epoch(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[8..]) as usize;
-        let end = molecule::unpack_number(&slice[12..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn base_reward(&self) -> Uint128Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[12..]) as usize;
-        let end = molecule::unpack_number(&slice[16..]) as usize;
-        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn half_epoch(&self) -> Uint64Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn propose_minimum_rate(&self) -> ByteReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        ByteReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn propose_discount_rate(&self) -> ByteReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        ByteReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn propose_count_smt_root(&self) -> Byte32Reader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
-        let end = molecule::unpack_number(&slice[32..]) as usize;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn type_ids(&self) -> TypeIdsReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[32..]) as usize;
-        let end = molecule::unpack_number(&slice[36..]) as usize;
-        TypeIdsReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn metadata(&self) -> MetadataListReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[36..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[40..]) as usize;
-            MetadataListReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[8..]) as usize;
+            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            MetadataListReader::new_unchecked(&self.as_slice()[start..])
+            Byte32Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for MetadataCellDataReader<'r> {
-    type Entity = MetadataCellData;
-    const NAME: &'static str = "MetadataCellDataReader";
+impl<'r> molecule::prelude::Reader<'r> for MetadataArgsReader<'r> {
+    type Entity = MetadataArgs;
+    const NAME: &'static str = "MetadataArgsReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        MetadataCellDataReader(slice)
+        MetadataArgsReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -2750,130 +4584,49 @@ impl<'r> molecule::prelude::Reader<'r> for MetadataCellDataReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        Uint128Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        ByteReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        ByteReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        Byte32Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
-        TypeIdsReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
-        MetadataListReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct MetadataCellDataBuilder {
-    pub(crate) version: Byte,
-    pub(crate) epoch: Uint64,
-    pub(crate) base_reward: Uint128,
-    pub(crate) half_epoch: Uint64,
-    pub(crate) propose_minimum_rate: Byte,
-    pub(crate) propose_discount_rate: Byte,
-    pub(crate) propose_count_smt_root: Byte32,
-    pub(crate) type_ids: TypeIds,
-    pub(crate) metadata: MetadataList,
+pub struct MetadataArgsBuilder {
+    pub(crate) metadata_type_id: Byte32,
 }
-impl MetadataCellDataBuilder {
-    pub const FIELD_COUNT: usize = 9;
-    pub fn version(mut self, v: Byte) -> Self {
-        self.version = v;
-        self
-    }
-    pub fn epoch(mut self, v: Uint64) -> Self {
-        self.epoch = v;
-        self
-    }
-    pub fn base_reward(mut self, v: Uint128) -> Self {
-        self.base_reward = v;
-        self
-    }
-    pub fn half_epoch(mut self, v: Uint64) -> Self {
-        self.half_epoch = v;
-        self
-    }
-    pub fn propose_minimum_rate(mut self, v: Byte) -> Self {
-        self.propose_minimum_rate = v;
-        self
-    }
-    pub fn propose_discount_rate(mut self, v: Byte) -> Self {
-        self.propose_discount_rate = v;
-        self
-    }
-    pub fn propose_count_smt_root(mut self, v: Byte32) -> Self {
-        self.propose_count_smt_root = v;
-        self
-    }
-    pub fn type_ids(mut self, v: TypeIds) -> Self {
-        self.type_ids = v;
-        self
-    }
-    pub fn metadata(mut self, v: MetadataList) -> Self {
-        self.metadata = v;
+impl MetadataArgsBuilder {
+    pub const FIELD_COUNT: usize = 1;
+    pub fn metadata_type_id(mut self, v: Byte32) -> Self {
+        self.metadata_type_id = v;
         self
     }
 }
-impl molecule::prelude::Builder for MetadataCellDataBuilder {
-    type Entity = MetadataCellData;
-    const NAME: &'static str = "MetadataCellDataBuilder";
+impl molecule::prelude::Builder for MetadataArgsBuilder {
+    type Entity = MetadataArgs;
+    const NAME: &'static str = "MetadataArgsBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.version.as_slice().len()
-            + self.epoch.as_slice().len()
-            + self.base_reward.as_slice().len()
-            + self.half_epoch.as_slice().len()
-            + self.propose_minimum_rate.as_slice().len()
-            + self.propose_discount_rate.as_slice().len()
-            + self.propose_count_smt_root.as_slice().len()
-            + self.type_ids.as_slice().len()
-            + self.metadata.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.metadata_type_id.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.version.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.epoch.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.base_reward.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.half_epoch.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.propose_minimum_rate.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.propose_discount_rate.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.propose_count_smt_root.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.type_ids.as_slice().len();
-        offsets.push(total_size);
-        total_size += self.metadata.as_slice().len();
+        total_size += self.metadata_type_id.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
-            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-        }
-        writer.write_all(self.version.as_slice())?;
-        writer.write_all(self.epoch.as_slice())?;
-        writer.write_all(self.base_reward.as_slice())?;
-        writer.write_all(self.half_epoch.as_slice())?;
-        writer.write_all(self.propose_minimum_rate.as_slice())?;
-        writer.write_all(self.propose_discount_rate.as_slice())?;
-        writer.write_all(self.propose_count_smt_root.as_slice())?;
-        writer.write_all(self.type_ids.as_slice())?;
-        writer.write_all(self.metadata.as_slice())?;
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.metadata_type_id.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        MetadataCellData::new_unchecked(inner.into())
+        MetadataArgs::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct MetadataArgs(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for MetadataArgs {
+pub struct MetadataWitness(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for MetadataWitness {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2882,15 +4635,30 @@ impl ::core::fmt::LowerHex for MetadataArgs {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for MetadataArgs {
+impl ::core::fmt::Debug for MetadataWitness {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for MetadataArgs {
+impl ::core::fmt::Display for MetadataWitness {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "metadata_type_id", self.metadata_type_id())?;
+        write!(f, "{}: {}", "mode", self.mode())?;
+        write!(f, ", {}: {}", "new_propose_proof", self.new_propose_proof())?;
+        write!(f, ", {}: {}", "smt_election_info", self.smt_election_info())?;
+        write!(f, ", {}: {}", "governance_sigs", self.governance_sigs())?;
+        write!(
+            f,
+            ", {}: {}",
+            "new_commission_proof",
+            self.new_commission_proof()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "new_reward_params_proof",
+            self.new_reward_params_proof()
+        )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -2898,17 +4666,19 @@ impl ::core::fmt::Display for MetadataArgs {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for MetadataArgs {
+impl ::core::default::Default for MetadataWitness {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            40, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            105, 0, 0, 0, 28, 0, 0, 0, 29, 0, 0, 0, 33, 0, 0, 0, 77, 0, 0, 0, 97, 0, 0, 0, 101, 0,
+            0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 16, 0, 0, 0, 36, 0, 0, 0, 40, 0, 0, 0, 20, 0, 0, 0,
+            12, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 20, 0, 0, 0,
+            12, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
-        MetadataArgs::new_unchecked(v.into())
+        MetadataWitness::new_unchecked(v.into())
     }
 }
-impl MetadataArgs {
-    pub const FIELD_COUNT: usize = 1;
+impl MetadataWitness {
+    pub const FIELD_COUNT: usize = 6;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -2925,25 +4695,55 @@ impl MetadataArgs {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn metadata_type_id(&self) -> Byte32 {
+    pub fn mode(&self) -> Byte {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn new_propose_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn smt_election_info(&self) -> StakeSmtElectionInfo {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        StakeSmtElectionInfo::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn governance_sigs(&self) -> GovernanceSignatures {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        GovernanceSignatures::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn new_commission_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn new_reward_params_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[8..]) as usize;
-            Byte32::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte32::new_unchecked(self.0.slice(start..))
+            Bytes::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> MetadataArgsReader<'r> {
-        MetadataArgsReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> MetadataWitnessReader<'r> {
+        MetadataWitnessReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for MetadataArgs {
-    type Builder = MetadataArgsBuilder;
-    const NAME: &'static str = "MetadataArgs";
+impl molecule::prelude::Entity for MetadataWitness {
+    type Builder = MetadataWitnessBuilder;
+    const NAME: &'static str = "MetadataWitness";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        MetadataArgs(data)
+        MetadataWitness(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -2952,21 +4752,27 @@ impl molecule::prelude::Entity for MetadataArgs {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        MetadataArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+        MetadataWitnessReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        MetadataArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        MetadataWitnessReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().metadata_type_id(self.metadata_type_id())
+        Self::new_builder()
+            .mode(self.mode())
+            .new_propose_proof(self.new_propose_proof())
+            .smt_election_info(self.smt_election_info())
+            .governance_sigs(self.governance_sigs())
+            .new_commission_proof(self.new_commission_proof())
+            .new_reward_params_proof(self.new_reward_params_proof())
     }
 }
 #[derive(Clone, Copy)]
-pub struct MetadataArgsReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for MetadataArgsReader<'r> {
+pub struct MetadataWitnessReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for MetadataWitnessReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2975,15 +4781,30 @@ impl<'r> ::core::fmt::LowerHex for MetadataArgsReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for MetadataArgsReader<'r> {
+impl<'r> ::core::fmt::Debug for MetadataWitnessReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for MetadataArgsReader<'r> {
+impl<'r> ::core::fmt::Display for MetadataWitnessReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "metadata_type_id", self.metadata_type_id())?;
+        write!(f, "{}: {}", "mode", self.mode())?;
+        write!(f, ", {}: {}", "new_propose_proof", self.new_propose_proof())?;
+        write!(f, ", {}: {}", "smt_election_info", self.smt_election_info())?;
+        write!(f, ", {}: {}", "governance_sigs", self.governance_sigs())?;
+        write!(
+            f,
+            ", {}: {}",
+            "new_commission_proof",
+            self.new_commission_proof()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "new_reward_params_proof",
+            self.new_reward_params_proof()
+        )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -2991,8 +4812,8 @@ impl<'r> ::core::fmt::Display for MetadataArgsReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> MetadataArgsReader<'r> {
-    pub const FIELD_COUNT: usize = 1;
+impl<'r> MetadataWitnessReader<'r> {
+    pub const FIELD_COUNT: usize = 6;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -3009,25 +4830,55 @@ impl<'r> MetadataArgsReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn metadata_type_id(&self) -> Byte32Reader<'r> {
+    pub fn mode(&self) -> ByteReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn new_propose_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn smt_election_info(&self) -> StakeSmtElectionInfoReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        StakeSmtElectionInfoReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn governance_sigs(&self) -> GovernanceSignaturesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        GovernanceSignaturesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn new_commission_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn new_reward_params_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[8..]) as usize;
-            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Byte32Reader::new_unchecked(&self.as_slice()[start..])
+            BytesReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for MetadataArgsReader<'r> {
-    type Entity = MetadataArgs;
-    const NAME: &'static str = "MetadataArgsReader";
+impl<'r> molecule::prelude::Reader<'r> for MetadataWitnessReader<'r> {
+    type Entity = MetadataWitness;
+    const NAME: &'static str = "MetadataWitnessReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        MetadataArgsReader(slice)
+        MetadataWitnessReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -3069,49 +4920,100 @@ impl<'r> molecule::prelude::Reader<'r> for MetadataArgsReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        StakeSmtElectionInfoReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        GovernanceSignaturesReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        BytesReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        BytesReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct MetadataArgsBuilder {
-    pub(crate) metadata_type_id: Byte32,
+pub struct MetadataWitnessBuilder {
+    pub(crate) mode: Byte,
+    pub(crate) new_propose_proof: Bytes,
+    pub(crate) smt_election_info: StakeSmtElectionInfo,
+    pub(crate) governance_sigs: GovernanceSignatures,
+    pub(crate) new_commission_proof: Bytes,
+    pub(crate) new_reward_params_proof: Bytes,
 }
-impl MetadataArgsBuilder {
-    pub const FIELD_COUNT: usize = 1;
-    pub fn metadata_type_id(mut self, v: Byte32) -> Self {
-        self.metadata_type_id = v;
+impl MetadataWitnessBuilder {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn mode(mut self, v: Byte) -> Self {
+        self.mode = v;
+        self
+    }
+    pub fn new_propose_proof(mut self, v: Bytes) -> Self {
+        self.new_propose_proof = v;
+        self
+    }
+    pub fn smt_election_info(mut self, v: StakeSmtElectionInfo) -> Self {
+        self.smt_election_info = v;
+        self
+    }
+    pub fn governance_sigs(mut self, v: GovernanceSignatures) -> Self {
+        self.governance_sigs = v;
+        self
+    }
+    pub fn new_commission_proof(mut self, v: Bytes) -> Self {
+        self.new_commission_proof = v;
+        self
+    }
+    pub fn new_reward_params_proof(mut self, v: Bytes) -> Self {
+        self.new_reward_params_proof = v;
         self
     }
 }
-impl molecule::prelude::Builder for MetadataArgsBuilder {
-    type Entity = MetadataArgs;
-    const NAME: &'static str = "MetadataArgsBuilder";
+impl molecule::prelude::Builder for MetadataWitnessBuilder {
+    type Entity = MetadataWitness;
+    const NAME: &'static str = "MetadataWitnessBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.metadata_type_id.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.mode.as_slice().len()
+            + self.new_propose_proof.as_slice().len()
+            + self.smt_election_info.as_slice().len()
+            + self.governance_sigs.as_slice().len()
+            + self.new_commission_proof.as_slice().len()
+            + self.new_reward_params_proof.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.metadata_type_id.as_slice().len();
+        total_size += self.mode.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.new_propose_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.smt_election_info.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.governance_sigs.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.new_commission_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.new_reward_params_proof.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.metadata_type_id.as_slice())?;
+        writer.write_all(self.mode.as_slice())?;
+        writer.write_all(self.new_propose_proof.as_slice())?;
+        writer.write_all(self.smt_election_info.as_slice())?;
+        writer.write_all(self.governance_sigs.as_slice())?;
+        writer.write_all(self.new_commission_proof.as_slice())?;
+        writer.write_all(self.new_reward_params_proof.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        MetadataArgs::new_unchecked(inner.into())
+        MetadataWitness::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct MetadataWitness(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for MetadataWitness {
+pub struct GovernanceSignatures(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for GovernanceSignatures {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3120,16 +5022,16 @@ impl ::core::fmt::LowerHex for MetadataWitness {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for MetadataWitness {
+impl ::core::fmt::Debug for GovernanceSignatures {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for MetadataWitness {
+impl ::core::fmt::Display for GovernanceSignatures {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "new_propose_proof", self.new_propose_proof())?;
-        write!(f, ", {}: {}", "smt_election_info", self.smt_election_info())?;
+        write!(f, "{}: {}", "signatures", self.signatures())?;
+        write!(f, ", {}: {}", "bitmap", self.bitmap())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -3137,17 +5039,15 @@ impl ::core::fmt::Display for MetadataWitness {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for MetadataWitness {
+impl ::core::default::Default for GovernanceSignatures {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            60, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 16, 0, 0, 0, 36, 0, 0,
-            0, 40, 0, 0, 0, 20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 4, 0, 0, 0,
+            20, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
         ];
-        MetadataWitness::new_unchecked(v.into())
+        GovernanceSignatures::new_unchecked(v.into())
     }
 }
-impl MetadataWitness {
+impl GovernanceSignatures {
     pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -3165,31 +5065,31 @@ impl MetadataWitness {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn new_propose_proof(&self) -> Bytes {
+    pub fn signatures(&self) -> BytesVec {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        Bytes::new_unchecked(self.0.slice(start..end))
+        BytesVec::new_unchecked(self.0.slice(start..end))
     }
-    pub fn smt_election_info(&self) -> StakeSmtElectionInfo {
+    pub fn bitmap(&self) -> Bytes {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[12..]) as usize;
-            StakeSmtElectionInfo::new_unchecked(self.0.slice(start..end))
+            Bytes::new_unchecked(self.0.slice(start..end))
         } else {
-            StakeSmtElectionInfo::new_unchecked(self.0.slice(start..))
+            Bytes::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> MetadataWitnessReader<'r> {
-        MetadataWitnessReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> GovernanceSignaturesReader<'r> {
+        GovernanceSignaturesReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for MetadataWitness {
-    type Builder = MetadataWitnessBuilder;
-    const NAME: &'static str = "MetadataWitness";
+impl molecule::prelude::Entity for GovernanceSignatures {
+    type Builder = GovernanceSignaturesBuilder;
+    const NAME: &'static str = "GovernanceSignatures";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        MetadataWitness(data)
+        GovernanceSignatures(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -3198,23 +5098,23 @@ impl molecule::prelude::Entity for MetadataWitness {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        MetadataWitnessReader::from_slice(slice).map(|reader| reader.to_entity())
+        GovernanceSignaturesReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        MetadataWitnessReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        GovernanceSignaturesReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .new_propose_proof(self.new_propose_proof())
-            .smt_election_info(self.smt_election_info())
+            .signatures(self.signatures())
+            .bitmap(self.bitmap())
     }
 }
 #[derive(Clone, Copy)]
-pub struct MetadataWitnessReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for MetadataWitnessReader<'r> {
+pub struct GovernanceSignaturesReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for GovernanceSignaturesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -3223,16 +5123,16 @@ impl<'r> ::core::fmt::LowerHex for MetadataWitnessReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for MetadataWitnessReader<'r> {
+impl<'r> ::core::fmt::Debug for GovernanceSignaturesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for MetadataWitnessReader<'r> {
+impl<'r> ::core::fmt::Display for GovernanceSignaturesReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "new_propose_proof", self.new_propose_proof())?;
-        write!(f, ", {}: {}", "smt_election_info", self.smt_election_info())?;
+        write!(f, "{}: {}", "signatures", self.signatures())?;
+        write!(f, ", {}: {}", "bitmap", self.bitmap())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -3240,7 +5140,7 @@ impl<'r> ::core::fmt::Display for MetadataWitnessReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> MetadataWitnessReader<'r> {
+impl<'r> GovernanceSignaturesReader<'r> {
     pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
@@ -3258,31 +5158,31 @@ impl<'r> MetadataWitnessReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn new_propose_proof(&self) -> BytesReader<'r> {
+    pub fn signatures(&self) -> BytesVecReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        BytesReader::new_unchecked(&self.as_slice()[start..end])
+        BytesVecReader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn smt_election_info(&self) -> StakeSmtElectionInfoReader<'r> {
+    pub fn bitmap(&self) -> BytesReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
             let end = molecule::unpack_number(&slice[12..]) as usize;
-            StakeSmtElectionInfoReader::new_unchecked(&self.as_slice()[start..end])
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            StakeSmtElectionInfoReader::new_unchecked(&self.as_slice()[start..])
+            BytesReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for MetadataWitnessReader<'r> {
-    type Entity = MetadataWitness;
-    const NAME: &'static str = "MetadataWitnessReader";
+impl<'r> molecule::prelude::Reader<'r> for GovernanceSignaturesReader<'r> {
+    type Entity = GovernanceSignatures;
+    const NAME: &'static str = "GovernanceSignaturesReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        MetadataWitnessReader(slice)
+        GovernanceSignaturesReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -3324,55 +5224,55 @@ impl<'r> molecule::prelude::Reader<'r> for MetadataWitnessReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        StakeSmtElectionInfoReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesVecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct MetadataWitnessBuilder {
-    pub(crate) new_propose_proof: Bytes,
-    pub(crate) smt_election_info: StakeSmtElectionInfo,
+pub struct GovernanceSignaturesBuilder {
+    pub(crate) signatures: BytesVec,
+    pub(crate) bitmap: Bytes,
 }
-impl MetadataWitnessBuilder {
+impl GovernanceSignaturesBuilder {
     pub const FIELD_COUNT: usize = 2;
-    pub fn new_propose_proof(mut self, v: Bytes) -> Self {
-        self.new_propose_proof = v;
+    pub fn signatures(mut self, v: BytesVec) -> Self {
+        self.signatures = v;
         self
     }
-    pub fn smt_election_info(mut self, v: StakeSmtElectionInfo) -> Self {
-        self.smt_election_info = v;
+    pub fn bitmap(mut self, v: Bytes) -> Self {
+        self.bitmap = v;
         self
     }
 }
-impl molecule::prelude::Builder for MetadataWitnessBuilder {
-    type Entity = MetadataWitness;
-    const NAME: &'static str = "MetadataWitnessBuilder";
+impl molecule::prelude::Builder for GovernanceSignaturesBuilder {
+    type Entity = GovernanceSignatures;
+    const NAME: &'static str = "GovernanceSignaturesBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.new_propose_proof.as_slice().len()
-            + self.smt_election_info.as_slice().len()
+            + self.signatures.as_slice().len()
+            + self.bitmap.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.new_propose_proof.as_slice().len();
+        total_size += self.signatures.as_slice().len();
         offsets.push(total_size);
-        total_size += self.smt_election_info.as_slice().len();
+        total_size += self.bitmap.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.new_propose_proof.as_slice())?;
-        writer.write_all(self.smt_election_info.as_slice())?;
+        writer.write_all(self.signatures.as_slice())?;
+        writer.write_all(self.bitmap.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        MetadataWitness::new_unchecked(inner.into())
+        GovernanceSignatures::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
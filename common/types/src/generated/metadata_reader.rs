@@ -103,6 +103,53 @@ impl ValidatorList {
     }
 }
 
+pub struct IdentityVec {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for IdentityVec {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl IdentityVec {
+    pub fn len(&self) -> usize {
+        self.cursor.fixvec_length()
+    }
+}
+
+impl IdentityVec {
+    pub fn get(&self, index: usize) -> Vec<u8> {
+        let cur = self.cursor.fixvec_slice_by_index(20, index).unwrap();
+        cur.into()
+    }
+}
+
+pub struct BytesVec {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for BytesVec {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl BytesVec {
+    pub fn len(&self) -> usize {
+        self.cursor.dynvec_length()
+    }
+}
+
+impl BytesVec {
+    pub fn get(&self, index: usize) -> Vec<u8> {
+        let cur = self.cursor.dynvec_slice_by_index(index).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
 pub struct Metadata {
     pub cursor: Cursor,
 }
@@ -413,6 +460,131 @@ impl MetadataCellData {
     }
 }
 
+impl MetadataCellData {
+    pub fn slash_rate(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(9).unwrap();
+        cur.into()
+    }
+}
+
+impl MetadataCellData {
+    pub fn liveness_slash_rate(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(10).unwrap();
+        cur.into()
+    }
+}
+
+impl MetadataCellData {
+    pub fn treasury_lock_hash(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(11).unwrap();
+        cur.into()
+    }
+}
+
+impl MetadataCellData {
+    pub fn governance(&self) -> GovernanceConfig {
+        let cur = self.cursor.table_slice_by_index(12).unwrap();
+        cur.into()
+    }
+}
+
+impl MetadataCellData {
+    pub fn type_ids_upgrade(&self) -> TypeIdsUpgrade {
+        let cur = self.cursor.table_slice_by_index(13).unwrap();
+        cur.into()
+    }
+}
+
+impl MetadataCellData {
+    pub fn unbonding_epochs(&self) -> u64 {
+        let cur = self.cursor.table_slice_by_index(14).unwrap();
+        cur.into()
+    }
+}
+
+impl MetadataCellData {
+    pub fn inauguration_delay(&self) -> u64 {
+        let cur = self.cursor.table_slice_by_index(15).unwrap();
+        cur.into()
+    }
+}
+
+impl MetadataCellData {
+    pub fn commission_smt_root(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(16).unwrap();
+        cur.into()
+    }
+}
+
+impl MetadataCellData {
+    pub fn reward_params_smt_root(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(17).unwrap();
+        cur.into()
+    }
+}
+
+pub struct TypeIdsUpgrade {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for TypeIdsUpgrade {
+    fn from(cursor: Cursor) -> Self {
+        TypeIdsUpgrade { cursor }
+    }
+}
+
+impl TypeIdsUpgrade {
+    pub fn effective_epoch(&self) -> u64 {
+        let cur = self.cursor.table_slice_by_index(0).unwrap();
+        cur.into()
+    }
+}
+
+impl TypeIdsUpgrade {
+    pub fn type_ids(&self) -> TypeIds {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        cur.into()
+    }
+}
+
+pub struct GovernanceConfig {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for GovernanceConfig {
+    fn from(cursor: Cursor) -> Self {
+        GovernanceConfig { cursor }
+    }
+}
+
+impl GovernanceConfig {
+    pub fn mode(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(0).unwrap();
+        cur.into()
+    }
+}
+
+impl GovernanceConfig {
+    pub fn threshold(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        cur.into()
+    }
+}
+
+impl GovernanceConfig {
+    pub fn members(&self) -> IdentityVec {
+        let cur = self.cursor.table_slice_by_index(2).unwrap();
+        cur.into()
+    }
+}
+
+impl GovernanceConfig {
+    pub fn upgrade_delay(&self) -> u64 {
+        let cur = self.cursor.table_slice_by_index(3).unwrap();
+        cur.into()
+    }
+}
+
 pub struct MetadataArgs {
     pub cursor: Cursor,
 }
@@ -441,8 +613,15 @@ impl From<Cursor> for MetadataWitness {
 }
 
 impl MetadataWitness {
-    pub fn new_propose_proof(&self) -> Vec<u8> {
+    pub fn mode(&self) -> u8 {
         let cur = self.cursor.table_slice_by_index(0).unwrap();
+        cur.into()
+    }
+}
+
+impl MetadataWitness {
+    pub fn new_propose_proof(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
         let cur2 = cur.convert_to_rawbytes().unwrap();
         cur2.into()
     }
@@ -450,11 +629,59 @@ impl MetadataWitness {
 
 impl MetadataWitness {
     pub fn smt_election_info(&self) -> StakeSmtElectionInfo {
-        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        let cur = self.cursor.table_slice_by_index(2).unwrap();
         cur.into()
     }
 }
 
+impl MetadataWitness {
+    pub fn governance_sigs(&self) -> GovernanceSignatures {
+        let cur = self.cursor.table_slice_by_index(3).unwrap();
+        cur.into()
+    }
+}
+
+impl MetadataWitness {
+    pub fn new_commission_proof(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(4).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+impl MetadataWitness {
+    pub fn new_reward_params_proof(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(5).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+pub struct GovernanceSignatures {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for GovernanceSignatures {
+    fn from(cursor: Cursor) -> Self {
+        GovernanceSignatures { cursor }
+    }
+}
+
+impl GovernanceSignatures {
+    pub fn signatures(&self) -> BytesVec {
+        let cur = self.cursor.table_slice_by_index(0).unwrap();
+        cur.into()
+    }
+}
+
+impl GovernanceSignatures {
+    pub fn bitmap(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
 pub struct DelegateInfo {
     pub cursor: Cursor,
 }
@@ -24,6 +24,12 @@ impl ::core::fmt::Display for RewardSmtCellData {
         write!(f, "{}: {}", "version", self.version())?;
         write!(f, ", {}: {}", "claim_smt_root", self.claim_smt_root())?;
         write!(f, ", {}: {}", "metadata_type_id", self.metadata_type_id())?;
+        write!(
+            f,
+            ", {}: {}",
+            "beneficiary_smt_root",
+            self.beneficiary_smt_root()
+        )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -34,15 +40,17 @@ impl ::core::fmt::Display for RewardSmtCellData {
 impl ::core::default::Default for RewardSmtCellData {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            81, 0, 0, 0, 16, 0, 0, 0, 17, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            117, 0, 0, 0, 20, 0, 0, 0, 21, 0, 0, 0, 53, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
         ];
         RewardSmtCellData::new_unchecked(v.into())
     }
 }
 impl RewardSmtCellData {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -74,8 +82,14 @@ impl RewardSmtCellData {
     pub fn metadata_type_id(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn beneficiary_smt_root(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
+            let end = molecule::unpack_number(&slice[20..]) as usize;
             Byte32::new_unchecked(self.0.slice(start..end))
         } else {
             Byte32::new_unchecked(self.0.slice(start..))
@@ -111,6 +125,7 @@ impl molecule::prelude::Entity for RewardSmtCellData {
             .version(self.version())
             .claim_smt_root(self.claim_smt_root())
             .metadata_type_id(self.metadata_type_id())
+            .beneficiary_smt_root(self.beneficiary_smt_root())
     }
 }
 #[derive(Clone, Copy)]
@@ -135,6 +150,12 @@ impl<'r> ::core::fmt::Display for RewardSmtCellDataReader<'r> {
         write!(f, "{}: {}", "version", self.version())?;
         write!(f, ", {}: {}", "claim_smt_root", self.claim_smt_root())?;
         write!(f, ", {}: {}", "metadata_type_id", self.metadata_type_id())?;
+        write!(
+            f,
+            ", {}: {}",
+            "beneficiary_smt_root",
+            self.beneficiary_smt_root()
+        )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -143,7 +164,7 @@ impl<'r> ::core::fmt::Display for RewardSmtCellDataReader<'r> {
     }
 }
 impl<'r> RewardSmtCellDataReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -175,8 +196,14 @@ impl<'r> RewardSmtCellDataReader<'r> {
     pub fn metadata_type_id(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn beneficiary_smt_root(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
+            let end = molecule::unpack_number(&slice[20..]) as usize;
             Byte32Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
             Byte32Reader::new_unchecked(&self.as_slice()[start..])
@@ -235,6 +262,7 @@ impl<'r> molecule::prelude::Reader<'r> for RewardSmtCellDataReader<'r> {
         ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
@@ -243,9 +271,10 @@ pub struct RewardSmtCellDataBuilder {
     pub(crate) version: Byte,
     pub(crate) claim_smt_root: Byte32,
     pub(crate) metadata_type_id: Byte32,
+    pub(crate) beneficiary_smt_root: Byte32,
 }
 impl RewardSmtCellDataBuilder {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 4;
     pub fn version(mut self, v: Byte) -> Self {
         self.version = v;
         self
@@ -258,6 +287,10 @@ impl RewardSmtCellDataBuilder {
         self.metadata_type_id = v;
         self
     }
+    pub fn beneficiary_smt_root(mut self, v: Byte32) -> Self {
+        self.beneficiary_smt_root = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for RewardSmtCellDataBuilder {
     type Entity = RewardSmtCellData;
@@ -267,6 +300,7 @@ impl molecule::prelude::Builder for RewardSmtCellDataBuilder {
             + self.version.as_slice().len()
             + self.claim_smt_root.as_slice().len()
             + self.metadata_type_id.as_slice().len()
+            + self.beneficiary_smt_root.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -277,6 +311,8 @@ impl molecule::prelude::Builder for RewardSmtCellDataBuilder {
         total_size += self.claim_smt_root.as_slice().len();
         offsets.push(total_size);
         total_size += self.metadata_type_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.beneficiary_smt_root.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -284,6 +320,7 @@ impl molecule::prelude::Builder for RewardSmtCellDataBuilder {
         writer.write_all(self.version.as_slice())?;
         writer.write_all(self.claim_smt_root.as_slice())?;
         writer.write_all(self.metadata_type_id.as_slice())?;
+        writer.write_all(self.beneficiary_smt_root.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -1195,6 +1232,13 @@ impl ::core::fmt::Display for RewardStakeInfo {
             "delegate_epoch_proof",
             self.delegate_epoch_proof()
         )?;
+        write!(f, ", {}: {}", "commission_rate", self.commission_rate())?;
+        write!(
+            f,
+            ", {}: {}",
+            "delegate_threshold",
+            self.delegate_threshold()
+        )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1205,15 +1249,16 @@ impl ::core::fmt::Display for RewardStakeInfo {
 impl ::core::default::Default for RewardStakeInfo {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            76, 0, 0, 0, 24, 0, 0, 0, 44, 0, 0, 0, 52, 0, 0, 0, 68, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+            101, 0, 0, 0, 32, 0, 0, 0, 52, 0, 0, 0, 60, 0, 0, 0, 76, 0, 0, 0, 80, 0, 0, 0, 84, 0,
+            0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
         RewardStakeInfo::new_unchecked(v.into())
     }
 }
 impl RewardStakeInfo {
-    pub const FIELD_COUNT: usize = 5;
+    pub const FIELD_COUNT: usize = 7;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1257,11 +1302,23 @@ impl RewardStakeInfo {
     pub fn delegate_epoch_proof(&self) -> Bytes {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn commission_rate(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn delegate_threshold(&self) -> Uint128 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[24..]) as usize;
-            Bytes::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[32..]) as usize;
+            Uint128::new_unchecked(self.0.slice(start..end))
         } else {
-            Bytes::new_unchecked(self.0.slice(start..))
+            Uint128::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> RewardStakeInfoReader<'r> {
@@ -1296,6 +1353,8 @@ impl molecule::prelude::Entity for RewardStakeInfo {
             .staker_amount(self.staker_amount())
             .delegate_infos(self.delegate_infos())
             .delegate_epoch_proof(self.delegate_epoch_proof())
+            .commission_rate(self.commission_rate())
+            .delegate_threshold(self.delegate_threshold())
     }
 }
 #[derive(Clone, Copy)]
@@ -1327,6 +1386,13 @@ impl<'r> ::core::fmt::Display for RewardStakeInfoReader<'r> {
             "delegate_epoch_proof",
             self.delegate_epoch_proof()
         )?;
+        write!(f, ", {}: {}", "commission_rate", self.commission_rate())?;
+        write!(
+            f,
+            ", {}: {}",
+            "delegate_threshold",
+            self.delegate_threshold()
+        )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1335,7 +1401,7 @@ impl<'r> ::core::fmt::Display for RewardStakeInfoReader<'r> {
     }
 }
 impl<'r> RewardStakeInfoReader<'r> {
-    pub const FIELD_COUNT: usize = 5;
+    pub const FIELD_COUNT: usize = 7;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1379,11 +1445,23 @@ impl<'r> RewardStakeInfoReader<'r> {
     pub fn delegate_epoch_proof(&self) -> BytesReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn commission_rate(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn delegate_threshold(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[24..]) as usize;
-            BytesReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[32..]) as usize;
+            Uint128Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            BytesReader::new_unchecked(&self.as_slice()[start..])
+            Uint128Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -1441,6 +1519,8 @@ impl<'r> molecule::prelude::Reader<'r> for RewardStakeInfoReader<'r> {
         Uint128Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         RewardDelegateInfosReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         BytesReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        ByteReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
         Ok(())
     }
 }
@@ -1451,9 +1531,11 @@ pub struct RewardStakeInfoBuilder {
     pub(crate) staker_amount: Uint128,
     pub(crate) delegate_infos: RewardDelegateInfos,
     pub(crate) delegate_epoch_proof: Bytes,
+    pub(crate) commission_rate: Byte,
+    pub(crate) delegate_threshold: Uint128,
 }
 impl RewardStakeInfoBuilder {
-    pub const FIELD_COUNT: usize = 5;
+    pub const FIELD_COUNT: usize = 7;
     pub fn validator(mut self, v: Identity) -> Self {
         self.validator = v;
         self
@@ -1474,6 +1556,14 @@ impl RewardStakeInfoBuilder {
         self.delegate_epoch_proof = v;
         self
     }
+    pub fn commission_rate(mut self, v: Byte) -> Self {
+        self.commission_rate = v;
+        self
+    }
+    pub fn delegate_threshold(mut self, v: Uint128) -> Self {
+        self.delegate_threshold = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for RewardStakeInfoBuilder {
     type Entity = RewardStakeInfo;
@@ -1485,6 +1575,8 @@ impl molecule::prelude::Builder for RewardStakeInfoBuilder {
             + self.staker_amount.as_slice().len()
             + self.delegate_infos.as_slice().len()
             + self.delegate_epoch_proof.as_slice().len()
+            + self.commission_rate.as_slice().len()
+            + self.delegate_threshold.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -1499,6 +1591,10 @@ impl molecule::prelude::Builder for RewardStakeInfoBuilder {
         total_size += self.delegate_infos.as_slice().len();
         offsets.push(total_size);
         total_size += self.delegate_epoch_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.commission_rate.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.delegate_threshold.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -1508,6 +1604,8 @@ impl molecule::prelude::Builder for RewardStakeInfoBuilder {
         writer.write_all(self.staker_amount.as_slice())?;
         writer.write_all(self.delegate_infos.as_slice())?;
         writer.write_all(self.delegate_epoch_proof.as_slice())?;
+        writer.write_all(self.commission_rate.as_slice())?;
+        writer.write_all(self.delegate_threshold.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -1860,8 +1958,8 @@ impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for RewardStakeInfosReaderItera
     }
 }
 #[derive(Clone)]
-pub struct EpochRewardStakeInfo(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for EpochRewardStakeInfo {
+pub struct EpochRewardParams(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for EpochRewardParams {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1870,25 +1968,27 @@ impl ::core::fmt::LowerHex for EpochRewardStakeInfo {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for EpochRewardStakeInfo {
+impl ::core::fmt::Debug for EpochRewardParams {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for EpochRewardStakeInfo {
+impl ::core::fmt::Display for EpochRewardParams {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "reward_stake_infos", self.reward_stake_infos())?;
-        write!(f, ", {}: {}", "count_proof", self.count_proof())?;
-        write!(f, ", {}: {}", "count_root", self.count_root())?;
-        write!(f, ", {}: {}", "count_epoch_proof", self.count_epoch_proof())?;
-        write!(f, ", {}: {}", "amount_proof", self.amount_proof())?;
-        write!(f, ", {}: {}", "amount_root", self.amount_root())?;
+        write!(f, "{}: {}", "base_reward", self.base_reward())?;
+        write!(f, ", {}: {}", "half_epoch", self.half_epoch())?;
         write!(
             f,
             ", {}: {}",
-            "amount_epoch_proof",
-            self.amount_epoch_proof()
+            "minimum_normal_propose_count",
+            self.minimum_normal_propose_count()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "propose_discount_rate",
+            self.propose_discount_rate()
         )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
@@ -1897,18 +1997,17 @@ impl ::core::fmt::Display for EpochRewardStakeInfo {
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for EpochRewardStakeInfo {
+impl ::core::default::Default for EpochRewardParams {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            60, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 40, 0, 0, 0, 44, 0, 0, 0, 48, 0, 0, 0, 52, 0, 0,
-            0, 56, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0,
+            53, 0, 0, 0, 20, 0, 0, 0, 36, 0, 0, 0, 44, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
-        EpochRewardStakeInfo::new_unchecked(v.into())
+        EpochRewardParams::new_unchecked(v.into())
     }
 }
-impl EpochRewardStakeInfo {
-    pub const FIELD_COUNT: usize = 7;
+impl EpochRewardParams {
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1925,61 +2024,43 @@ impl EpochRewardStakeInfo {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn reward_stake_infos(&self) -> RewardStakeInfos {
+    pub fn base_reward(&self) -> Uint128 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        RewardStakeInfos::new_unchecked(self.0.slice(start..end))
+        Uint128::new_unchecked(self.0.slice(start..end))
     }
-    pub fn count_proof(&self) -> Bytes {
+    pub fn half_epoch(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        Bytes::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn count_root(&self) -> Bytes {
+    pub fn minimum_normal_propose_count(&self) -> Uint64 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        Bytes::new_unchecked(self.0.slice(start..end))
+        Uint64::new_unchecked(self.0.slice(start..end))
     }
-    pub fn count_epoch_proof(&self) -> Bytes {
+    pub fn propose_discount_rate(&self) -> Byte {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        Bytes::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn amount_proof(&self) -> Bytes {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        Bytes::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn amount_root(&self) -> Bytes {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        Bytes::new_unchecked(self.0.slice(start..end))
-    }
-    pub fn amount_epoch_proof(&self) -> Bytes {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[32..]) as usize;
-            Bytes::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Byte::new_unchecked(self.0.slice(start..end))
         } else {
-            Bytes::new_unchecked(self.0.slice(start..))
+            Byte::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> EpochRewardStakeInfoReader<'r> {
-        EpochRewardStakeInfoReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> EpochRewardParamsReader<'r> {
+        EpochRewardParamsReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for EpochRewardStakeInfo {
-    type Builder = EpochRewardStakeInfoBuilder;
-    const NAME: &'static str = "EpochRewardStakeInfo";
+impl molecule::prelude::Entity for EpochRewardParams {
+    type Builder = EpochRewardParamsBuilder;
+    const NAME: &'static str = "EpochRewardParams";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        EpochRewardStakeInfo(data)
+        EpochRewardParams(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -1988,28 +2069,25 @@ impl molecule::prelude::Entity for EpochRewardStakeInfo {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        EpochRewardStakeInfoReader::from_slice(slice).map(|reader| reader.to_entity())
+        EpochRewardParamsReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        EpochRewardStakeInfoReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        EpochRewardParamsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .reward_stake_infos(self.reward_stake_infos())
-            .count_proof(self.count_proof())
-            .count_root(self.count_root())
-            .count_epoch_proof(self.count_epoch_proof())
-            .amount_proof(self.amount_proof())
-            .amount_root(self.amount_root())
-            .amount_epoch_proof(self.amount_epoch_proof())
+            .base_reward(self.base_reward())
+            .half_epoch(self.half_epoch())
+            .minimum_normal_propose_count(self.minimum_normal_propose_count())
+            .propose_discount_rate(self.propose_discount_rate())
     }
 }
 #[derive(Clone, Copy)]
-pub struct EpochRewardStakeInfoReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for EpochRewardStakeInfoReader<'r> {
+pub struct EpochRewardParamsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for EpochRewardParamsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2018,25 +2096,27 @@ impl<'r> ::core::fmt::LowerHex for EpochRewardStakeInfoReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for EpochRewardStakeInfoReader<'r> {
+impl<'r> ::core::fmt::Debug for EpochRewardParamsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for EpochRewardStakeInfoReader<'r> {
+impl<'r> ::core::fmt::Display for EpochRewardParamsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "reward_stake_infos", self.reward_stake_infos())?;
-        write!(f, ", {}: {}", "count_proof", self.count_proof())?;
-        write!(f, ", {}: {}", "count_root", self.count_root())?;
-        write!(f, ", {}: {}", "count_epoch_proof", self.count_epoch_proof())?;
-        write!(f, ", {}: {}", "amount_proof", self.amount_proof())?;
-        write!(f, ", {}: {}", "amount_root", self.amount_root())?;
+        write!(f, "{}: {}", "base_reward", self.base_reward())?;
+        write!(f, ", {}: {}", "half_epoch", self.half_epoch())?;
         write!(
             f,
             ", {}: {}",
-            "amount_epoch_proof",
-            self.amount_epoch_proof()
+            "minimum_normal_propose_count",
+            self.minimum_normal_propose_count()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "propose_discount_rate",
+            self.propose_discount_rate()
         )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
@@ -2045,8 +2125,8 @@ impl<'r> ::core::fmt::Display for EpochRewardStakeInfoReader<'r> {
         write!(f, " }}")
     }
 }
-impl<'r> EpochRewardStakeInfoReader<'r> {
-    pub const FIELD_COUNT: usize = 7;
+impl<'r> EpochRewardParamsReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -2063,61 +2143,43 @@ impl<'r> EpochRewardStakeInfoReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn reward_stake_infos(&self) -> RewardStakeInfosReader<'r> {
+    pub fn base_reward(&self) -> Uint128Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
         let end = molecule::unpack_number(&slice[8..]) as usize;
-        RewardStakeInfosReader::new_unchecked(&self.as_slice()[start..end])
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn count_proof(&self) -> BytesReader<'r> {
+    pub fn half_epoch(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
         let end = molecule::unpack_number(&slice[12..]) as usize;
-        BytesReader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn count_root(&self) -> BytesReader<'r> {
+    pub fn minimum_normal_propose_count(&self) -> Uint64Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
         let end = molecule::unpack_number(&slice[16..]) as usize;
-        BytesReader::new_unchecked(&self.as_slice()[start..end])
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn count_epoch_proof(&self) -> BytesReader<'r> {
+    pub fn propose_discount_rate(&self) -> ByteReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
-        let end = molecule::unpack_number(&slice[20..]) as usize;
-        BytesReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn amount_proof(&self) -> BytesReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[20..]) as usize;
-        let end = molecule::unpack_number(&slice[24..]) as usize;
-        BytesReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn amount_root(&self) -> BytesReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[24..]) as usize;
-        let end = molecule::unpack_number(&slice[28..]) as usize;
-        BytesReader::new_unchecked(&self.as_slice()[start..end])
-    }
-    pub fn amount_epoch_proof(&self) -> BytesReader<'r> {
-        let slice = self.as_slice();
-        let start = molecule::unpack_number(&slice[28..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[32..]) as usize;
-            BytesReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            ByteReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            BytesReader::new_unchecked(&self.as_slice()[start..])
+            ByteReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for EpochRewardStakeInfoReader<'r> {
-    type Entity = EpochRewardStakeInfo;
-    const NAME: &'static str = "EpochRewardStakeInfoReader";
+impl<'r> molecule::prelude::Reader<'r> for EpochRewardParamsReader<'r> {
+    type Entity = EpochRewardParams;
+    const NAME: &'static str = "EpochRewardParamsReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        EpochRewardStakeInfoReader(slice)
+        EpochRewardParamsReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -2159,110 +2221,3485 @@ impl<'r> molecule::prelude::Reader<'r> for EpochRewardStakeInfoReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        RewardStakeInfosReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
-        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
-        BytesReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
-        BytesReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
-        BytesReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
-        BytesReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        ByteReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct EpochRewardStakeInfoBuilder {
-    pub(crate) reward_stake_infos: RewardStakeInfos,
-    pub(crate) count_proof: Bytes,
-    pub(crate) count_root: Bytes,
-    pub(crate) count_epoch_proof: Bytes,
-    pub(crate) amount_proof: Bytes,
-    pub(crate) amount_root: Bytes,
-    pub(crate) amount_epoch_proof: Bytes,
+pub struct EpochRewardParamsBuilder {
+    pub(crate) base_reward: Uint128,
+    pub(crate) half_epoch: Uint64,
+    pub(crate) minimum_normal_propose_count: Uint64,
+    pub(crate) propose_discount_rate: Byte,
 }
-impl EpochRewardStakeInfoBuilder {
-    pub const FIELD_COUNT: usize = 7;
-    pub fn reward_stake_infos(mut self, v: RewardStakeInfos) -> Self {
-        self.reward_stake_infos = v;
-        self
-    }
-    pub fn count_proof(mut self, v: Bytes) -> Self {
-        self.count_proof = v;
-        self
-    }
-    pub fn count_root(mut self, v: Bytes) -> Self {
-        self.count_root = v;
-        self
-    }
-    pub fn count_epoch_proof(mut self, v: Bytes) -> Self {
-        self.count_epoch_proof = v;
+impl EpochRewardParamsBuilder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn base_reward(mut self, v: Uint128) -> Self {
+        self.base_reward = v;
         self
     }
-    pub fn amount_proof(mut self, v: Bytes) -> Self {
-        self.amount_proof = v;
+    pub fn half_epoch(mut self, v: Uint64) -> Self {
+        self.half_epoch = v;
         self
     }
-    pub fn amount_root(mut self, v: Bytes) -> Self {
-        self.amount_root = v;
+    pub fn minimum_normal_propose_count(mut self, v: Uint64) -> Self {
+        self.minimum_normal_propose_count = v;
         self
     }
-    pub fn amount_epoch_proof(mut self, v: Bytes) -> Self {
-        self.amount_epoch_proof = v;
+    pub fn propose_discount_rate(mut self, v: Byte) -> Self {
+        self.propose_discount_rate = v;
         self
     }
 }
-impl molecule::prelude::Builder for EpochRewardStakeInfoBuilder {
-    type Entity = EpochRewardStakeInfo;
-    const NAME: &'static str = "EpochRewardStakeInfoBuilder";
+impl molecule::prelude::Builder for EpochRewardParamsBuilder {
+    type Entity = EpochRewardParams;
+    const NAME: &'static str = "EpochRewardParamsBuilder";
     fn expected_length(&self) -> usize {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
-            + self.reward_stake_infos.as_slice().len()
-            + self.count_proof.as_slice().len()
-            + self.count_root.as_slice().len()
-            + self.count_epoch_proof.as_slice().len()
-            + self.amount_proof.as_slice().len()
-            + self.amount_root.as_slice().len()
-            + self.amount_epoch_proof.as_slice().len()
+            + self.base_reward.as_slice().len()
+            + self.half_epoch.as_slice().len()
+            + self.minimum_normal_propose_count.as_slice().len()
+            + self.propose_discount_rate.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.reward_stake_infos.as_slice().len();
+        total_size += self.base_reward.as_slice().len();
         offsets.push(total_size);
-        total_size += self.count_proof.as_slice().len();
+        total_size += self.half_epoch.as_slice().len();
         offsets.push(total_size);
-        total_size += self.count_root.as_slice().len();
+        total_size += self.minimum_normal_propose_count.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.propose_discount_rate.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.base_reward.as_slice())?;
+        writer.write_all(self.half_epoch.as_slice())?;
+        writer.write_all(self.minimum_normal_propose_count.as_slice())?;
+        writer.write_all(self.propose_discount_rate.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        EpochRewardParams::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct EpochRewardStakeInfo(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for EpochRewardStakeInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for EpochRewardStakeInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for EpochRewardStakeInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "reward_stake_infos", self.reward_stake_infos())?;
+        write!(f, ", {}: {}", "count_proof", self.count_proof())?;
+        write!(f, ", {}: {}", "count_root", self.count_root())?;
+        write!(f, ", {}: {}", "count_epoch_proof", self.count_epoch_proof())?;
+        write!(f, ", {}: {}", "amount_proof", self.amount_proof())?;
+        write!(f, ", {}: {}", "amount_root", self.amount_root())?;
+        write!(
+            f,
+            ", {}: {}",
+            "amount_epoch_proof",
+            self.amount_epoch_proof()
+        )?;
+        write!(f, ", {}: {}", "commission_proof", self.commission_proof())?;
+        write!(f, ", {}: {}", "commission_root", self.commission_root())?;
+        write!(
+            f,
+            ", {}: {}",
+            "commission_epoch_proof",
+            self.commission_epoch_proof()
+        )?;
+        write!(f, ", {}: {}", "reward_params", self.reward_params())?;
+        write!(
+            f,
+            ", {}: {}",
+            "reward_params_proof",
+            self.reward_params_proof()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for EpochRewardStakeInfo {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            149, 0, 0, 0, 52, 0, 0, 0, 56, 0, 0, 0, 60, 0, 0, 0, 64, 0, 0, 0, 68, 0, 0, 0, 72, 0,
+            0, 0, 76, 0, 0, 0, 80, 0, 0, 0, 84, 0, 0, 0, 88, 0, 0, 0, 92, 0, 0, 0, 145, 0, 0, 0, 4,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 20, 0, 0, 0, 36, 0, 0, 0, 44, 0, 0, 0, 52,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        EpochRewardStakeInfo::new_unchecked(v.into())
+    }
+}
+impl EpochRewardStakeInfo {
+    pub const FIELD_COUNT: usize = 12;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn reward_stake_infos(&self) -> RewardStakeInfos {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        RewardStakeInfos::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn count_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn count_root(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn count_epoch_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn amount_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn amount_root(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn amount_epoch_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn commission_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn commission_root(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn commission_epoch_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn reward_params(&self) -> EpochRewardParams {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        EpochRewardParams::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn reward_params_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[52..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start..end))
+        } else {
+            Bytes::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> EpochRewardStakeInfoReader<'r> {
+        EpochRewardStakeInfoReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for EpochRewardStakeInfo {
+    type Builder = EpochRewardStakeInfoBuilder;
+    const NAME: &'static str = "EpochRewardStakeInfo";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        EpochRewardStakeInfo(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        EpochRewardStakeInfoReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        EpochRewardStakeInfoReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .reward_stake_infos(self.reward_stake_infos())
+            .count_proof(self.count_proof())
+            .count_root(self.count_root())
+            .count_epoch_proof(self.count_epoch_proof())
+            .amount_proof(self.amount_proof())
+            .amount_root(self.amount_root())
+            .amount_epoch_proof(self.amount_epoch_proof())
+            .commission_proof(self.commission_proof())
+            .commission_root(self.commission_root())
+            .commission_epoch_proof(self.commission_epoch_proof())
+            .reward_params(self.reward_params())
+            .reward_params_proof(self.reward_params_proof())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct EpochRewardStakeInfoReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for EpochRewardStakeInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for EpochRewardStakeInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for EpochRewardStakeInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "reward_stake_infos", self.reward_stake_infos())?;
+        write!(f, ", {}: {}", "count_proof", self.count_proof())?;
+        write!(f, ", {}: {}", "count_root", self.count_root())?;
+        write!(f, ", {}: {}", "count_epoch_proof", self.count_epoch_proof())?;
+        write!(f, ", {}: {}", "amount_proof", self.amount_proof())?;
+        write!(f, ", {}: {}", "amount_root", self.amount_root())?;
+        write!(
+            f,
+            ", {}: {}",
+            "amount_epoch_proof",
+            self.amount_epoch_proof()
+        )?;
+        write!(f, ", {}: {}", "commission_proof", self.commission_proof())?;
+        write!(f, ", {}: {}", "commission_root", self.commission_root())?;
+        write!(
+            f,
+            ", {}: {}",
+            "commission_epoch_proof",
+            self.commission_epoch_proof()
+        )?;
+        write!(f, ", {}: {}", "reward_params", self.reward_params())?;
+        write!(
+            f,
+            ", {}: {}",
+            "reward_params_proof",
+            self.reward_params_proof()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> EpochRewardStakeInfoReader<'r> {
+    pub const FIELD_COUNT: usize = 12;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn reward_stake_infos(&self) -> RewardStakeInfosReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        RewardStakeInfosReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn count_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn count_root(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn count_epoch_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn amount_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn amount_root(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn amount_epoch_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn commission_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn commission_root(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn commission_epoch_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn reward_params(&self) -> EpochRewardParamsReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        EpochRewardParamsReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn reward_params_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[52..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for EpochRewardStakeInfoReader<'r> {
+    type Entity = EpochRewardStakeInfo;
+    const NAME: &'static str = "EpochRewardStakeInfoReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        EpochRewardStakeInfoReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        RewardStakeInfosReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        BytesReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        BytesReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        BytesReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        BytesReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        BytesReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        BytesReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        BytesReader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        EpochRewardParamsReader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
+        BytesReader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct EpochRewardStakeInfoBuilder {
+    pub(crate) reward_stake_infos: RewardStakeInfos,
+    pub(crate) count_proof: Bytes,
+    pub(crate) count_root: Bytes,
+    pub(crate) count_epoch_proof: Bytes,
+    pub(crate) amount_proof: Bytes,
+    pub(crate) amount_root: Bytes,
+    pub(crate) amount_epoch_proof: Bytes,
+    pub(crate) commission_proof: Bytes,
+    pub(crate) commission_root: Bytes,
+    pub(crate) commission_epoch_proof: Bytes,
+    pub(crate) reward_params: EpochRewardParams,
+    pub(crate) reward_params_proof: Bytes,
+}
+impl EpochRewardStakeInfoBuilder {
+    pub const FIELD_COUNT: usize = 12;
+    pub fn reward_stake_infos(mut self, v: RewardStakeInfos) -> Self {
+        self.reward_stake_infos = v;
+        self
+    }
+    pub fn count_proof(mut self, v: Bytes) -> Self {
+        self.count_proof = v;
+        self
+    }
+    pub fn count_root(mut self, v: Bytes) -> Self {
+        self.count_root = v;
+        self
+    }
+    pub fn count_epoch_proof(mut self, v: Bytes) -> Self {
+        self.count_epoch_proof = v;
+        self
+    }
+    pub fn amount_proof(mut self, v: Bytes) -> Self {
+        self.amount_proof = v;
+        self
+    }
+    pub fn amount_root(mut self, v: Bytes) -> Self {
+        self.amount_root = v;
+        self
+    }
+    pub fn amount_epoch_proof(mut self, v: Bytes) -> Self {
+        self.amount_epoch_proof = v;
+        self
+    }
+    pub fn commission_proof(mut self, v: Bytes) -> Self {
+        self.commission_proof = v;
+        self
+    }
+    pub fn commission_root(mut self, v: Bytes) -> Self {
+        self.commission_root = v;
+        self
+    }
+    pub fn commission_epoch_proof(mut self, v: Bytes) -> Self {
+        self.commission_epoch_proof = v;
+        self
+    }
+    pub fn reward_params(mut self, v: EpochRewardParams) -> Self {
+        self.reward_params = v;
+        self
+    }
+    pub fn reward_params_proof(mut self, v: Bytes) -> Self {
+        self.reward_params_proof = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for EpochRewardStakeInfoBuilder {
+    type Entity = EpochRewardStakeInfo;
+    const NAME: &'static str = "EpochRewardStakeInfoBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.reward_stake_infos.as_slice().len()
+            + self.count_proof.as_slice().len()
+            + self.count_root.as_slice().len()
+            + self.count_epoch_proof.as_slice().len()
+            + self.amount_proof.as_slice().len()
+            + self.amount_root.as_slice().len()
+            + self.amount_epoch_proof.as_slice().len()
+            + self.commission_proof.as_slice().len()
+            + self.commission_root.as_slice().len()
+            + self.commission_epoch_proof.as_slice().len()
+            + self.reward_params.as_slice().len()
+            + self.reward_params_proof.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.reward_stake_infos.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.count_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.count_root.as_slice().len();
         offsets.push(total_size);
         total_size += self.count_epoch_proof.as_slice().len();
         offsets.push(total_size);
-        total_size += self.amount_proof.as_slice().len();
+        total_size += self.amount_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.amount_root.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.amount_epoch_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.commission_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.commission_root.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.commission_epoch_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.reward_params.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.reward_params_proof.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.reward_stake_infos.as_slice())?;
+        writer.write_all(self.count_proof.as_slice())?;
+        writer.write_all(self.count_root.as_slice())?;
+        writer.write_all(self.count_epoch_proof.as_slice())?;
+        writer.write_all(self.amount_proof.as_slice())?;
+        writer.write_all(self.amount_root.as_slice())?;
+        writer.write_all(self.amount_epoch_proof.as_slice())?;
+        writer.write_all(self.commission_proof.as_slice())?;
+        writer.write_all(self.commission_root.as_slice())?;
+        writer.write_all(self.commission_epoch_proof.as_slice())?;
+        writer.write_all(self.reward_params.as_slice())?;
+        writer.write_all(self.reward_params_proof.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        EpochRewardStakeInfo::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct EpochRewardStakeInfos(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for EpochRewardStakeInfos {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for EpochRewardStakeInfos {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for EpochRewardStakeInfos {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for EpochRewardStakeInfos {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        EpochRewardStakeInfos::new_unchecked(v.into())
+    }
+}
+impl EpochRewardStakeInfos {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<EpochRewardStakeInfo> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> EpochRewardStakeInfo {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            EpochRewardStakeInfo::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            EpochRewardStakeInfo::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> EpochRewardStakeInfosReader<'r> {
+        EpochRewardStakeInfosReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for EpochRewardStakeInfos {
+    type Builder = EpochRewardStakeInfosBuilder;
+    const NAME: &'static str = "EpochRewardStakeInfos";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        EpochRewardStakeInfos(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        EpochRewardStakeInfosReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        EpochRewardStakeInfosReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct EpochRewardStakeInfosReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for EpochRewardStakeInfosReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for EpochRewardStakeInfosReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for EpochRewardStakeInfosReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> EpochRewardStakeInfosReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<EpochRewardStakeInfoReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> EpochRewardStakeInfoReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            EpochRewardStakeInfoReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            EpochRewardStakeInfoReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for EpochRewardStakeInfosReader<'r> {
+    type Entity = EpochRewardStakeInfos;
+    const NAME: &'static str = "EpochRewardStakeInfosReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        EpochRewardStakeInfosReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            EpochRewardStakeInfoReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct EpochRewardStakeInfosBuilder(pub(crate) Vec<EpochRewardStakeInfo>);
+impl EpochRewardStakeInfosBuilder {
+    pub fn set(mut self, v: Vec<EpochRewardStakeInfo>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: EpochRewardStakeInfo) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = EpochRewardStakeInfo>>(
+        mut self,
+        iter: T,
+    ) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(
+        &mut self,
+        index: usize,
+        v: EpochRewardStakeInfo,
+    ) -> Option<EpochRewardStakeInfo> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for EpochRewardStakeInfosBuilder {
+    type Entity = EpochRewardStakeInfos;
+    const NAME: &'static str = "EpochRewardStakeInfosBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        EpochRewardStakeInfos::new_unchecked(inner.into())
+    }
+}
+pub struct EpochRewardStakeInfosIterator(EpochRewardStakeInfos, usize, usize);
+impl ::core::iter::Iterator for EpochRewardStakeInfosIterator {
+    type Item = EpochRewardStakeInfo;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for EpochRewardStakeInfosIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for EpochRewardStakeInfos {
+    type Item = EpochRewardStakeInfo;
+    type IntoIter = EpochRewardStakeInfosIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        EpochRewardStakeInfosIterator(self, 0, len)
+    }
+}
+impl<'r> EpochRewardStakeInfosReader<'r> {
+    pub fn iter<'t>(&'t self) -> EpochRewardStakeInfosReaderIterator<'t, 'r> {
+        EpochRewardStakeInfosReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct EpochRewardStakeInfosReaderIterator<'t, 'r>(
+    &'t EpochRewardStakeInfosReader<'r>,
+    usize,
+    usize,
+);
+impl<'t: 'r, 'r> ::core::iter::Iterator for EpochRewardStakeInfosReaderIterator<'t, 'r> {
+    type Item = EpochRewardStakeInfoReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for EpochRewardStakeInfosReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct BatchStakerInfo(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BatchStakerInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for BatchStakerInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for BatchStakerInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "validator", self.validator())?;
+        write!(
+            f,
+            ", {}: {}",
+            "delegate_epoch_proof",
+            self.delegate_epoch_proof()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for BatchStakerInfo {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            36, 0, 0, 0, 12, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        BatchStakerInfo::new_unchecked(v.into())
+    }
+}
+impl BatchStakerInfo {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn validator(&self) -> Identity {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Identity::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn delegate_epoch_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start..end))
+        } else {
+            Bytes::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BatchStakerInfoReader<'r> {
+        BatchStakerInfoReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for BatchStakerInfo {
+    type Builder = BatchStakerInfoBuilder;
+    const NAME: &'static str = "BatchStakerInfo";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        BatchStakerInfo(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BatchStakerInfoReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BatchStakerInfoReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .validator(self.validator())
+            .delegate_epoch_proof(self.delegate_epoch_proof())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BatchStakerInfoReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BatchStakerInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BatchStakerInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BatchStakerInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "validator", self.validator())?;
+        write!(
+            f,
+            ", {}: {}",
+            "delegate_epoch_proof",
+            self.delegate_epoch_proof()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> BatchStakerInfoReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn validator(&self) -> IdentityReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        IdentityReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn delegate_epoch_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BatchStakerInfoReader<'r> {
+    type Entity = BatchStakerInfo;
+    const NAME: &'static str = "BatchStakerInfoReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BatchStakerInfoReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        IdentityReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct BatchStakerInfoBuilder {
+    pub(crate) validator: Identity,
+    pub(crate) delegate_epoch_proof: Bytes,
+}
+impl BatchStakerInfoBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn validator(mut self, v: Identity) -> Self {
+        self.validator = v;
+        self
+    }
+    pub fn delegate_epoch_proof(mut self, v: Bytes) -> Self {
+        self.delegate_epoch_proof = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for BatchStakerInfoBuilder {
+    type Entity = BatchStakerInfo;
+    const NAME: &'static str = "BatchStakerInfoBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.validator.as_slice().len()
+            + self.delegate_epoch_proof.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.validator.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.delegate_epoch_proof.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.validator.as_slice())?;
+        writer.write_all(self.delegate_epoch_proof.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        BatchStakerInfo::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct BatchStakerInfos(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BatchStakerInfos {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for BatchStakerInfos {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for BatchStakerInfos {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for BatchStakerInfos {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        BatchStakerInfos::new_unchecked(v.into())
+    }
+}
+impl BatchStakerInfos {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<BatchStakerInfo> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> BatchStakerInfo {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            BatchStakerInfo::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            BatchStakerInfo::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BatchStakerInfosReader<'r> {
+        BatchStakerInfosReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for BatchStakerInfos {
+    type Builder = BatchStakerInfosBuilder;
+    const NAME: &'static str = "BatchStakerInfos";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        BatchStakerInfos(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BatchStakerInfosReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BatchStakerInfosReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BatchStakerInfosReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BatchStakerInfosReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BatchStakerInfosReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BatchStakerInfosReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> BatchStakerInfosReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<BatchStakerInfoReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> BatchStakerInfoReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            BatchStakerInfoReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            BatchStakerInfoReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BatchStakerInfosReader<'r> {
+    type Entity = BatchStakerInfos;
+    const NAME: &'static str = "BatchStakerInfosReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BatchStakerInfosReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            BatchStakerInfoReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct BatchStakerInfosBuilder(pub(crate) Vec<BatchStakerInfo>);
+impl BatchStakerInfosBuilder {
+    pub fn set(mut self, v: Vec<BatchStakerInfo>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: BatchStakerInfo) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = BatchStakerInfo>>(
+        mut self,
+        iter: T,
+    ) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: BatchStakerInfo) -> Option<BatchStakerInfo> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for BatchStakerInfosBuilder {
+    type Entity = BatchStakerInfos;
+    const NAME: &'static str = "BatchStakerInfosBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        BatchStakerInfos::new_unchecked(inner.into())
+    }
+}
+pub struct BatchStakerInfosIterator(BatchStakerInfos, usize, usize);
+impl ::core::iter::Iterator for BatchStakerInfosIterator {
+    type Item = BatchStakerInfo;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for BatchStakerInfosIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for BatchStakerInfos {
+    type Item = BatchStakerInfo;
+    type IntoIter = BatchStakerInfosIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        BatchStakerInfosIterator(self, 0, len)
+    }
+}
+impl<'r> BatchStakerInfosReader<'r> {
+    pub fn iter<'t>(&'t self) -> BatchStakerInfosReaderIterator<'t, 'r> {
+        BatchStakerInfosReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct BatchStakerInfosReaderIterator<'t, 'r>(&'t BatchStakerInfosReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for BatchStakerInfosReaderIterator<'t, 'r> {
+    type Item = BatchStakerInfoReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for BatchStakerInfosReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct BatchRewardStakeInfo(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BatchRewardStakeInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for BatchRewardStakeInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for BatchRewardStakeInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "staker_index", self.staker_index())?;
+        write!(f, ", {}: {}", "propose_count", self.propose_count())?;
+        write!(f, ", {}: {}", "staker_amount", self.staker_amount())?;
+        write!(f, ", {}: {}", "delegate_infos", self.delegate_infos())?;
+        write!(f, ", {}: {}", "commission_rate", self.commission_rate())?;
+        write!(
+            f,
+            ", {}: {}",
+            "delegate_threshold",
+            self.delegate_threshold()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for BatchRewardStakeInfo {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            77, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 40, 0, 0, 0, 56, 0, 0, 0, 60, 0, 0, 0, 61, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        BatchRewardStakeInfo::new_unchecked(v.into())
+    }
+}
+impl BatchRewardStakeInfo {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn staker_index(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn propose_count(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn staker_amount(&self) -> Uint128 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint128::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn delegate_infos(&self) -> RewardDelegateInfos {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        RewardDelegateInfos::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn commission_rate(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn delegate_threshold(&self) -> Uint128 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            Uint128::new_unchecked(self.0.slice(start..end))
+        } else {
+            Uint128::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BatchRewardStakeInfoReader<'r> {
+        BatchRewardStakeInfoReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for BatchRewardStakeInfo {
+    type Builder = BatchRewardStakeInfoBuilder;
+    const NAME: &'static str = "BatchRewardStakeInfo";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        BatchRewardStakeInfo(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BatchRewardStakeInfoReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BatchRewardStakeInfoReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .staker_index(self.staker_index())
+            .propose_count(self.propose_count())
+            .staker_amount(self.staker_amount())
+            .delegate_infos(self.delegate_infos())
+            .commission_rate(self.commission_rate())
+            .delegate_threshold(self.delegate_threshold())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BatchRewardStakeInfoReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BatchRewardStakeInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BatchRewardStakeInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BatchRewardStakeInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "staker_index", self.staker_index())?;
+        write!(f, ", {}: {}", "propose_count", self.propose_count())?;
+        write!(f, ", {}: {}", "staker_amount", self.staker_amount())?;
+        write!(f, ", {}: {}", "delegate_infos", self.delegate_infos())?;
+        write!(f, ", {}: {}", "commission_rate", self.commission_rate())?;
+        write!(
+            f,
+            ", {}: {}",
+            "delegate_threshold",
+            self.delegate_threshold()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> BatchRewardStakeInfoReader<'r> {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn staker_index(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn propose_count(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn staker_amount(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn delegate_infos(&self) -> RewardDelegateInfosReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        RewardDelegateInfosReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn commission_rate(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn delegate_threshold(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Uint128Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BatchRewardStakeInfoReader<'r> {
+    type Entity = BatchRewardStakeInfo;
+    const NAME: &'static str = "BatchRewardStakeInfoReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BatchRewardStakeInfoReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        RewardDelegateInfosReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        ByteReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct BatchRewardStakeInfoBuilder {
+    pub(crate) staker_index: Uint32,
+    pub(crate) propose_count: Uint64,
+    pub(crate) staker_amount: Uint128,
+    pub(crate) delegate_infos: RewardDelegateInfos,
+    pub(crate) commission_rate: Byte,
+    pub(crate) delegate_threshold: Uint128,
+}
+impl BatchRewardStakeInfoBuilder {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn staker_index(mut self, v: Uint32) -> Self {
+        self.staker_index = v;
+        self
+    }
+    pub fn propose_count(mut self, v: Uint64) -> Self {
+        self.propose_count = v;
+        self
+    }
+    pub fn staker_amount(mut self, v: Uint128) -> Self {
+        self.staker_amount = v;
+        self
+    }
+    pub fn delegate_infos(mut self, v: RewardDelegateInfos) -> Self {
+        self.delegate_infos = v;
+        self
+    }
+    pub fn commission_rate(mut self, v: Byte) -> Self {
+        self.commission_rate = v;
+        self
+    }
+    pub fn delegate_threshold(mut self, v: Uint128) -> Self {
+        self.delegate_threshold = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for BatchRewardStakeInfoBuilder {
+    type Entity = BatchRewardStakeInfo;
+    const NAME: &'static str = "BatchRewardStakeInfoBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.staker_index.as_slice().len()
+            + self.propose_count.as_slice().len()
+            + self.staker_amount.as_slice().len()
+            + self.delegate_infos.as_slice().len()
+            + self.commission_rate.as_slice().len()
+            + self.delegate_threshold.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.staker_index.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.propose_count.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.staker_amount.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.delegate_infos.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.commission_rate.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.delegate_threshold.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.staker_index.as_slice())?;
+        writer.write_all(self.propose_count.as_slice())?;
+        writer.write_all(self.staker_amount.as_slice())?;
+        writer.write_all(self.delegate_infos.as_slice())?;
+        writer.write_all(self.commission_rate.as_slice())?;
+        writer.write_all(self.delegate_threshold.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        BatchRewardStakeInfo::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct BatchRewardStakeInfos(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BatchRewardStakeInfos {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for BatchRewardStakeInfos {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for BatchRewardStakeInfos {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for BatchRewardStakeInfos {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        BatchRewardStakeInfos::new_unchecked(v.into())
+    }
+}
+impl BatchRewardStakeInfos {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<BatchRewardStakeInfo> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> BatchRewardStakeInfo {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            BatchRewardStakeInfo::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            BatchRewardStakeInfo::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BatchRewardStakeInfosReader<'r> {
+        BatchRewardStakeInfosReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for BatchRewardStakeInfos {
+    type Builder = BatchRewardStakeInfosBuilder;
+    const NAME: &'static str = "BatchRewardStakeInfos";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        BatchRewardStakeInfos(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BatchRewardStakeInfosReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BatchRewardStakeInfosReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BatchRewardStakeInfosReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BatchRewardStakeInfosReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BatchRewardStakeInfosReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BatchRewardStakeInfosReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> BatchRewardStakeInfosReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<BatchRewardStakeInfoReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> BatchRewardStakeInfoReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            BatchRewardStakeInfoReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            BatchRewardStakeInfoReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BatchRewardStakeInfosReader<'r> {
+    type Entity = BatchRewardStakeInfos;
+    const NAME: &'static str = "BatchRewardStakeInfosReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BatchRewardStakeInfosReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            BatchRewardStakeInfoReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct BatchRewardStakeInfosBuilder(pub(crate) Vec<BatchRewardStakeInfo>);
+impl BatchRewardStakeInfosBuilder {
+    pub fn set(mut self, v: Vec<BatchRewardStakeInfo>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: BatchRewardStakeInfo) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = BatchRewardStakeInfo>>(
+        mut self,
+        iter: T,
+    ) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(
+        &mut self,
+        index: usize,
+        v: BatchRewardStakeInfo,
+    ) -> Option<BatchRewardStakeInfo> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for BatchRewardStakeInfosBuilder {
+    type Entity = BatchRewardStakeInfos;
+    const NAME: &'static str = "BatchRewardStakeInfosBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        BatchRewardStakeInfos::new_unchecked(inner.into())
+    }
+}
+pub struct BatchRewardStakeInfosIterator(BatchRewardStakeInfos, usize, usize);
+impl ::core::iter::Iterator for BatchRewardStakeInfosIterator {
+    type Item = BatchRewardStakeInfo;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for BatchRewardStakeInfosIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for BatchRewardStakeInfos {
+    type Item = BatchRewardStakeInfo;
+    type IntoIter = BatchRewardStakeInfosIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        BatchRewardStakeInfosIterator(self, 0, len)
+    }
+}
+impl<'r> BatchRewardStakeInfosReader<'r> {
+    pub fn iter<'t>(&'t self) -> BatchRewardStakeInfosReaderIterator<'t, 'r> {
+        BatchRewardStakeInfosReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct BatchRewardStakeInfosReaderIterator<'t, 'r>(
+    &'t BatchRewardStakeInfosReader<'r>,
+    usize,
+    usize,
+);
+impl<'t: 'r, 'r> ::core::iter::Iterator for BatchRewardStakeInfosReaderIterator<'t, 'r> {
+    type Item = BatchRewardStakeInfoReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for BatchRewardStakeInfosReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct BatchEpochRewardInfo(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BatchEpochRewardInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for BatchEpochRewardInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for BatchEpochRewardInfo {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "reward_stake_infos", self.reward_stake_infos())?;
+        write!(f, ", {}: {}", "count_proof", self.count_proof())?;
+        write!(f, ", {}: {}", "count_root", self.count_root())?;
+        write!(f, ", {}: {}", "amount_proof", self.amount_proof())?;
+        write!(f, ", {}: {}", "amount_root", self.amount_root())?;
+        write!(f, ", {}: {}", "commission_proof", self.commission_proof())?;
+        write!(f, ", {}: {}", "commission_root", self.commission_root())?;
+        write!(f, ", {}: {}", "reward_params", self.reward_params())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for BatchEpochRewardInfo {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            117, 0, 0, 0, 36, 0, 0, 0, 40, 0, 0, 0, 44, 0, 0, 0, 48, 0, 0, 0, 52, 0, 0, 0, 56, 0,
+            0, 0, 60, 0, 0, 0, 64, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 20, 0, 0, 0, 36, 0, 0, 0, 44, 0, 0, 0, 52,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ];
+        BatchEpochRewardInfo::new_unchecked(v.into())
+    }
+}
+impl BatchEpochRewardInfo {
+    pub const FIELD_COUNT: usize = 8;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn reward_stake_infos(&self) -> BatchRewardStakeInfos {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BatchRewardStakeInfos::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn count_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn count_root(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn amount_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn amount_root(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn commission_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn commission_root(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn reward_params(&self) -> EpochRewardParams {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[36..]) as usize;
+            EpochRewardParams::new_unchecked(self.0.slice(start..end))
+        } else {
+            EpochRewardParams::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BatchEpochRewardInfoReader<'r> {
+        BatchEpochRewardInfoReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for BatchEpochRewardInfo {
+    type Builder = BatchEpochRewardInfoBuilder;
+    const NAME: &'static str = "BatchEpochRewardInfo";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        BatchEpochRewardInfo(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BatchEpochRewardInfoReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BatchEpochRewardInfoReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .reward_stake_infos(self.reward_stake_infos())
+            .count_proof(self.count_proof())
+            .count_root(self.count_root())
+            .amount_proof(self.amount_proof())
+            .amount_root(self.amount_root())
+            .commission_proof(self.commission_proof())
+            .commission_root(self.commission_root())
+            .reward_params(self.reward_params())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BatchEpochRewardInfoReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BatchEpochRewardInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BatchEpochRewardInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BatchEpochRewardInfoReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "reward_stake_infos", self.reward_stake_infos())?;
+        write!(f, ", {}: {}", "count_proof", self.count_proof())?;
+        write!(f, ", {}: {}", "count_root", self.count_root())?;
+        write!(f, ", {}: {}", "amount_proof", self.amount_proof())?;
+        write!(f, ", {}: {}", "amount_root", self.amount_root())?;
+        write!(f, ", {}: {}", "commission_proof", self.commission_proof())?;
+        write!(f, ", {}: {}", "commission_root", self.commission_root())?;
+        write!(f, ", {}: {}", "reward_params", self.reward_params())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> BatchEpochRewardInfoReader<'r> {
+    pub const FIELD_COUNT: usize = 8;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn reward_stake_infos(&self) -> BatchRewardStakeInfosReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BatchRewardStakeInfosReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn count_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn count_root(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn amount_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn amount_root(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn commission_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn commission_root(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn reward_params(&self) -> EpochRewardParamsReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[36..]) as usize;
+            EpochRewardParamsReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            EpochRewardParamsReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BatchEpochRewardInfoReader<'r> {
+    type Entity = BatchEpochRewardInfo;
+    const NAME: &'static str = "BatchEpochRewardInfoReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BatchEpochRewardInfoReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BatchRewardStakeInfosReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        BytesReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        BytesReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        BytesReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        BytesReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        EpochRewardParamsReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct BatchEpochRewardInfoBuilder {
+    pub(crate) reward_stake_infos: BatchRewardStakeInfos,
+    pub(crate) count_proof: Bytes,
+    pub(crate) count_root: Bytes,
+    pub(crate) amount_proof: Bytes,
+    pub(crate) amount_root: Bytes,
+    pub(crate) commission_proof: Bytes,
+    pub(crate) commission_root: Bytes,
+    pub(crate) reward_params: EpochRewardParams,
+}
+impl BatchEpochRewardInfoBuilder {
+    pub const FIELD_COUNT: usize = 8;
+    pub fn reward_stake_infos(mut self, v: BatchRewardStakeInfos) -> Self {
+        self.reward_stake_infos = v;
+        self
+    }
+    pub fn count_proof(mut self, v: Bytes) -> Self {
+        self.count_proof = v;
+        self
+    }
+    pub fn count_root(mut self, v: Bytes) -> Self {
+        self.count_root = v;
+        self
+    }
+    pub fn amount_proof(mut self, v: Bytes) -> Self {
+        self.amount_proof = v;
+        self
+    }
+    pub fn amount_root(mut self, v: Bytes) -> Self {
+        self.amount_root = v;
+        self
+    }
+    pub fn commission_proof(mut self, v: Bytes) -> Self {
+        self.commission_proof = v;
+        self
+    }
+    pub fn commission_root(mut self, v: Bytes) -> Self {
+        self.commission_root = v;
+        self
+    }
+    pub fn reward_params(mut self, v: EpochRewardParams) -> Self {
+        self.reward_params = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for BatchEpochRewardInfoBuilder {
+    type Entity = BatchEpochRewardInfo;
+    const NAME: &'static str = "BatchEpochRewardInfoBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.reward_stake_infos.as_slice().len()
+            + self.count_proof.as_slice().len()
+            + self.count_root.as_slice().len()
+            + self.amount_proof.as_slice().len()
+            + self.amount_root.as_slice().len()
+            + self.commission_proof.as_slice().len()
+            + self.commission_root.as_slice().len()
+            + self.reward_params.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.reward_stake_infos.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.count_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.count_root.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.amount_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.amount_root.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.commission_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.commission_root.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.reward_params.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.reward_stake_infos.as_slice())?;
+        writer.write_all(self.count_proof.as_slice())?;
+        writer.write_all(self.count_root.as_slice())?;
+        writer.write_all(self.amount_proof.as_slice())?;
+        writer.write_all(self.amount_root.as_slice())?;
+        writer.write_all(self.commission_proof.as_slice())?;
+        writer.write_all(self.commission_root.as_slice())?;
+        writer.write_all(self.reward_params.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        BatchEpochRewardInfo::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct BatchEpochRewardInfos(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BatchEpochRewardInfos {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for BatchEpochRewardInfos {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for BatchEpochRewardInfos {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for BatchEpochRewardInfos {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        BatchEpochRewardInfos::new_unchecked(v.into())
+    }
+}
+impl BatchEpochRewardInfos {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<BatchEpochRewardInfo> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> BatchEpochRewardInfo {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            BatchEpochRewardInfo::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            BatchEpochRewardInfo::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BatchEpochRewardInfosReader<'r> {
+        BatchEpochRewardInfosReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for BatchEpochRewardInfos {
+    type Builder = BatchEpochRewardInfosBuilder;
+    const NAME: &'static str = "BatchEpochRewardInfos";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        BatchEpochRewardInfos(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BatchEpochRewardInfosReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BatchEpochRewardInfosReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BatchEpochRewardInfosReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BatchEpochRewardInfosReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BatchEpochRewardInfosReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BatchEpochRewardInfosReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> BatchEpochRewardInfosReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<BatchEpochRewardInfoReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> BatchEpochRewardInfoReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            BatchEpochRewardInfoReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            BatchEpochRewardInfoReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BatchEpochRewardInfosReader<'r> {
+    type Entity = BatchEpochRewardInfos;
+    const NAME: &'static str = "BatchEpochRewardInfosReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BatchEpochRewardInfosReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            BatchEpochRewardInfoReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct BatchEpochRewardInfosBuilder(pub(crate) Vec<BatchEpochRewardInfo>);
+impl BatchEpochRewardInfosBuilder {
+    pub fn set(mut self, v: Vec<BatchEpochRewardInfo>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: BatchEpochRewardInfo) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = BatchEpochRewardInfo>>(
+        mut self,
+        iter: T,
+    ) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(
+        &mut self,
+        index: usize,
+        v: BatchEpochRewardInfo,
+    ) -> Option<BatchEpochRewardInfo> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for BatchEpochRewardInfosBuilder {
+    type Entity = BatchEpochRewardInfos;
+    const NAME: &'static str = "BatchEpochRewardInfosBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        BatchEpochRewardInfos::new_unchecked(inner.into())
+    }
+}
+pub struct BatchEpochRewardInfosIterator(BatchEpochRewardInfos, usize, usize);
+impl ::core::iter::Iterator for BatchEpochRewardInfosIterator {
+    type Item = BatchEpochRewardInfo;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for BatchEpochRewardInfosIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for BatchEpochRewardInfos {
+    type Item = BatchEpochRewardInfo;
+    type IntoIter = BatchEpochRewardInfosIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        BatchEpochRewardInfosIterator(self, 0, len)
+    }
+}
+impl<'r> BatchEpochRewardInfosReader<'r> {
+    pub fn iter<'t>(&'t self) -> BatchEpochRewardInfosReaderIterator<'t, 'r> {
+        BatchEpochRewardInfosReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct BatchEpochRewardInfosReaderIterator<'t, 'r>(
+    &'t BatchEpochRewardInfosReader<'r>,
+    usize,
+    usize,
+);
+impl<'t: 'r, 'r> ::core::iter::Iterator for BatchEpochRewardInfosReaderIterator<'t, 'r> {
+    type Item = BatchEpochRewardInfoReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for BatchEpochRewardInfosReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct BatchRewardInfos(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BatchRewardInfos {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for BatchRewardInfos {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for BatchRewardInfos {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "staker_infos", self.staker_infos())?;
+        write!(f, ", {}: {}", "epoch_infos", self.epoch_infos())?;
+        write!(f, ", {}: {}", "count_epoch_proof", self.count_epoch_proof())?;
+        write!(
+            f,
+            ", {}: {}",
+            "amount_epoch_proof",
+            self.amount_epoch_proof()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "commission_epoch_proof",
+            self.commission_epoch_proof()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "reward_params_proof",
+            self.reward_params_proof()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for BatchRewardInfos {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            52, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 40, 0, 0, 0, 44, 0, 0, 0, 48, 0, 0,
+            0, 4, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        BatchRewardInfos::new_unchecked(v.into())
+    }
+}
+impl BatchRewardInfos {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn staker_infos(&self) -> BatchStakerInfos {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BatchStakerInfos::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn epoch_infos(&self) -> BatchEpochRewardInfos {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        BatchEpochRewardInfos::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn count_epoch_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn amount_epoch_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn commission_epoch_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn reward_params_proof(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start..end))
+        } else {
+            Bytes::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BatchRewardInfosReader<'r> {
+        BatchRewardInfosReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for BatchRewardInfos {
+    type Builder = BatchRewardInfosBuilder;
+    const NAME: &'static str = "BatchRewardInfos";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        BatchRewardInfos(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BatchRewardInfosReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BatchRewardInfosReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .staker_infos(self.staker_infos())
+            .epoch_infos(self.epoch_infos())
+            .count_epoch_proof(self.count_epoch_proof())
+            .amount_epoch_proof(self.amount_epoch_proof())
+            .commission_epoch_proof(self.commission_epoch_proof())
+            .reward_params_proof(self.reward_params_proof())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BatchRewardInfosReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BatchRewardInfosReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BatchRewardInfosReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BatchRewardInfosReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "staker_infos", self.staker_infos())?;
+        write!(f, ", {}: {}", "epoch_infos", self.epoch_infos())?;
+        write!(f, ", {}: {}", "count_epoch_proof", self.count_epoch_proof())?;
+        write!(
+            f,
+            ", {}: {}",
+            "amount_epoch_proof",
+            self.amount_epoch_proof()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "commission_epoch_proof",
+            self.commission_epoch_proof()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "reward_params_proof",
+            self.reward_params_proof()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> BatchRewardInfosReader<'r> {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn staker_infos(&self) -> BatchStakerInfosReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BatchStakerInfosReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn epoch_infos(&self) -> BatchEpochRewardInfosReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        BatchEpochRewardInfosReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn count_epoch_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn amount_epoch_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn commission_epoch_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn reward_params_proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BatchRewardInfosReader<'r> {
+    type Entity = BatchRewardInfos;
+    const NAME: &'static str = "BatchRewardInfosReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BatchRewardInfosReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BatchStakerInfosReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BatchEpochRewardInfosReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        BytesReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        BytesReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        BytesReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct BatchRewardInfosBuilder {
+    pub(crate) staker_infos: BatchStakerInfos,
+    pub(crate) epoch_infos: BatchEpochRewardInfos,
+    pub(crate) count_epoch_proof: Bytes,
+    pub(crate) amount_epoch_proof: Bytes,
+    pub(crate) commission_epoch_proof: Bytes,
+    pub(crate) reward_params_proof: Bytes,
+}
+impl BatchRewardInfosBuilder {
+    pub const FIELD_COUNT: usize = 6;
+    pub fn staker_infos(mut self, v: BatchStakerInfos) -> Self {
+        self.staker_infos = v;
+        self
+    }
+    pub fn epoch_infos(mut self, v: BatchEpochRewardInfos) -> Self {
+        self.epoch_infos = v;
+        self
+    }
+    pub fn count_epoch_proof(mut self, v: Bytes) -> Self {
+        self.count_epoch_proof = v;
+        self
+    }
+    pub fn amount_epoch_proof(mut self, v: Bytes) -> Self {
+        self.amount_epoch_proof = v;
+        self
+    }
+    pub fn commission_epoch_proof(mut self, v: Bytes) -> Self {
+        self.commission_epoch_proof = v;
+        self
+    }
+    pub fn reward_params_proof(mut self, v: Bytes) -> Self {
+        self.reward_params_proof = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for BatchRewardInfosBuilder {
+    type Entity = BatchRewardInfos;
+    const NAME: &'static str = "BatchRewardInfosBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.staker_infos.as_slice().len()
+            + self.epoch_infos.as_slice().len()
+            + self.count_epoch_proof.as_slice().len()
+            + self.amount_epoch_proof.as_slice().len()
+            + self.commission_epoch_proof.as_slice().len()
+            + self.reward_params_proof.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.amount_root.as_slice().len();
+        total_size += self.staker_infos.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.epoch_infos.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.count_epoch_proof.as_slice().len();
         offsets.push(total_size);
         total_size += self.amount_epoch_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.commission_epoch_proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.reward_params_proof.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.reward_stake_infos.as_slice())?;
-        writer.write_all(self.count_proof.as_slice())?;
-        writer.write_all(self.count_root.as_slice())?;
+        writer.write_all(self.staker_infos.as_slice())?;
+        writer.write_all(self.epoch_infos.as_slice())?;
         writer.write_all(self.count_epoch_proof.as_slice())?;
-        writer.write_all(self.amount_proof.as_slice())?;
-        writer.write_all(self.amount_root.as_slice())?;
         writer.write_all(self.amount_epoch_proof.as_slice())?;
+        writer.write_all(self.commission_epoch_proof.as_slice())?;
+        writer.write_all(self.reward_params_proof.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        EpochRewardStakeInfo::new_unchecked(inner.into())
+        BatchRewardInfos::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct EpochRewardStakeInfos(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for EpochRewardStakeInfos {
+pub struct RewardBeneficiary(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for RewardBeneficiary {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2271,75 +5708,100 @@ impl ::core::fmt::LowerHex for EpochRewardStakeInfos {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for EpochRewardStakeInfos {
+impl ::core::fmt::Debug for RewardBeneficiary {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for EpochRewardStakeInfos {
+impl ::core::fmt::Display for RewardBeneficiary {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "lock_hash", self.lock_hash())?;
+        write!(f, ", {}: {}", "proof", self.proof())?;
+        write!(f, ", {}: {}", "new_lock_hash", self.new_lock_hash())?;
+        write!(f, ", {}: {}", "eth_sig", self.eth_sig())?;
+        write!(f, ", {}: {}", "sig_mode", self.sig_mode())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
-        write!(f, "]")
+        write!(f, " }}")
     }
 }
-impl ::core::default::Default for EpochRewardStakeInfos {
+impl ::core::default::Default for RewardBeneficiary {
     fn default() -> Self {
-        let v: Vec<u8> = vec![4, 0, 0, 0];
-        EpochRewardStakeInfos::new_unchecked(v.into())
+        let v: Vec<u8> = vec![
+            158, 0, 0, 0, 24, 0, 0, 0, 56, 0, 0, 0, 60, 0, 0, 0, 92, 0, 0, 0, 157, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        RewardBeneficiary::new_unchecked(v.into())
     }
 }
-impl EpochRewardStakeInfos {
+impl RewardBeneficiary {
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn item_count(&self) -> usize {
+    pub fn field_count(&self) -> usize {
         if self.total_size() == molecule::NUMBER_SIZE {
             0
         } else {
             (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn get(&self, idx: usize) -> Option<EpochRewardStakeInfo> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn lock_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn get_unchecked(&self, idx: usize) -> EpochRewardStakeInfo {
+    pub fn proof(&self) -> Bytes {
         let slice = self.as_slice();
-        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
-        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
-        if idx == self.len() - 1 {
-            EpochRewardStakeInfo::new_unchecked(self.0.slice(start..))
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn new_lock_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn eth_sig(&self) -> Byte65 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte65::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sig_mode(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            Byte::new_unchecked(self.0.slice(start..end))
         } else {
-            let end_idx = start_idx + molecule::NUMBER_SIZE;
-            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
-            EpochRewardStakeInfo::new_unchecked(self.0.slice(start..end))
+            Byte::new_unchecked(self.0.slice(start..))
         }
     }
-    pub fn as_reader<'r>(&'r self) -> EpochRewardStakeInfosReader<'r> {
-        EpochRewardStakeInfosReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> RewardBeneficiaryReader<'r> {
+        RewardBeneficiaryReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for EpochRewardStakeInfos {
-    type Builder = EpochRewardStakeInfosBuilder;
-    const NAME: &'static str = "EpochRewardStakeInfos";
+impl molecule::prelude::Entity for RewardBeneficiary {
+    type Builder = RewardBeneficiaryBuilder;
+    const NAME: &'static str = "RewardBeneficiary";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        EpochRewardStakeInfos(data)
+        RewardBeneficiary(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -2348,21 +5810,26 @@ impl molecule::prelude::Entity for EpochRewardStakeInfos {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        EpochRewardStakeInfosReader::from_slice(slice).map(|reader| reader.to_entity())
+        RewardBeneficiaryReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        EpochRewardStakeInfosReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        RewardBeneficiaryReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().extend(self.into_iter())
+        Self::new_builder()
+            .lock_hash(self.lock_hash())
+            .proof(self.proof())
+            .new_lock_hash(self.new_lock_hash())
+            .eth_sig(self.eth_sig())
+            .sig_mode(self.sig_mode())
     }
 }
 #[derive(Clone, Copy)]
-pub struct EpochRewardStakeInfosReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for EpochRewardStakeInfosReader<'r> {
+pub struct RewardBeneficiaryReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for RewardBeneficiaryReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -2371,69 +5838,87 @@ impl<'r> ::core::fmt::LowerHex for EpochRewardStakeInfosReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for EpochRewardStakeInfosReader<'r> {
+impl<'r> ::core::fmt::Debug for RewardBeneficiaryReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for EpochRewardStakeInfosReader<'r> {
+impl<'r> ::core::fmt::Display for RewardBeneficiaryReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "lock_hash", self.lock_hash())?;
+        write!(f, ", {}: {}", "proof", self.proof())?;
+        write!(f, ", {}: {}", "new_lock_hash", self.new_lock_hash())?;
+        write!(f, ", {}: {}", "eth_sig", self.eth_sig())?;
+        write!(f, ", {}: {}", "sig_mode", self.sig_mode())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
         }
-        write!(f, "]")
+        write!(f, " }}")
     }
 }
-impl<'r> EpochRewardStakeInfosReader<'r> {
+impl<'r> RewardBeneficiaryReader<'r> {
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn item_count(&self) -> usize {
+    pub fn field_count(&self) -> usize {
         if self.total_size() == molecule::NUMBER_SIZE {
             0
         } else {
             (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
         }
     }
-    pub fn len(&self) -> usize {
-        self.item_count()
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn lock_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn proof(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn new_lock_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn get(&self, idx: usize) -> Option<EpochRewardStakeInfoReader<'r>> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+    pub fn eth_sig(&self) -> Byte65Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte65Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn get_unchecked(&self, idx: usize) -> EpochRewardStakeInfoReader<'r> {
+    pub fn sig_mode(&self) -> ByteReader<'r> {
         let slice = self.as_slice();
-        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
-        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
-        if idx == self.len() - 1 {
-            EpochRewardStakeInfoReader::new_unchecked(&self.as_slice()[start..])
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            ByteReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            let end_idx = start_idx + molecule::NUMBER_SIZE;
-            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
-            EpochRewardStakeInfoReader::new_unchecked(&self.as_slice()[start..end])
+            ByteReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for EpochRewardStakeInfosReader<'r> {
-    type Entity = EpochRewardStakeInfos;
-    const NAME: &'static str = "EpochRewardStakeInfosReader";
+impl<'r> molecule::prelude::Reader<'r> for RewardBeneficiaryReader<'r> {
+    type Entity = RewardBeneficiary;
+    const NAME: &'static str = "RewardBeneficiaryReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        EpochRewardStakeInfosReader(slice)
+        RewardBeneficiaryReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -2448,16 +5933,11 @@ impl<'r> molecule::prelude::Reader<'r> for EpochRewardStakeInfosReader<'r> {
         if slice_len != total_size {
             return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
         }
-        if slice_len == molecule::NUMBER_SIZE {
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
             return Ok(());
         }
         if slice_len < molecule::NUMBER_SIZE * 2 {
-            return ve!(
-                Self,
-                TotalSizeNotMatch,
-                molecule::NUMBER_SIZE * 2,
-                slice_len
-            );
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
         }
         let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
         if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
@@ -2466,6 +5946,12 @@ impl<'r> molecule::prelude::Reader<'r> for EpochRewardStakeInfosReader<'r> {
         if slice_len < offset_first {
             return ve!(Self, HeaderIsBroken, offset_first, slice_len);
         }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
         let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
             .chunks_exact(molecule::NUMBER_SIZE)
             .map(|x| molecule::unpack_number(x) as usize)
@@ -2474,140 +5960,85 @@ impl<'r> molecule::prelude::Reader<'r> for EpochRewardStakeInfosReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        for pair in offsets.windows(2) {
-            let start = pair[0];
-            let end = pair[1];
-            EpochRewardStakeInfoReader::verify(&slice[start..end], compatible)?;
-        }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        BytesReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte65Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        ByteReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
-pub struct EpochRewardStakeInfosBuilder(pub(crate) Vec<EpochRewardStakeInfo>);
-impl EpochRewardStakeInfosBuilder {
-    pub fn set(mut self, v: Vec<EpochRewardStakeInfo>) -> Self {
-        self.0 = v;
+pub struct RewardBeneficiaryBuilder {
+    pub(crate) lock_hash: Byte32,
+    pub(crate) proof: Bytes,
+    pub(crate) new_lock_hash: Byte32,
+    pub(crate) eth_sig: Byte65,
+    pub(crate) sig_mode: Byte,
+}
+impl RewardBeneficiaryBuilder {
+    pub const FIELD_COUNT: usize = 5;
+    pub fn lock_hash(mut self, v: Byte32) -> Self {
+        self.lock_hash = v;
         self
     }
-    pub fn push(mut self, v: EpochRewardStakeInfo) -> Self {
-        self.0.push(v);
+    pub fn proof(mut self, v: Bytes) -> Self {
+        self.proof = v;
         self
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = EpochRewardStakeInfo>>(
-        mut self,
-        iter: T,
-    ) -> Self {
-        for elem in iter {
-            self.0.push(elem);
-        }
+    pub fn new_lock_hash(mut self, v: Byte32) -> Self {
+        self.new_lock_hash = v;
         self
     }
-    pub fn replace(
-        &mut self,
-        index: usize,
-        v: EpochRewardStakeInfo,
-    ) -> Option<EpochRewardStakeInfo> {
-        self.0
-            .get_mut(index)
-            .map(|item| ::core::mem::replace(item, v))
+    pub fn eth_sig(mut self, v: Byte65) -> Self {
+        self.eth_sig = v;
+        self
+    }
+    pub fn sig_mode(mut self, v: Byte) -> Self {
+        self.sig_mode = v;
+        self
     }
 }
-impl molecule::prelude::Builder for EpochRewardStakeInfosBuilder {
-    type Entity = EpochRewardStakeInfos;
-    const NAME: &'static str = "EpochRewardStakeInfosBuilder";
+impl molecule::prelude::Builder for RewardBeneficiaryBuilder {
+    type Entity = RewardBeneficiary;
+    const NAME: &'static str = "RewardBeneficiaryBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (self.0.len() + 1)
-            + self
-                .0
-                .iter()
-                .map(|inner| inner.as_slice().len())
-                .sum::<usize>()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.lock_hash.as_slice().len()
+            + self.proof.as_slice().len()
+            + self.new_lock_hash.as_slice().len()
+            + self.eth_sig.as_slice().len()
+            + self.sig_mode.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        let item_count = self.0.len();
-        if item_count == 0 {
-            writer.write_all(&molecule::pack_number(
-                molecule::NUMBER_SIZE as molecule::Number,
-            ))?;
-        } else {
-            let (total_size, offsets) = self.0.iter().fold(
-                (
-                    molecule::NUMBER_SIZE * (item_count + 1),
-                    Vec::with_capacity(item_count),
-                ),
-                |(start, mut offsets), inner| {
-                    offsets.push(start);
-                    (start + inner.as_slice().len(), offsets)
-                },
-            );
-            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
-            for offset in offsets.into_iter() {
-                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
-            }
-            for inner in self.0.iter() {
-                writer.write_all(inner.as_slice())?;
-            }
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.lock_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.proof.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.new_lock_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.eth_sig.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sig_mode.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
+        writer.write_all(self.lock_hash.as_slice())?;
+        writer.write_all(self.proof.as_slice())?;
+        writer.write_all(self.new_lock_hash.as_slice())?;
+        writer.write_all(self.eth_sig.as_slice())?;
+        writer.write_all(self.sig_mode.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        EpochRewardStakeInfos::new_unchecked(inner.into())
-    }
-}
-pub struct EpochRewardStakeInfosIterator(EpochRewardStakeInfos, usize, usize);
-impl ::core::iter::Iterator for EpochRewardStakeInfosIterator {
-    type Item = EpochRewardStakeInfo;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl ::core::iter::ExactSizeIterator for EpochRewardStakeInfosIterator {
-    fn len(&self) -> usize {
-        self.2 - self.1
-    }
-}
-impl ::core::iter::IntoIterator for EpochRewardStakeInfos {
-    type Item = EpochRewardStakeInfo;
-    type IntoIter = EpochRewardStakeInfosIterator;
-    fn into_iter(self) -> Self::IntoIter {
-        let len = self.len();
-        EpochRewardStakeInfosIterator(self, 0, len)
-    }
-}
-impl<'r> EpochRewardStakeInfosReader<'r> {
-    pub fn iter<'t>(&'t self) -> EpochRewardStakeInfosReaderIterator<'t, 'r> {
-        EpochRewardStakeInfosReaderIterator(&self, 0, self.len())
-    }
-}
-pub struct EpochRewardStakeInfosReaderIterator<'t, 'r>(
-    &'t EpochRewardStakeInfosReader<'r>,
-    usize,
-    usize,
-);
-impl<'t: 'r, 'r> ::core::iter::Iterator for EpochRewardStakeInfosReaderIterator<'t, 'r> {
-    type Item = EpochRewardStakeInfoReader<'t>;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for EpochRewardStakeInfosReaderIterator<'t, 'r> {
-    fn len(&self) -> usize {
-        self.2 - self.1
+        RewardBeneficiary::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
@@ -2643,6 +6074,17 @@ impl ::core::fmt::Display for RewardWitness {
             "new_not_claim_info",
             self.new_not_claim_info()
         )?;
+        write!(
+            f,
+            ", {}: {}",
+            "batch_reward_infos",
+            self.batch_reward_infos()
+        )?;
+        write!(f, ", {}: {}", "mode", self.mode())?;
+        write!(f, ", {}: {}", "beneficiary", self.beneficiary())?;
+        write!(f, ", {}: {}", "compound_staker", self.compound_staker())?;
+        write!(f, ", {}: {}", "eth_sig", self.eth_sig())?;
+        write!(f, ", {}: {}", "sig_mode", self.sig_mode())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -2653,16 +6095,27 @@ impl ::core::fmt::Display for RewardWitness {
 impl ::core::default::Default for RewardWitness {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            92, 0, 0, 0, 20, 0, 0, 0, 40, 0, 0, 0, 64, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 24, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0,
+            157, 1, 0, 0, 44, 0, 0, 0, 64, 0, 0, 0, 88, 0, 0, 0, 92, 0, 0, 0, 116, 0, 0, 0, 168, 0,
+            0, 0, 169, 0, 0, 0, 71, 1, 0, 0, 91, 1, 0, 0, 156, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 24, 0, 0, 0, 12, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 40, 0, 0, 0,
+            44, 0, 0, 0, 48, 0, 0, 0, 4, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 158, 0, 0, 0, 24, 0, 0, 0, 56, 0, 0, 0, 60, 0, 0, 0, 92, 0, 0, 0, 157,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
         RewardWitness::new_unchecked(v.into())
     }
 }
 impl RewardWitness {
-    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_COUNT: usize = 10;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -2700,11 +6153,47 @@ impl RewardWitness {
     pub fn new_not_claim_info(&self) -> NotClaimInfo {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        NotClaimInfo::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn batch_reward_infos(&self) -> BatchRewardInfos {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        BatchRewardInfos::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn mode(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn beneficiary(&self) -> RewardBeneficiary {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        RewardBeneficiary::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn compound_staker(&self) -> Identity {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Identity::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn eth_sig(&self) -> Byte65 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Byte65::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sig_mode(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            NotClaimInfo::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[44..]) as usize;
+            Byte::new_unchecked(self.0.slice(start..end))
         } else {
-            NotClaimInfo::new_unchecked(self.0.slice(start..))
+            Byte::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> RewardWitnessReader<'r> {
@@ -2738,6 +6227,12 @@ impl molecule::prelude::Entity for RewardWitness {
             .old_not_claim_info(self.old_not_claim_info())
             .reward_infos(self.reward_infos())
             .new_not_claim_info(self.new_not_claim_info())
+            .batch_reward_infos(self.batch_reward_infos())
+            .mode(self.mode())
+            .beneficiary(self.beneficiary())
+            .compound_staker(self.compound_staker())
+            .eth_sig(self.eth_sig())
+            .sig_mode(self.sig_mode())
     }
 }
 #[derive(Clone, Copy)]
@@ -2773,6 +6268,17 @@ impl<'r> ::core::fmt::Display for RewardWitnessReader<'r> {
             "new_not_claim_info",
             self.new_not_claim_info()
         )?;
+        write!(
+            f,
+            ", {}: {}",
+            "batch_reward_infos",
+            self.batch_reward_infos()
+        )?;
+        write!(f, ", {}: {}", "mode", self.mode())?;
+        write!(f, ", {}: {}", "beneficiary", self.beneficiary())?;
+        write!(f, ", {}: {}", "compound_staker", self.compound_staker())?;
+        write!(f, ", {}: {}", "eth_sig", self.eth_sig())?;
+        write!(f, ", {}: {}", "sig_mode", self.sig_mode())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -2781,7 +6287,7 @@ impl<'r> ::core::fmt::Display for RewardWitnessReader<'r> {
     }
 }
 impl<'r> RewardWitnessReader<'r> {
-    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_COUNT: usize = 10;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -2819,11 +6325,47 @@ impl<'r> RewardWitnessReader<'r> {
     pub fn new_not_claim_info(&self) -> NotClaimInfoReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        NotClaimInfoReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn batch_reward_infos(&self) -> BatchRewardInfosReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        BatchRewardInfosReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn mode(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn beneficiary(&self) -> RewardBeneficiaryReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        RewardBeneficiaryReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn compound_staker(&self) -> IdentityReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        IdentityReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn eth_sig(&self) -> Byte65Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Byte65Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sig_mode(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[20..]) as usize;
-            NotClaimInfoReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[44..]) as usize;
+            ByteReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            NotClaimInfoReader::new_unchecked(&self.as_slice()[start..])
+            ByteReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -2880,6 +6422,12 @@ impl<'r> molecule::prelude::Reader<'r> for RewardWitnessReader<'r> {
         NotClaimInfoReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         EpochRewardStakeInfosReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         NotClaimInfoReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        BatchRewardInfosReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        ByteReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        RewardBeneficiaryReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        IdentityReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        Byte65Reader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        ByteReader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
         Ok(())
     }
 }
@@ -2889,9 +6437,15 @@ pub struct RewardWitnessBuilder {
     pub(crate) old_not_claim_info: NotClaimInfo,
     pub(crate) reward_infos: EpochRewardStakeInfos,
     pub(crate) new_not_claim_info: NotClaimInfo,
+    pub(crate) batch_reward_infos: BatchRewardInfos,
+    pub(crate) mode: Byte,
+    pub(crate) beneficiary: RewardBeneficiary,
+    pub(crate) compound_staker: Identity,
+    pub(crate) eth_sig: Byte65,
+    pub(crate) sig_mode: Byte,
 }
 impl RewardWitnessBuilder {
-    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_COUNT: usize = 10;
     pub fn miner(mut self, v: Identity) -> Self {
         self.miner = v;
         self
@@ -2908,6 +6462,30 @@ impl RewardWitnessBuilder {
         self.new_not_claim_info = v;
         self
     }
+    pub fn batch_reward_infos(mut self, v: BatchRewardInfos) -> Self {
+        self.batch_reward_infos = v;
+        self
+    }
+    pub fn mode(mut self, v: Byte) -> Self {
+        self.mode = v;
+        self
+    }
+    pub fn beneficiary(mut self, v: RewardBeneficiary) -> Self {
+        self.beneficiary = v;
+        self
+    }
+    pub fn compound_staker(mut self, v: Identity) -> Self {
+        self.compound_staker = v;
+        self
+    }
+    pub fn eth_sig(mut self, v: Byte65) -> Self {
+        self.eth_sig = v;
+        self
+    }
+    pub fn sig_mode(mut self, v: Byte) -> Self {
+        self.sig_mode = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for RewardWitnessBuilder {
     type Entity = RewardWitness;
@@ -2918,6 +6496,12 @@ impl molecule::prelude::Builder for RewardWitnessBuilder {
             + self.old_not_claim_info.as_slice().len()
             + self.reward_infos.as_slice().len()
             + self.new_not_claim_info.as_slice().len()
+            + self.batch_reward_infos.as_slice().len()
+            + self.mode.as_slice().len()
+            + self.beneficiary.as_slice().len()
+            + self.compound_staker.as_slice().len()
+            + self.eth_sig.as_slice().len()
+            + self.sig_mode.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -2930,6 +6514,18 @@ impl molecule::prelude::Builder for RewardWitnessBuilder {
         total_size += self.reward_infos.as_slice().len();
         offsets.push(total_size);
         total_size += self.new_not_claim_info.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.batch_reward_infos.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.mode.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.beneficiary.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.compound_staker.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.eth_sig.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sig_mode.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -2938,6 +6534,12 @@ impl molecule::prelude::Builder for RewardWitnessBuilder {
         writer.write_all(self.old_not_claim_info.as_slice())?;
         writer.write_all(self.reward_infos.as_slice())?;
         writer.write_all(self.new_not_claim_info.as_slice())?;
+        writer.write_all(self.batch_reward_infos.as_slice())?;
+        writer.write_all(self.mode.as_slice())?;
+        writer.write_all(self.beneficiary.as_slice())?;
+        writer.write_all(self.compound_staker.as_slice())?;
+        writer.write_all(self.eth_sig.as_slice())?;
+        writer.write_all(self.sig_mode.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
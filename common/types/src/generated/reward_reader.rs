@@ -36,6 +36,13 @@ impl RewardSmtCellData {
     }
 }
 
+impl RewardSmtCellData {
+    pub fn beneficiary_smt_root(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(3).unwrap();
+        cur.into()
+    }
+}
+
 pub struct NotClaimInfo {
     pub cursor: Cursor,
 }
@@ -154,6 +161,20 @@ impl RewardStakeInfo {
     }
 }
 
+impl RewardStakeInfo {
+    pub fn commission_rate(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(5).unwrap();
+        cur.into()
+    }
+}
+
+impl RewardStakeInfo {
+    pub fn delegate_threshold(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(6).unwrap();
+        cur.into()
+    }
+}
+
 pub struct RewardStakeInfos {
     pub cursor: Cursor,
 }
@@ -177,6 +198,44 @@ impl RewardStakeInfos {
     }
 }
 
+pub struct EpochRewardParams {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for EpochRewardParams {
+    fn from(cursor: Cursor) -> Self {
+        EpochRewardParams { cursor }
+    }
+}
+
+impl EpochRewardParams {
+    pub fn base_reward(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(0).unwrap();
+        cur.into()
+    }
+}
+
+impl EpochRewardParams {
+    pub fn half_epoch(&self) -> u64 {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        cur.into()
+    }
+}
+
+impl EpochRewardParams {
+    pub fn minimum_normal_propose_count(&self) -> u64 {
+        let cur = self.cursor.table_slice_by_index(2).unwrap();
+        cur.into()
+    }
+}
+
+impl EpochRewardParams {
+    pub fn propose_discount_rate(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(3).unwrap();
+        cur.into()
+    }
+}
+
 pub struct EpochRewardStakeInfo {
     pub cursor: Cursor,
 }
@@ -242,6 +301,45 @@ impl EpochRewardStakeInfo {
     }
 }
 
+impl EpochRewardStakeInfo {
+    pub fn commission_proof(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(7).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+impl EpochRewardStakeInfo {
+    pub fn commission_root(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(8).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+impl EpochRewardStakeInfo {
+    pub fn commission_epoch_proof(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(9).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+impl EpochRewardStakeInfo {
+    pub fn reward_params(&self) -> EpochRewardParams {
+        let cur = self.cursor.table_slice_by_index(10).unwrap();
+        cur.into()
+    }
+}
+
+impl EpochRewardStakeInfo {
+    pub fn reward_params_proof(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(11).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
 pub struct EpochRewardStakeInfos {
     pub cursor: Cursor,
 }
@@ -265,6 +363,326 @@ impl EpochRewardStakeInfos {
     }
 }
 
+pub struct BatchStakerInfo {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for BatchStakerInfo {
+    fn from(cursor: Cursor) -> Self {
+        BatchStakerInfo { cursor }
+    }
+}
+
+impl BatchStakerInfo {
+    pub fn validator(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(0).unwrap();
+        cur.into()
+    }
+}
+
+impl BatchStakerInfo {
+    pub fn delegate_epoch_proof(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+pub struct BatchStakerInfos {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for BatchStakerInfos {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl BatchStakerInfos {
+    pub fn len(&self) -> usize {
+        self.cursor.dynvec_length()
+    }
+}
+
+impl BatchStakerInfos {
+    pub fn get(&self, index: usize) -> BatchStakerInfo {
+        let cur = self.cursor.dynvec_slice_by_index(index).unwrap();
+        cur.into()
+    }
+}
+
+pub struct BatchRewardStakeInfo {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for BatchRewardStakeInfo {
+    fn from(cursor: Cursor) -> Self {
+        BatchRewardStakeInfo { cursor }
+    }
+}
+
+impl BatchRewardStakeInfo {
+    pub fn staker_index(&self) -> u32 {
+        let cur = self.cursor.table_slice_by_index(0).unwrap();
+        cur.into()
+    }
+}
+
+impl BatchRewardStakeInfo {
+    pub fn propose_count(&self) -> u64 {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        cur.into()
+    }
+}
+
+impl BatchRewardStakeInfo {
+    pub fn staker_amount(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(2).unwrap();
+        cur.into()
+    }
+}
+
+impl BatchRewardStakeInfo {
+    pub fn delegate_infos(&self) -> RewardDelegateInfos {
+        let cur = self.cursor.table_slice_by_index(3).unwrap();
+        cur.into()
+    }
+}
+
+impl BatchRewardStakeInfo {
+    pub fn commission_rate(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(4).unwrap();
+        cur.into()
+    }
+}
+
+impl BatchRewardStakeInfo {
+    pub fn delegate_threshold(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(5).unwrap();
+        cur.into()
+    }
+}
+
+pub struct BatchRewardStakeInfos {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for BatchRewardStakeInfos {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl BatchRewardStakeInfos {
+    pub fn len(&self) -> usize {
+        self.cursor.dynvec_length()
+    }
+}
+
+impl BatchRewardStakeInfos {
+    pub fn get(&self, index: usize) -> BatchRewardStakeInfo {
+        let cur = self.cursor.dynvec_slice_by_index(index).unwrap();
+        cur.into()
+    }
+}
+
+pub struct BatchEpochRewardInfo {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for BatchEpochRewardInfo {
+    fn from(cursor: Cursor) -> Self {
+        BatchEpochRewardInfo { cursor }
+    }
+}
+
+impl BatchEpochRewardInfo {
+    pub fn reward_stake_infos(&self) -> BatchRewardStakeInfos {
+        let cur = self.cursor.table_slice_by_index(0).unwrap();
+        cur.into()
+    }
+}
+
+impl BatchEpochRewardInfo {
+    pub fn count_proof(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+impl BatchEpochRewardInfo {
+    pub fn count_root(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(2).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+impl BatchEpochRewardInfo {
+    pub fn amount_proof(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(3).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+impl BatchEpochRewardInfo {
+    pub fn amount_root(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(4).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+impl BatchEpochRewardInfo {
+    pub fn commission_proof(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(5).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+impl BatchEpochRewardInfo {
+    pub fn commission_root(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(6).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+impl BatchEpochRewardInfo {
+    pub fn reward_params(&self) -> EpochRewardParams {
+        let cur = self.cursor.table_slice_by_index(7).unwrap();
+        cur.into()
+    }
+}
+
+pub struct BatchEpochRewardInfos {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for BatchEpochRewardInfos {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl BatchEpochRewardInfos {
+    pub fn len(&self) -> usize {
+        self.cursor.dynvec_length()
+    }
+}
+
+impl BatchEpochRewardInfos {
+    pub fn get(&self, index: usize) -> BatchEpochRewardInfo {
+        let cur = self.cursor.dynvec_slice_by_index(index).unwrap();
+        cur.into()
+    }
+}
+
+pub struct BatchRewardInfos {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for BatchRewardInfos {
+    fn from(cursor: Cursor) -> Self {
+        BatchRewardInfos { cursor }
+    }
+}
+
+impl BatchRewardInfos {
+    pub fn staker_infos(&self) -> BatchStakerInfos {
+        let cur = self.cursor.table_slice_by_index(0).unwrap();
+        cur.into()
+    }
+}
+
+impl BatchRewardInfos {
+    pub fn epoch_infos(&self) -> BatchEpochRewardInfos {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        cur.into()
+    }
+}
+
+impl BatchRewardInfos {
+    pub fn count_epoch_proof(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(2).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+impl BatchRewardInfos {
+    pub fn amount_epoch_proof(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(3).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+impl BatchRewardInfos {
+    pub fn commission_epoch_proof(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(4).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+impl BatchRewardInfos {
+    pub fn reward_params_proof(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(5).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+pub struct RewardBeneficiary {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for RewardBeneficiary {
+    fn from(cursor: Cursor) -> Self {
+        RewardBeneficiary { cursor }
+    }
+}
+
+impl RewardBeneficiary {
+    pub fn lock_hash(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(0).unwrap();
+        cur.into()
+    }
+}
+
+impl RewardBeneficiary {
+    pub fn proof(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        let cur2 = cur.convert_to_rawbytes().unwrap();
+        cur2.into()
+    }
+}
+
+impl RewardBeneficiary {
+    pub fn new_lock_hash(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(2).unwrap();
+        cur.into()
+    }
+}
+
+impl RewardBeneficiary {
+    pub fn eth_sig(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(3).unwrap();
+        cur.into()
+    }
+}
+
+impl RewardBeneficiary {
+    pub fn sig_mode(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(4).unwrap();
+        cur.into()
+    }
+}
+
 pub struct RewardWitness {
     pub cursor: Cursor,
 }
@@ -302,3 +720,45 @@ impl RewardWitness {
         cur.into()
     }
 }
+
+impl RewardWitness {
+    pub fn batch_reward_infos(&self) -> BatchRewardInfos {
+        let cur = self.cursor.table_slice_by_index(4).unwrap();
+        cur.into()
+    }
+}
+
+impl RewardWitness {
+    pub fn mode(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(5).unwrap();
+        cur.into()
+    }
+}
+
+impl RewardWitness {
+    pub fn beneficiary(&self) -> RewardBeneficiary {
+        let cur = self.cursor.table_slice_by_index(6).unwrap();
+        cur.into()
+    }
+}
+
+impl RewardWitness {
+    pub fn compound_staker(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(7).unwrap();
+        cur.into()
+    }
+}
+
+impl RewardWitness {
+    pub fn eth_sig(&self) -> Vec<u8> {
+        let cur = self.cursor.table_slice_by_index(8).unwrap();
+        cur.into()
+    }
+}
+
+impl RewardWitness {
+    pub fn sig_mode(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(9).unwrap();
+        cur.into()
+    }
+}
@@ -23,6 +23,7 @@ impl ::core::fmt::Display for SelectionLockArgs {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "reward_smt_type_id", self.reward_smt_type_id())?;
         write!(f, ", {}: {}", "issue_lock_hash", self.issue_lock_hash())?;
+        write!(f, ", {}: {}", "issue_type_hash", self.issue_type_hash())?;
         write!(f, " }}")
     }
 }
@@ -31,21 +32,25 @@ impl ::core::default::Default for SelectionLockArgs {
         let v: Vec<u8> = vec![
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
         SelectionLockArgs::new_unchecked(v.into())
     }
 }
 impl SelectionLockArgs {
-    pub const TOTAL_SIZE: usize = 64;
-    pub const FIELD_SIZES: [usize; 2] = [32, 32];
-    pub const FIELD_COUNT: usize = 2;
+    pub const TOTAL_SIZE: usize = 96;
+    pub const FIELD_SIZES: [usize; 3] = [32, 32, 32];
+    pub const FIELD_COUNT: usize = 3;
     pub fn reward_smt_type_id(&self) -> Byte32 {
         Byte32::new_unchecked(self.0.slice(0..32))
     }
     pub fn issue_lock_hash(&self) -> Byte32 {
         Byte32::new_unchecked(self.0.slice(32..64))
     }
+    pub fn issue_type_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(64..96))
+    }
     pub fn as_reader<'r>(&'r self) -> SelectionLockArgsReader<'r> {
         SelectionLockArgsReader::new_unchecked(self.as_slice())
     }
@@ -75,6 +80,7 @@ impl molecule::prelude::Entity for SelectionLockArgs {
         Self::new_builder()
             .reward_smt_type_id(self.reward_smt_type_id())
             .issue_lock_hash(self.issue_lock_hash())
+            .issue_type_hash(self.issue_type_hash())
     }
 }
 #[derive(Clone, Copy)]
@@ -98,19 +104,23 @@ impl<'r> ::core::fmt::Display for SelectionLockArgsReader<'r> {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "reward_smt_type_id", self.reward_smt_type_id())?;
         write!(f, ", {}: {}", "issue_lock_hash", self.issue_lock_hash())?;
+        write!(f, ", {}: {}", "issue_type_hash", self.issue_type_hash())?;
         write!(f, " }}")
     }
 }
 impl<'r> SelectionLockArgsReader<'r> {
-    pub const TOTAL_SIZE: usize = 64;
-    pub const FIELD_SIZES: [usize; 2] = [32, 32];
-    pub const FIELD_COUNT: usize = 2;
+    pub const TOTAL_SIZE: usize = 96;
+    pub const FIELD_SIZES: [usize; 3] = [32, 32, 32];
+    pub const FIELD_COUNT: usize = 3;
     pub fn reward_smt_type_id(&self) -> Byte32Reader<'r> {
         Byte32Reader::new_unchecked(&self.as_slice()[0..32])
     }
     pub fn issue_lock_hash(&self) -> Byte32Reader<'r> {
         Byte32Reader::new_unchecked(&self.as_slice()[32..64])
     }
+    pub fn issue_type_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[64..96])
+    }
 }
 impl<'r> molecule::prelude::Reader<'r> for SelectionLockArgsReader<'r> {
     type Entity = SelectionLockArgs;
@@ -137,11 +147,12 @@ impl<'r> molecule::prelude::Reader<'r> for SelectionLockArgsReader<'r> {
 pub struct SelectionLockArgsBuilder {
     pub(crate) reward_smt_type_id: Byte32,
     pub(crate) issue_lock_hash: Byte32,
+    pub(crate) issue_type_hash: Byte32,
 }
 impl SelectionLockArgsBuilder {
-    pub const TOTAL_SIZE: usize = 64;
-    pub const FIELD_SIZES: [usize; 2] = [32, 32];
-    pub const FIELD_COUNT: usize = 2;
+    pub const TOTAL_SIZE: usize = 96;
+    pub const FIELD_SIZES: [usize; 3] = [32, 32, 32];
+    pub const FIELD_COUNT: usize = 3;
     pub fn reward_smt_type_id(mut self, v: Byte32) -> Self {
         self.reward_smt_type_id = v;
         self
@@ -150,6 +161,10 @@ impl SelectionLockArgsBuilder {
         self.issue_lock_hash = v;
         self
     }
+    pub fn issue_type_hash(mut self, v: Byte32) -> Self {
+        self.issue_type_hash = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for SelectionLockArgsBuilder {
     type Entity = SelectionLockArgs;
@@ -160,6 +175,7 @@ impl molecule::prelude::Builder for SelectionLockArgsBuilder {
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         writer.write_all(self.reward_smt_type_id.as_slice())?;
         writer.write_all(self.issue_lock_hash.as_slice())?;
+        writer.write_all(self.issue_type_hash.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
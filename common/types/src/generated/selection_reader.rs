@@ -28,3 +28,10 @@ impl SelectionLockArgs {
         cur.into()
     }
 }
+
+impl SelectionLockArgs {
+    pub fn issue_type_hash(&self) -> Vec<u8> {
+        let cur = self.cursor.slice_by_offset(64, 32).unwrap();
+        cur.into()
+    }
+}
@@ -1971,6 +1971,7 @@ impl ::core::fmt::Display for StakeAtWitness {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "mode", self.mode())?;
         write!(f, ", {}: {}", "eth_sig", self.eth_sig())?;
+        write!(f, ", {}: {}", "sig_mode", self.sig_mode())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1981,15 +1982,15 @@ impl ::core::fmt::Display for StakeAtWitness {
 impl ::core::default::Default for StakeAtWitness {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            78, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            83, 0, 0, 0, 16, 0, 0, 0, 17, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
         StakeAtWitness::new_unchecked(v.into())
     }
 }
 impl StakeAtWitness {
-    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -2015,11 +2016,17 @@ impl StakeAtWitness {
     pub fn eth_sig(&self) -> Byte65 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte65::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sig_mode(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            Byte65::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            Byte::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte65::new_unchecked(self.0.slice(start..))
+            Byte::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> StakeAtWitnessReader<'r> {
@@ -2051,6 +2058,7 @@ impl molecule::prelude::Entity for StakeAtWitness {
         Self::new_builder()
             .mode(self.mode())
             .eth_sig(self.eth_sig())
+            .sig_mode(self.sig_mode())
     }
 }
 #[derive(Clone, Copy)]
@@ -2074,6 +2082,7 @@ impl<'r> ::core::fmt::Display for StakeAtWitnessReader<'r> {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "mode", self.mode())?;
         write!(f, ", {}: {}", "eth_sig", self.eth_sig())?;
+        write!(f, ", {}: {}", "sig_mode", self.sig_mode())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -2082,7 +2091,7 @@ impl<'r> ::core::fmt::Display for StakeAtWitnessReader<'r> {
     }
 }
 impl<'r> StakeAtWitnessReader<'r> {
-    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -2108,11 +2117,17 @@ impl<'r> StakeAtWitnessReader<'r> {
     pub fn eth_sig(&self) -> Byte65Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte65Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sig_mode(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[12..]) as usize;
-            Byte65Reader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            ByteReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Byte65Reader::new_unchecked(&self.as_slice()[start..])
+            ByteReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -2167,6 +2182,7 @@ impl<'r> molecule::prelude::Reader<'r> for StakeAtWitnessReader<'r> {
         }
         ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Byte65Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        ByteReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Ok(())
     }
 }
@@ -2174,9 +2190,10 @@ impl<'r> molecule::prelude::Reader<'r> for StakeAtWitnessReader<'r> {
 pub struct StakeAtWitnessBuilder {
     pub(crate) mode: Byte,
     pub(crate) eth_sig: Byte65,
+    pub(crate) sig_mode: Byte,
 }
 impl StakeAtWitnessBuilder {
-    pub const FIELD_COUNT: usize = 2;
+    pub const FIELD_COUNT: usize = 3;
     pub fn mode(mut self, v: Byte) -> Self {
         self.mode = v;
         self
@@ -2185,6 +2202,10 @@ impl StakeAtWitnessBuilder {
         self.eth_sig = v;
         self
     }
+    pub fn sig_mode(mut self, v: Byte) -> Self {
+        self.sig_mode = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for StakeAtWitnessBuilder {
     type Entity = StakeAtWitness;
@@ -2193,6 +2214,7 @@ impl molecule::prelude::Builder for StakeAtWitnessBuilder {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.mode.as_slice().len()
             + self.eth_sig.as_slice().len()
+            + self.sig_mode.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -2201,12 +2223,15 @@ impl molecule::prelude::Builder for StakeAtWitnessBuilder {
         total_size += self.mode.as_slice().len();
         offsets.push(total_size);
         total_size += self.eth_sig.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sig_mode.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
         writer.write_all(self.mode.as_slice())?;
         writer.write_all(self.eth_sig.as_slice())?;
+        writer.write_all(self.sig_mode.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -2217,6 +2242,277 @@ impl molecule::prelude::Builder for StakeAtWitnessBuilder {
     }
 }
 #[derive(Clone)]
+pub struct IdentityVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for IdentityVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for IdentityVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for IdentityVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for IdentityVec {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0, 0, 0, 0];
+        IdentityVec::new_unchecked(v.into())
+    }
+}
+impl IdentityVec {
+    pub const ITEM_SIZE: usize = 20;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Identity> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> Identity {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Identity::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn as_reader<'r>(&'r self) -> IdentityVecReader<'r> {
+        IdentityVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for IdentityVec {
+    type Builder = IdentityVecBuilder;
+    const NAME: &'static str = "IdentityVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        IdentityVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        IdentityVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        IdentityVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct IdentityVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for IdentityVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for IdentityVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for IdentityVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> IdentityVecReader<'r> {
+    pub const ITEM_SIZE: usize = 20;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<IdentityReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> IdentityReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        IdentityReader::new_unchecked(&self.as_slice()[start..end])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for IdentityVecReader<'r> {
+    type Entity = IdentityVec;
+    const NAME: &'static str = "IdentityVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        IdentityVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct IdentityVecBuilder(pub(crate) Vec<Identity>);
+impl IdentityVecBuilder {
+    pub const ITEM_SIZE: usize = 20;
+    pub fn set(mut self, v: Vec<Identity>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: Identity) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Identity>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: Identity) -> Option<Identity> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for IdentityVecBuilder {
+    type Entity = IdentityVec;
+    const NAME: &'static str = "IdentityVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        IdentityVec::new_unchecked(inner.into())
+    }
+}
+pub struct IdentityVecIterator(IdentityVec, usize, usize);
+impl ::core::iter::Iterator for IdentityVecIterator {
+    type Item = Identity;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for IdentityVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for IdentityVec {
+    type Item = Identity;
+    type IntoIter = IdentityVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        IdentityVecIterator(self, 0, len)
+    }
+}
+impl<'r> IdentityVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> IdentityVecReaderIterator<'t, 'r> {
+        IdentityVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct IdentityVecReaderIterator<'t, 'r>(&'t IdentityVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for IdentityVecReaderIterator<'t, 'r> {
+    type Item = IdentityReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for IdentityVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
 pub struct StakeSmtCellData(molecule::bytes::Bytes);
 impl ::core::fmt::LowerHex for StakeSmtCellData {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
@@ -2238,6 +2534,13 @@ impl ::core::fmt::Display for StakeSmtCellData {
         write!(f, "{}: {}", "version", self.version())?;
         write!(f, ", {}: {}", "smt_root", self.smt_root())?;
         write!(f, ", {}: {}", "metadata_type_id", self.metadata_type_id())?;
+        write!(f, ", {}: {}", "exited_stakers", self.exited_stakers())?;
+        write!(
+            f,
+            ", {}: {}",
+            "next_liveness_slash_epoch",
+            self.next_liveness_slash_epoch()
+        )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -2248,15 +2551,16 @@ impl ::core::fmt::Display for StakeSmtCellData {
 impl ::core::default::Default for StakeSmtCellData {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            81, 0, 0, 0, 16, 0, 0, 0, 17, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            101, 0, 0, 0, 24, 0, 0, 0, 25, 0, 0, 0, 57, 0, 0, 0, 89, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
         StakeSmtCellData::new_unchecked(v.into())
     }
 }
 impl StakeSmtCellData {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -2288,11 +2592,23 @@ impl StakeSmtCellData {
     pub fn metadata_type_id(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn exited_stakers(&self) -> IdentityVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        IdentityVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn next_liveness_slash_epoch(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            Byte32::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            Uint64::new_unchecked(self.0.slice(start..end))
         } else {
-            Byte32::new_unchecked(self.0.slice(start..))
+            Uint64::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> StakeSmtCellDataReader<'r> {
@@ -2325,6 +2641,8 @@ impl molecule::prelude::Entity for StakeSmtCellData {
             .version(self.version())
             .smt_root(self.smt_root())
             .metadata_type_id(self.metadata_type_id())
+            .exited_stakers(self.exited_stakers())
+            .next_liveness_slash_epoch(self.next_liveness_slash_epoch())
     }
 }
 #[derive(Clone, Copy)]
@@ -2349,6 +2667,13 @@ impl<'r> ::core::fmt::Display for StakeSmtCellDataReader<'r> {
         write!(f, "{}: {}", "version", self.version())?;
         write!(f, ", {}: {}", "smt_root", self.smt_root())?;
         write!(f, ", {}: {}", "metadata_type_id", self.metadata_type_id())?;
+        write!(f, ", {}: {}", "exited_stakers", self.exited_stakers())?;
+        write!(
+            f,
+            ", {}: {}",
+            "next_liveness_slash_epoch",
+            self.next_liveness_slash_epoch()
+        )?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -2357,7 +2682,7 @@ impl<'r> ::core::fmt::Display for StakeSmtCellDataReader<'r> {
     }
 }
 impl<'r> StakeSmtCellDataReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 5;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -2389,11 +2714,23 @@ impl<'r> StakeSmtCellDataReader<'r> {
     pub fn metadata_type_id(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn exited_stakers(&self) -> IdentityVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        IdentityVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn next_liveness_slash_epoch(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[24..]) as usize;
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            Byte32Reader::new_unchecked(&self.as_slice()[start..])
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -2449,6 +2786,8 @@ impl<'r> molecule::prelude::Reader<'r> for StakeSmtCellDataReader<'r> {
         ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        IdentityVecReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
         Ok(())
     }
 }
@@ -2457,9 +2796,11 @@ pub struct StakeSmtCellDataBuilder {
     pub(crate) version: Byte,
     pub(crate) smt_root: Byte32,
     pub(crate) metadata_type_id: Byte32,
+    pub(crate) exited_stakers: IdentityVec,
+    pub(crate) next_liveness_slash_epoch: Uint64,
 }
 impl StakeSmtCellDataBuilder {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 5;
     pub fn version(mut self, v: Byte) -> Self {
         self.version = v;
         self
@@ -2472,6 +2813,14 @@ impl StakeSmtCellDataBuilder {
         self.metadata_type_id = v;
         self
     }
+    pub fn exited_stakers(mut self, v: IdentityVec) -> Self {
+        self.exited_stakers = v;
+        self
+    }
+    pub fn next_liveness_slash_epoch(mut self, v: Uint64) -> Self {
+        self.next_liveness_slash_epoch = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for StakeSmtCellDataBuilder {
     type Entity = StakeSmtCellData;
@@ -2481,6 +2830,8 @@ impl molecule::prelude::Builder for StakeSmtCellDataBuilder {
             + self.version.as_slice().len()
             + self.smt_root.as_slice().len()
             + self.metadata_type_id.as_slice().len()
+            + self.exited_stakers.as_slice().len()
+            + self.next_liveness_slash_epoch.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -2491,6 +2842,10 @@ impl molecule::prelude::Builder for StakeSmtCellDataBuilder {
         total_size += self.smt_root.as_slice().len();
         offsets.push(total_size);
         total_size += self.metadata_type_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.exited_stakers.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.next_liveness_slash_epoch.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -2498,6 +2853,8 @@ impl molecule::prelude::Builder for StakeSmtCellDataBuilder {
         writer.write_all(self.version.as_slice())?;
         writer.write_all(self.smt_root.as_slice())?;
         writer.write_all(self.metadata_type_id.as_slice())?;
+        writer.write_all(self.exited_stakers.as_slice())?;
+        writer.write_all(self.next_liveness_slash_epoch.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
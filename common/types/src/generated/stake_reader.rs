@@ -246,6 +246,13 @@ impl StakeAtWitness {
     }
 }
 
+impl StakeAtWitness {
+    pub fn sig_mode(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(2).unwrap();
+        cur.into()
+    }
+}
+
 pub struct StakeSmtCellData {
     pub cursor: Cursor,
 }
@@ -277,6 +284,43 @@ impl StakeSmtCellData {
     }
 }
 
+impl StakeSmtCellData {
+    pub fn exited_stakers(&self) -> IdentityVec {
+        let cur = self.cursor.table_slice_by_index(3).unwrap();
+        cur.into()
+    }
+}
+
+impl StakeSmtCellData {
+    pub fn next_liveness_slash_epoch(&self) -> u64 {
+        let cur = self.cursor.table_slice_by_index(4).unwrap();
+        cur.into()
+    }
+}
+
+pub struct IdentityVec {
+    pub cursor: Cursor,
+}
+
+impl From<Cursor> for IdentityVec {
+    fn from(cursor: Cursor) -> Self {
+        Self { cursor }
+    }
+}
+
+impl IdentityVec {
+    pub fn len(&self) -> usize {
+        self.cursor.fixvec_length()
+    }
+}
+
+impl IdentityVec {
+    pub fn get(&self, index: usize) -> Vec<u8> {
+        let cur = self.cursor.fixvec_slice_by_index(20, index).unwrap();
+        cur.into()
+    }
+}
+
 pub struct StakeInfo {
     pub cursor: Cursor,
 }
@@ -1756,6 +1756,7 @@ impl ::core::fmt::Display for WithdrawWitness {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "signature", self.signature())?;
+        write!(f, ", {}: {}", "sig_mode", self.sig_mode())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1765,12 +1766,12 @@ impl ::core::fmt::Display for WithdrawWitness {
 }
 impl ::core::default::Default for WithdrawWitness {
     fn default() -> Self {
-        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0];
+        let v: Vec<u8> = vec![17, 0, 0, 0, 12, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0];
         WithdrawWitness::new_unchecked(v.into())
     }
 }
 impl WithdrawWitness {
-    pub const FIELD_COUNT: usize = 1;
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1790,11 +1791,17 @@ impl WithdrawWitness {
     pub fn signature(&self) -> Bytes {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn sig_mode(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[8..]) as usize;
-            Bytes::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Byte::new_unchecked(self.0.slice(start..end))
         } else {
-            Bytes::new_unchecked(self.0.slice(start..))
+            Byte::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> WithdrawWitnessReader<'r> {
@@ -1823,7 +1830,9 @@ impl molecule::prelude::Entity for WithdrawWitness {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().signature(self.signature())
+        Self::new_builder()
+            .signature(self.signature())
+            .sig_mode(self.sig_mode())
     }
 }
 #[derive(Clone, Copy)]
@@ -1846,6 +1855,7 @@ impl<'r> ::core::fmt::Display for WithdrawWitnessReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "signature", self.signature())?;
+        write!(f, ", {}: {}", "sig_mode", self.sig_mode())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1854,7 +1864,7 @@ impl<'r> ::core::fmt::Display for WithdrawWitnessReader<'r> {
     }
 }
 impl<'r> WithdrawWitnessReader<'r> {
-    pub const FIELD_COUNT: usize = 1;
+    pub const FIELD_COUNT: usize = 2;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1874,11 +1884,17 @@ impl<'r> WithdrawWitnessReader<'r> {
     pub fn signature(&self) -> BytesReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn sig_mode(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[8..]) as usize;
-            BytesReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            ByteReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            BytesReader::new_unchecked(&self.as_slice()[start..])
+            ByteReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -1932,36 +1948,47 @@ impl<'r> molecule::prelude::Reader<'r> for WithdrawWitnessReader<'r> {
             return ve!(Self, OffsetsNotMatch);
         }
         BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
 pub struct WithdrawWitnessBuilder {
     pub(crate) signature: Bytes,
+    pub(crate) sig_mode: Byte,
 }
 impl WithdrawWitnessBuilder {
-    pub const FIELD_COUNT: usize = 1;
+    pub const FIELD_COUNT: usize = 2;
     pub fn signature(mut self, v: Bytes) -> Self {
         self.signature = v;
         self
     }
+    pub fn sig_mode(mut self, v: Byte) -> Self {
+        self.sig_mode = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for WithdrawWitnessBuilder {
     type Entity = WithdrawWitness;
     const NAME: &'static str = "WithdrawWitnessBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.signature.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.signature.as_slice().len()
+            + self.sig_mode.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
         total_size += self.signature.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.sig_mode.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
         writer.write_all(self.signature.as_slice())?;
+        writer.write_all(self.sig_mode.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -165,3 +165,10 @@ impl WithdrawWitness {
         cur2.into()
     }
 }
+
+impl WithdrawWitness {
+    pub fn sig_mode(&self) -> u8 {
+        let cur = self.cursor.table_slice_by_index(1).unwrap();
+        cur.into()
+    }
+}
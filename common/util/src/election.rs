@@ -0,0 +1,84 @@
+extern crate alloc;
+
+use crate::{
+    error::Error,
+    helper::{get_metada_data_by_type_id, MinerGroupInfoObject},
+    smt::LockInfo,
+};
+use alloc::collections::BTreeSet;
+use ckb_std::{ckb_constants::Source, debug};
+use core::fmt::Debug;
+use core::result::Result;
+
+// Election policy shared by stake smt and metadata, so that they always agree on who is elected.
+//
+// Validators elected at the end of epoch n are ranked by stake and delegation inaugurated in epoch
+// n + inauguration_delay of metadata, and serve with the consensus parameters of epoch
+// n + inauguration_delay - 1, so the quorum always comes from the last metadata of the list.
+//
+// 1. candidate pool: stake smt keeps the top CANDIDATE_POOL_FACTOR * quorum stakers ranked by
+//    their own stake, the rest are removed from stake smt and withdrawn.
+// 2. validator count: metadata elects the top quorum miners of the pool ranked by total amount of
+//    stake and delegation, the rest are removed with their delegators and withdrawn.
+// 3. tie-break: ranking follows the Ord of the candidate type, which is descending by amount,
+//    then descending by address for equal amounts.
+pub const CANDIDATE_POOL_FACTOR: usize = 3;
+
+pub fn get_election_quorum(metadata_type_id: &[u8; 32], source: Source) -> Result<u16, Error> {
+    let metadata = get_metada_data_by_type_id(metadata_type_id, source)?;
+    let metadata_list = metadata.metadata();
+    if metadata_list.len() == 0 {
+        return Err(Error::MetadataSizeWrong);
+    }
+    Ok(metadata_list.get(metadata_list.len() - 1).quorum())
+}
+
+pub fn candidate_pool_size(quorum: u16) -> usize {
+    CANDIDATE_POOL_FACTOR * quorum as usize
+}
+
+pub fn validator_count(quorum: u16) -> usize {
+    quorum as usize
+}
+
+// split candidates into the top `size` ones and the rest
+fn select_top<T: Ord + Clone + Debug>(
+    candidates: &BTreeSet<T>,
+    size: usize,
+) -> (BTreeSet<T>, BTreeSet<T>) {
+    let selected = candidates
+        .iter()
+        .take(size)
+        .cloned()
+        .collect::<BTreeSet<T>>();
+    let unselected = candidates
+        .iter()
+        .skip(size)
+        .cloned()
+        .collect::<BTreeSet<T>>();
+    debug!(
+        "candidates len: {}, selected len: {}, unselected len: {}, size: {}",
+        candidates.len(),
+        selected.len(),
+        unselected.len(),
+        size
+    );
+    (selected, unselected)
+}
+
+pub fn select_candidates(
+    stakers: &BTreeSet<LockInfo>,
+    quorum: u16,
+) -> (BTreeSet<LockInfo>, BTreeSet<LockInfo>) {
+    select_top(stakers, candidate_pool_size(quorum))
+}
+
+pub fn select_validators(
+    miners: &BTreeSet<MinerGroupInfoObject>,
+    quorum: u16,
+) -> (
+    BTreeSet<MinerGroupInfoObject>,
+    BTreeSet<MinerGroupInfoObject>,
+) {
+    select_top(miners, validator_count(quorum))
+}
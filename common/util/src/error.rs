@@ -91,6 +91,8 @@ pub enum Error {
     ProofRlpError, // mock multisig verify
     CheckpointLackOfQuorum,
     CheckpointProposalHashMismatch,
+    CheckpointProposalMismatch,
+    CheckpointBlockNotContinuous,
 
     // metadata
     MetadataNoStakeSmt = 100,
@@ -106,6 +108,8 @@ pub enum Error {
     StakerNotFound,
     MetadataNotFound,
     MetadataProposeCountVerifyFail,
+    MetadataEpochMismatch,
+    MetadataDuplicateMiner,
 
     // withdraw
     WithdrawWrongRecordSize = 115,
@@ -119,6 +123,8 @@ pub enum Error {
     WrongLockEpoch,
     WrongOutWithdrawEpoch,
     WrongOutWithdraw,
+    WithdrawDuplicateCell,
+    WithdrawEpochOverflow,
 
     // reward
     RewardWrongAmount = -10,
@@ -129,9 +135,16 @@ pub enum Error {
     RewardWrongDelegateAmount,
     RewardOldNewMismatch,
     RewardWrongOwner,
+    RewardBatchMismatch,
+    RewardDuplicateStaker,
 
     // requirement
     CommissionRateTooLarge = -20,
+    DelegatorSizeOutOfBounds,
+    DelegateThresholdOutOfBounds,
+    RequirementDataMismatch,
+    CommissionChangeTooLarge,
+    CommissionChangeNotDelayed,
 
     // molecule::error::VerificationError
     TotalSizeNotMatch = -30,
@@ -139,6 +152,70 @@ pub enum Error {
     UnknownItem,
     OffsetsNotMatch,
     FieldCountNotMatch,
+
+    // checkpoint propose count and evidence
+    CheckpointProposeCountMismatch = -40,
+    CheckpointEvidenceCellError,
+    CheckpointEvidenceNotConflict,
+    CheckpointEvidenceNoOffender,
+    CheckpointEvidenceDuplicated,
+
+    // slash
+    SlashNoOffender = -50,
+    SlashBadEvidence,
+    SlashNotLastCheckpoint,
+    SlashModeMismatch,
+    SlashAtAmountMismatch,
+    SlashTreasuryAmountMismatch,
+    SlashStakerMismatch,
+    SlashRateOutOfBounds,
+    SlashLivenessRepeated,
+
+    // governance
+    GovernanceUnauthorized = -60,
+    GovernanceOutOfBounds,
+    GovernanceDataMismatch,
+
+    // type ids upgrade
+    TypeIdsUpgradeNotPending = -70,
+    TypeIdsUpgradeTimelocked,
+    TypeIdsUpgradeForbidden,
+
+    // redelegate
+    RedelegateInvalid = -80,
+    RedelegateConflict,
+    RedelegateStakerMissing,
+    RedelegateTooMuch,
+    RedelegateRejected,
+
+    // staker exit
+    ExitedStakersMismatch = -90,
+    StakeSmtCellMissing,
+    DelegateToExitedStaker,
+    ReleaseStakerNotExited,
+    ReleaseModeMismatch,
+
+    // commission and reward params snapshot
+    CommissionSnapshotMissing = -100,
+    MetadataCommissionVerifyFail,
+    RewardCommissionBottomFail,
+    RewardCommissionTopFail,
+    MetadataRewardParamsVerifyFail,
+    RewardParamsFail,
+
+    // issue
+    IssueCellCountError = -110,
+    IssueDataMismatch,
+    IssueSupplyMismatch,
+    IssueExceedMaxSupply,
+    IssueUnauthorizedMint,
+
+    // reward beneficiary and compound
+    RewardBeneficiaryProofFail = -120,
+    RewardCompoundMismatch,
+    RewardClaimUnauthorized,
+    RewardClaimProofFail,
+    RewardClaimEpochMismatch,
 }
 
 impl From<SysError> for Error {
@@ -1,45 +1,160 @@
 extern crate alloc;
-use ckb_std::debug;
+
+use crate::{
+    error::Error,
+    helper::{keccak256, pubkey_to_eth_addr},
+};
+use alloc::vec::Vec;
+use ckb_std::{debug, high_level::load_tx_hash};
+use core::result::Result;
 use secp256k1_utils::recover_uncompressed_key;
-use sha3::{Digest, Keccak256};
 
-use crate::error::Error;
+// signing modes selected by the sig_mode byte of lock witnesses
+pub const SIG_MODE_TX_HASH: u8 = 0; // signature over raw tx hash
+pub const SIG_MODE_EIP712: u8 = 1; // EIP-712 typed data of AxonOperation
+
+const EIP712_DOMAIN_NAME: &str = "Axon Staking";
+const EIP712_DOMAIN_VERSION: &str = "1";
+const EIP712_DOMAIN_TYPE: &str = "EIP712Domain(string name,string version)";
+const AXON_OPERATION_TYPE: &str = "AxonOperation(string operation,uint256 amount,uint64 inaugurationEpoch,bytes32 metadataTypeId,bytes32 txHash)";
+
+#[derive(Clone, Copy, Debug)]
+pub enum Operation {
+    Stake,
+    Unstake,
+    Delegate,
+    Withdraw,
+    UpdateRequirement,
+    SetRewardBeneficiary,
+    ClaimReward,
+}
+
+impl Operation {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Operation::Stake => "stake",
+            Operation::Unstake => "unstake",
+            Operation::Delegate => "delegate",
+            Operation::Withdraw => "withdraw",
+            Operation::UpdateRequirement => "update_requirement",
+            Operation::SetRewardBeneficiary => "set_reward_beneficiary",
+            Operation::ClaimReward => "claim_reward",
+        }
+    }
+}
+
+// what the owner of a stake, delegate or withdraw AT cell authorizes, shown by wallets in EIP-712 mode
+#[derive(Debug)]
+pub struct TypedOperation {
+    pub operation: Operation,
+    pub amount: u128,
+    pub inauguration_epoch: u64,
+    pub metadata_type_id: [u8; 32],
+}
 
-// pub type EthAddress = [u8; 20];
+fn keccak_str(value: &str) -> [u8; 32] {
+    keccak256(&value.as_bytes().to_vec())
+}
+
+fn encode_u128(value: u128) -> [u8; 32] {
+    let mut buf = [0u8; 32];
+    buf[16..].copy_from_slice(&value.to_be_bytes());
+    buf
+}
+
+fn encode_u64(value: u64) -> [u8; 32] {
+    let mut buf = [0u8; 32];
+    buf[24..].copy_from_slice(&value.to_be_bytes());
+    buf
+}
+
+fn domain_separator() -> [u8; 32] {
+    let mut data = Vec::new();
+    data.extend_from_slice(&keccak_str(EIP712_DOMAIN_TYPE));
+    data.extend_from_slice(&keccak_str(EIP712_DOMAIN_NAME));
+    data.extend_from_slice(&keccak_str(EIP712_DOMAIN_VERSION));
+    keccak256(&data)
+}
+
+impl TypedOperation {
+    fn struct_hash(&self, tx_hash: &[u8; 32]) -> [u8; 32] {
+        let mut data = Vec::new();
+        data.extend_from_slice(&keccak_str(AXON_OPERATION_TYPE));
+        data.extend_from_slice(&keccak_str(self.operation.as_str()));
+        data.extend_from_slice(&encode_u128(self.amount));
+        data.extend_from_slice(&encode_u64(self.inauguration_epoch));
+        data.extend_from_slice(&self.metadata_type_id);
+        data.extend_from_slice(tx_hash);
+        keccak256(&data)
+    }
+
+    // keccak256("\x19\x01" ‖ domainSeparator ‖ hashStruct(message))
+    pub fn eip712_hash(&self, tx_hash: &[u8; 32]) -> [u8; 32] {
+        let mut data = Vec::new();
+        data.extend_from_slice(&[0x19, 0x01]);
+        data.extend_from_slice(&domain_separator());
+        data.extend_from_slice(&self.struct_hash(tx_hash));
+        keccak256(&data)
+    }
+}
 
 #[derive(Default)]
 pub struct Secp256k1Eth;
 
 impl Secp256k1Eth {
+    pub fn recover(&self, signature: [u8; 65], message: [u8; 32]) -> Result<[u8; 20], Error> {
+        let pubkey = recover_uncompressed_key(message.into(), signature).map_err(|err| {
+            debug!("failed to recover secp256k1 pubkey, error number: {}", err);
+            Error::EthPubkeyRecoverError
+        })?;
+
+        let pubkey_hash = pubkey_to_eth_addr(&pubkey.to_vec());
+        debug!(
+            "recover pubkey: {:?}, pubkey_hash: {:?}",
+            pubkey, pubkey_hash
+        );
+        Ok(pubkey_hash)
+    }
+
     pub fn verify_alone(
         &self,
         eth_address: [u8; 20],
         signature: [u8; 65],
         message: [u8; 32],
     ) -> Result<bool, Error> {
-        // ) -> bool {
-        let pubkey = recover_uncompressed_key(message.into(), signature).map_err(|err| {
-            debug!(
-                "Secp256k1Eth failed to recover secp256k1 pubkey, error number: {}",
-                err
-            );
-            Error::EthPubkeyRecoverFail
-        })?;
-        let pubkey_hash = {
-            let mut hasher = Keccak256::new();
-            hasher.update(&pubkey[1..]);
-            let buf = hasher.finalize();
-            let mut pubkey_hash = [0u8; 20];
-            pubkey_hash.copy_from_slice(&buf[12..]);
-            pubkey_hash
-        };
+        let pubkey_hash = self.recover(signature, message)?;
         debug!(
-            "Secp256k1Eth pubkey: {:?}, pubkey_hash: {:?}, eth_address: {:?}",
-            pubkey, pubkey_hash, eth_address
+            "verify_alone pubkey_hash: {:?}, eth_address: {:?}",
+            pubkey_hash, eth_address
         );
-        if pubkey_hash != eth_address {
-            return Ok(false);
-        }
-        Ok(true)
+        Ok(pubkey_hash == eth_address)
+    }
+}
+
+// verify the owner signs the tx, either over the raw tx hash or over the typed operation
+pub fn verify_eth_signature(
+    sig_mode: u8,
+    eth_address: &[u8],
+    signature: &[u8],
+    operation: &TypedOperation,
+) -> Result<(), Error> {
+    let tx_hash = load_tx_hash()?;
+    let message = match sig_mode {
+        SIG_MODE_TX_HASH => tx_hash,
+        SIG_MODE_EIP712 => operation.eip712_hash(&tx_hash),
+        _ => return Err(Error::UnknownMode),
+    };
+    let eth_address: [u8; 20] = eth_address
+        .try_into()
+        .map_err(|_| Error::SignatureMismatch)?;
+    let signature: [u8; 65] = signature.try_into().map_err(|_| Error::SignatureMismatch)?;
+    let result = Secp256k1Eth::default().verify_alone(eth_address, signature, message)?;
+    debug!(
+        "verify eth signature, sig_mode: {}, operation: {:?}, message: {:?}, result: {}",
+        sig_mode, operation, message, result
+    );
+    if !result {
+        return Err(Error::SignatureMismatch);
     }
+    Ok(())
 }
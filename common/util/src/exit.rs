@@ -0,0 +1,48 @@
+extern crate alloc;
+
+use crate::{error::Error, helper::*};
+use alloc::collections::BTreeSet;
+use axon_types::{stake_reader::StakeSmtCellData, Cursor};
+use ckb_std::{
+    ckb_constants::Source,
+    high_level::{load_cell_data, load_cell_type_hash, QueryIter},
+};
+use core::result::Result;
+
+// Staker exit shared by stake smt and delegate smt.
+//
+// 1. a staker unstaking all of its stake or dropping out of the candidate pool leaves stake smt
+//    and is marked in exited_stakers of stake smt, so is a staker slashed to nothing. A partial
+//    unstake or slash keeps the staker and its delegators.
+// 2. delegate smt releases all delegators of an exited staker to their withdraw cells in
+//    RELEASE_MODE, in a follow-up transaction where stake smt, also in RELEASE_MODE, drops the
+//    released stakers from exited_stakers, so the list doesn't grow forever. Stakers slashed or
+//    exited without delegators are released with an empty delegator set.
+// 3. delegate smt rejects new delegations to exited stakers, a staker elected into the candidate
+//    pool again is no longer exited.
+pub const RELEASE_MODE: u8 = 3;
+
+pub fn exited_stakers_of(stake_smt_data: &StakeSmtCellData) -> Result<BTreeSet<[u8; 20]>, Error> {
+    let exited_stakers = stake_smt_data.exited_stakers();
+    let mut stakers = BTreeSet::new();
+    for i in 0..exited_stakers.len() {
+        if !stakers.insert(exited_stakers.get(i).try_into().unwrap()) {
+            return Err(Error::ExitedStakersMismatch);
+        }
+    }
+    Ok(stakers)
+}
+
+// stake smt cell updated in this transaction wins over the one in cell deps
+pub fn get_exited_stakers(stake_smt_type_id: &[u8; 32]) -> Result<BTreeSet<[u8; 20]>, Error> {
+    let source = if get_cell_count_by_type_hash(&stake_smt_type_id.to_vec(), Source::Output) > 0 {
+        Source::Output
+    } else {
+        Source::CellDep
+    };
+    let index = QueryIter::new(load_cell_type_hash, source)
+        .position(|type_hash| type_hash == Some(*stake_smt_type_id))
+        .ok_or(Error::StakeSmtCellMissing)?;
+    let stake_smt_data: StakeSmtCellData = Cursor::from(load_cell_data(index, source)?).into();
+    exited_stakers_of(&stake_smt_data)
+}
@@ -60,7 +60,7 @@ use tiny_keccak::{Hasher, Keccak};
 //     }
 // }
 
-#[derive(Clone, Default, Eq, PartialOrd, Debug)]
+#[derive(Clone, Default, Eq, Debug)]
 pub struct MinerGroupInfoObject {
     pub staker: [u8; 20],
     pub stake_amount: u128,
@@ -102,25 +102,87 @@ impl MinerGroupInfoObject {
     }
 }
 
+// the same order as LockInfo, descending by total amount, then by staker address on ties
 impl Ord for MinerGroupInfoObject {
     fn cmp(&self, other: &Self) -> Ordering {
-        let order = other.get_total_amount().cmp(&self.get_total_amount());
-        order
+        match other.get_total_amount().cmp(&self.get_total_amount()) {
+            Ordering::Equal => other.staker.cmp(&self.staker),
+            order => order,
+        }
+    }
+}
+
+impl PartialOrd for MinerGroupInfoObject {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
 impl PartialEq for MinerGroupInfoObject {
     fn eq(&self, other: &Self) -> bool {
-        self.staker == other.staker
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ValidatorObject {
+    pub bls_pub_key: [u8; 48],
+    pub address: [u8; 20],
+    pub propose_weight: u32,
+    pub vote_weight: u32,
+}
+
+impl ValidatorObject {
+    pub fn new(validator: &metadata_reader::Validator) -> Self {
+        Self {
+            bls_pub_key: validator.bls_pub_key().as_slice().try_into().unwrap(),
+            address: validator.address().as_slice().try_into().unwrap(),
+            propose_weight: validator.propose_weight(),
+            vote_weight: validator.vote_weight(),
+        }
     }
 }
 
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Default, Debug, PartialEq)]
 pub struct ProposeCountObject {
     pub addr: [u8; 20],
     pub count: u64,
 }
 
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct CommissionObject {
+    pub addr: [u8; 20],
+    pub commission_rate: u8,
+    pub delegate_threshold: u128,
+}
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct RewardParamsObject {
+    pub base_reward: u128,
+    pub half_epoch: u64,
+    pub minimum_normal_propose_count: u64,
+    pub propose_discount_rate: u8,
+}
+
+// reward parameters in force during the epoch of metadata, the election records them before
+// governance may change them for later epochs
+pub fn get_reward_params(metadata: &MetadataCellData) -> RewardParamsObject {
+    let propose_minimum_rate = metadata.propose_minimum_rate();
+    let metadata0 = metadata.metadata().get(0);
+    let epoch_total_block_num = metadata0.epoch_len() as u64 * metadata0.period_len() as u64;
+    // the election runs every epoch, so neither an empty validator list nor bounded consensus
+    // parameters may make it panic
+    let minimum_normal_propose_count = (epoch_total_block_num * propose_minimum_rate as u64)
+        .checked_div(metadata0.validators().len() as u64 * 100)
+        .unwrap_or(0);
+    RewardParamsObject {
+        base_reward: bytes_to_u128(&metadata.base_reward()),
+        half_epoch: metadata.half_epoch(),
+        minimum_normal_propose_count,
+        propose_discount_rate: metadata.propose_discount_rate(),
+    }
+}
+
 pub fn find_script_input(script: &Script) -> bool {
     let script_hash = calc_script_hash(&script).to_vec();
     debug!("script_hash = {:?}", script_hash);
@@ -294,6 +356,30 @@ pub fn verify_owner_normal_at(miner: &Vec<u8>, type_hash: &Vec<u8>) -> Result<()
     Ok(())
 }
 
+// rewards registered to a beneficiary can only go to normal at cells of its lock
+pub fn verify_beneficiary_normal_at(
+    beneficiary_lock_hash: &Vec<u8>,
+    type_hash: &Vec<u8>,
+) -> Result<(), Error> {
+    QueryIter::new(load_cell_type_hash, Source::Output)
+        .enumerate()
+        .map(|(i, cell_type_hash)| {
+            if cell_type_hash.unwrap_or([0u8; 32]) == type_hash[..] {
+                let lock_hash = load_cell_lock_hash(i, Source::Output)?;
+                debug!(
+                    "lock_hash: {:?}, beneficiary: {:?}",
+                    lock_hash, beneficiary_lock_hash
+                );
+                if lock_hash[..] != beneficiary_lock_hash[..] {
+                    return Err(Error::RewardWrongOwner);
+                }
+            }
+            Ok(())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(())
+}
+
 pub fn get_stake_at_data_by_lock_hash(
     cell_lock_hash: &[u8; 32],
     source: Source,
@@ -359,35 +445,40 @@ pub fn get_delegate_at_data_by_lock_hash(
     Ok((sudt.unwrap(), delegate_at_data.unwrap()))
 }
 
-pub fn get_withdraw_at_data_by_lock_hash(
+// all withdraw AT cells of the owner, the withdraw lock merges them in unlock mode
+pub fn get_withdraw_at_datas_by_lock_hash(
     cell_lock_hash: &[u8; 32],
     source: Source,
-) -> Result<(u128, withdraw_reader::WithdrawAtCellData), Error> {
-    let mut sudt = None;
-    let mut withdraw_at_data = None;
-    QueryIter::new(load_cell_lock_hash, source)
-        .enumerate()
-        .for_each(|(i, lock_hash)| {
-            if lock_hash == cell_lock_hash[..] {
-                let data = load_cell_data(i, source).unwrap();
-                if data.len() >= 16 {
-                    sudt = Some(bytes_to_u128(&data[..16].to_vec()));
-                    assert!(withdraw_at_data.is_none());
-                    withdraw_at_data = {
-                        let withdraw_data: withdraw_reader::WithdrawAtCellData =
-                            Cursor::from(data[16..].to_vec()).into();
-                        Some(withdraw_data)
-                    };
-                }
+) -> Result<Vec<(u128, withdraw_reader::WithdrawAtCellData)>, Error> {
+    let mut withdraw_at_datas = Vec::new();
+    for (i, lock_hash) in QueryIter::new(load_cell_lock_hash, source).enumerate() {
+        if lock_hash == cell_lock_hash[..] {
+            let data = load_cell_data(i, source)?;
+            if data.len() < 16 {
+                return Err(Error::WithdrawBadSudtDataFormat);
             }
-        });
-    if sudt.is_none() {
-        return Err(Error::WithdrawBadSudtDataFormat);
+            let sudt = bytes_to_u128(&data[..16].to_vec());
+            let withdraw_data: withdraw_reader::WithdrawAtCellData =
+                Cursor::from(data[16..].to_vec()).into();
+            withdraw_at_datas.push((sudt, withdraw_data));
+        }
     }
-    if withdraw_at_data.is_none() {
+    if withdraw_at_datas.is_empty() {
         return Err(Error::WithdrawDataEmpty);
     }
-    Ok((sudt.unwrap(), withdraw_at_data.unwrap()))
+    Ok(withdraw_at_datas)
+}
+
+// the only withdraw AT cell of the owner, several ones must be merged by the owner first
+pub fn get_withdraw_at_data_by_lock_hash(
+    cell_lock_hash: &[u8; 32],
+    source: Source,
+) -> Result<(u128, withdraw_reader::WithdrawAtCellData), Error> {
+    let mut withdraw_at_datas = get_withdraw_at_datas_by_lock_hash(cell_lock_hash, source)?;
+    if withdraw_at_datas.len() > 1 {
+        return Err(Error::WithdrawDuplicateCell);
+    }
+    Ok(withdraw_at_datas.remove(0))
 }
 
 pub fn get_delegate_delta(
@@ -445,17 +536,44 @@ pub fn get_stake_deltas(
     Ok(stake_deltas)
 }
 
-pub fn get_delegate_update_infos(
-    staker: &Vec<u8>,
+// xudt is only minted into a stake or delegate AT cell by a compound reward claim, whose amount is
+// checked by reward and issue type scripts along with the reward smt update
+pub fn check_at_amount_or_compound(
+    input_at_amount: u128,
+    output_at_amount: u128,
+    reward_smt_type_hash: &Vec<u8>,
+) -> Result<(), Error> {
+    if input_at_amount == output_at_amount {
+        return Ok(());
+    }
+    if output_at_amount > input_at_amount
+        && get_cell_count_by_type_hash(reward_smt_type_hash, Source::Input) > 0
+    {
+        debug!(
+            "at amount compounded: {}",
+            output_at_amount - input_at_amount
+        );
+        return Ok(());
+    }
+    Err(Error::InputOutputAtAmountNotEqual)
+}
+
+// redelegation moves delegated amount from `from_staker` to `staker` without passing through withdraw
+pub fn is_redelegation(delegate_info_delta: &DelegateInfoDelta) -> bool {
+    delegate_info_delta.from_staker() != [0u8; 20]
+}
+
+fn get_matched_delegate_infos<F>(
     cell_type_hash: &[u8; 32],
     delegate_at_code_hash: &Vec<u8>,
     source: Source,
-) -> Result<Vec<([u8; 20], [u8; 32], DelegateInfoDelta)>, Error> {
-    let mut delegate_update_infos = Vec::<([u8; 20], [u8; 32], DelegateInfoDelta)>::default();
-    debug!(
-        "get_delegate_update_infos staker: {:?}, cell_type_hash: {:?}",
-        staker, cell_type_hash
-    );
+    first_only: bool,
+    mut matches: F,
+) -> Vec<([u8; 20], [u8; 32], DelegateInfoDelta)>
+where
+    F: FnMut(&DelegateInfoDelta) -> bool,
+{
+    let mut matched_infos = Vec::<([u8; 20], [u8; 32], DelegateInfoDelta)>::default();
     QueryIter::new(load_cell_type_hash, source)
         .enumerate()
         .for_each(|(i, type_hash)| {
@@ -472,22 +590,66 @@ pub fn get_delegate_update_infos(
                     let delegate_infos = delegate_at_data.delegator_infos();
                     for i in 0..delegate_infos.len() {
                         let delegate_info = delegate_infos.get(i);
-                        if delegate_info.staker() == *staker {
+                        if matches(&delegate_info) {
                             let address: [u8; 20] =
                                 delegate_at_data.l2_address().as_slice().try_into().unwrap();
                             // debug!("delegate_info.staker: {:?}, amount: {}", delegate_info.staker(), delegate_info.amount());
-                            delegate_update_infos.push((address, lock_hash, delegate_info));
-                            break;
+                            matched_infos.push((address, lock_hash, delegate_info));
+                            if first_only {
+                                break;
+                            }
                         }
                     }
                 }
             }
         });
+    matched_infos
+}
+
+pub fn get_delegate_update_infos(
+    staker: &Vec<u8>,
+    cell_type_hash: &[u8; 32],
+    delegate_at_code_hash: &Vec<u8>,
+    source: Source,
+) -> Result<Vec<([u8; 20], [u8; 32], DelegateInfoDelta)>, Error> {
+    debug!(
+        "get_delegate_update_infos staker: {:?}, cell_type_hash: {:?}",
+        staker, cell_type_hash
+    );
+    let delegate_update_infos = get_matched_delegate_infos(
+        cell_type_hash,
+        delegate_at_code_hash,
+        source,
+        true,
+        |info| info.staker() == *staker,
+    );
 
     debug!("delegate_update_infos len: {}", delegate_update_infos.len());
     Ok(delegate_update_infos)
 }
 
+// delegate info deltas which redelegate from the staker to other stakers
+pub fn get_redelegate_out_infos(
+    from_staker: &Vec<u8>,
+    cell_type_hash: &[u8; 32],
+    delegate_at_code_hash: &Vec<u8>,
+    source: Source,
+) -> Result<Vec<([u8; 20], [u8; 32], DelegateInfoDelta)>, Error> {
+    let redelegate_out_infos = get_matched_delegate_infos(
+        cell_type_hash,
+        delegate_at_code_hash,
+        source,
+        false,
+        |info| is_redelegation(info) && info.from_staker() == *from_staker,
+    );
+    debug!(
+        "redelegate_out_infos from staker: {:?}, len: {}",
+        from_staker,
+        redelegate_out_infos.len()
+    );
+    Ok(redelegate_out_infos)
+}
+
 pub fn get_cell_count(type_id: &Vec<u8>, source: Source) -> u8 {
     let mut cells_count = 0u8;
     QueryIter::new(load_cell_lock_hash, source).for_each(|lock_hash| {
@@ -592,25 +754,46 @@ pub fn get_type_ids(metadata_type_id: &[u8; 32], source: Source) -> Result<TypeI
 pub fn get_current_validators(
     cell_type_id: &[u8; 32],
     source: Source,
-) -> Result<Vec<[u8; 48]>, Error> {
-    let mut metadata: Option<MetadataCellData> = None;
-    QueryIter::new(load_cell_type_hash, source)
-        .enumerate()
-        .for_each(|(i, type_hash)| {
-            if &type_hash.unwrap_or([0u8; 32]) == cell_type_id {
-                let data = load_cell_data(i, source).unwrap();
-                metadata = Some(Cursor::from(data[..].to_vec()).into());
-            }
-        });
+) -> Result<Vec<ValidatorObject>, Error> {
+    let metadata = get_metada_data_by_type_id(cell_type_id, source)?;
+    let mut validators: Vec<ValidatorObject> = Vec::new();
+    let validator_list = metadata.metadata().get(0).validators(); // validator of current is in index 0
+    for i in 0..validator_list.len() {
+        validators.push(ValidatorObject::new(&validator_list.get(i)));
+    }
+    Ok(validators)
+}
 
-    let mut bls_pub_keys: Vec<[u8; 48]> = Vec::new();
-    let validator_list = metadata.unwrap().metadata().get(0).validators(); // validator of current is in index 0
+// metadata cell keeps validators of its own epoch in index 0 and of the next epoch in index 1
+// unless the inauguration delay is 1, blocks of any other epoch can't be verified with this
+// metadata cell
+pub fn get_validators_by_epoch(
+    cell_type_id: &[u8; 32],
+    epoch: u64,
+    source: Source,
+) -> Result<Vec<ValidatorObject>, Error> {
+    let metadata = get_metada_data_by_type_id(cell_type_id, source)?;
+    let index: usize = match epoch.checked_sub(metadata.epoch()) {
+        Some(0) => EpochClass::CURRENT.into(),
+        Some(1) => EpochClass::NEXT.into(),
+        _ => return Err(Error::MetadataEpochMismatch),
+    };
+    debug!(
+        "metadata epoch: {}, signed epoch: {}, validators index: {}",
+        metadata.epoch(),
+        epoch,
+        index
+    );
+    let metadata_list = metadata.metadata();
+    if index >= metadata_list.len() {
+        return Err(Error::MetadataEpochMismatch);
+    }
+    let mut validators: Vec<ValidatorObject> = Vec::new();
+    let validator_list = metadata_list.get(index).validators();
     for i in 0..validator_list.len() {
-        let validator = validator_list.get(i);
-        let bls_pub_key: [u8; 48] = validator.bls_pub_key().as_slice().try_into().unwrap();
-        bls_pub_keys.push(bls_pub_key);
+        validators.push(ValidatorObject::new(&validator_list.get(i)));
     }
-    Ok(bls_pub_keys)
+    Ok(validators)
 }
 
 pub fn get_epoch_len(metadata_type_id: &[u8; 32], source: Source) -> Result<u32, Error> {
@@ -620,6 +803,30 @@ pub fn get_epoch_len(metadata_type_id: &[u8; 32], source: Source) -> Result<u32,
     Ok(metadata0.epoch_len())
 }
 
+pub fn get_period_len(metadata_type_id: &[u8; 32], source: Source) -> Result<u32, Error> {
+    let metadata = get_metada_data_by_type_id(metadata_type_id, source)?;
+    let metadata_list = metadata.metadata();
+    let metadata0 = metadata_list.get(0);
+    Ok(metadata0.period_len())
+}
+
+pub fn get_unbonding_epochs(metadata_type_id: &[u8; 32], source: Source) -> Result<u64, Error> {
+    let metadata = get_metada_data_by_type_id(metadata_type_id, source)?;
+    Ok(metadata.unbonding_epochs())
+}
+
+// stake and delegate deltas submitted in current epoch take effect in the returned epoch
+pub fn get_inauguration_epoch(
+    metadata_type_id: &[u8; 32],
+    current_epoch: u64,
+    source: Source,
+) -> Result<u64, Error> {
+    let metadata = get_metada_data_by_type_id(metadata_type_id, source)?;
+    current_epoch
+        .checked_add(metadata.inauguration_delay())
+        .ok_or(Error::BadInaugurationEpoch)
+}
+
 pub fn get_quorum_size(
     metadata_type_id: &[u8; 32],
     index: EpochClass,
@@ -742,11 +949,12 @@ pub fn get_reward_smt_data(type_id: &[u8; 32], source: Source) -> Result<RewardS
     Ok(reward_smt_data.unwrap())
 }
 
-pub fn get_delegate_requirement(
+// None if requirement info in the stake AT cell is left empty
+fn get_delegate_requirement_type_id(
     staker: &Vec<u8>,
     metadata_type_id: &[u8; 32],
     stake_at_code_hash: &Vec<u8>,
-) -> Result<DelegateRequirement, Error> {
+) -> Result<Option<[u8; 32]>, Error> {
     // debug!(
     //     "staker: {:?}, metadata_type_id: {:?}, stake_at_code_hash: {:?}",
     //     staker, metadata_type_id, stake_at_code_hash
@@ -762,16 +970,41 @@ pub fn get_delegate_requirement(
     // debug!("stake_at_lock_hash: {:?}", stake_at_lock_hash);
     let (_, stake_at_data) = get_stake_at_data_by_lock_hash(&stake_at_lock_hash, Source::CellDep)?;
     let delegate_requirement = stake_at_data.requirement_info();
+    if delegate_requirement.code_hash() == [0u8; 32] {
+        return Ok(None);
+    }
     let delegate_args = {
         let mut args = Vec::new();
         args.extend_from_slice(&delegate_requirement.requirement().metadata_type_id());
         args.extend_from_slice(&delegate_requirement.requirement().requirement_type_id());
         args
     };
-    let delegate_requirement_type_id =
-        get_script_hash(&delegate_requirement.code_hash(), &delegate_args);
+    Ok(Some(get_script_hash(
+        &delegate_requirement.code_hash(),
+        &delegate_args,
+    )))
+}
 
+pub fn has_delegate_requirement(
+    staker: &Vec<u8>,
+    metadata_type_id: &[u8; 32],
+    stake_at_code_hash: &Vec<u8>,
+) -> Result<bool, Error> {
+    Ok(get_delegate_requirement_type_id(staker, metadata_type_id, stake_at_code_hash)?.is_some())
+}
+
+pub fn get_delegate_requirement(
+    staker: &Vec<u8>,
+    metadata_type_id: &[u8; 32],
+    stake_at_code_hash: &Vec<u8>,
+) -> Result<DelegateRequirement, Error> {
     let mut delegate_requirement = DelegateRequirement::new_builder().build();
+    let delegate_requirement_type_id =
+        match get_delegate_requirement_type_id(staker, metadata_type_id, stake_at_code_hash)? {
+            Some(type_id) => type_id,
+            None => return Ok(delegate_requirement),
+        };
+
     QueryIter::new(load_cell_type_hash, Source::CellDep)
         .enumerate()
         .for_each(|(i, type_hash)| {
@@ -787,6 +1020,31 @@ pub fn get_delegate_requirement(
     Ok(delegate_requirement)
 }
 
+// requirement cell of the staker in cell deps, None if the staker never set one or it is missing
+pub fn get_delegate_cell_data(
+    staker: &Vec<u8>,
+    metadata_type_id: &[u8; 32],
+    stake_at_code_hash: &Vec<u8>,
+) -> Result<Option<delegate_reader::DelegateCellData>, Error> {
+    let delegate_requirement_type_id =
+        match get_delegate_requirement_type_id(staker, metadata_type_id, stake_at_code_hash)? {
+            Some(type_id) => type_id,
+            None => return Ok(None),
+        };
+
+    let mut delegate_cell_data = None;
+    QueryIter::new(load_cell_type_hash, Source::CellDep)
+        .enumerate()
+        .for_each(|(i, type_hash)| {
+            if type_hash == Some(delegate_requirement_type_id) {
+                let data = load_cell_data(i, Source::CellDep).unwrap();
+                delegate_cell_data = Some(Cursor::from(data.to_vec()).into());
+            }
+        });
+
+    Ok(delegate_cell_data)
+}
+
 pub fn axon_byte32(bytes: &[u8]) -> basic::Byte32 {
     basic::Byte32::new_unchecked(bytes.to_vec().into())
 }
@@ -4,7 +4,12 @@
 #![feature(alloc_error_handler)]
 #![feature(panic_info_message)]
 
+pub mod election;
 pub mod error;
+pub mod eth;
+pub mod exit;
 pub mod helper;
+pub mod requirement;
+pub mod slash;
 pub mod smt;
 pub mod stake;
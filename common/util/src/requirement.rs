@@ -0,0 +1,44 @@
+use crate::helper::{bytes_to_u128, CommissionObject};
+use axon_types::delegate_reader::DelegateCellData;
+
+// Commission rules shared by requirement and reward, protecting delegators from a staker hiking
+// commission right before a reward claim.
+//
+// 1. a commission change signed by the staker takes effect from the inauguration epoch of the
+//    transaction, the rate in force until then is kept as previous_rate.
+// 2. the new rate differs from the rate in force by at most MAX_COMMISSION_CHANGE percent. A
+//    pending change can only be replaced by one starting from the rate in force, so the rate in
+//    force moves by at most MAX_COMMISSION_CHANGE percent per epoch.
+// 3. metadata records the commission in force of every validator in commission smt at the end of
+//    each epoch, reward reads it from there, so no change applies to epochs not claimed yet.
+pub const MAX_COMMISSION_CHANGE: u8 = 5;
+
+pub fn commission_rate_at(delegate_cell_data: &DelegateCellData, epoch: u64) -> u8 {
+    let commission_change = delegate_cell_data.commission_change();
+    if epoch >= commission_change.effective_epoch() {
+        delegate_cell_data.delegate_requirement().commission_rate()
+    } else {
+        commission_change.previous_rate()
+    }
+}
+
+pub fn is_commission_change_allowed(rate_in_force: u8, new_rate: u8) -> bool {
+    let change = if new_rate > rate_in_force {
+        new_rate - rate_in_force
+    } else {
+        rate_in_force - new_rate
+    };
+    change <= MAX_COMMISSION_CHANGE
+}
+
+pub fn commission_at(
+    addr: [u8; 20],
+    delegate_cell_data: &DelegateCellData,
+    epoch: u64,
+) -> CommissionObject {
+    CommissionObject {
+        addr,
+        commission_rate: commission_rate_at(delegate_cell_data, epoch),
+        delegate_threshold: bytes_to_u128(&delegate_cell_data.delegate_requirement().threshold()),
+    }
+}
@@ -0,0 +1,281 @@
+extern crate alloc;
+
+use crate::{error::Error, helper::*};
+use alloc::vec::Vec;
+use axon_types::{
+    checkpoint::CheckpointEvidenceArgs,
+    checkpoint_reader::{CheckpointCellData, CheckpointEvidenceCellData},
+    delegate_reader::DelegateSmtWitness,
+    metadata_reader::MetadataCellData,
+    stake_reader::StakeSmtWitness,
+    Cursor,
+};
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::prelude::Entity,
+    debug,
+    high_level::{
+        load_cell_data, load_cell_lock_hash, load_cell_type, load_cell_type_hash,
+        load_witness_args, QueryIter,
+    },
+};
+use core::result::Result;
+
+// witness mode of stake smt and delegate smt cells to slash stakers and their delegators
+pub const SLASH_MODE: u8 = 2;
+
+pub struct SlashInfo {
+    pub offenders: Vec<[u8; 20]>,
+    pub slash_rate: u8,
+    pub treasury_lock_hash: [u8; 32],
+    // epoch of the last checkpoint when offenders are measured by liveness
+    pub liveness_epoch: Option<u64>,
+}
+
+impl SlashInfo {
+    // the slashed part of amount and the part returned to its owner
+    pub fn split_amount(&self, amount: u128) -> Result<(u128, u128), Error> {
+        let slash_amount = amount
+            .checked_mul(self.slash_rate as u128)
+            .ok_or(Error::SlashRateOutOfBounds)?
+            / 100;
+        let remaining_amount = amount
+            .checked_sub(slash_amount)
+            .ok_or(Error::SlashRateOutOfBounds)?;
+        Ok((slash_amount, remaining_amount))
+    }
+
+    pub fn is_offender(&self, addr: &[u8; 20]) -> bool {
+        self.offenders.iter().any(|offender| offender == addr)
+    }
+}
+
+// offenders come from the equivocation evidence cell in inputs, which is consumed by the slash.
+// without evidence, they are measured from propose counts of the last checkpoint of current epoch
+pub fn get_slash_info(metadata_type_id: &[u8; 32]) -> Result<SlashInfo, Error> {
+    let metadata = get_metada_data_by_type_id(metadata_type_id, Source::CellDep)?;
+    let type_ids = metadata.type_ids();
+    let checkpoint_type_hash = get_script_hash(
+        &type_ids.checkpoint_code_hash(),
+        &type_ids.checkpoint_type_id(),
+    );
+    let checkpoint = get_checkpoint_from_celldeps(&checkpoint_type_hash.to_vec())?;
+
+    let evidence =
+        get_evidence_from_inputs(&type_ids.checkpoint_code_hash(), &checkpoint_type_hash)?;
+    let mut liveness_epoch = None;
+    let offenders = match evidence {
+        Some(evidence) => {
+            // evidence can only be used within the epoch it is submitted
+            if evidence.metadata_type_id() != metadata_type_id.to_vec()
+                || evidence.epoch() != checkpoint.epoch()
+            {
+                return Err(Error::SlashBadEvidence);
+            }
+            let evidence_offenders = evidence.offenders();
+            (0..evidence_offenders.len())
+                .map(|i| evidence_offenders.get(i).try_into().unwrap())
+                .collect::<Vec<[u8; 20]>>()
+        }
+        None => {
+            liveness_epoch = Some(checkpoint.epoch());
+            get_liveness_offenders(&metadata, &checkpoint)?
+        }
+    };
+    debug!("slash offenders: {:?}", offenders);
+    if offenders.is_empty() {
+        return Err(Error::SlashNoOffender);
+    }
+    if metadata.slash_rate() > 100 {
+        return Err(Error::SlashRateOutOfBounds);
+    }
+
+    Ok(SlashInfo {
+        offenders,
+        slash_rate: metadata.slash_rate(),
+        treasury_lock_hash: metadata.treasury_lock_hash().try_into().unwrap(),
+        liveness_epoch,
+    })
+}
+
+fn get_evidence_from_inputs(
+    checkpoint_code_hash: &Vec<u8>,
+    checkpoint_type_hash: &[u8; 32],
+) -> Result<Option<CheckpointEvidenceCellData>, Error> {
+    let mut evidence = None;
+    QueryIter::new(load_cell_type, Source::Input)
+        .enumerate()
+        .map(|(i, type_script)| {
+            let type_script = match type_script {
+                Some(type_script) => type_script,
+                None => return Ok(()),
+            };
+            let args = type_script.args().raw_data();
+            if type_script.code_hash().as_slice() != checkpoint_code_hash.as_slice()
+                || args.len() != CheckpointEvidenceArgs::TOTAL_SIZE
+                || args[..32] != checkpoint_type_hash[..]
+            {
+                return Ok(());
+            }
+            if evidence.is_some() {
+                return Err(Error::SlashBadEvidence);
+            }
+            let data = load_cell_data(i, Source::Input)?;
+            evidence = Some(CheckpointEvidenceCellData::from(Cursor::from(data)));
+            Ok(())
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    Ok(evidence)
+}
+
+// the last checkpoint of an epoch holds propose counts of the whole epoch, validators proposing
+// less than liveness_slash_rate percent of the blocks expected by their propose_weight are offenders
+fn get_liveness_offenders(
+    metadata: &MetadataCellData,
+    checkpoint: &CheckpointCellData,
+) -> Result<Vec<[u8; 20]>, Error> {
+    let metadata0 = metadata.metadata().get(0);
+    let epoch_len = metadata0.epoch_len();
+    if checkpoint.period() != epoch_len - 1 {
+        return Err(Error::SlashNotLastCheckpoint);
+    }
+
+    let validators = metadata0.validators();
+    let total_weight: u64 = (0..validators.len())
+        .map(|i| validators.get(i).propose_weight() as u64)
+        .sum();
+    if total_weight == 0 {
+        return Ok(Vec::new());
+    }
+    let liveness_slash_rate = metadata.liveness_slash_rate() as u64;
+    if liveness_slash_rate > 100 {
+        return Err(Error::SlashRateOutOfBounds);
+    }
+    let total_count = epoch_len as u64 * metadata0.period_len() as u64;
+    let propose_counts = checkpoint.propose_count();
+    let mut offenders = Vec::new();
+    for i in 0..validators.len() {
+        let validator = validators.get(i);
+        let address: [u8; 20] = validator.address().try_into().unwrap();
+        let expected_count = total_count * validator.propose_weight() as u64 / total_weight;
+        let count = (0..propose_counts.len())
+            .map(|j| propose_counts.get(j))
+            .find(|propose_count| propose_count.address() == address)
+            .map_or(0, |propose_count| propose_count.count());
+        debug!(
+            "validator: {:?}, propose count: {}, expected: {}",
+            address, count, expected_count
+        );
+        if count * 100 < expected_count * liveness_slash_rate {
+            offenders.push(address);
+        }
+    }
+    Ok(offenders)
+}
+
+fn get_smt_witness_input_type(type_hash: &[u8; 32]) -> Result<Vec<u8>, Error> {
+    let index = QueryIter::new(load_cell_type_hash, Source::Input)
+        .position(|cell_type_hash| cell_type_hash.unwrap_or([0u8; 32]) == *type_hash)
+        .ok_or(Error::SlashModeMismatch)?;
+    let witness_input_type = load_witness_args(index, Source::Input)?
+        .input_type()
+        .to_opt()
+        .ok_or(Error::WitnessInputTypeError)?;
+    Ok(witness_input_type.raw_data().to_vec())
+}
+
+pub fn get_stake_smt_witness(stake_smt_type_hash: &[u8; 32]) -> Result<StakeSmtWitness, Error> {
+    let witness_input_type = get_smt_witness_input_type(stake_smt_type_hash)?;
+    Ok(Cursor::from(witness_input_type).into())
+}
+
+pub fn get_delegate_smt_witness(
+    delegate_smt_type_hash: &[u8; 32],
+) -> Result<DelegateSmtWitness, Error> {
+    let witness_input_type = get_smt_witness_input_type(delegate_smt_type_hash)?;
+    Ok(Cursor::from(witness_input_type).into())
+}
+
+// stake and delegate AT cells share the args layout of metadata_type_id and owner address
+pub fn calc_at_lock_hash(
+    at_code_hash: &Vec<u8>,
+    metadata_type_id: &[u8; 32],
+    addr: &[u8; 20],
+) -> [u8; 32] {
+    let mut args = Vec::new();
+    args.extend_from_slice(metadata_type_id);
+    args.extend_from_slice(addr);
+    get_script_hash(at_code_hash, &args)
+}
+
+// returns the total amount and the lock data after amount of the AT cells
+fn get_xudt_by_lock_hash(
+    xudt_type_hash: &Vec<u8>,
+    lock_hash: &[u8; 32],
+    source: Source,
+) -> Result<(u128, Vec<Vec<u8>>), Error> {
+    let mut amount = 0u128;
+    let mut lock_datas = Vec::new();
+    QueryIter::new(load_cell_lock_hash, source)
+        .enumerate()
+        .map(|(i, cell_lock_hash)| {
+            if cell_lock_hash != *lock_hash {
+                return Ok(());
+            }
+            if load_cell_type_hash(i, source)?.unwrap_or([0u8; 32]) != xudt_type_hash[..] {
+                return Ok(());
+            }
+            let data = load_cell_data(i, source)?;
+            if data.len() < 16 {
+                return Err(Error::BadSudtDataFormat);
+            }
+            amount += bytes_to_u128(&data[..16].to_vec());
+            lock_datas.push(data[16..].to_vec());
+            Ok(())
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    Ok((amount, lock_datas))
+}
+
+// AT cell of a slashed or released staker or delegator must release exactly the given amount,
+// pending stake changes in its lock data are kept
+pub fn verify_at_released(
+    xudt_type_hash: &Vec<u8>,
+    lock_hash: &[u8; 32],
+    amount: u128,
+) -> Result<(), Error> {
+    let (input_amount, input_lock_datas) =
+        get_xudt_by_lock_hash(xudt_type_hash, lock_hash, Source::Input)?;
+    let (output_amount, output_lock_datas) =
+        get_xudt_by_lock_hash(xudt_type_hash, lock_hash, Source::Output)?;
+    if input_lock_datas != output_lock_datas {
+        return Err(Error::SlashAtAmountMismatch);
+    }
+    debug!(
+        "at cell input_amount: {}, output_amount: {}, released: {}",
+        input_amount, output_amount, amount
+    );
+    if input_amount < output_amount || input_amount - output_amount != amount {
+        return Err(Error::SlashAtAmountMismatch);
+    }
+    Ok(())
+}
+
+pub fn verify_treasury_amount(
+    xudt_type_hash: &Vec<u8>,
+    treasury_lock_hash: &[u8; 32],
+    amount: u128,
+) -> Result<(), Error> {
+    let (input_amount, _) =
+        get_xudt_by_lock_hash(xudt_type_hash, treasury_lock_hash, Source::Input)?;
+    let (output_amount, _) =
+        get_xudt_by_lock_hash(xudt_type_hash, treasury_lock_hash, Source::Output)?;
+    debug!(
+        "treasury input_amount: {}, output_amount: {}, slashed: {}",
+        input_amount, output_amount, amount
+    );
+    if output_amount < input_amount || output_amount - input_amount != amount {
+        return Err(Error::SlashTreasuryAmountMismatch);
+    }
+    Ok(())
+}
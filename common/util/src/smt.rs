@@ -12,7 +12,7 @@ use sparse_merkle_tree::{
 };
 
 use crate::error::Error;
-use crate::helper::ProposeCountObject;
+use crate::helper::{CommissionObject, ProposeCountObject, RewardParamsObject};
 
 // define SMT value
 #[derive(Default, Clone, Copy, Debug)]
@@ -59,6 +59,8 @@ pub type PROPOSE_BOTTOM_SMT =
     SparseMerkleTree<Blake2bHasher, ProposeBottomValue, DefaultStore<ProposeBottomValue>>;
 #[allow(non_camel_case_types)]
 pub type CLAIM_SMT = SparseMerkleTree<Blake2bHasher, EpochValue, DefaultStore<EpochValue>>;
+#[allow(non_camel_case_types)]
+pub type COMMISSION_BOTTOM_SMT = SparseMerkleTree<Blake2bHasher, H256, DefaultStore<H256>>;
 
 // helper function
 pub fn new_blake2b() -> Blake2b {
@@ -112,6 +114,17 @@ impl Ord for LockInfo {
     }
 }
 
+// key of evidence smt in checkpoint cell, the value is 1 once the equivocation is submitted
+pub fn evidence_to_h256(height: u64, round: u64, addr: &[u8; 20]) -> H256 {
+    let mut buf = [0u8; 32];
+    let mut hasher = new_blake2b();
+    hasher.update(&height.to_le_bytes());
+    hasher.update(&round.to_le_bytes());
+    hasher.update(addr);
+    hasher.finalize(&mut buf);
+    buf.into()
+}
+
 pub fn addr_to_h256(addr: &[u8; 20]) -> H256 {
     let mut buf = [0u8; 32];
     buf[..20].copy_from_slice(addr);
@@ -145,6 +158,37 @@ pub fn get_bottom_smt_root(lock_infos: &BTreeSet<LockInfo>) -> H256 {
     *tree.root()
 }
 
+// commission rate and delegate threshold of a validator, value of commission smt bottom leaf
+pub fn commission_to_h256(commission_rate: u8, delegate_threshold: u128) -> H256 {
+    let mut buf = [0u8; 32];
+    buf[0] = commission_rate;
+    buf[1..17].copy_from_slice(&delegate_threshold.to_le_bytes());
+    buf.into()
+}
+
+// reward parameters of an epoch, value of reward params smt leaf
+pub fn reward_params_to_h256(params: &RewardParamsObject) -> H256 {
+    let mut buf = [0u8; 32];
+    let mut hasher = new_blake2b();
+    hasher.update(&params.base_reward.to_le_bytes());
+    hasher.update(&params.half_epoch.to_le_bytes());
+    hasher.update(&params.minimum_normal_propose_count.to_le_bytes());
+    hasher.update(&[params.propose_discount_rate]);
+    hasher.finalize(&mut buf);
+    buf.into()
+}
+
+pub fn get_bottom_smt_root_commission(commissions: &Vec<CommissionObject>) -> H256 {
+    let mut tree = COMMISSION_BOTTOM_SMT::default();
+    for commission in commissions.iter() {
+        let key: H256 = addr_to_h256(&commission.addr);
+        let value = commission_to_h256(commission.commission_rate, commission.delegate_threshold);
+        tree.update(key, value).expect("update");
+    }
+
+    *tree.root()
+}
+
 pub fn get_bottom_smt_root_propose(propose_infos: &Vec<ProposeCountObject>) -> H256 {
     let mut tree = PROPOSE_BOTTOM_SMT::default();
     for propose_info in propose_infos.iter() {
@@ -187,6 +231,31 @@ pub fn verify_top_smt(
     Ok(result)
 }
 
+// offenders of the equivocation at (height, round) are absent from the old evidence smt and
+// recorded in the new one, so the same evidence can't be submitted twice
+pub fn verify_evidence_smt(
+    height: u64,
+    round: u64,
+    offenders: &Vec<[u8; 20]>,
+    old_root: H256,
+    new_root: H256,
+    proof: Vec<u8>,
+) -> Result<(), Error> {
+    let keys = offenders
+        .iter()
+        .map(|offender| evidence_to_h256(height, round, offender))
+        .collect::<Vec<_>>();
+    let old_leaves = keys.iter().map(|key| (*key, H256::zero())).collect();
+    if !smt_verify_leaves(old_leaves, old_root, CompiledMerkleProof(proof.clone()))? {
+        return Err(Error::CheckpointEvidenceDuplicated);
+    }
+    let new_leaves = keys.iter().map(|key| (*key, u64_to_h256(1))).collect();
+    if !smt_verify_leaves(new_leaves, new_root, CompiledMerkleProof(proof))? {
+        return Err(Error::CheckpointEvidenceCellError);
+    }
+    Ok(())
+}
+
 pub fn verify_top_smt_for_metadata_update(
     key: H256,
     next_key: H256,
@@ -249,6 +318,36 @@ pub fn verify_2layer_smt_propose(
     verify_top_smt(epoch, bottom_root, top_root, top_proof)
 }
 
+// commissions of an epoch are recorded only once, the same proof shows the epoch is absent from
+// the old top root, so recorded epochs can never be rewritten
+pub fn verify_2layer_smt_commission(
+    commissions: &Vec<CommissionObject>,
+    epoch: H256,
+    old_top_root: H256,
+    new_top_root: H256,
+    top_proof: CompiledMerkleProof,
+) -> Result<bool, Error> {
+    let bottom_root = get_bottom_smt_root_commission(commissions);
+    if !verify_top_smt(epoch, H256::zero(), old_top_root, top_proof.clone())? {
+        return Ok(false);
+    }
+    verify_top_smt(epoch, bottom_root, new_top_root, top_proof)
+}
+
+// like commissions, reward parameters of an epoch are recorded only once
+pub fn verify_smt_reward_params(
+    params: &RewardParamsObject,
+    epoch: H256,
+    old_root: H256,
+    new_root: H256,
+    proof: CompiledMerkleProof,
+) -> Result<bool, Error> {
+    if !verify_top_smt(epoch, H256::zero(), old_root, proof.clone())? {
+        return Ok(false);
+    }
+    verify_top_smt(epoch, reward_params_to_h256(params), new_root, proof)
+}
+
 // pub fn build_smt_tree_and_get_root(
 //     lock_infos: &BTreeSet<LockInfo>,
 //     proof: &Option<Vec<u8>>,
@@ -9,26 +9,44 @@ use alloc::vec::Vec;
 // https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
 use ckb_std::{
     ckb_constants::Source,
+    ckb_types::{packed::Script, prelude::*},
     debug,
-    high_level::{load_script, load_witness_args},
+    high_level::{
+        load_cell_data, load_cell_type, load_cell_type_hash, load_script, load_witness_args,
+        QueryIter,
+    },
 };
 
 use axon_types::{
-    checkpoint_reader::{self as axon, CheckpointCellData},
+    checkpoint::CheckpointEvidenceArgs as CheckpointEvidenceArgsEntity,
+    checkpoint_reader::{
+        self as axon, CheckpointCellData, CheckpointEvidenceArgs, CheckpointEvidenceCellData,
+        ProposalVec,
+    },
     Cursor,
 };
 use bit_vec::BitVec;
 use ckb_type_id::{load_type_id_from_script_args, validate_type_id};
 use keccak_hash::keccak;
 use rlp::{Rlp, RlpStream};
-use util::{error::Error, helper::*};
+use util::{
+    error::Error,
+    helper::*,
+    slash::{get_stake_smt_witness, SLASH_MODE},
+    smt::verify_evidence_smt,
+};
 
 pub fn main() -> Result<(), Error> {
+    let script = load_script()?;
+    if script.args().raw_data().len() == CheckpointEvidenceArgsEntity::TOTAL_SIZE {
+        debug!("checkpoint evidence cell");
+        return verify_evidence_cell(&script);
+    }
+
     let type_id = load_type_id_from_script_args(0)?;
     debug!("type_id: {:?}", type_id);
     validate_type_id(type_id)?;
 
-    let script = load_script()?;
     let checkpoint_type_id = util::helper::calc_script_hash(&script).to_vec();
     debug!("checkpoint_type_id = {:?}", checkpoint_type_id);
     let input_checkpoint_count = get_cell_count_by_type_hash(&checkpoint_type_id, Source::Input);
@@ -54,15 +72,213 @@ pub fn main() -> Result<(), Error> {
         .try_into()
         .unwrap();
 
-    debug!("verify_multsig");
-    verify_multsig(&metadata_type_id, &output_checkpoint_data)?;
+    let witness = get_checkpoint_witness(Source::GroupInput, 0)?;
+    match witness.mode() {
+        0 => {
+            let (proposal, proof) = (witness.proposal(), witness.proof());
 
-    debug!("verify_checkpoint_data");
-    verify_checkpoint_data(
-        &metadata_type_id,
-        &input_checkpoint_data,
-        &output_checkpoint_data,
-    )?;
+            debug!("verify_metadata_epoch");
+            verify_metadata_epoch(
+                &metadata_type_id,
+                &input_checkpoint_data,
+                &output_checkpoint_data,
+            )?;
+
+            debug!("verify_multsig");
+            verify_multsig(
+                &metadata_type_id,
+                &output_checkpoint_data,
+                &proposal,
+                &proof,
+            )?;
+
+            debug!("verify_checkpoint_data");
+            verify_checkpoint_data(
+                &metadata_type_id,
+                &input_checkpoint_data,
+                &output_checkpoint_data,
+            )?;
+
+            debug!("verify_checkpoint_proposal");
+            verify_checkpoint_proposal(
+                &metadata_type_id,
+                &proposal,
+                &proof,
+                &input_checkpoint_data,
+                &output_checkpoint_data,
+            )?;
+
+            debug!("verify_period_proposals");
+            let proposers = verify_period_proposals(
+                &proposal,
+                &witness.period_proposals(),
+                &input_checkpoint_data,
+            )?;
+
+            debug!("verify_propose_count");
+            verify_propose_count(&proposers, &input_checkpoint_data, &output_checkpoint_data)?;
+        }
+        1 => {
+            debug!("verify_metadata_epoch");
+            verify_metadata_epoch(
+                &metadata_type_id,
+                &input_checkpoint_data,
+                &output_checkpoint_data,
+            )?;
+
+            debug!("verify_equivocation_evidence");
+            verify_equivocation_evidence(
+                &script,
+                &checkpoint_type_id,
+                &metadata_type_id,
+                &input_checkpoint_data,
+                &output_checkpoint_data,
+                &witness,
+            )?;
+        }
+        _ => {
+            return Err(Error::UnknownMode);
+        }
+    }
+
+    Ok(())
+}
+
+// decoded fields of the Axon block proposal signed by validators
+// refer to https://github.com/axonweb3/axon-tools/blob/main/axon-tools-riscv/src/types.rs
+struct ProposalObject {
+    prev_hash: Vec<u8>,
+    proposer: [u8; 20],
+    prev_state_root: Vec<u8>,
+    timestamp: u64,
+    number: u64,
+}
+
+impl ProposalObject {
+    fn decode(proposal: &Vec<u8>) -> Result<Self, Error> {
+        let proposal_rlp = Rlp::new(proposal);
+        let prev_hash: Vec<u8> = proposal_rlp.val_at(0).map_err(|_| Error::ProofRlpError)?;
+        let proposer: Vec<u8> = proposal_rlp.val_at(1).map_err(|_| Error::ProofRlpError)?;
+        let proposer: [u8; 20] = proposer
+            .as_slice()
+            .try_into()
+            .map_err(|_| Error::ProofRlpError)?;
+        let prev_state_root: Vec<u8> = proposal_rlp.val_at(2).map_err(|_| Error::ProofRlpError)?;
+        let timestamp: u64 = proposal_rlp.val_at(5).map_err(|_| Error::ProofRlpError)?;
+        let number: u64 = proposal_rlp.val_at(6).map_err(|_| Error::ProofRlpError)?;
+        Ok(Self {
+            prev_hash,
+            proposer,
+            prev_state_root,
+            timestamp,
+            number,
+        })
+    }
+}
+
+// decoded fields of the Overlord proof signed by validators
+struct ProofObject {
+    height: u64,
+    round: u64,
+    block_hash: Vec<u8>,
+    signature: Vec<u8>,
+    bitmap: BitVec,
+}
+
+impl ProofObject {
+    fn decode(proof: &Vec<u8>) -> Result<Self, Error> {
+        let proof_rlp = Rlp::new(proof);
+        let height: u64 = proof_rlp.val_at(0).map_err(|_| Error::ProofRlpError)?;
+        let round: u64 = proof_rlp.val_at(1).map_err(|_| Error::ProofRlpError)?;
+        let block_hash: Vec<u8> = proof_rlp.val_at(2).map_err(|_| Error::ProofRlpError)?;
+        let signature: Vec<u8> = proof_rlp.val_at(3).map_err(|_| Error::ProofRlpError)?;
+        if signature.len() != 96 {
+            return Err(Error::ProofRlpError);
+        }
+        let bitmap: Vec<u8> = proof_rlp.val_at(4).map_err(|_| Error::ProofRlpError)?;
+        Ok(Self {
+            height,
+            round,
+            block_hash,
+            signature,
+            bitmap: BitVec::from_bytes(bitmap.as_slice()),
+        })
+    }
+
+    // validators marked in the bitmap
+    fn signers(&self, validators: &Vec<ValidatorObject>) -> Result<Vec<ValidatorObject>, Error> {
+        self.bitmap
+            .iter()
+            .enumerate()
+            .filter_map(|(i, flag)| {
+                if flag {
+                    if let Some(validator) = validators.get(i) {
+                        return Some(Ok(*validator));
+                    } else {
+                        return Some(Err(Error::ProofRlpError));
+                    }
+                }
+                None
+            })
+            .collect::<Result<Vec<_>, _>>()
+    }
+
+    // prepare signing message and check blst signature validation
+    fn verify_signature(&self, signers: &Vec<ValidatorObject>) -> Result<(), Error> {
+        debug!("height = {}, round = {}", self.height, self.round);
+        let mut message = RlpStream::new();
+        message
+            .begin_list(4)
+            .append(&self.height)
+            .append(&self.round)
+            .append(&2u8)
+            .append(&self.block_hash);
+        let pubkeys = signers
+            .iter()
+            .map(|validator| validator.bls_pub_key)
+            .collect::<Vec<_>>();
+        debug!(
+            "verify_blst_signature, raw message: {:?}",
+            message.as_raw().to_vec()
+        );
+        let message = keccak256(&message.as_raw().to_vec());
+        debug!("verify_blst_signature, hash message: {:?}", message);
+        if !blst::verify_blst_signature(&pubkeys, &self.signature, &message.to_vec()) {
+            return Err(Error::SignatureMismatch);
+        }
+        Ok(())
+    }
+}
+
+fn get_checkpoint_witness(source: Source, index: usize) -> Result<axon::CheckpointWitness, Error> {
+    let witness_args = load_witness_args(index, source)?;
+    // extract proposal and proof data from witness input_type
+    let witness_input_type = witness_args.input_type().to_opt();
+    if witness_input_type.is_none() {
+        return Err(Error::WitnessLockError);
+    }
+    Ok(Cursor::from(witness_input_type.unwrap().raw_data().to_vec()).into())
+}
+
+// metadata cell dep must belong to the epoch of input checkpoint cell, or to the epoch of output
+// checkpoint cell once validators are rotated at the epoch boundary
+fn verify_metadata_epoch(
+    metadata_type_id: &[u8; 32],
+    input_checkpoint_data: &CheckpointCellData,
+    output_checkpoint_data: &CheckpointCellData,
+) -> Result<(), Error> {
+    let metadata_epoch = get_metada_data_by_type_id(metadata_type_id, Source::CellDep)?.epoch();
+    debug!(
+        "metadata epoch = {}, input epoch = {}, output epoch = {}",
+        metadata_epoch,
+        input_checkpoint_data.epoch(),
+        output_checkpoint_data.epoch()
+    );
+    if metadata_epoch != input_checkpoint_data.epoch()
+        && metadata_epoch != output_checkpoint_data.epoch()
+    {
+        return Err(Error::MetadataEpochMismatch);
+    }
 
     Ok(())
 }
@@ -74,6 +290,7 @@ fn verify_checkpoint_data(
 ) -> Result<(), Error> {
     if input_checkpoint_data.version() != output_checkpoint_data.version()
         || input_checkpoint_data.metadata_type_id() != output_checkpoint_data.metadata_type_id()
+        || input_checkpoint_data.evidence_smt_root() != output_checkpoint_data.evidence_smt_root()
     {
         return Err(Error::CheckpointDataMismatch);
     }
@@ -111,90 +328,368 @@ fn verify_checkpoint_data(
     Ok(())
 }
 
-fn verify_multsig(
+// bind the signed proposal to output checkpoint cell and check it follows input checkpoint cell
+fn verify_checkpoint_proposal(
     metadata_type_id: &[u8; 32],
+    proposal: &Vec<u8>,
+    proof: &Vec<u8>,
+    input_checkpoint_data: &CheckpointCellData,
     output_checkpoint_data: &CheckpointCellData,
 ) -> Result<(), Error> {
-    let witness_args = load_witness_args(0, Source::GroupInput)?;
-    // extract proposal and proof data from witness lock
-    let (proposal, proof) = {
-        let witness_input_type = witness_args.input_type().to_opt();
-        if witness_input_type.is_none() {
-            return Err(Error::WitnessLockError);
+    let proposal = ProposalObject::decode(proposal)?;
+    let proof = ProofObject::decode(proof)?;
+    debug!(
+        "proposal number = {}, timestamp = {}, proof height = {}",
+        proposal.number, proposal.timestamp, proof.height
+    );
+
+    // the proposal carries the state root committed by its parent block, which is the
+    // latest state root the validators have signed for
+    if proposal.number != proof.height
+        || output_checkpoint_data.latest_block_height() != proposal.number
+        || output_checkpoint_data.latest_block_hash() != proof.block_hash
+        || output_checkpoint_data.state_root() != proposal.prev_state_root
+        || output_checkpoint_data.timestamp() != proposal.timestamp
+    {
+        return Err(Error::CheckpointProposalMismatch);
+    }
+
+    // every checkpoint covers exactly period_len blocks, and time never goes backwards
+    let period_len = get_period_len(metadata_type_id, Source::CellDep)?;
+    let input_height = input_checkpoint_data.latest_block_height();
+    let output_height = output_checkpoint_data.latest_block_height();
+    debug!(
+        "input_height = {}, output_height = {}, period_len = {}",
+        input_height, output_height, period_len
+    );
+    if output_height <= input_height
+        || output_height - input_height != period_len as u64
+        || output_checkpoint_data.timestamp() < input_checkpoint_data.timestamp()
+    {
+        return Err(Error::CheckpointBlockNotContinuous);
+    }
+
+    Ok(())
+}
+
+// the signed block commits to its ancestors through prev_hash, so the proposals of the whole
+// period, chained from the last checkpointed block to the signed one, are covered by the proof
+fn verify_period_proposals(
+    proposal: &Vec<u8>,
+    period_proposals: &ProposalVec,
+    input_checkpoint_data: &CheckpointCellData,
+) -> Result<Vec<[u8; 20]>, Error> {
+    let mut parent_hash = input_checkpoint_data.latest_block_hash();
+    let mut parent_height = input_checkpoint_data.latest_block_height();
+    let mut proposers = Vec::new();
+    for i in 0..=period_proposals.len() {
+        let raw_proposal = if i < period_proposals.len() {
+            period_proposals.get(i)
+        } else {
+            proposal.clone()
+        };
+        let decoded = ProposalObject::decode(&raw_proposal)?;
+        if decoded.prev_hash != parent_hash || decoded.number != parent_height + 1 {
+            debug!(
+                "proposal {} is not continuous, number = {}, parent height = {}",
+                i, decoded.number, parent_height
+            );
+            return Err(Error::CheckpointBlockNotContinuous);
         }
-        let value: axon::CheckpointWitness =
-            Cursor::from(witness_input_type.unwrap().raw_data().to_vec()).into();
-        (value.proposal(), value.proof())
-    };
+        parent_hash = keccak(raw_proposal).as_bytes().to_vec();
+        parent_height = decoded.number;
+        proposers.push(decoded.proposer);
+    }
+
+    Ok(proposers)
+}
 
+fn get_propose_count_objs(checkpoint_data: &CheckpointCellData) -> Vec<ProposeCountObject> {
+    let propose_counts = checkpoint_data.propose_count();
+    let mut propose_count_objs = Vec::new();
+    for i in 0..propose_counts.len() {
+        let propose_count = propose_counts.get(i);
+        propose_count_objs.push(ProposeCountObject {
+            addr: propose_count.address().as_slice().try_into().unwrap(),
+            count: propose_count.count(),
+        });
+    }
+    propose_count_objs
+}
+
+// every block of the period is credited to its own proposer. counts accumulate within an epoch
+// and restart from the first period of a new epoch, so the last checkpoint of an epoch holds the
+// counts of the epoch
+fn verify_propose_count(
+    proposers: &Vec<[u8; 20]>,
+    input_checkpoint_data: &CheckpointCellData,
+    output_checkpoint_data: &CheckpointCellData,
+) -> Result<(), Error> {
+    let mut expected_propose_counts =
+        if input_checkpoint_data.epoch() == output_checkpoint_data.epoch() {
+            get_propose_count_objs(input_checkpoint_data)
+        } else {
+            Vec::new()
+        };
+    for proposer in proposers {
+        match expected_propose_counts
+            .iter_mut()
+            .find(|propose_count| propose_count.addr == *proposer)
+        {
+            Some(propose_count) => propose_count.count += 1,
+            None => expected_propose_counts.push(ProposeCountObject {
+                addr: *proposer,
+                count: 1,
+            }),
+        }
+    }
+
+    let output_propose_counts = get_propose_count_objs(output_checkpoint_data);
+    debug!(
+        "expected propose_count: {:?}, output propose_count: {:?}",
+        expected_propose_counts, output_propose_counts
+    );
+    if expected_propose_counts != output_propose_counts {
+        return Err(Error::CheckpointProposeCountMismatch);
+    }
+
+    Ok(())
+}
+
+fn verify_multsig(
+    metadata_type_id: &[u8; 32],
+    output_checkpoint_data: &CheckpointCellData,
+    proposal: &Vec<u8>,
+    proof: &Vec<u8>,
+) -> Result<(), Error> {
     // get hash of proposal and check equality with hash in proof
-    let proof_rlp = Rlp::new(&proof);
-    let block_hash: Vec<u8> = proof_rlp.val_at(2).map_err(|_| Error::ProofRlpError)?;
+    let proof = ProofObject::decode(proof)?;
     let proposal_hash = keccak(proposal.clone()).as_bytes().to_vec();
     debug!(
         "block_hash: {:?}, proposal_hash: {:?}",
-        block_hash, proposal_hash
+        proof.block_hash, proposal_hash
     );
-    if proposal_hash != block_hash {
+    if proposal_hash != proof.block_hash {
         return Err(Error::CheckpointProposalHashMismatch);
     }
 
-    // the following mulsig check of l2 validators is mock！！
-    // get validate stake_infos from stake cell in cell_dep and check pBFT consensus validation
+    // get validators from metadata cell in cell_dep, the bitmap in proof marks the signers.
+    // the signed block belongs to the epoch of output checkpoint cell, which is the next epoch
+    // of metadata cell when crossing the epoch boundary
     let epoch = output_checkpoint_data.epoch();
-    let bls_pub_keys = get_current_validators(metadata_type_id, Source::CellDep)?;
-    let nodes_bitmap = {
-        let bitmap: Vec<u8> = proof_rlp.val_at(4).map_err(|_| Error::ProofRlpError)?;
-        BitVec::from_bytes(bitmap.as_slice())
-    };
-    let active_num = nodes_bitmap.iter().filter(|b| *b).count();
+    let validators = get_validators_by_epoch(metadata_type_id, epoch, Source::CellDep)?;
+    let active_validators = proof.signers(&validators)?;
+    verify_vote_weight(epoch, &validators, &active_validators)?;
+
+    proof.verify_signature(&active_validators)
+}
+
+// the same rule as Overlord consensus: the summed vote_weight of signers must be
+// strictly greater than 2/3 of the summed vote_weight of all validators
+fn verify_vote_weight(
+    epoch: u64,
+    validators: &Vec<ValidatorObject>,
+    active_validators: &Vec<ValidatorObject>,
+) -> Result<(), Error> {
+    let total_weight: u64 = validators
+        .iter()
+        .map(|validator| validator.vote_weight as u64)
+        .sum();
+    let active_weight: u64 = active_validators
+        .iter()
+        .map(|validator| validator.vote_weight as u64)
+        .sum();
     debug!(
-        "epoch = {}, nodes = {}/{}",
+        "epoch = {}, nodes = {}/{}, vote weight = {}/{}",
         epoch,
-        active_num,
-        bls_pub_keys.len()
+        active_validators.len(),
+        validators.len(),
+        active_weight,
+        total_weight
     );
-    if active_num <= bls_pub_keys.len() * 2 / 3 {
+    if active_weight * 3 <= total_weight * 2 {
         return Err(Error::CheckpointLackOfQuorum);
     }
 
-    // prepare signing message and check blst signature validation
-    let height: u64 = proof_rlp.val_at(0).map_err(|_| Error::ProofRlpError)?;
-    let round: u64 = proof_rlp.val_at(1).map_err(|_| Error::ProofRlpError)?;
-    debug!("height = {}, round = {}", height, round);
-    let mut message = RlpStream::new();
-    message
-        .begin_list(4)
-        .append(&height)
-        .append(&round)
-        .append(&2u8)
-        .append(&block_hash);
-    let signature: Vec<u8> = proof_rlp.val_at(3).map_err(|_| Error::ProofRlpError)?;
-    if signature.len() != 96 {
-        return Err(Error::ProofRlpError);
-    }
-    let active_pubkeys = nodes_bitmap
-        .into_iter()
+    Ok(())
+}
+
+// checkpoint cell keeps unchanged in evidence mode except the evidence smt
+fn verify_checkpoint_unchanged(
+    input_checkpoint_data: &CheckpointCellData,
+    output_checkpoint_data: &CheckpointCellData,
+) -> Result<(), Error> {
+    if input_checkpoint_data.version() != output_checkpoint_data.version()
+        || input_checkpoint_data.epoch() != output_checkpoint_data.epoch()
+        || input_checkpoint_data.period() != output_checkpoint_data.period()
+        || input_checkpoint_data.state_root() != output_checkpoint_data.state_root()
+        || input_checkpoint_data.latest_block_height()
+            != output_checkpoint_data.latest_block_height()
+        || input_checkpoint_data.latest_block_hash() != output_checkpoint_data.latest_block_hash()
+        || input_checkpoint_data.metadata_type_id() != output_checkpoint_data.metadata_type_id()
+        || input_checkpoint_data.timestamp() != output_checkpoint_data.timestamp()
+        || get_propose_count_objs(input_checkpoint_data)
+            != get_propose_count_objs(output_checkpoint_data)
+    {
+        return Err(Error::CheckpointDataMismatch);
+    }
+    Ok(())
+}
+
+// two valid proofs of the same height and round for different blocks prove that validators
+// signed both of them equivocated. the offenders are recorded in the evidence smt of checkpoint
+// cell, and exactly one evidence cell recording the offenders must be created
+fn verify_equivocation_evidence(
+    checkpoint_script: &Script,
+    checkpoint_type_id: &Vec<u8>,
+    metadata_type_id: &[u8; 32],
+    input_checkpoint_data: &CheckpointCellData,
+    checkpoint_data: &CheckpointCellData,
+    witness: &axon::CheckpointWitness,
+) -> Result<(), Error> {
+    verify_checkpoint_unchanged(input_checkpoint_data, checkpoint_data)?;
+
+    let proof = ProofObject::decode(&witness.proof())?;
+    let conflict_proof = ProofObject::decode(&witness.conflict_proof())?;
+    debug!(
+        "height = {}/{}, round = {}/{}",
+        proof.height, conflict_proof.height, proof.round, conflict_proof.round
+    );
+    if proof.height != conflict_proof.height
+        || proof.round != conflict_proof.round
+        || proof.block_hash == conflict_proof.block_hash
+    {
+        return Err(Error::CheckpointEvidenceNotConflict);
+    }
+
+    // both proofs are checked against the validators of current epoch
+    let validators =
+        get_validators_by_epoch(metadata_type_id, checkpoint_data.epoch(), Source::CellDep)?;
+    let signers = proof.signers(&validators)?;
+    proof.verify_signature(&signers)?;
+    let conflict_signers = conflict_proof.signers(&validators)?;
+    conflict_proof.verify_signature(&conflict_signers)?;
+
+    let offenders = signers
+        .iter()
+        .filter(|signer| {
+            conflict_signers
+                .iter()
+                .any(|conflict_signer| conflict_signer.address == signer.address)
+        })
+        .map(|signer| signer.address)
+        .collect::<Vec<_>>();
+    debug!("offenders: {:?}", offenders);
+    if offenders.is_empty() {
+        return Err(Error::CheckpointEvidenceNoOffender);
+    }
+
+    let old_evidence_root: [u8; 32] = input_checkpoint_data
+        .evidence_smt_root()
+        .as_slice()
+        .try_into()
+        .unwrap();
+    let new_evidence_root: [u8; 32] = checkpoint_data
+        .evidence_smt_root()
+        .as_slice()
+        .try_into()
+        .unwrap();
+    verify_evidence_smt(
+        proof.height,
+        proof.round,
+        &offenders,
+        old_evidence_root.into(),
+        new_evidence_root.into(),
+        witness.evidence_proof(),
+    )?;
+
+    // find the evidence cell by checkpoint code and args
+    let mut evidence_data = None;
+    QueryIter::new(load_cell_type, Source::Output)
         .enumerate()
-        .filter_map(|(i, flag)| {
-            if flag {
-                if let Some(pub_key) = bls_pub_keys.get(i) {
-                    return Some(Ok(*pub_key));
-                } else {
-                    return Some(Err(Error::ProofRlpError));
-                }
+        .map(|(i, type_script)| {
+            let type_script = match type_script {
+                Some(type_script) => type_script,
+                None => return Ok(()),
+            };
+            if type_script.code_hash().as_slice() != checkpoint_script.code_hash().as_slice()
+                || type_script.hash_type().as_slice() != checkpoint_script.hash_type().as_slice()
+                || type_script.args().raw_data().len() != CheckpointEvidenceArgsEntity::TOTAL_SIZE
+            {
+                return Ok(());
             }
-            None
+            let args: CheckpointEvidenceArgs =
+                Cursor::from(type_script.args().raw_data().to_vec()).into();
+            if args.checkpoint_type_id() != *checkpoint_type_id {
+                return Ok(());
+            }
+            if evidence_data.is_some()
+                || args.height() != proof.height
+                || args.round() != proof.round
+            {
+                return Err(Error::CheckpointEvidenceCellError);
+            }
+            evidence_data = Some(CheckpointEvidenceCellData::from(Cursor::from(
+                load_cell_data(i, Source::Output)?,
+            )));
+            Ok(())
         })
-        .collect::<Result<Vec<_>, _>>()?;
-    debug!(
-        "verify_blst_signature, raw message: {:?}",
-        message.as_raw().to_vec()
-    );
-    let message = keccak256(&message.as_raw().to_vec());
-    debug!("verify_blst_signature, hash message: {:?}", message);
-    if !blst::verify_blst_signature(&active_pubkeys, &signature, &message.to_vec()) {
-        return Err(Error::SignatureMismatch);
+        .collect::<Result<Vec<_>, Error>>()?;
+    let evidence_data = evidence_data.ok_or(Error::CheckpointEvidenceCellError)?;
+
+    let evidence_offenders = evidence_data.offenders();
+    if evidence_data.version() != 0
+        || evidence_data.metadata_type_id() != metadata_type_id.to_vec()
+        || evidence_data.epoch() != checkpoint_data.epoch()
+        || evidence_data.height() != proof.height
+        || evidence_data.round() != proof.round
+        || evidence_offenders.len() != offenders.len()
+    {
+        return Err(Error::CheckpointEvidenceCellError);
+    }
+    for (i, offender) in offenders.iter().enumerate() {
+        if evidence_offenders.get(i) != offender.to_vec() {
+            return Err(Error::CheckpointEvidenceCellError);
+        }
+    }
+
+    Ok(())
+}
+
+// evidence cell can only be created along with the checkpoint cell in evidence mode, which
+// verifies its content. destroying evidence cells is left to the lock script
+fn verify_evidence_cell(script: &Script) -> Result<(), Error> {
+    let input_evidence_count = QueryIter::new(load_cell_type, Source::GroupInput).count();
+    if input_evidence_count > 0 {
+        // evidence is only consumed by slashing the offenders in stake smt
+        let evidence_data = load_cell_data(0, Source::GroupInput)?;
+        let evidence_data: CheckpointEvidenceCellData = Cursor::from(evidence_data).into();
+        let type_ids = get_type_ids(
+            &evidence_data.metadata_type_id().try_into().unwrap(),
+            Source::CellDep,
+        )?;
+        let stake_smt_type_hash = get_script_hash(
+            &type_ids.stake_smt_code_hash(),
+            &type_ids.stake_smt_type_id(),
+        );
+        if get_stake_smt_witness(&stake_smt_type_hash)?.mode() != SLASH_MODE {
+            return Err(Error::SlashModeMismatch);
+        }
+    }
+
+    let evidence_count = QueryIter::new(load_cell_type, Source::GroupOutput).count();
+    if evidence_count == 0 {
+        return Ok(());
+    }
+
+    let args: CheckpointEvidenceArgs = Cursor::from(script.args().raw_data().to_vec()).into();
+    let checkpoint_type_id = args.checkpoint_type_id();
+    let checkpoint_index = QueryIter::new(load_cell_type_hash, Source::Input)
+        .position(|type_hash| type_hash.unwrap_or([0u8; 32]) == checkpoint_type_id[..])
+        .ok_or(Error::CheckpointEvidenceCellError)?;
+    let witness = get_checkpoint_witness(Source::Input, checkpoint_index)?;
+    if witness.mode() != 1 {
+        return Err(Error::CheckpointEvidenceCellError);
     }
 
     Ok(())
@@ -20,7 +20,9 @@ use axon_types::{
 };
 use util::{
     error::Error,
+    exit::{exited_stakers_of, get_exited_stakers, RELEASE_MODE},
     helper::*,
+    slash::*,
     smt::{u64_to_h256, verify_2layer_smt, LockInfo},
 };
 
@@ -92,8 +94,13 @@ pub fn main() -> Result<(), Error> {
                         &type_ids.checkpoint_type_id(),
                     );
                     debug!("checkpoint_script_hash: {:?}", checkpoint_script_hash);
+                    let exited_stakers = get_exited_stakers(&get_script_hash(
+                        &type_ids.stake_smt_code_hash(),
+                        &type_ids.stake_smt_type_id(),
+                    ))?;
                     update_delegate_smt(
                         &delegate_smt_update_infos,
+                        &exited_stakers,
                         &checkpoint_script_hash.to_vec(),
                         &type_ids.xudt_type_hash(),
                         &metadata_type_id,
@@ -106,6 +113,22 @@ pub fn main() -> Result<(), Error> {
                     // election
                     elect_validators(&metadata_type_id.as_slice().try_into().unwrap())?;
                 }
+                2 => {
+                    // slash delegators of offending stakers
+                    slash_delegators(
+                        &delegate_smt_update_infos,
+                        &delegate_smt_type_id,
+                        &metadata_type_id,
+                    )?;
+                }
+                3 => {
+                    // release delegators of exited stakers
+                    release_delegators(
+                        &delegate_smt_update_infos,
+                        &delegate_smt_type_id,
+                        &metadata_type_id,
+                    )?;
+                }
                 _ => {
                     return Err(Error::UnknownMode);
                 }
@@ -178,20 +201,49 @@ fn update_delegate_info(
     Ok(())
 }
 
+// redelegated amount leaves the staker without being withdrawn, it must be fully delegated
+fn redelegate_out_delegate_info(
+    delegator: &[u8; 20],
+    delegate_info_delta: &DelegateInfoDelta,
+    delegate_infos_set: &mut BTreeSet<LockInfo>,
+) -> Result<(), Error> {
+    let delegate_info = delegate_infos_set
+        .iter()
+        .find(|delegate_info| *delegator == delegate_info.addr)
+        .cloned()
+        .ok_or(Error::RedelegateTooMuch)?;
+    let redelegate_amount = bytes_to_u128(&delegate_info_delta.amount());
+    if redelegate_amount > delegate_info.amount {
+        return Err(Error::RedelegateTooMuch);
+    }
+
+    delegate_infos_set.remove(&delegate_info);
+    let delegate_info_obj = LockInfo {
+        addr: *delegator,
+        amount: delegate_info.amount - redelegate_amount,
+    };
+    delegate_infos_set.insert(delegate_info_obj);
+    debug!("redelegate out delegate_info_obj: {:?}", delegate_info_obj);
+
+    Ok(())
+}
+
 fn verify_delegator_selection(
     old_delegate_infos_set: &BTreeSet<LockInfo>,
     new_delegate_infos_set: &BTreeSet<LockInfo>,
     delegator_update_infos: &Vec<([u8; 20], [u8; 32], DelegateInfoDelta)>,
     new_epoch_root: [u8; 32],
     new_epoch_proof: Vec<u8>,
-    epoch: u64,
+    inauguration_epoch: u64,
+    threshold: u128,
     max_delegator_size: u32,
     staker: &Vec<u8>,
     delegate_withdraw_infos: &mut WithdrawAmountMap,
 ) -> Result<(), Error> {
-    // sort delegator by amount
+    // sort delegator by amount, delegators below threshold of the staker are evicted
     let mut top = new_delegate_infos_set
         .iter()
+        .filter(|elem| elem.amount >= threshold)
         .take(max_delegator_size as usize);
     let mut select_delegate_infos_set = BTreeSet::new();
     while let Some(elem) = top.next() {
@@ -203,7 +255,7 @@ fn verify_delegator_selection(
     let new_epoch_proof = CompiledMerkleProof(new_epoch_proof);
     let result = verify_2layer_smt(
         &select_delegate_infos_set,
-        u64_to_h256(epoch + 2),
+        u64_to_h256(inauguration_epoch),
         new_epoch_root,
         new_epoch_proof,
     )?;
@@ -216,27 +268,27 @@ fn verify_delegator_selection(
         return Err(Error::DelegateSmtVerifySelectionError);
     }
 
-    let mut deleted_delegate_infos_set = BTreeSet::<LockInfo>::new();
-    if new_delegate_infos_set.len() > select_delegate_infos_set.len() {
-        let mut iter = new_delegate_infos_set.iter();
-        let deleted_size = new_delegate_infos_set.len() - select_delegate_infos_set.len();
-        for _ in 0..deleted_size {
-            if let Some(elem) = iter.next_back() {
-                deleted_delegate_infos_set.insert(elem.clone());
-                debug!("deleted_delegate_infos : {:?}", *elem);
-            }
-        }
+    let deleted_delegate_infos_set = new_delegate_infos_set
+        .difference(&select_delegate_infos_set)
+        .cloned()
+        .collect::<BTreeSet<LockInfo>>();
+    for elem in &deleted_delegate_infos_set {
+        debug!("deleted_delegate_infos : {:?}", *elem);
     }
     debug!(
         "deleted delegate infos size: {}",
         deleted_delegate_infos_set.len()
     );
 
-    for (delegator_addr, delegate_at_lock_hash, _delegate_info_delta) in delegator_update_infos {
+    for (delegator_addr, delegate_at_lock_hash, delegate_info_delta) in delegator_update_infos {
         //after updated to smt cell, the output delegate should be reset
         let contains = deleted_delegate_infos_set
             .iter()
             .any(|item| item.addr == *delegator_addr);
+        // redelegated amount has left the source staker, so the target staker must accept it
+        if contains && is_redelegation(delegate_info_delta) {
+            return Err(Error::RedelegateRejected);
+        }
         if !contains {
             let output_delegate_info_delta =
                 get_delegate_delta(&staker, &delegate_at_lock_hash, Source::Output)?;
@@ -262,6 +314,7 @@ fn verify_delegator_selection(
 
 fn update_delegate_smt(
     delegate_smt_update_infos: &delegate_reader::DelegateSmtUpdateInfo,
+    exited_stakers: &BTreeSet<[u8; 20]>,
     checkpoint_type_id: &Vec<u8>,
     xudt_type_hash: &Vec<u8>,
     metadata_type_id: &[u8; 32],
@@ -288,8 +341,16 @@ fn update_delegate_smt(
 
     // construct old delegate smt root & verify
     let epoch = get_current_epoch(&checkpoint_type_id)?;
-    debug!("get_current_epoch: {}", epoch);
+    let min_inauguration_epoch = get_inauguration_epoch(metadata_type_id, epoch, Source::CellDep)?;
+    debug!(
+        "get_current_epoch: {}, min_inauguration_epoch: {}",
+        epoch, min_inauguration_epoch
+    );
     let stake_group_infos = delegate_smt_update_infos.all_stake_group_infos();
+    // both stakers of a redelegation are updated in the same transaction
+    let group_stakers = (0..stake_group_infos.len())
+        .map(|i| stake_group_infos.get(i).staker())
+        .collect::<BTreeSet<_>>();
     let mut all_stakers_delegate_withdraw_infos = WithdrawAmountMap::new();
     for i in 0..stake_group_infos.len() {
         // verify old delegate info
@@ -319,7 +380,7 @@ fn update_delegate_smt(
         let old_epoch_root: H256 = old_epoch_root.into();
         let result = verify_2layer_smt(
             &old_delegate_infos_set,
-            u64_to_h256(epoch + 2),
+            u64_to_h256(min_inauguration_epoch),
             old_epoch_root,
             old_epoch_proof,
         )?;
@@ -328,9 +389,27 @@ fn update_delegate_smt(
             result
         );
 
+        // amounts redelegated to other stakers leave old delegate infos first, they are never withdrawn
+        let xudt_type_hash: [u8; 32] = xudt_type_hash.as_slice().try_into().unwrap();
+        let redelegate_out_infos = get_redelegate_out_infos(
+            &staker,
+            &xudt_type_hash,
+            delegate_at_code_hash,
+            Source::Input,
+        )?;
+        for (delegator_addr, _delegate_at_lock_hash, delegate_info_delta) in &redelegate_out_infos {
+            if !group_stakers.contains(&delegate_info_delta.staker()) {
+                return Err(Error::RedelegateStakerMissing);
+            }
+            redelegate_out_delegate_info(
+                delegator_addr,
+                delegate_info_delta,
+                &mut old_delegate_infos_set,
+            )?;
+        }
+
         // initial value of new delegate info set is old delegate info set
         let mut new_delegate_infos_set = old_delegate_infos_set.clone();
-        let xudt_type_hash: [u8; 32] = xudt_type_hash.as_slice().try_into().unwrap();
         // debug!("xudt_type_hash: {:?}", xudt_type_hash);
         // update old delegate info to new delegate info based on input delegate at cells
         // get this staker's delegate update infos
@@ -342,12 +421,22 @@ fn update_delegate_smt(
         )?;
         // update old delegate infos to new delegate infos
         let mut delegate_withdraw_infos = WithdrawAmountMap::new(); // only for only staker's delegators
+        let staker_addr: [u8; 20] = staker.as_slice().try_into().unwrap();
+        let staker_exited = exited_stakers.contains(&staker_addr);
         for (delegator_addr, _delegate_at_lock_hash, delegate_info_delta) in &delegator_update_infos
         {
             let inauguration_epoch = delegate_info_delta.inauguration_epoch();
-            if inauguration_epoch < epoch + 2 {
+            if inauguration_epoch < min_inauguration_epoch {
                 return Err(Error::DelegateStaleInfo);
             }
+            if staker_exited && delegate_info_delta.is_increase() == 1 {
+                return Err(Error::DelegateToExitedStaker);
+            }
+            if is_redelegation(delegate_info_delta)
+                && !group_stakers.contains(&delegate_info_delta.from_staker())
+            {
+                return Err(Error::RedelegateStakerMissing);
+            }
 
             // get the delegator's new delegate info for this staker
             update_delegate_info(
@@ -364,9 +453,11 @@ fn update_delegate_smt(
             staker.as_slice().try_into().unwrap(),
             &new_delegate_smt_data,
         )?;
-        let max_delegator_size = get_delegator_size(&staker, metadata_type_id, stake_at_code_hash)?;
+        let (threshold, max_delegator_size) =
+            get_delegator_requirement(&staker, metadata_type_id, stake_at_code_hash)?;
         debug!(
-            "max_delegator_size: {}, old_delegator_size: {}, new_delegator_size: {}",
+            "threshold: {}, max_delegator_size: {}, old_delegator_size: {}, new_delegator_size: {}",
+            threshold,
             max_delegator_size,
             old_delegate_infos_set.len(),
             new_delegate_infos_set.len()
@@ -378,7 +469,8 @@ fn update_delegate_smt(
             &delegator_update_infos,
             new_epoch_root,
             new_proof,
-            epoch,
+            min_inauguration_epoch,
+            threshold,
             max_delegator_size,
             &staker,
             &mut delegate_withdraw_infos,
@@ -404,20 +496,23 @@ fn update_delegate_smt(
     Ok(())
 }
 
-fn get_delegator_size(
+// minimum delegate amount and maximum delegator count the staker accepts
+fn get_delegator_requirement(
     staker: &Vec<u8>,
     metadata_type_id: &[u8; 32],
     stake_at_code_hash: &Vec<u8>,
-) -> Result<u32, Error> {
+) -> Result<(u128, u32), Error> {
     let delegate_requirement =
         get_delegate_requirement(staker, metadata_type_id, stake_at_code_hash)?;
 
-    Ok(bytes_to_u32(
+    let threshold = bytes_to_u128(&delegate_requirement.threshold().as_slice().to_vec());
+    let max_delegator_size = bytes_to_u32(
         &delegate_requirement
             .max_delegator_size()
             .as_slice()
             .to_vec(),
-    ))
+    );
+    Ok((threshold, max_delegator_size))
 }
 
 fn verify_withdraw_amount(
@@ -465,3 +560,268 @@ fn elect_validators(metadata_type_id: &[u8; 32]) -> Result<(), Error> {
     }
     Ok(())
 }
+
+fn slash_delegators(
+    delegate_smt_update_infos: &delegate_reader::DelegateSmtUpdateInfo,
+    cell_type_id: &[u8; 32],
+    metadata_type_id: &[u8; 32],
+) -> Result<(), Error> {
+    debug!("slash delegators mode");
+    let type_ids = get_type_ids(metadata_type_id, Source::CellDep)?;
+    let delegate_smt_type_hash = get_script_hash(
+        &type_ids.delegate_smt_code_hash(),
+        &type_ids.delegate_smt_type_id(),
+    );
+    if delegate_smt_type_hash != *cell_type_id {
+        return Err(Error::DelegateSmtTypeIdMismatch);
+    }
+    let old_delegate_smt_data = get_delegate_smt_data(cell_type_id, Source::Input)?;
+    let new_delegate_smt_data = get_delegate_smt_data(cell_type_id, Source::Output)?;
+    if old_delegate_smt_data.version() != new_delegate_smt_data.version()
+        || old_delegate_smt_data.metadata_type_id() != new_delegate_smt_data.metadata_type_id()
+    {
+        return Err(Error::UpdateDataError);
+    }
+
+    // slashed stakers and their amounts come from stake smt witness, which is verified by stake smt
+    let stake_smt_type_hash = get_script_hash(
+        &type_ids.stake_smt_code_hash(),
+        &type_ids.stake_smt_type_id(),
+    );
+    let stake_smt_witness = get_stake_smt_witness(&stake_smt_type_hash)?;
+    if stake_smt_witness.mode() != SLASH_MODE {
+        return Err(Error::SlashModeMismatch);
+    }
+    let slash_info = get_slash_info(metadata_type_id)?;
+    let all_stake_infos = stake_smt_witness.update_info().all_stake_infos();
+    let mut slashed_stakers = BTreeSet::new();
+    let mut slashed_amount = 0u128;
+    for i in 0..all_stake_infos.len() {
+        let stake_info = all_stake_infos.get(i);
+        let staker: [u8; 20] = stake_info.addr().try_into().unwrap();
+        if slash_info.is_offender(&staker) {
+            slashed_stakers.insert(staker);
+            let (slash_amount, _) = slash_info.split_amount(bytes_to_u128(&stake_info.amount()))?;
+            slashed_amount += slash_amount;
+        }
+    }
+
+    // every slashed staker's delegate smt is slashed exactly once
+    let stake_group_infos = delegate_smt_update_infos.all_stake_group_infos();
+    if stake_group_infos.len() != slashed_stakers.len() {
+        return Err(Error::SlashStakerMismatch);
+    }
+    let epoch = get_current_epoch(
+        &get_script_hash(
+            &type_ids.checkpoint_code_hash(),
+            &type_ids.checkpoint_type_id(),
+        )
+        .to_vec(),
+    )?;
+    let min_inauguration_epoch = get_inauguration_epoch(metadata_type_id, epoch, Source::CellDep)?;
+    let mut released_amounts = WithdrawAmountMap::new();
+    for i in 0..stake_group_infos.len() {
+        let stake_group_info = stake_group_infos.get(i);
+        let staker: [u8; 20] = stake_group_info.staker().try_into().unwrap();
+        if !slashed_stakers.remove(&staker) {
+            return Err(Error::SlashStakerMismatch);
+        }
+
+        // delegations are reduced by the same rate as the staker, the rest stays delegated
+        debug!("slash staker {:?} delegators", staker);
+        let old_delegate_infos_set = get_delegate_infos(&stake_group_info);
+        let mut new_delegate_infos_set = BTreeSet::new();
+        for delegate_info in &old_delegate_infos_set {
+            let (slash_amount, remaining_amount) = slash_info.split_amount(delegate_info.amount)?;
+            released_amounts.insert(delegate_info.addr, slash_amount);
+            new_delegate_infos_set.insert(LockInfo {
+                addr: delegate_info.addr,
+                amount: remaining_amount,
+            });
+            slashed_amount += slash_amount;
+        }
+        replace_delegate_infos(
+            &stake_group_info,
+            &old_delegate_infos_set,
+            &new_delegate_infos_set,
+            &old_delegate_smt_data,
+            &new_delegate_smt_data,
+            min_inauguration_epoch,
+        )?;
+    }
+
+    let xudt_type_hash = type_ids.xudt_type_hash();
+    for (delegator, amount) in &released_amounts.map {
+        let delegate_at_lock_hash = calc_at_lock_hash(
+            &type_ids.delegate_at_code_hash(),
+            metadata_type_id,
+            delegator,
+        );
+        verify_at_released(&xudt_type_hash, &delegate_at_lock_hash, *amount)?;
+    }
+
+    debug!("total slashed amount: {}", slashed_amount);
+    verify_treasury_amount(
+        &xudt_type_hash,
+        &slash_info.treasury_lock_hash,
+        slashed_amount,
+    )?;
+
+    Ok(())
+}
+
+fn get_delegate_infos(stake_group_info: &delegate_reader::StakeGroupInfo) -> BTreeSet<LockInfo> {
+    let delegate_infos = stake_group_info.delegate_infos();
+    let mut delegate_infos_set = BTreeSet::new();
+    for j in 0..delegate_infos.len() {
+        let delegate_info = delegate_infos.get(j);
+        delegate_infos_set.insert(LockInfo {
+            addr: delegate_info.delegator_addr().try_into().unwrap(),
+            amount: bytes_to_u128(&delegate_info.amount()),
+        });
+    }
+    delegate_infos_set
+}
+
+// verify all delegations of the staker are removed from delegate smt, returns the removed ones
+fn clear_delegate_infos(
+    stake_group_info: &delegate_reader::StakeGroupInfo,
+    old_delegate_smt_data: &delegate_reader::DelegateSmtCellData,
+    new_delegate_smt_data: &delegate_reader::DelegateSmtCellData,
+    epoch: u64,
+) -> Result<BTreeSet<LockInfo>, Error> {
+    let old_delegate_infos_set = get_delegate_infos(stake_group_info);
+    replace_delegate_infos(
+        stake_group_info,
+        &old_delegate_infos_set,
+        &BTreeSet::new(),
+        old_delegate_smt_data,
+        new_delegate_smt_data,
+        epoch,
+    )?;
+    Ok(old_delegate_infos_set)
+}
+
+// verify the staker's delegations in delegate smt are replaced by the new ones
+fn replace_delegate_infos(
+    stake_group_info: &delegate_reader::StakeGroupInfo,
+    old_delegate_infos_set: &BTreeSet<LockInfo>,
+    new_delegate_infos_set: &BTreeSet<LockInfo>,
+    old_delegate_smt_data: &delegate_reader::DelegateSmtCellData,
+    new_delegate_smt_data: &delegate_reader::DelegateSmtCellData,
+    epoch: u64,
+) -> Result<(), Error> {
+    let staker: [u8; 20] = stake_group_info.staker().try_into().unwrap();
+    let old_epoch_root = get_delegate_smt_root_from_cell_data(&staker, old_delegate_smt_data)?;
+    let result = verify_2layer_smt(
+        old_delegate_infos_set,
+        u64_to_h256(epoch),
+        old_epoch_root.into(),
+        CompiledMerkleProof(stake_group_info.delegate_old_epoch_proof()),
+    )?;
+    if !result {
+        return Err(Error::DelegateSmtVerifySelectionError);
+    }
+
+    let new_epoch_root = get_delegate_smt_root_from_cell_data(&staker, new_delegate_smt_data)?;
+    let result = verify_2layer_smt(
+        new_delegate_infos_set,
+        u64_to_h256(epoch),
+        new_epoch_root.into(),
+        CompiledMerkleProof(stake_group_info.delegate_new_epoch_proof()),
+    )?;
+    debug!("replace staker {:?} delegators, result: {}", staker, result);
+    if !result {
+        return Err(Error::DelegateSmtVerifySelectionError);
+    }
+
+    Ok(())
+}
+
+fn release_delegators(
+    delegate_smt_update_infos: &delegate_reader::DelegateSmtUpdateInfo,
+    cell_type_id: &[u8; 32],
+    metadata_type_id: &[u8; 32],
+) -> Result<(), Error> {
+    debug!("release delegators mode");
+    let type_ids = get_type_ids(metadata_type_id, Source::CellDep)?;
+    let delegate_smt_type_hash = get_script_hash(
+        &type_ids.delegate_smt_code_hash(),
+        &type_ids.delegate_smt_type_id(),
+    );
+    if delegate_smt_type_hash != *cell_type_id {
+        return Err(Error::DelegateSmtTypeIdMismatch);
+    }
+    let old_delegate_smt_data = get_delegate_smt_data(cell_type_id, Source::Input)?;
+    let new_delegate_smt_data = get_delegate_smt_data(cell_type_id, Source::Output)?;
+    if old_delegate_smt_data.version() != new_delegate_smt_data.version()
+        || old_delegate_smt_data.metadata_type_id() != new_delegate_smt_data.metadata_type_id()
+    {
+        return Err(Error::UpdateDataError);
+    }
+
+    // stake smt drops released stakers from exited stakers in the same transaction
+    let stake_smt_type_id = get_script_hash(
+        &type_ids.stake_smt_code_hash(),
+        &type_ids.stake_smt_type_id(),
+    );
+    let stake_smt_witness =
+        get_stake_smt_witness(&stake_smt_type_id).map_err(|_| Error::ReleaseModeMismatch)?;
+    if stake_smt_witness.mode() != RELEASE_MODE {
+        return Err(Error::ReleaseModeMismatch);
+    }
+    let mut exited_stakers =
+        exited_stakers_of(&get_stake_smt_data(&stake_smt_type_id, Source::Input)?)?;
+    let epoch = get_current_epoch(
+        &get_script_hash(
+            &type_ids.checkpoint_code_hash(),
+            &type_ids.checkpoint_type_id(),
+        )
+        .to_vec(),
+    )?;
+    let min_inauguration_epoch = get_inauguration_epoch(metadata_type_id, epoch, Source::CellDep)?;
+    let mut withdraw_amounts = WithdrawAmountMap::new();
+    let stake_group_infos = delegate_smt_update_infos.all_stake_group_infos();
+    for i in 0..stake_group_infos.len() {
+        let stake_group_info = stake_group_infos.get(i);
+        let staker: [u8; 20] = stake_group_info.staker().try_into().unwrap();
+        // every exited staker is released at most once in a transaction
+        if !exited_stakers.remove(&staker) {
+            return Err(Error::ReleaseStakerNotExited);
+        }
+
+        let delegate_infos_set = clear_delegate_infos(
+            &stake_group_info,
+            &old_delegate_smt_data,
+            &new_delegate_smt_data,
+            min_inauguration_epoch,
+        )?;
+        debug!("release staker {:?} delegators", staker);
+        for delegate_info in &delegate_infos_set {
+            withdraw_amounts.insert(delegate_info.addr, delegate_info.amount);
+        }
+    }
+    if exited_stakers_of(&get_stake_smt_data(&stake_smt_type_id, Source::Output)?)?
+        != exited_stakers
+    {
+        return Err(Error::ExitedStakersMismatch);
+    }
+
+    // released amounts leave delegate AT cells for withdraw cells of the same delegators
+    let xudt_type_hash = type_ids.xudt_type_hash();
+    for (delegator, amount) in &withdraw_amounts.map {
+        let delegate_at_lock_hash = calc_at_lock_hash(
+            &type_ids.delegate_at_code_hash(),
+            metadata_type_id,
+            delegator,
+        );
+        verify_at_released(&xudt_type_hash, &delegate_at_lock_hash, *amount)?;
+    }
+    verify_withdraw_amount(
+        withdraw_amounts,
+        metadata_type_id,
+        &type_ids.withdraw_code_hash(),
+    )?;
+
+    Ok(())
+}
@@ -16,7 +16,11 @@ use axon_types::{
     delegate_reader::{self},
     Cursor,
 };
-use util::{error::Error, helper::*};
+use util::{
+    error::Error,
+    eth::{verify_eth_signature, Operation, TypedOperation},
+    helper::*,
+};
 
 pub fn main() -> Result<(), Error> {
     let script = load_script()?;
@@ -44,7 +48,7 @@ pub fn main() -> Result<(), Error> {
     let witness_args = load_witness_args(0, Source::GroupInput);
     match witness_args {
         Ok(witness) => {
-            let mode = {
+            let (mode, sig_mode, eth_sig) = {
                 let witness_lock = witness.lock().to_opt();
                 if witness_lock.is_none() {
                     return Err(Error::WitnessLockError);
@@ -56,7 +60,7 @@ pub fn main() -> Result<(), Error> {
                 let value: delegate_reader::DelegateAtWitness =
                     Cursor::from(witness_lock.unwrap().raw_data().to_vec()).into();
                 // debug!("witness mode: {}", value.mode());
-                value.mode()
+                (value.mode(), value.sig_mode(), value.eth_sig())
             };
             debug!("delegate at mode: {}", mode);
 
@@ -68,11 +72,17 @@ pub fn main() -> Result<(), Error> {
                         &type_ids.checkpoint_type_id(),
                     );
                     // debug!("checkpoint_script_hash: {:?}", checkpoint_script_hash);
+                    let reward_smt_type_hash =
+                        get_script_hash(&type_ids.reward_code_hash(), &type_ids.reward_type_id());
                     update_delegate_at_cell(
                         &delegator_identity,
+                        sig_mode,
+                        &eth_sig,
+                        &metadata_type_id,
                         &delegate_at_lock_hash,
                         &checkpoint_script_hash.to_vec(),
                         &type_ids.xudt_type_hash(),
+                        &reward_smt_type_hash.to_vec(),
                     )?;
                 }
                 1 => {
@@ -120,15 +130,15 @@ fn check_l2_addr(l2_addr_args: &Vec<u8>, delegate_at_lock_hash: &[u8; 32]) -> Re
 
 pub fn update_delegate_at_cell(
     delegator_identity: &Vec<u8>,
+    sig_mode: u8,
+    eth_sig: &Vec<u8>,
+    metadata_type_id: &Vec<u8>,
     delegate_at_lock_hash: &[u8; 32],
     checkpoint_type_id: &Vec<u8>,
     xudt_type_hash: &Vec<u8>,
+    reward_smt_type_hash: &Vec<u8>,
 ) -> Result<(), Error> {
     debug!("update delegate info in delegate at cell");
-    // if !secp256k1::verify_signature(&delegator_identity) {
-    //     return Err(Error::SignatureMismatch);
-    // }
-
     check_xudt_type_hash(xudt_type_hash)?;
 
     let input_at_amount = get_xudt_by_type_hash(xudt_type_hash, Source::Input)?;
@@ -137,9 +147,7 @@ pub fn update_delegate_at_cell(
         "input_at_amount: {}, output_at_amount: {}",
         input_at_amount, output_at_amount
     );
-    if input_at_amount != output_at_amount {
-        return Err(Error::InputOutputAtAmountNotEqual);
-    }
+    check_at_amount_or_compound(input_at_amount, output_at_amount, reward_smt_type_hash)?;
 
     let (input_delegate_at_amount, input_delegate_at_data) =
         get_delegate_at_data_by_lock_hash(&delegate_at_lock_hash, Source::Input)?;
@@ -152,14 +160,33 @@ pub fn update_delegate_at_cell(
     }
 
     let epoch = get_current_epoch(checkpoint_type_id)?;
+    let inauguration_epoch = get_inauguration_epoch(
+        &metadata_type_id.as_slice().try_into().unwrap(),
+        epoch,
+        Source::CellDep,
+    )?;
     debug!(
-        "input_delegate_at_amount: {}, output_delegate_at_amount: {}, epoch: {}",
-        input_delegate_at_amount, output_delegate_at_amount, epoch
+        "input_delegate_at_amount: {}, output_delegate_at_amount: {}, epoch: {}, inauguration_epoch: {}",
+        input_delegate_at_amount, output_delegate_at_amount, epoch, inauguration_epoch
     );
 
+    // delegator signs the AT amount locked for delegation after this tx
+    let operation = TypedOperation {
+        operation: Operation::Delegate,
+        amount: output_delegate_at_amount,
+        inauguration_epoch,
+        metadata_type_id: metadata_type_id.as_slice().try_into().unwrap(),
+    };
+    verify_eth_signature(sig_mode, delegator_identity, eth_sig, &operation)?;
+
     let mut delegate_at_change = 0i128;
     let input_delegate_info_deltas = input_delegate_at_data.delegator_infos();
     let output_delegate_info_deltas = output_delegate_at_data.delegator_infos();
+    let redelegate_from_stakers = (0..output_delegate_info_deltas.len())
+        .map(|i| output_delegate_info_deltas.get(i))
+        .filter(|info| is_redelegation(info))
+        .map(|info| info.from_staker())
+        .collect::<Vec<_>>();
     for i in 0..output_delegate_info_deltas.len() {
         let output_delegate_info = output_delegate_info_deltas.get(i);
         let output_delegate = bytes_to_u128(&output_delegate_info.amount());
@@ -169,9 +196,22 @@ pub fn update_delegate_at_cell(
         if staker == *delegator_identity {
             return Err(Error::DelegateSelf);
         }
-        if output_inauguration_epoch != epoch + 2 {
+        if output_inauguration_epoch != inauguration_epoch {
             return Err(Error::BadInaugurationEpoch);
         }
+        // the staker redelegated from can't be delegated or undelegated at the same time
+        if redelegate_from_stakers.contains(&staker) {
+            return Err(Error::RedelegateConflict);
+        }
+        // redelegation only moves delegated amount between stakers, no AT is locked or released
+        let output_redelegation = is_redelegation(&output_delegate_info);
+        if output_redelegation {
+            let from_staker = output_delegate_info.from_staker();
+            if !output_increase || from_staker == staker || from_staker == *delegator_identity {
+                return Err(Error::RedelegateInvalid);
+            }
+        }
+        let output_increase = output_increase && !output_redelegation;
 
         let mut input_delegate = 0u128;
         let mut input_increase = true;
@@ -179,7 +219,8 @@ pub fn update_delegate_at_cell(
             let input_delegate_info = input_delegate_info_deltas.get(i);
             if input_delegate_info.staker().as_slice().to_vec() == staker {
                 input_delegate = bytes_to_u128(&input_delegate_info.amount());
-                input_increase = input_delegate_info.is_increase() == 1;
+                input_increase = input_delegate_info.is_increase() == 1
+                    && !is_redelegation(&input_delegate_info);
                 break;
             }
         }
@@ -0,0 +1,95 @@
+// Import from `core` instead of from `std` since we are in no-std mode
+use core::result::Result;
+
+// Import CKB syscalls and structures
+// https://nervosnetwork.github.io/ckb-std/riscv64imac-unknown-none-elf/doc/ckb_std/index.html
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::{bytes::Bytes, prelude::*},
+    debug,
+    high_level::{load_cell, load_cell_data, load_script, QueryIter},
+};
+use ckb_type_id::{load_type_id_from_script_args, validate_type_id};
+
+use axon_types::{issue_reader, Cursor};
+use util::{error::Error, helper::*};
+
+fn get_issue_data(source: Source) -> Result<issue_reader::IssueCellData, Error> {
+    let data = load_cell_data(0, source)?;
+    if data.len() < 65 {
+        return Err(Error::Encoding);
+    }
+    Ok(Cursor::from(data).into())
+}
+
+pub fn main() -> Result<(), Error> {
+    let type_id = load_type_id_from_script_args(0)?;
+    validate_type_id(type_id)?;
+
+    let script = load_script()?;
+    let args: Bytes = script.args().unpack();
+    let issue_args: issue_reader::IssueArgs = Cursor::from(args.to_vec()).into();
+
+    // only one issue cell can be consumed and created
+    let input_count = QueryIter::new(load_cell, Source::GroupInput).count();
+    let output_count = QueryIter::new(load_cell, Source::GroupOutput).count();
+    if input_count > 1 || output_count != 1 {
+        return Err(Error::IssueCellCountError);
+    }
+
+    let output_data = get_issue_data(Source::GroupOutput)?;
+    let new_supply = bytes_to_u128(&output_data.current_supply());
+    let max_supply = bytes_to_u128(&output_data.max_suppley());
+    if input_count == 0 {
+        debug!("issue cell creation");
+        // nothing is issued before the issue cell exists
+        if new_supply != 0 {
+            return Err(Error::IssueSupplyMismatch);
+        }
+        return Ok(());
+    }
+
+    let input_data = get_issue_data(Source::GroupInput)?;
+    if input_data.version() != output_data.version()
+        || input_data.max_suppley() != output_data.max_suppley()
+        || input_data.sudt_type_script() != output_data.sudt_type_script()
+    {
+        return Err(Error::IssueDataMismatch);
+    }
+
+    // current_supply follows the xudt minted in this tx, burning is not tracked by issue cell
+    let xudt_type_hash = input_data.sudt_type_script();
+    let input_xudt = get_xudt_by_type_hash(&xudt_type_hash, Source::Input)?;
+    let output_xudt = get_xudt_by_type_hash(&xudt_type_hash, Source::Output)?;
+    if output_xudt < input_xudt {
+        return Err(Error::IssueSupplyMismatch);
+    }
+    let minted = output_xudt - input_xudt;
+    let old_supply = bytes_to_u128(&input_data.current_supply());
+    debug!(
+        "old_supply: {}, new_supply: {}, max_supply: {}, minted: {}",
+        old_supply, new_supply, max_supply, minted
+    );
+    if old_supply.checked_add(minted) != Some(new_supply) {
+        return Err(Error::IssueSupplyMismatch);
+    }
+    if new_supply > max_supply {
+        return Err(Error::IssueExceedMaxSupply);
+    }
+
+    // mint along with reward smt update, whose type script checks the reward amount, or by admin
+    if minted > 0 {
+        let reward_smt_count =
+            get_cell_count_by_type_hash(&issue_args.reward_smt_type_id(), Source::Input);
+        let admin_count = get_cell_count(&issue_args.admin_lock_hash(), Source::Input);
+        debug!(
+            "reward_smt_count: {}, admin_count: {}",
+            reward_smt_count, admin_count
+        );
+        if reward_smt_count == 0 && admin_count == 0 {
+            return Err(Error::IssueUnauthorizedMint);
+        }
+    }
+
+    Ok(())
+}
@@ -6,7 +6,10 @@ use alloc::vec::Vec;
 use alloc::{collections::BTreeSet, vec};
 
 // use axon_types::metadata;
-use axon_types::metadata_reader::{self, ElectionSmtProof, MetadataWitness, StakeSmtElectionInfo};
+use axon_types::metadata_reader::{
+    self, ElectionSmtProof, GovernanceConfig, GovernanceSignatures, MetadataWitness,
+    StakeSmtElectionInfo, TypeIdsUpgrade,
+};
 // use axon_types::reward_reader::EpochRewardStakeInfo;
 use axon_types::{
     checkpoint_reader::CheckpointCellData, metadata_reader::MetadataCellData,
@@ -20,24 +23,32 @@ use axon_types::{
 use ckb_std::{
     ckb_constants::Source,
     debug,
-    high_level::{load_script, load_witness_args},
+    high_level::{load_script, load_tx_hash, load_witness_args},
 };
 
 use axon_types::{metadata_reader::Metadata, Cursor};
+use bit_vec::BitVec;
 use ckb_type_id::{load_type_id_from_script_args, validate_type_id};
 use sparse_merkle_tree::{CompiledMerkleProof, H256};
+use util::election::{get_election_quorum, select_validators};
+use util::eth::Secp256k1Eth;
 use util::helper::{
     calc_script_hash, get_cell_count_by_type_hash, get_current_epoch, get_delegate_smt_root,
-    get_quorum_size, get_script_hash, get_stake_smt_root, get_withdraw_at_data_by_lock_hash,
-    MinerGroupInfoObject,
+    get_reward_params, get_script_hash, get_stake_smt_root, get_withdraw_at_data_by_lock_hash,
+    has_delegate_requirement, CommissionObject, MinerGroupInfoObject,
+};
+use util::requirement::commission_at;
+use util::smt::{
+    u64_to_h256, verify_2layer_smt_commission, verify_2layer_smt_propose, verify_smt_reward_params,
+    LockInfo,
 };
-use util::smt::{u64_to_h256, verify_2layer_smt_propose, LockInfo};
 use util::stake::WithdrawAmountMap;
 use util::{
     error::Error,
     helper::{
-        calc_withdrawal_lock_hash, get_checkpoint_by_type_id, get_epoch_len,
-        get_metada_data_by_type_id, get_type_ids, ProposeCountObject,
+        calc_withdrawal_lock_hash, get_checkpoint_by_type_id, get_delegate_cell_data,
+        get_epoch_len, get_inauguration_epoch, get_metada_data_by_type_id, get_type_ids,
+        ProposeCountObject,
     },
 };
 
@@ -55,7 +66,8 @@ pub fn main() -> Result<(), Error> {
         get_cell_count_by_type_hash(&metadata_type_id.to_vec(), Source::Input);
     if input_metadata_count == 0 {
         debug!("metadata cell creation");
-        return Ok(());
+        let output_metadata = get_metada_data_by_type_id(&metadata_type_id, Source::Output)?;
+        return verify_chain_params_bounds(&output_metadata);
     }
 
     let witness_args = load_witness_args(0, Source::GroupInput);
@@ -78,8 +90,46 @@ pub fn main() -> Result<(), Error> {
     let type_ids = get_type_ids(&metadata_type_id, Source::Input)?;
     let input_metadata = get_metada_data_by_type_id(&metadata_type_id, Source::Input)?;
     let output_metadata = get_metada_data_by_type_id(&metadata_type_id, Source::Output)?;
+    match metadata_witness.mode() {
+        0 => {
+            // kicker elects validators at the end of epoch
+            update_epoch(
+                &metadata_type_id,
+                &type_ids,
+                &input_metadata,
+                &output_metadata,
+                &metadata_witness,
+            )?;
+        }
+        1 => {
+            debug!("verify_governance");
+            verify_governance(&input_metadata, &output_metadata, &metadata_witness)?;
+        }
+        2 => {
+            debug!("verify_type_ids_upgrade_proposal");
+            verify_type_ids_upgrade_proposal(&input_metadata, &output_metadata, &metadata_witness)?;
+        }
+        3 => {
+            debug!("verify_type_ids_upgrade_application");
+            verify_type_ids_upgrade_application(&input_metadata, &output_metadata)?;
+        }
+        _ => {
+            return Err(Error::UnknownMode);
+        }
+    }
+
+    Ok(())
+}
+
+fn update_epoch(
+    metadata_type_id: &[u8; 32],
+    type_ids: &TypeIds,
+    input_metadata: &MetadataCellData,
+    output_metadata: &MetadataCellData,
+    metadata_witness: &MetadataWitness,
+) -> Result<(), Error> {
     debug!("verify_chain_config");
-    verify_chain_config(&input_metadata, &output_metadata)?;
+    verify_chain_config(input_metadata, output_metadata)?;
 
     // debug!("checkpoint type id: {:?}", type_ids.checkpoint_type_id());
     // debug!(
@@ -98,10 +148,28 @@ pub fn main() -> Result<(), Error> {
     verify_last_checkpoint_of_epoch(&metadata_type_id.to_vec(), &checkpoint_data)?;
 
     debug!("verify_propose_counts");
-    verify_propose_counts(&checkpoint_data, &output_metadata, &metadata_witness)?;
+    verify_propose_counts(&checkpoint_data, output_metadata, metadata_witness)?;
+
+    debug!("verify_commissions");
+    verify_commissions(
+        metadata_type_id,
+        type_ids,
+        &checkpoint_data,
+        input_metadata,
+        output_metadata,
+        metadata_witness,
+    )?;
+
+    debug!("verify_reward_params");
+    verify_reward_params(
+        &checkpoint_data,
+        input_metadata,
+        output_metadata,
+        metadata_witness,
+    )?;
 
     debug!("verify_election");
-    verify_election(&type_ids, &metadata_witness.smt_election_info())?;
+    verify_election(type_ids, &metadata_witness.smt_election_info())?;
 
     Ok(())
 }
@@ -130,6 +198,90 @@ fn is_type_ids_equal(ids1: &TypeIds, ids2: &TypeIds) -> bool {
     true
 }
 
+fn is_governance_equal(left: &GovernanceConfig, right: &GovernanceConfig) -> bool {
+    let (left_members, right_members) = (left.members(), right.members());
+    if left.mode() != right.mode()
+        || left.threshold() != right.threshold()
+        || left.upgrade_delay() != right.upgrade_delay()
+        || left_members.len() != right_members.len()
+    {
+        return false;
+    }
+    (0..left_members.len()).all(|i| left_members.get(i) == right_members.get(i))
+}
+
+fn is_type_ids_upgrade_equal(left: &TypeIdsUpgrade, right: &TypeIdsUpgrade) -> bool {
+    left.effective_epoch() == right.effective_epoch()
+        && is_type_ids_equal(&left.type_ids(), &right.type_ids())
+}
+
+// fields of metadata cell data besides the metadata list, which one kind of transition may rewrite
+#[derive(Clone, Copy, PartialEq)]
+enum MutableField {
+    Epoch,
+    RewardParams,
+    ProposeCountSmtRoot,
+    CommissionSmtRoot,
+    RewardParamsSmtRoot,
+    TypeIds,
+    TypeIdsUpgrade,
+}
+
+// every field not listed in `mutable` keeps unchanged, the metadata list is left to the caller
+fn is_cell_data_unchanged(
+    input_metadata: &MetadataCellData,
+    output_metadata: &MetadataCellData,
+    mutable: &[MutableField],
+) -> bool {
+    let fixed = |field: MutableField| !mutable.contains(&field);
+    if fixed(MutableField::Epoch) && input_metadata.epoch() != output_metadata.epoch() {
+        return false;
+    }
+    if fixed(MutableField::RewardParams)
+        && (input_metadata.base_reward() != output_metadata.base_reward()
+            || input_metadata.half_epoch() != output_metadata.half_epoch()
+            || input_metadata.propose_minimum_rate() != output_metadata.propose_minimum_rate()
+            || input_metadata.propose_discount_rate() != output_metadata.propose_discount_rate())
+    {
+        return false;
+    }
+    if fixed(MutableField::ProposeCountSmtRoot)
+        && input_metadata.propose_count_smt_root() != output_metadata.propose_count_smt_root()
+    {
+        return false;
+    }
+    if fixed(MutableField::CommissionSmtRoot)
+        && input_metadata.commission_smt_root() != output_metadata.commission_smt_root()
+    {
+        return false;
+    }
+    if fixed(MutableField::RewardParamsSmtRoot)
+        && input_metadata.reward_params_smt_root() != output_metadata.reward_params_smt_root()
+    {
+        return false;
+    }
+    if fixed(MutableField::TypeIds)
+        && !is_type_ids_equal(&input_metadata.type_ids(), &output_metadata.type_ids())
+    {
+        return false;
+    }
+    if fixed(MutableField::TypeIdsUpgrade)
+        && !is_type_ids_upgrade_equal(
+            &input_metadata.type_ids_upgrade(),
+            &output_metadata.type_ids_upgrade(),
+        )
+    {
+        return false;
+    }
+    input_metadata.version() == output_metadata.version()
+        && input_metadata.slash_rate() == output_metadata.slash_rate()
+        && input_metadata.liveness_slash_rate() == output_metadata.liveness_slash_rate()
+        && input_metadata.treasury_lock_hash() == output_metadata.treasury_lock_hash()
+        && input_metadata.unbonding_epochs() == output_metadata.unbonding_epochs()
+        && input_metadata.inauguration_delay() == output_metadata.inauguration_delay()
+        && is_governance_equal(&input_metadata.governance(), &output_metadata.governance())
+}
+
 // verify data correctness exclude propose count and election
 fn verify_chain_config(
     input_metadata: &MetadataCellData,
@@ -142,45 +294,53 @@ fn verify_chain_config(
 
     let input_metadatas = input_metadata.metadata();
     let output_metadatas = output_metadata.metadata();
-    let metadata_list_size = 2;
-    if input_metadatas.len() != metadata_list_size || output_metadatas.len() != metadata_list_size {
+    let metadata_list_size = input_metadata.inauguration_delay() as usize;
+    if metadata_list_size == 0
+        || input_metadatas.len() != metadata_list_size
+        || output_metadatas.len() != metadata_list_size
+    {
         return Err(Error::MetadataSizeWrong);
     }
 
-    if input_metadata.version() != output_metadata.version()
-        || input_metadata.base_reward() != output_metadata.base_reward()
-        || input_metadata.half_epoch() != output_metadata.half_epoch()
-        || input_metadata.propose_minimum_rate() != output_metadata.propose_minimum_rate()
-        || input_metadata.propose_discount_rate() != output_metadata.propose_discount_rate()
-        || !is_type_ids_equal(&input_metadata.type_ids(), &output_metadata.type_ids())
-    {
+    // propose count, commission and reward params roots are verified by the election itself
+    if !is_cell_data_unchanged(
+        input_metadata,
+        output_metadata,
+        &[
+            MutableField::Epoch,
+            MutableField::ProposeCountSmtRoot,
+            MutableField::CommissionSmtRoot,
+            MutableField::RewardParamsSmtRoot,
+        ],
+    ) {
         return Err(Error::MetadataInputOutputMismatch);
     }
 
-    let input_metadata1 = input_metadatas.get(1);
-    let output_metadata0 = output_metadatas.get(0);
-    let output_metadata1 = output_metadatas.get(1);
-
-    if !is_metadata_equal(&input_metadata1, &output_metadata0) {
-        debug!("input_metadata1: MetadataInputOutputMismatch");
-        return Err(Error::MetadataInputOutputMismatch);
+    // metadata of the following epochs moves one entry forward
+    for i in 1..metadata_list_size {
+        if !is_metadata_equal(&input_metadatas.get(i), &output_metadatas.get(i - 1)) {
+            debug!("input_metadata{}: MetadataInputOutputMismatch", i);
+            return Err(Error::MetadataInputOutputMismatch);
+        }
     }
 
-    // output metadata2 will update something, like validators, block height, etc.
-    if output_metadata1.brake_ratio() != output_metadata0.brake_ratio()
-        || output_metadata1.epoch_len() != output_metadata0.epoch_len()
-        || output_metadata1.gas_limit() != output_metadata0.gas_limit()
-        || output_metadata1.gas_price() != output_metadata0.gas_price()
-        || output_metadata1.interval() != output_metadata0.interval()
-        || output_metadata1.max_tx_size() != output_metadata0.max_tx_size()
-        || output_metadata1.period_len() != output_metadata0.period_len()
-        || output_metadata1.precommit_ratio() != output_metadata0.precommit_ratio()
-        || output_metadata1.prevote_ratio() != output_metadata0.prevote_ratio()
-        || output_metadata1.propose_ratio() != output_metadata0.propose_ratio()
-        || output_metadata1.quorum() != output_metadata0.quorum()
-        || output_metadata1.tx_num_limit() != output_metadata0.tx_num_limit()
+    // the last output metadata will update something, like validators, block height, etc.
+    let input_last = input_metadatas.get(metadata_list_size - 1);
+    let output_last = output_metadatas.get(metadata_list_size - 1);
+    if output_last.brake_ratio() != input_last.brake_ratio()
+        || output_last.epoch_len() != input_last.epoch_len()
+        || output_last.gas_limit() != input_last.gas_limit()
+        || output_last.gas_price() != input_last.gas_price()
+        || output_last.interval() != input_last.interval()
+        || output_last.max_tx_size() != input_last.max_tx_size()
+        || output_last.period_len() != input_last.period_len()
+        || output_last.precommit_ratio() != input_last.precommit_ratio()
+        || output_last.prevote_ratio() != input_last.prevote_ratio()
+        || output_last.propose_ratio() != input_last.propose_ratio()
+        || output_last.quorum() != input_last.quorum()
+        || output_last.tx_num_limit() != input_last.tx_num_limit()
     {
-        debug!("output_metadata1: MetadataInputOutputMismatch");
+        debug!("output_last: MetadataInputOutputMismatch");
         return Err(Error::MetadataInputOutputMismatch);
     }
 
@@ -239,6 +399,83 @@ fn verify_propose_counts(
     Ok(())
 }
 
+// record the commission in force of every validator of the ending epoch, reward uses it later
+fn verify_commissions(
+    metadata_type_id: &[u8; 32],
+    type_ids: &TypeIds,
+    checkpoint_data: &CheckpointCellData,
+    input_metadata: &MetadataCellData,
+    output_metadata: &MetadataCellData,
+    metadata_witness: &MetadataWitness,
+) -> Result<(), Error> {
+    let epoch = checkpoint_data.epoch();
+    let validators = input_metadata.metadata().get(0).validators();
+    let mut commissions = Vec::new();
+    for i in 0..validators.len() {
+        let addr = validators.get(i).address();
+        let stake_at_code_hash = type_ids.stake_at_code_hash();
+        let commission = match get_delegate_cell_data(&addr, metadata_type_id, &stake_at_code_hash)?
+        {
+            Some(delegate_cell_data) => {
+                commission_at(addr.try_into().unwrap(), &delegate_cell_data, epoch)
+            }
+            // a validator never setting a requirement takes no commission and no threshold
+            None if !has_delegate_requirement(&addr, metadata_type_id, &stake_at_code_hash)? => {
+                CommissionObject {
+                    addr: addr.try_into().unwrap(),
+                    ..Default::default()
+                }
+            }
+            None => return Err(Error::CommissionSnapshotMissing),
+        };
+        debug!("commission: {:?}", commission);
+        commissions.push(commission);
+    }
+
+    let old_root: [u8; 32] = input_metadata.commission_smt_root().try_into().unwrap();
+    let new_root: [u8; 32] = output_metadata.commission_smt_root().try_into().unwrap();
+    let result = verify_2layer_smt_commission(
+        &commissions,
+        u64_to_h256(epoch),
+        old_root.into(),
+        new_root.into(),
+        CompiledMerkleProof(metadata_witness.new_commission_proof()),
+    )?;
+    debug!("verify_2layer_smt_commission result: {:?}", result);
+    if !result {
+        return Err(Error::MetadataCommissionVerifyFail);
+    }
+
+    Ok(())
+}
+
+// record the reward parameters in force during the ending epoch, so that governance changing them
+// later doesn't alter rewards of the epoch
+fn verify_reward_params(
+    checkpoint_data: &CheckpointCellData,
+    input_metadata: &MetadataCellData,
+    output_metadata: &MetadataCellData,
+    metadata_witness: &MetadataWitness,
+) -> Result<(), Error> {
+    let reward_params = get_reward_params(input_metadata);
+    debug!("reward_params: {:?}", reward_params);
+    let old_root: [u8; 32] = input_metadata.reward_params_smt_root().try_into().unwrap();
+    let new_root: [u8; 32] = output_metadata.reward_params_smt_root().try_into().unwrap();
+    let result = verify_smt_reward_params(
+        &reward_params,
+        u64_to_h256(checkpoint_data.epoch()),
+        old_root.into(),
+        new_root.into(),
+        CompiledMerkleProof(metadata_witness.new_reward_params_proof()),
+    )?;
+    debug!("verify_smt_reward_params result: {:?}", result);
+    if !result {
+        return Err(Error::MetadataRewardParamsVerifyFail);
+    }
+
+    Ok(())
+}
+
 fn verify_election(type_ids: &TypeIds, election_infos: &StakeSmtElectionInfo) -> Result<(), Error> {
     /*
         let stake_smt_type_id = type_ids.stake_smt_type_id();
@@ -265,11 +502,7 @@ fn verify_election(type_ids: &TypeIds, election_infos: &StakeSmtElectionInfo) ->
     */
     let metadata_type_id =
         get_script_hash(&type_ids.metadata_code_hash(), &type_ids.metadata_type_id());
-    let quorum = get_quorum_size(
-        &metadata_type_id,
-        util::stake::EpochClass::NEXT,
-        Source::Input,
-    )?;
+    let quorum = get_election_quorum(&metadata_type_id, Source::Input)?;
     debug!("quorum: {:?}", quorum);
     let delete_miners = verify_election_metadata(&type_ids, quorum, election_infos)?;
 
@@ -423,8 +656,7 @@ fn verify_election_metadata(
     quorum_size: u16,
     election_infos: &StakeSmtElectionInfo,
 ) -> Result<BTreeSet<MinerGroupInfoObject>, Error> {
-    // get stake & delegate data of epoch n + 1 & n + 2,  from witness of stake smt cell
-    // staker info of n + 2
+    // get stake & delegate data of epoch n + inauguration_delay from witness of stake smt cell
     let election_info_n2 = election_infos.n2();
     let mut miners_n2_before_selection = BTreeSet::new();
     let checkpoint_script_hash = get_script_hash(
@@ -433,7 +665,10 @@ fn verify_election_metadata(
     );
     let input_epoch = get_current_epoch(&checkpoint_script_hash.to_vec())?;
     debug!("get_current_epoch: {:?}", input_epoch);
-    let input_waiting_epoch = input_epoch + 2;
+    let metadata_type_id =
+        get_script_hash(&type_ids.metadata_code_hash(), &type_ids.metadata_type_id());
+    let input_waiting_epoch =
+        get_inauguration_epoch(&metadata_type_id, input_epoch, Source::Input)?;
     // verify stake and delegate infos in witness is correct, construct miners to get updated data
     verify_stake_delegate(
         &election_info_n2,
@@ -445,8 +680,7 @@ fn verify_election_metadata(
 
     // only keep top quorum stakers as validators, others as delete_stakers & delete_delegators
 
-    let (validators, delete_miners) =
-        get_selected_unselected(&miners_n2_before_selection, quorum_size)?;
+    let (validators, delete_miners) = select_validators(&miners_n2_before_selection, quorum_size);
     // get output metadata, verify the validators data.
     debug!("verify_new_validators, {:?}", validators);
     let output_quasi_epoch = input_waiting_epoch;
@@ -455,46 +689,6 @@ fn verify_election_metadata(
     Ok(delete_miners)
 }
 
-fn get_selected_unselected(
-    miners_n2_before_selection: &BTreeSet<MinerGroupInfoObject>,
-    select_num: u16,
-) -> Result<
-    (
-        BTreeSet<MinerGroupInfoObject>,
-        BTreeSet<MinerGroupInfoObject>,
-    ),
-    Error,
-> {
-    let iter = miners_n2_before_selection.iter();
-    let mut top_quorum = iter.take(select_num.into());
-    let mut select_set = BTreeSet::new();
-    while let Some(elem) = top_quorum.next() {
-        select_set.insert((*elem).clone());
-    }
-
-    let mut delete_set = BTreeSet::<MinerGroupInfoObject>::new();
-    if miners_n2_before_selection.len() > select_set.len() {
-        let mut iter = miners_n2_before_selection.iter();
-        let deleted_size = miners_n2_before_selection.len() - select_set.len();
-        for _ in 0..deleted_size {
-            if let Some(elem) = iter.next_back() {
-                delete_set.insert(elem.clone());
-                debug!("deleted_infos : {:?}", *elem);
-            }
-        }
-    }
-
-    debug!(
-        "input set len: {}, select_set.len():{}, deleted  size: {}, quorum: {}",
-        miners_n2_before_selection.len(),
-        select_set.len(),
-        delete_set.len(),
-        select_num
-    );
-
-    Ok((select_set, delete_set))
-}
-
 // verify stake and delegate infos, fill miners with respect to election_info
 pub fn verify_stake_delegate(
     election_info: &ElectionSmtProof,
@@ -505,12 +699,18 @@ pub fn verify_stake_delegate(
 ) -> Result<(), Error> {
     let miner_infos = election_info.miners();
     let mut stake_infos = BTreeSet::new();
+    let mut stakers = BTreeSet::new();
 
     // get stake infos and miner group info
     for i in 0..miner_infos.len() {
         let miner_info = &miner_infos.get(i);
         let miner_group_obj = MinerGroupInfoObject::new(miner_info);
 
+        // a duplicate staker would be merged silently by sets below
+        if !stakers.insert(miner_group_obj.staker) {
+            debug!("duplicate miner: {:?}", miner_group_obj.staker);
+            return Err(Error::MetadataDuplicateMiner);
+        }
         stake_infos.insert(LockInfo {
             addr: miner_group_obj.staker,
             amount: miner_group_obj.stake_amount,
@@ -651,3 +851,336 @@ fn verify_new_validators(
 
     Ok(())
 }
+
+// bounds of consensus parameters which can be changed by governance
+const EPOCH_LEN_BOUND: (u32, u32) = (1, 10_000);
+const PERIOD_LEN_BOUND: (u32, u32) = (1, 100_000);
+const QUORUM_BOUND: (u16, u16) = (1, 1_000);
+const GAS_LIMIT_BOUND: (u64, u64) = (21_000, 10_000_000_000);
+const GAS_PRICE_BOUND: (u64, u64) = (1, 1_000_000_000_000);
+const INTERVAL_BOUND: (u32, u32) = (100, 60_000); // milliseconds
+const CONSENSUS_RATIO_BOUND: (u32, u32) = (1, 100);
+const TX_NUM_LIMIT_BOUND: (u32, u32) = (1, 100_000);
+const MAX_TX_SIZE_BOUND: (u32, u32) = (1_024, 10_485_760);
+
+fn check_bound<T: PartialOrd + core::fmt::Debug>(
+    name: &str,
+    value: T,
+    bound: (T, T),
+) -> Result<(), Error> {
+    if value < bound.0 || value > bound.1 {
+        debug!("governance {} {:?} out of bound {:?}", name, value, bound);
+        return Err(Error::GovernanceOutOfBounds);
+    }
+    Ok(())
+}
+
+const RATE_BOUND: (u8, u8) = (0, 100); // percent
+const HALF_EPOCH_BOUND: (u64, u64) = (1, u64::MAX);
+// metadata keeps one entry for each epoch from n to n + inauguration_delay - 1, the election at
+// the end of epoch n fills the entry of epoch n + inauguration_delay. with a delay of 1 there is
+// no scheduled entry, so consensus parameters stay the same as the ones of the genesis metadata
+const INAUGURATION_DELAY_BOUND: (u64, u64) = (1, 8);
+// unstaked AT is locked for at least one epoch, a withdraw cell keeps unbonding_epochs + 1 records
+const UNBONDING_EPOCHS_BOUND: (u64, u64) = (1, 1_000);
+
+// chain parameters out of consensus, checked whenever the metadata cell is created or rewritten
+fn verify_chain_params_bounds(metadata: &MetadataCellData) -> Result<(), Error> {
+    check_bound("half_epoch", metadata.half_epoch(), HALF_EPOCH_BOUND)?;
+    check_bound(
+        "inauguration_delay",
+        metadata.inauguration_delay(),
+        INAUGURATION_DELAY_BOUND,
+    )?;
+    if metadata.metadata().len() as u64 != metadata.inauguration_delay() {
+        return Err(Error::MetadataSizeWrong);
+    }
+    check_bound(
+        "unbonding_epochs",
+        metadata.unbonding_epochs(),
+        UNBONDING_EPOCHS_BOUND,
+    )?;
+    check_bound(
+        "propose_minimum_rate",
+        metadata.propose_minimum_rate(),
+        RATE_BOUND,
+    )?;
+    check_bound(
+        "propose_discount_rate",
+        metadata.propose_discount_rate(),
+        RATE_BOUND,
+    )?;
+    check_bound("slash_rate", metadata.slash_rate(), RATE_BOUND)?;
+    check_bound(
+        "liveness_slash_rate",
+        metadata.liveness_slash_rate(),
+        RATE_BOUND,
+    )?;
+    Ok(())
+}
+
+fn verify_governance_bounds(metadata: &Metadata) -> Result<(), Error> {
+    check_bound("epoch_len", metadata.epoch_len(), EPOCH_LEN_BOUND)?;
+    check_bound("period_len", metadata.period_len(), PERIOD_LEN_BOUND)?;
+    check_bound("quorum", metadata.quorum(), QUORUM_BOUND)?;
+    check_bound("gas_limit", metadata.gas_limit(), GAS_LIMIT_BOUND)?;
+    check_bound("gas_price", metadata.gas_price(), GAS_PRICE_BOUND)?;
+    check_bound("interval", metadata.interval(), INTERVAL_BOUND)?;
+    check_bound(
+        "propose_ratio",
+        metadata.propose_ratio(),
+        CONSENSUS_RATIO_BOUND,
+    )?;
+    check_bound(
+        "prevote_ratio",
+        metadata.prevote_ratio(),
+        CONSENSUS_RATIO_BOUND,
+    )?;
+    check_bound(
+        "precommit_ratio",
+        metadata.precommit_ratio(),
+        CONSENSUS_RATIO_BOUND,
+    )?;
+    check_bound("brake_ratio", metadata.brake_ratio(), CONSENSUS_RATIO_BOUND)?;
+    check_bound("tx_num_limit", metadata.tx_num_limit(), TX_NUM_LIMIT_BOUND)?;
+    check_bound("max_tx_size", metadata.max_tx_size(), MAX_TX_SIZE_BOUND)?;
+    Ok(())
+}
+
+// governance changes consensus parameters of the following epochs and the reward parameters,
+// everything else keeps unchanged. reward parameters of ended epochs are kept in reward params
+// smt, so new ones apply from the running epoch on
+fn verify_governance_data(
+    input_metadata: &MetadataCellData,
+    output_metadata: &MetadataCellData,
+) -> Result<(), Error> {
+    if !is_cell_data_unchanged(
+        input_metadata,
+        output_metadata,
+        &[MutableField::RewardParams],
+    ) {
+        return Err(Error::GovernanceDataMismatch);
+    }
+
+    let input_metadatas = input_metadata.metadata();
+    let output_metadatas = output_metadata.metadata();
+    let metadata_list_size = input_metadata.inauguration_delay() as usize;
+    if input_metadatas.len() != metadata_list_size || output_metadatas.len() != metadata_list_size {
+        return Err(Error::MetadataSizeWrong);
+    }
+    let (input_metadata0, output_metadata0) = (input_metadatas.get(0), output_metadatas.get(0));
+    if !is_metadata_equal(&input_metadata0, &output_metadata0)
+        || input_metadata0.block_height() != output_metadata0.block_height()
+    {
+        return Err(Error::GovernanceDataMismatch);
+    }
+    for i in 1..metadata_list_size {
+        let (input, output) = (input_metadatas.get(i), output_metadatas.get(i));
+        if !is_validators_equal(&input.validators(), &output.validators())
+            || input.block_height() != output.block_height()
+        {
+            return Err(Error::GovernanceDataMismatch);
+        }
+    }
+
+    Ok(())
+}
+
+// members or validators sign the transaction hash, which commits to both input and output
+// metadata cells, so that the signatures can't be replayed
+fn verify_governance_signatures(
+    input_metadata: &MetadataCellData,
+    governance_sigs: &GovernanceSignatures,
+) -> Result<(), Error> {
+    let governance = input_metadata.governance();
+    let message = load_tx_hash()?;
+    let signatures = governance_sigs.signatures();
+    match governance.mode() {
+        0 => {
+            let members = governance.members();
+            let members = (0..members.len())
+                .map(|i| members.get(i))
+                .collect::<Vec<_>>();
+            let secp256_eth = Secp256k1Eth::default();
+            let mut signers = BTreeSet::new();
+            for i in 0..signatures.len() {
+                let signature: [u8; 65] = signatures
+                    .get(i)
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| Error::GovernanceUnauthorized)?;
+                let signer = secp256_eth.recover(signature, message)?;
+                if !members.iter().any(|member| member.as_slice() == signer) {
+                    return Err(Error::GovernanceUnauthorized);
+                }
+                signers.insert(signer);
+            }
+            debug!(
+                "governance signers: {}, threshold: {}",
+                signers.len(),
+                governance.threshold()
+            );
+            if governance.threshold() == 0 || signers.len() < governance.threshold() as usize {
+                return Err(Error::GovernanceUnauthorized);
+            }
+        }
+        1 => {
+            // the same quorum rule as checkpoint, validators of current epoch must hold more
+            // than 2/3 vote weight
+            let validators = input_metadata.metadata().get(0).validators();
+            let bitmap = BitVec::from_bytes(governance_sigs.bitmap().as_slice());
+            let mut total_weight = 0u64;
+            let mut active_weight = 0u64;
+            let mut pubkeys = Vec::new();
+            for i in 0..validators.len() {
+                let validator = validators.get(i);
+                total_weight += validator.vote_weight() as u64;
+                if bitmap.get(i).unwrap_or(false) {
+                    active_weight += validator.vote_weight() as u64;
+                    pubkeys.push(validator.bls_pub_key().as_slice().try_into().unwrap());
+                }
+            }
+            debug!("governance vote weight: {}/{}", active_weight, total_weight);
+            if bitmap.len() > (validators.len() + 7) / 8 * 8
+                || signatures.len() != 1
+                || active_weight * 3 <= total_weight * 2
+            {
+                return Err(Error::GovernanceUnauthorized);
+            }
+            if !blst::verify_blst_signature(&pubkeys, &signatures.get(0), &message.to_vec()) {
+                return Err(Error::SignatureMismatch);
+            }
+        }
+        _ => {
+            return Err(Error::GovernanceUnauthorized);
+        }
+    }
+
+    Ok(())
+}
+
+// governance schedules new consensus parameters in metadata of the following epochs, which take
+// effect once the elections move them to metadata of current epoch
+fn verify_governance(
+    input_metadata: &MetadataCellData,
+    output_metadata: &MetadataCellData,
+    metadata_witness: &MetadataWitness,
+) -> Result<(), Error> {
+    verify_governance_data(input_metadata, output_metadata)?;
+    verify_chain_params_bounds(output_metadata)?;
+    let output_metadatas = output_metadata.metadata();
+    for i in 1..output_metadatas.len() {
+        verify_governance_bounds(&output_metadatas.get(i))?;
+    }
+    verify_governance_signatures(input_metadata, &metadata_witness.governance_sigs())
+}
+
+// upgrade only swaps code hashes of type scripts, type ids and hashes identifying the chain are
+// fixed, so does the code hash of metadata itself which is part of metadata type script. lock
+// code hashes are fixed too, otherwise AT, delegate AT and withdraw cells locked by the old code
+// are stranded
+fn is_type_ids_upgradable(current: &TypeIds, upgrade: &TypeIds) -> bool {
+    current.issue_type_id() == upgrade.issue_type_id()
+        && current.selection_type_id() == upgrade.selection_type_id()
+        && current.xudt_owner_lock_hash() == upgrade.xudt_owner_lock_hash()
+        && current.metadata_code_hash() == upgrade.metadata_code_hash()
+        && current.metadata_type_id() == upgrade.metadata_type_id()
+        && current.checkpoint_type_id() == upgrade.checkpoint_type_id()
+        && current.stake_smt_type_id() == upgrade.stake_smt_type_id()
+        && current.delegate_smt_type_id() == upgrade.delegate_smt_type_id()
+        && current.reward_type_id() == upgrade.reward_type_id()
+        && current.xudt_type_hash() == upgrade.xudt_type_hash()
+        && current.stake_at_code_hash() == upgrade.stake_at_code_hash()
+        && current.delegate_at_code_hash() == upgrade.delegate_at_code_hash()
+        && current.withdraw_code_hash() == upgrade.withdraw_code_hash()
+}
+
+// everything except type ids and the pending upgrade keeps unchanged
+fn verify_type_ids_upgrade_data(
+    input_metadata: &MetadataCellData,
+    output_metadata: &MetadataCellData,
+) -> Result<(), Error> {
+    if !is_cell_data_unchanged(
+        input_metadata,
+        output_metadata,
+        &[MutableField::TypeIds, MutableField::TypeIdsUpgrade],
+    ) {
+        return Err(Error::GovernanceDataMismatch);
+    }
+    verify_chain_params_bounds(output_metadata)?;
+
+    let input_metadatas = input_metadata.metadata();
+    let output_metadatas = output_metadata.metadata();
+    if input_metadatas.len() != output_metadatas.len() {
+        return Err(Error::MetadataSizeWrong);
+    }
+    for i in 0..input_metadatas.len() {
+        let (input, output) = (input_metadatas.get(i), output_metadatas.get(i));
+        if !is_metadata_equal(&input, &output)
+            || !is_validators_equal(&input.validators(), &output.validators())
+            || input.block_height() != output.block_height()
+        {
+            return Err(Error::GovernanceDataMismatch);
+        }
+    }
+
+    Ok(())
+}
+
+// a pending upgrade waits at least one epoch, so that an effective epoch of 0 always means none
+const MIN_UPGRADE_DELAY: u64 = 1;
+
+// governance proposes new code hashes, a new proposal replaces the pending one
+fn verify_type_ids_upgrade_proposal(
+    input_metadata: &MetadataCellData,
+    output_metadata: &MetadataCellData,
+    metadata_witness: &MetadataWitness,
+) -> Result<(), Error> {
+    verify_type_ids_upgrade_data(input_metadata, output_metadata)?;
+    let type_ids = input_metadata.type_ids();
+    if !is_type_ids_equal(&type_ids, &output_metadata.type_ids()) {
+        return Err(Error::GovernanceDataMismatch);
+    }
+
+    let upgrade = output_metadata.type_ids_upgrade();
+    let upgrade_delay = input_metadata
+        .governance()
+        .upgrade_delay()
+        .max(MIN_UPGRADE_DELAY);
+    debug!(
+        "type ids upgrade effective epoch: {}, current epoch: {}, delay: {}",
+        upgrade.effective_epoch(),
+        input_metadata.epoch(),
+        upgrade_delay
+    );
+    if upgrade.effective_epoch() != input_metadata.epoch() + upgrade_delay {
+        return Err(Error::TypeIdsUpgradeTimelocked);
+    }
+    if !is_type_ids_upgradable(&type_ids, &upgrade.type_ids()) {
+        return Err(Error::TypeIdsUpgradeForbidden);
+    }
+
+    verify_governance_signatures(input_metadata, &metadata_witness.governance_sigs())
+}
+
+// anyone can apply a pending upgrade once its time lock expires
+fn verify_type_ids_upgrade_application(
+    input_metadata: &MetadataCellData,
+    output_metadata: &MetadataCellData,
+) -> Result<(), Error> {
+    verify_type_ids_upgrade_data(input_metadata, output_metadata)?;
+    let upgrade = input_metadata.type_ids_upgrade();
+    if upgrade.effective_epoch() == 0 {
+        return Err(Error::TypeIdsUpgradeNotPending);
+    }
+    if input_metadata.epoch() < upgrade.effective_epoch() {
+        return Err(Error::TypeIdsUpgradeTimelocked);
+    }
+    if !is_type_ids_equal(&upgrade.type_ids(), &output_metadata.type_ids())
+        || output_metadata.type_ids_upgrade().effective_epoch() != 0
+    {
+        return Err(Error::GovernanceDataMismatch);
+    }
+
+    Ok(())
+}
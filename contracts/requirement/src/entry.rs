@@ -5,12 +5,23 @@ use ckb_std::{
     ckb_constants::Source,
     ckb_types::prelude::*,
     debug,
-    high_level::{load_cell_data, load_cell_type, load_script, QueryIter},
+    high_level::{load_cell_data, load_cell_type, load_script, load_witness_args, QueryIter},
 };
 use ckb_type_id::{load_type_id_from_script_args, validate_type_id};
 
-use axon_types::delegate::DelegateCellData;
-use util::error::Error;
+use axon_types::{delegate::DelegateCellData, delegate_reader, Cursor};
+use util::{
+    error::Error,
+    eth::{verify_eth_signature, Operation, TypedOperation},
+    helper::{
+        bytes_to_u128, bytes_to_u32, find_script_input, get_current_epoch, get_inauguration_epoch,
+        get_script_hash, get_type_ids,
+    },
+    requirement::{commission_rate_at, is_commission_change_allowed},
+};
+
+// delegate smt keeps at most max_delegator_size delegators of a staker in one bottom smt
+const MAX_DELEGATOR_SIZE_BOUND: (u32, u32) = (1, 1_000);
 
 pub fn main() -> Result<(), Error> {
     let script = load_script()?;
@@ -40,5 +51,111 @@ pub fn main() -> Result<(), Error> {
         return Err(Error::CommissionRateTooLarge);
     }
 
+    let max_delegator_size = bytes_to_u32(
+        &req.delegate_requirement()
+            .max_delegator_size()
+            .as_slice()
+            .to_vec(),
+    );
+    if max_delegator_size < MAX_DELEGATOR_SIZE_BOUND.0
+        || max_delegator_size > MAX_DELEGATOR_SIZE_BOUND.1
+    {
+        return Err(Error::DelegatorSizeOutOfBounds);
+    }
+    // total delegation of a staker full of delegators at threshold must not overflow
+    let threshold = bytes_to_u128(&req.delegate_requirement().threshold().as_slice().to_vec());
+    debug!(
+        "threshold: {}, max_delegator_size: {}",
+        threshold, max_delegator_size
+    );
+    if threshold.checked_mul(max_delegator_size as u128).is_none() {
+        return Err(Error::DelegateThresholdOutOfBounds);
+    }
+
+    let output_data: delegate_reader::DelegateCellData = Cursor::from(data.to_vec()).into();
+    if find_script_input(&script) {
+        let metadata_type_id: [u8; 32] = script_args.raw_data()[..32].try_into().unwrap();
+        update_requirement(&metadata_type_id, &output_data)?;
+    } else if output_data.commission_change().previous_rate() != commission_rate {
+        // a new requirement cell has no pending commission change
+        return Err(Error::RequirementDataMismatch);
+    }
+
+    Ok(())
+}
+
+fn update_requirement(
+    metadata_type_id: &[u8; 32],
+    output_data: &delegate_reader::DelegateCellData,
+) -> Result<(), Error> {
+    let input_data: delegate_reader::DelegateCellData =
+        Cursor::from(load_cell_data(0, Source::GroupInput)?).into();
+    if input_data.version() != output_data.version()
+        || input_data.l1_address() != output_data.l1_address()
+        || input_data.l2_address() != output_data.l2_address()
+        || input_data.metadata_type_id() != output_data.metadata_type_id()
+    {
+        return Err(Error::RequirementDataMismatch);
+    }
+
+    // only the staker owning the requirement cell can update it
+    let witness = load_witness_args(0, Source::GroupInput)?;
+    let witness_input_type = witness.input_type().to_opt();
+    if witness_input_type.is_none() {
+        return Err(Error::WitnessInputTypeError);
+    }
+    let witness: delegate_reader::RequirementWitness =
+        Cursor::from(witness_input_type.unwrap().raw_data().to_vec()).into();
+    let new_rate = output_data.delegate_requirement().commission_rate();
+    let commission_change = output_data.commission_change();
+    let operation = TypedOperation {
+        operation: Operation::UpdateRequirement,
+        amount: new_rate as u128,
+        inauguration_epoch: commission_change.effective_epoch(),
+        metadata_type_id: *metadata_type_id,
+    };
+    verify_eth_signature(
+        witness.sig_mode(),
+        &output_data.l2_address(),
+        &witness.eth_sig(),
+        &operation,
+    )?;
+
+    let input_rate = input_data.delegate_requirement().commission_rate();
+    let input_change = input_data.commission_change();
+    if new_rate == input_rate
+        && commission_change.previous_rate() == input_change.previous_rate()
+        && commission_change.effective_epoch() == input_change.effective_epoch()
+    {
+        // threshold or max_delegator_size only, they apply at the next delegator selection
+        return Ok(());
+    }
+
+    let type_ids = get_type_ids(metadata_type_id, Source::CellDep)?;
+    let checkpoint_type_hash = get_script_hash(
+        &type_ids.checkpoint_code_hash(),
+        &type_ids.checkpoint_type_id(),
+    );
+    let current_epoch = get_current_epoch(&checkpoint_type_hash.to_vec())?;
+    let rate_in_force = commission_rate_at(&input_data, current_epoch);
+    debug!(
+        "current_epoch: {}, rate_in_force: {}, new_rate: {}, effective_epoch: {}",
+        current_epoch,
+        rate_in_force,
+        new_rate,
+        commission_change.effective_epoch()
+    );
+    if commission_change.previous_rate() != rate_in_force {
+        return Err(Error::RequirementDataMismatch);
+    }
+    if commission_change.effective_epoch()
+        != get_inauguration_epoch(metadata_type_id, current_epoch, Source::CellDep)?
+    {
+        return Err(Error::CommissionChangeNotDelayed);
+    }
+    if !is_commission_change_allowed(rate_in_force, new_rate) {
+        return Err(Error::CommissionChangeTooLarge);
+    }
+
     Ok(())
 }
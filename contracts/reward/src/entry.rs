@@ -1,15 +1,19 @@
 // Import from `core` instead of from `std` since we are in no-std mode
 use alloc::vec::Vec;
 use alloc::{collections::BTreeSet, vec};
-use axon_types::metadata_reader::MetadataCellData;
+use axon_types::delegate_reader::{DelegateInfoDeltas, DelegateSmtCellData};
+use axon_types::metadata_reader::TypeIds;
 use axon_types::reward_reader::NotClaimInfo;
 use axon_types::reward_reader::RewardSmtCellData;
+use axon_types::reward_reader::{
+    BatchRewardInfos, EpochRewardParams, EpochRewardStakeInfos, RewardDelegateInfos,
+};
 use ckb_type_id::{load_type_id_from_script_args, validate_type_id};
 use core::result::Result;
-use sparse_merkle_tree::{blake2b::Blake2bHasher, CompiledMerkleProof, H256};
+use sparse_merkle_tree::{CompiledMerkleProof, H256};
 use util::smt::{
-    addr_to_h256, smt_verify_leaves, u128_to_h256, u64_to_h256, verify_2layer_smt, verify_top_smt,
-    LockInfo,
+    addr_to_h256, commission_to_h256, get_bottom_smt_root, reward_params_to_h256,
+    smt_verify_leaves, u128_to_h256, u64_to_h256, verify_2layer_smt, verify_top_smt, LockInfo,
 };
 
 // Import CKB syscalls and structures
@@ -18,17 +22,27 @@ use util::smt::{
 use ckb_std::{
     ckb_constants::Source,
     debug,
-    high_level::{load_script, load_witness_args},
+    high_level::{load_cell_lock_hash, load_script, load_witness_args, QueryIter},
 };
 
 use axon_types::{reward_reader, Cursor};
-use util::{error::Error, helper::*};
+use util::{
+    error::Error,
+    eth::{verify_eth_signature, Operation, TypedOperation},
+    helper::*,
+};
+
+const CLAIM_MODE: u8 = 0;
+const REGISTER_BENEFICIARY_MODE: u8 = 1;
+const COMPOUND_MODE: u8 = 2;
 
 #[derive(Clone, Copy, Debug, Default)]
 struct RewardStakeInfoObject {
     staker: [u8; 20],
     propose_count: u64,
     stake_amount: u128,
+    commission_rate: u8,
+    delegate_threshold: u128,
 }
 
 #[derive(Clone, Default)]
@@ -40,6 +54,25 @@ struct EpochRewardStakeInfoObject {
     amount_proof: Vec<u8>,      // bottom stake amount smt proof
     amount_root: [u8; 32],
     amount_epoch_proof: Vec<u8>,
+    commission_proof: Vec<u8>, // bottom commission smt proof
+    commission_root: [u8; 32],
+    commission_epoch_proof: Vec<u8>,
+}
+
+impl EpochRewardStakeInfoObject {
+    // delegate bottom roots are always rebuilt from all delegators of a staker, and if the listed
+    // stakers also rebuild the stake amount bottom root, no entry of the miner can be left out
+    fn lists_all_stakers(&self) -> bool {
+        let lock_infos = self
+            .stake_infos
+            .iter()
+            .map(|stake_info_obj| LockInfo {
+                addr: stake_info_obj.staker,
+                amount: stake_info_obj.stake_amount,
+            })
+            .collect::<BTreeSet<LockInfo>>();
+        get_bottom_smt_root(&lock_infos) == self.amount_root.into()
+    }
 }
 
 #[derive(Clone, Default)]
@@ -58,6 +91,51 @@ struct EpochRewardObject {
     reward_objs: Vec<RewardObject>,
 }
 
+impl EpochRewardObject {
+    // rewards of all listed stakers add up, so a staker can't be listed twice in an epoch
+    fn add_reward_obj(&mut self, reward_obj: RewardObject) -> Result<(), Error> {
+        if self
+            .reward_objs
+            .iter()
+            .any(|obj| obj.staker == reward_obj.staker)
+        {
+            return Err(Error::RewardDuplicateStaker);
+        }
+        self.reward_objs.push(reward_obj);
+        Ok(())
+    }
+}
+
+// roots shared by all claimed epochs
+struct ClaimContext {
+    miner: Vec<u8>,
+    stake_smt_root: [u8; 32],
+    propose_count_smt_root: [u8; 32],
+    commission_smt_root: [u8; 32],
+    reward_params_smt_root: [u8; 32],
+    delegate_smt_data: DelegateSmtCellData,
+}
+
+impl ClaimContext {
+    fn epoch_reward(
+        &self,
+        current_epoch: u64,
+        reward_params: &RewardParamsObject,
+        epoch_reward_obj: &EpochRewardObject,
+    ) -> Result<u128, Error> {
+        calculate_reward(&self.miner, epoch_reward_obj, current_epoch, reward_params)
+    }
+}
+
+fn to_reward_params(reward_params: &EpochRewardParams) -> RewardParamsObject {
+    RewardParamsObject {
+        base_reward: bytes_to_u128(&reward_params.base_reward()),
+        half_epoch: reward_params.half_epoch(),
+        minimum_normal_propose_count: reward_params.minimum_normal_propose_count(),
+        propose_discount_rate: reward_params.propose_discount_rate(),
+    }
+}
+
 fn verify_claim_smt(
     miner: &Vec<u8>,
     claim_epoch: &u64,
@@ -78,6 +156,9 @@ fn verify_claim_smt(
     let claim_root: H256 = claim_root.into();
     let result = verify_top_smt(miner_h256, claim_epoch_h256, claim_root, proof)?;
     debug!("verify claim smt result: {}", result);
+    if !result {
+        return Err(Error::RewardClaimProofFail);
+    }
     Ok(())
 }
 
@@ -86,8 +167,13 @@ fn verify_old_new_claim_smt(
     miner: &Vec<u8>,
     old_not_claim_info: &NotClaimInfo,
     new_not_claim_info: &NotClaimInfo,
-) -> Result<(u64, u64, Vec<u8>), Error> {
+) -> Result<(u64, u64, Vec<u8>, Vec<u8>), Error> {
+    // claim epochs only move forward, so no epoch can be claimed twice
     let old_claim_epoch = old_not_claim_info.epoch();
+    let new_claim_epoch = new_not_claim_info.epoch();
+    if old_claim_epoch >= new_claim_epoch {
+        return Err(Error::RewardClaimEpochMismatch);
+    }
     let old_reward_smt_data = get_reward_smt_data(
         reward_smt_type_id.as_slice().try_into().unwrap(),
         Source::GroupInput,
@@ -100,7 +186,6 @@ fn verify_old_new_claim_smt(
         &old_reward_smt_data,
     )?;
 
-    let new_claim_epoch = new_not_claim_info.epoch();
     let new_reward_smt_data = get_reward_smt_data(
         reward_smt_type_id.as_slice().try_into().unwrap(),
         Source::GroupOutput,
@@ -114,6 +199,7 @@ fn verify_old_new_claim_smt(
 
     if old_reward_smt_data.version() != new_reward_smt_data.version()
         || old_reward_smt_data.metadata_type_id() != new_reward_smt_data.metadata_type_id()
+        || old_reward_smt_data.beneficiary_smt_root() != new_reward_smt_data.beneficiary_smt_root()
     {
         return Err(Error::RewardOldNewMismatch);
     }
@@ -122,9 +208,182 @@ fn verify_old_new_claim_smt(
         old_claim_epoch,
         new_claim_epoch,
         old_reward_smt_data.metadata_type_id(),
+        old_reward_smt_data.beneficiary_smt_root(),
     ))
 }
 
+fn verify_beneficiary(
+    miner: &Vec<u8>,
+    lock_hash: &Vec<u8>,
+    beneficiary_smt_root: &Vec<u8>,
+    proof: Vec<u8>,
+) -> Result<(), Error> {
+    let miner_h256 = addr_to_h256(&miner.as_slice().try_into().unwrap());
+    let lock_hash: [u8; 32] = lock_hash.as_slice().try_into().unwrap();
+    let beneficiary_smt_root: [u8; 32] = beneficiary_smt_root.as_slice().try_into().unwrap();
+    let result = verify_top_smt(
+        miner_h256,
+        lock_hash.into(),
+        beneficiary_smt_root.into(),
+        CompiledMerkleProof(proof),
+    )?;
+    debug!("verify beneficiary smt result: {}", result);
+    if result == false {
+        return Err(Error::RewardBeneficiaryProofFail);
+    }
+    Ok(())
+}
+
+// the miner registers, changes or removes its beneficiary, no reward is claimed
+fn register_beneficiary(
+    reward_smt_type_id: &Vec<u8>,
+    reward_witness: &reward_reader::RewardWitness,
+) -> Result<(), Error> {
+    let old_reward_smt_data = get_reward_smt_data(
+        reward_smt_type_id.as_slice().try_into().unwrap(),
+        Source::GroupInput,
+    )?;
+    let new_reward_smt_data = get_reward_smt_data(
+        reward_smt_type_id.as_slice().try_into().unwrap(),
+        Source::GroupOutput,
+    )?;
+    if old_reward_smt_data.version() != new_reward_smt_data.version()
+        || old_reward_smt_data.claim_smt_root() != new_reward_smt_data.claim_smt_root()
+        || old_reward_smt_data.metadata_type_id() != new_reward_smt_data.metadata_type_id()
+    {
+        return Err(Error::RewardOldNewMismatch);
+    }
+
+    // old and new beneficiary share the key, so one proof serves both roots
+    let miner = reward_witness.miner();
+    let beneficiary = reward_witness.beneficiary();
+    verify_beneficiary(
+        &miner,
+        &beneficiary.lock_hash(),
+        &old_reward_smt_data.beneficiary_smt_root(),
+        beneficiary.proof(),
+    )?;
+    verify_beneficiary(
+        &miner,
+        &beneficiary.new_lock_hash(),
+        &new_reward_smt_data.beneficiary_smt_root(),
+        beneficiary.proof(),
+    )?;
+
+    let metadata_type_id: [u8; 32] = old_reward_smt_data
+        .metadata_type_id()
+        .as_slice()
+        .try_into()
+        .unwrap();
+    let operation = TypedOperation {
+        operation: Operation::SetRewardBeneficiary,
+        amount: 0,
+        inauguration_epoch: 0,
+        metadata_type_id,
+    };
+    verify_eth_signature(
+        beneficiary.sig_mode(),
+        &miner,
+        &beneficiary.eth_sig(),
+        &operation,
+    )?;
+
+    // issue cell allows minting along with reward smt update, so nothing can be minted here
+    let type_ids = get_type_ids(&metadata_type_id, Source::CellDep)?;
+    let xudt_type_hash = type_ids.xudt_type_hash();
+    let input_total_amount = get_xudt_by_type_hash(&xudt_type_hash, Source::Input)?;
+    let output_total_amount = get_xudt_by_type_hash(&xudt_type_hash, Source::Output)?;
+    if input_total_amount != output_total_amount {
+        return Err(Error::RewardWrongAmount);
+    }
+
+    Ok(())
+}
+
+// the reward goes into the miner's stake AT cell, or its delegate AT cell for compound_staker, as
+// an increase delta, the AT lock checks the miner's signature and the inauguration epoch
+fn compound_reward(
+    miner: &Vec<u8>,
+    compound_staker: &Vec<u8>,
+    reward_amount: u128,
+    metadata_type_id: &[u8; 32],
+    type_ids: &TypeIds,
+) -> Result<(), Error> {
+    let mut at_args = metadata_type_id.to_vec();
+    at_args.extend_from_slice(miner);
+    let (input_delta, output_delta, input_at_amount, output_at_amount) =
+        if compound_staker.as_slice() == [0u8; 20] {
+            debug!("compound reward into stake");
+            let stake_at_lock_hash = get_script_hash(&type_ids.stake_at_code_hash(), &at_args);
+            let (input_at_amount, input_stake_at_data) =
+                get_stake_at_data_by_lock_hash(&stake_at_lock_hash, Source::Input)?;
+            let (output_at_amount, output_stake_at_data) =
+                get_stake_at_data_by_lock_hash(&stake_at_lock_hash, Source::Output)?;
+            let input_delta = input_stake_at_data.delta();
+            let output_delta = output_stake_at_data.delta();
+            if output_delta.is_increase() != 1 {
+                return Err(Error::RewardCompoundMismatch);
+            }
+            // the output delta replaces the input one, which would cancel a pending unstake
+            let input_delta_amount = bytes_to_u128(&input_delta.amount());
+            if input_delta.is_increase() != 1 && input_delta_amount != 0 {
+                return Err(Error::RewardCompoundMismatch);
+            }
+            let input_delta = input_delta_amount;
+            (
+                input_delta,
+                bytes_to_u128(&output_delta.amount()),
+                input_at_amount,
+                output_at_amount,
+            )
+        } else {
+            debug!("compound reward into delegation");
+            let delegate_at_lock_hash =
+                get_script_hash(&type_ids.delegate_at_code_hash(), &at_args);
+            let (input_at_amount, input_delegate_at_data) =
+                get_delegate_at_data_by_lock_hash(&delegate_at_lock_hash, Source::Input)?;
+            let (output_at_amount, output_delegate_at_data) =
+                get_delegate_at_data_by_lock_hash(&delegate_at_lock_hash, Source::Output)?;
+            let find_delta = |infos: DelegateInfoDeltas| {
+                (0..infos.len())
+                    .map(|i| infos.get(i))
+                    .find(|info| info.staker() == *compound_staker)
+            };
+            let output_delta = find_delta(output_delegate_at_data.delegator_infos())
+                .ok_or(Error::RewardCompoundMismatch)?;
+            if output_delta.is_increase() != 1 || is_redelegation(&output_delta) {
+                return Err(Error::RewardCompoundMismatch);
+            }
+            // likewise a pending undelegation or redelegation to the staker can't be replaced
+            let input_delta = match find_delta(input_delegate_at_data.delegator_infos()) {
+                Some(info) => {
+                    let amount = bytes_to_u128(&info.amount());
+                    if amount != 0 && (info.is_increase() != 1 || is_redelegation(&info)) {
+                        return Err(Error::RewardCompoundMismatch);
+                    }
+                    amount
+                }
+                None => 0,
+            };
+            (
+                input_delta,
+                bytes_to_u128(&output_delta.amount()),
+                input_at_amount,
+                output_at_amount,
+            )
+        };
+    debug!(
+        "compound input_delta: {}, output_delta: {}, input_at_amount: {}, output_at_amount: {}",
+        input_delta, output_delta, input_at_amount, output_at_amount
+    );
+    if input_at_amount.checked_add(reward_amount) != Some(output_at_amount)
+        || input_delta.checked_add(reward_amount) != Some(output_delta)
+    {
+        return Err(Error::RewardCompoundMismatch);
+    }
+    Ok(())
+}
+
 pub fn main() -> Result<(), Error> {
     let type_id = load_type_id_from_script_args(0)?;
     // debug!("type_id: {:?}", type_id);
@@ -150,17 +409,26 @@ pub fn main() -> Result<(), Error> {
             Cursor::from(witness_input_type.unwrap().raw_data().to_vec()).into();
         value
     };
+    let mode = reward_witness.mode();
+    match mode {
+        CLAIM_MODE | COMPOUND_MODE => {}
+        REGISTER_BENEFICIARY_MODE => {
+            return register_beneficiary(&reward_smt_type_id, &reward_witness);
+        }
+        _ => return Err(Error::UnknownMode),
+    }
 
     debug!("verify reward claim info");
     let miner = reward_witness.miner();
     let old_not_claim_info = reward_witness.old_not_claim_info();
     let new_not_claim_info = reward_witness.new_not_claim_info();
-    let (old_claim_epoch, new_claim_epoch, meta_type_id) = verify_old_new_claim_smt(
-        &reward_smt_type_id,
-        &miner,
-        &old_not_claim_info,
-        &new_not_claim_info,
-    )?;
+    let (old_claim_epoch, new_claim_epoch, meta_type_id, beneficiary_smt_root) =
+        verify_old_new_claim_smt(
+            &reward_smt_type_id,
+            &miner,
+            &old_not_claim_info,
+            &new_not_claim_info,
+        )?;
 
     // debug!("get type ids, {:?}", meta_type_id);
     let metadata_type_id = meta_type_id.as_slice().try_into().unwrap();
@@ -186,16 +454,173 @@ pub fn main() -> Result<(), Error> {
         .as_slice()
         .try_into()
         .unwrap();
-    let base_reward = bytes_to_u128(&metadata.base_reward());
-    let half_epoch = metadata.half_epoch();
-    let minimum_normal_propose_count = get_minimum_normal_propose_count(&metadata);
+    let commission_smt_root: [u8; 32] = metadata
+        .commission_smt_root()
+        .as_slice()
+        .try_into()
+        .unwrap();
+    let reward_params_smt_root: [u8; 32] = metadata
+        .reward_params_smt_root()
+        .as_slice()
+        .try_into()
+        .unwrap();
+    // rewards of an epoch are settled once it ends
+    if new_claim_epoch > metadata.epoch() {
+        return Err(Error::RewardClaimEpochMismatch);
+    }
+
+    let claim_ctx = ClaimContext {
+        miner: miner.clone(),
+        stake_smt_root,
+        propose_count_smt_root,
+        commission_smt_root,
+        reward_params_smt_root,
+        delegate_smt_data,
+    };
+
+    let batch_reward_infos = reward_witness.batch_reward_infos();
+    let (reward_amount, all_stakers_listed) = if batch_reward_infos.epoch_infos().len() > 0 {
+        if reward_witness.reward_infos().len() > 0
+            || new_claim_epoch.checked_sub(old_claim_epoch)
+                != Some(batch_reward_infos.epoch_infos().len() as u64)
+        {
+            return Err(Error::RewardBatchMismatch);
+        }
+        calculate_batch_claim_reward(
+            &claim_ctx,
+            old_claim_epoch,
+            new_claim_epoch,
+            &batch_reward_infos,
+        )?
+    } else {
+        calculate_epoch_claim_reward(
+            &claim_ctx,
+            old_claim_epoch,
+            new_claim_epoch,
+            &reward_witness.reward_infos(),
+        )?
+    };
+
+    // get at amount of normal at cell from output
+    let xudt_type_hash = type_ids.xudt_type_hash();
+    let input_total_amount = get_xudt_by_type_hash(&xudt_type_hash, Source::Input)?;
+    let output_total_amount = get_xudt_by_type_hash(&xudt_type_hash, Source::Output)?;
     debug!(
-        "base_reward: {}, half_epoch: {}, minimum_normal_propose_count: {}",
-        base_reward, half_epoch, minimum_normal_propose_count
+        "reward_amount: {}, input_total_amount: {}, output_total_amount: {}",
+        reward_amount, input_total_amount, output_total_amount
     );
+    if input_total_amount + reward_amount != output_total_amount {
+        return Err(Error::RewardWrongAmount);
+    }
+
+    if mode == COMPOUND_MODE {
+        return compound_reward(
+            &miner,
+            &reward_witness.compound_staker(),
+            reward_amount,
+            &metadata_type_id,
+            &type_ids,
+        );
+    }
+
+    // the claim needs no signature so a keeper can submit it, rewards only go to the miner's
+    // registered beneficiary, or the miner itself if there is none
+    let beneficiary = reward_witness.beneficiary();
+    let beneficiary_lock_hash = beneficiary.lock_hash();
+    verify_beneficiary(
+        &miner,
+        &beneficiary_lock_hash,
+        &beneficiary_smt_root,
+        beneficiary.proof(),
+    )?;
+    if beneficiary_lock_hash == [0u8; 32] {
+        verify_owner_normal_at(&miner, &xudt_type_hash)?;
+    } else {
+        verify_beneficiary_normal_at(&beneficiary_lock_hash, &xudt_type_hash)?;
+    }
+
+    // bottom smt proofs allow omitting stakers, so a keeper could leave out the miner's entries and
+    // still advance its claim epoch, such claims need the beneficiary's input or the miner's signature
+    if !all_stakers_listed {
+        let unlocked_by_beneficiary = beneficiary_lock_hash != [0u8; 32]
+            && QueryIter::new(load_cell_lock_hash, Source::Input)
+                .any(|lock_hash| lock_hash.as_slice() == beneficiary_lock_hash.as_slice());
+        if !unlocked_by_beneficiary {
+            let operation = TypedOperation {
+                operation: Operation::ClaimReward,
+                amount: reward_amount,
+                inauguration_epoch: new_claim_epoch,
+                metadata_type_id,
+            };
+            verify_eth_signature(
+                reward_witness.sig_mode(),
+                &miner,
+                &reward_witness.eth_sig(),
+                &operation,
+            )
+            .map_err(|_| Error::RewardClaimUnauthorized)?;
+        }
+    }
 
+    Ok(())
+}
+
+// delegate infos of one staker in one epoch, with their total amount and the miner's amount if it delegates
+fn collect_delegate_infos(
+    miner: &Vec<u8>,
+    delegate_infos: &RewardDelegateInfos,
+) -> (BTreeSet<LockInfo>, u128, Option<u128>) {
+    let mut delegate_infos_set = BTreeSet::new();
+    let mut total_delegate_amount = 0u128;
+    let mut miner_delegate_amount = None;
+    for k in 0..delegate_infos.len() {
+        let delegate_info = delegate_infos.get(k);
+        let delegate_info_obj = LockInfo {
+            addr: delegate_info
+                .delegator_addr()
+                .as_slice()
+                .try_into()
+                .unwrap(),
+            amount: bytes_to_u128(&delegate_info.amount()),
+        };
+        delegate_infos_set.insert(delegate_info_obj);
+        total_delegate_amount += bytes_to_u128(&delegate_info.amount());
+        if delegate_info.delegator_addr() == *miner {
+            miner_delegate_amount = Some(bytes_to_u128(&delegate_info.amount()));
+        }
+    }
+    (
+        delegate_infos_set,
+        total_delegate_amount,
+        miner_delegate_amount,
+    )
+}
+
+fn to_reward_obj(
+    stake_info_obj: &RewardStakeInfoObject,
+    total_delegate_amount: u128,
+    delegate_amount: Option<u128>,
+) -> RewardObject {
+    RewardObject {
+        staker: stake_info_obj.staker,
+        stake_amount: stake_info_obj.stake_amount,
+        delegate_amount,
+        total_delegate_amount,
+        propose_count: stake_info_obj.propose_count,
+        commission_rate: stake_info_obj.commission_rate,
+    }
+}
+
+// one EpochRewardStakeInfo per epoch, each proven against the top smts on its own
+fn calculate_epoch_claim_reward(
+    claim_ctx: &ClaimContext,
+    old_claim_epoch: u64,
+    new_claim_epoch: u64,
+    reward_infos: &EpochRewardStakeInfos,
+) -> Result<(u128, bool), Error> {
+    let miner = &claim_ctx.miner;
     let mut reward_amount: u128 = 0;
-    let reward_infos = reward_witness.reward_infos();
+    let mut all_stakers_listed = true;
     for current_epoch in old_claim_epoch..new_claim_epoch {
         // many epoch, 1st layer
         let mut epoch_reward_obj = EpochRewardObject::default(); // used to calculate reward
@@ -210,63 +635,45 @@ pub fn main() -> Result<(), Error> {
         // get one staker's propose count, stake amount, verify its delegate info
         for j in 0..staker_infos.len() {
             // many staker, 2nd layer
-            let mut reward_obj = RewardObject::default();
             let stake_info = staker_infos.get(j);
             let staker = stake_info.validator();
-            reward_obj.commission_rate =
-                get_commission_rate(&staker, &metadata_type_id, &type_ids.stake_at_code_hash())?;
-            let delegate_infos = stake_info.delegate_infos();
-            let mut delegate_infos_set = BTreeSet::new();
-            let mut total_delegate_amount = 0u128;
-            for k in 0..delegate_infos.len() {
-                let delegate_info = delegate_infos.get(k);
-                let delegate_info_obj = LockInfo {
-                    addr: delegate_info
-                        .delegator_addr()
-                        .as_slice()
-                        .try_into()
-                        .unwrap(),
-                    amount: bytes_to_u128(&delegate_info.amount()),
-                };
-                delegate_infos_set.insert(delegate_info_obj);
-                total_delegate_amount += bytes_to_u128(&delegate_info.amount());
-                if delegate_info.delegator_addr() == miner {
-                    reward_obj.delegate_amount = Some(bytes_to_u128(&delegate_info.amount()));
-                }
-            }
+            let (delegate_infos_set, total_delegate_amount, delegate_amount) =
+                collect_delegate_infos(miner, &stake_info.delegate_infos());
             let delegate_epoch_proof = stake_info.delegate_epoch_proof();
             let delegate_epoch_proof = CompiledMerkleProof(delegate_epoch_proof);
             let delegate_epoch_root = get_delegate_smt_root_from_cell_data(
                 staker.as_slice().try_into().unwrap(),
-                &delegate_smt_data,
+                &claim_ctx.delegate_smt_data,
             )?;
             let delegate_epoch_root: H256 = delegate_epoch_root.into();
-            verify_2layer_smt(
+            let result = verify_2layer_smt(
                 &delegate_infos_set,
                 u64_to_h256(current_epoch),
                 delegate_epoch_root,
                 delegate_epoch_proof,
             )?;
+            if !result {
+                return Err(Error::RewardWrongDelegateAmount);
+            }
 
-            let propose_count = stake_info.propose_count();
-            let stake_amount = bytes_to_u128(&stake_info.staker_amount());
-
+            // commission in force at this epoch, verified against commission smt of metadata
             let stake_info_obj = RewardStakeInfoObject {
                 staker: staker.as_slice().try_into().unwrap(),
-                propose_count: propose_count,
-                stake_amount: stake_amount,
+                propose_count: stake_info.propose_count(),
+                stake_amount: bytes_to_u128(&stake_info.staker_amount()),
+                commission_rate: stake_info.commission_rate(),
+                delegate_threshold: bytes_to_u128(&stake_info.delegate_threshold()),
             };
             debug!(
                 "stake_info_obj: {:?}, total_delegate_amount: {}",
                 stake_info_obj, total_delegate_amount
             );
             stake_info_objs.push(stake_info_obj);
-
-            reward_obj.staker = staker.as_slice().try_into().unwrap();
-            reward_obj.stake_amount = stake_amount;
-            reward_obj.propose_count = propose_count;
-            reward_obj.total_delegate_amount = total_delegate_amount;
-            epoch_reward_obj.reward_objs.push(reward_obj);
+            epoch_reward_obj.add_reward_obj(to_reward_obj(
+                &stake_info_obj,
+                total_delegate_amount,
+                delegate_amount,
+            ))?;
         }
 
         epoch_reward_stake_info_obj.stake_infos = stake_info_objs;
@@ -285,69 +692,206 @@ pub fn main() -> Result<(), Error> {
             .unwrap();
         epoch_reward_stake_info_obj.count_proof = epoch_reward_info.count_proof();
         epoch_reward_stake_info_obj.count_epoch_proof = epoch_reward_info.count_epoch_proof();
+
+        epoch_reward_stake_info_obj.commission_root = epoch_reward_info
+            .commission_root()
+            .as_slice()
+            .try_into()
+            .unwrap();
+        epoch_reward_stake_info_obj.commission_proof = epoch_reward_info.commission_proof();
+        epoch_reward_stake_info_obj.commission_epoch_proof =
+            epoch_reward_info.commission_epoch_proof();
         verify_stake_propse(
             current_epoch,
             &epoch_reward_stake_info_obj,
-            &stake_smt_root,
-            &propose_count_smt_root,
+            &claim_ctx.stake_smt_root,
+            &claim_ctx.propose_count_smt_root,
         )?;
-
-        let epoch_reward = calculate_reward(
-            &miner,
-            &epoch_reward_obj,
-            base_reward,
+        verify_commission(
             current_epoch,
-            half_epoch,
-            minimum_normal_propose_count,
-            metadata.propose_discount_rate(),
+            &epoch_reward_stake_info_obj,
+            &claim_ctx.commission_smt_root,
         )?;
-        reward_amount += epoch_reward;
-    }
 
-    // get at amount of normal at cell from output
-    let xudt_type_hash = type_ids.xudt_type_hash();
-    let input_total_amount = get_xudt_by_type_hash(&xudt_type_hash, Source::Input)?;
-    let output_total_amount = get_xudt_by_type_hash(&xudt_type_hash, Source::Output)?;
-    debug!(
-        "reward_amount: {}, input_total_amount: {}, output_total_amount: {}",
-        reward_amount, input_total_amount, output_total_amount
-    );
-    if input_total_amount + reward_amount != output_total_amount {
-        return Err(Error::RewardWrongAmount);
+        // reward parameters in force at this epoch, verified against reward params smt of metadata
+        let reward_params = to_reward_params(&epoch_reward_info.reward_params());
+        debug!(
+            "epoch: {}, reward_params: {:?}",
+            current_epoch, reward_params
+        );
+        verify_epoch_leaves(
+            vec![(
+                u64_to_h256(current_epoch),
+                reward_params_to_h256(&reward_params),
+            )],
+            &claim_ctx.reward_params_smt_root,
+            epoch_reward_info.reward_params_proof(),
+            Error::RewardParamsFail,
+        )?;
+
+        all_stakers_listed &= epoch_reward_stake_info_obj.lists_all_stakers();
+        reward_amount +=
+            claim_ctx.epoch_reward(current_epoch, &reward_params, &epoch_reward_obj)?;
     }
+    Ok((reward_amount, all_stakers_listed))
+}
 
-    verify_owner_normal_at(&miner, &xudt_type_hash)?;
+// stakers are listed once, the top smt leaves of all epochs are proven by one proof per smt, and
+// the delegate top smt leaves of a staker by one proof per staker
+fn calculate_batch_claim_reward(
+    claim_ctx: &ClaimContext,
+    old_claim_epoch: u64,
+    new_claim_epoch: u64,
+    batch_reward_infos: &BatchRewardInfos,
+) -> Result<(u128, bool), Error> {
+    let miner = &claim_ctx.miner;
+    let staker_infos = batch_reward_infos.staker_infos();
+    let epoch_infos = batch_reward_infos.epoch_infos();
+    let stakers = (0..staker_infos.len())
+        .map(|i| {
+            staker_infos
+                .get(i)
+                .validator()
+                .as_slice()
+                .try_into()
+                .unwrap()
+        })
+        .collect::<Vec<[u8; 20]>>();
 
-    Ok(())
-}
+    let mut delegate_leaves: Vec<Vec<(H256, H256)>> = vec![Vec::new(); stakers.len()];
+    let mut amount_leaves = Vec::new();
+    let mut count_leaves = Vec::new();
+    let mut commission_leaves = Vec::new();
+    let mut reward_params_leaves = Vec::new();
+    let mut reward_amount: u128 = 0;
+    let mut all_stakers_listed = true;
+    for (i, current_epoch) in (old_claim_epoch..new_claim_epoch).enumerate() {
+        let mut epoch_reward_obj = EpochRewardObject::default();
+        epoch_reward_obj.miner = miner.as_slice().try_into().unwrap();
 
-fn get_commission_rate(
-    staker: &Vec<u8>,
-    metadata_type_id: &[u8; 32],
-    stake_at_code_hash: &Vec<u8>,
-) -> Result<u8, Error> {
-    let delegate_requirement =
-        get_delegate_requirement(staker, metadata_type_id, stake_at_code_hash)?;
-    Ok(delegate_requirement.commission_rate().as_slice()[0])
-}
+        let epoch_info = epoch_infos.get(i);
+        let stake_infos = epoch_info.reward_stake_infos();
+        let mut stake_info_objs = Vec::new();
+        for j in 0..stake_infos.len() {
+            let stake_info = stake_infos.get(j);
+            let staker_index = stake_info.staker_index() as usize;
+            if staker_index >= stakers.len() {
+                return Err(Error::RewardBatchMismatch);
+            }
+            let (delegate_infos_set, total_delegate_amount, delegate_amount) =
+                collect_delegate_infos(miner, &stake_info.delegate_infos());
+            delegate_leaves[staker_index].push((
+                u64_to_h256(current_epoch),
+                get_bottom_smt_root(&delegate_infos_set),
+            ));
+
+            let stake_info_obj = RewardStakeInfoObject {
+                staker: stakers[staker_index],
+                propose_count: stake_info.propose_count(),
+                stake_amount: bytes_to_u128(&stake_info.staker_amount()),
+                commission_rate: stake_info.commission_rate(),
+                delegate_threshold: bytes_to_u128(&stake_info.delegate_threshold()),
+            };
+            debug!(
+                "epoch: {}, stake_info_obj: {:?}, total_delegate_amount: {}",
+                current_epoch, stake_info_obj, total_delegate_amount
+            );
+            stake_info_objs.push(stake_info_obj);
+            epoch_reward_obj.add_reward_obj(to_reward_obj(
+                &stake_info_obj,
+                total_delegate_amount,
+                delegate_amount,
+            ))?;
+        }
+
+        let epoch_reward_stake_info_obj = EpochRewardStakeInfoObject {
+            stake_infos: stake_info_objs,
+            count_proof: epoch_info.count_proof(),
+            count_root: epoch_info.count_root().as_slice().try_into().unwrap(),
+            amount_proof: epoch_info.amount_proof(),
+            amount_root: epoch_info.amount_root().as_slice().try_into().unwrap(),
+            commission_proof: epoch_info.commission_proof(),
+            commission_root: epoch_info.commission_root().as_slice().try_into().unwrap(),
+            ..Default::default()
+        };
+        verify_stake_bottom(&epoch_reward_stake_info_obj)?;
+        verify_propose_bottom(&epoch_reward_stake_info_obj)?;
+        verify_commission_bottom(&epoch_reward_stake_info_obj)?;
+        let epoch_key = u64_to_h256(current_epoch);
+        amount_leaves.push((epoch_key, epoch_reward_stake_info_obj.amount_root.into()));
+        count_leaves.push((epoch_key, epoch_reward_stake_info_obj.count_root.into()));
+        commission_leaves.push((
+            epoch_key,
+            epoch_reward_stake_info_obj.commission_root.into(),
+        ));
+        let reward_params = to_reward_params(&epoch_info.reward_params());
+        debug!(
+            "epoch: {}, reward_params: {:?}",
+            current_epoch, reward_params
+        );
+        reward_params_leaves.push((epoch_key, reward_params_to_h256(&reward_params)));
+
+        all_stakers_listed &= epoch_reward_stake_info_obj.lists_all_stakers();
+        reward_amount +=
+            claim_ctx.epoch_reward(current_epoch, &reward_params, &epoch_reward_obj)?;
+    }
 
-fn get_minimum_normal_propose_count(metadata: &MetadataCellData) -> u64 {
-    let propose_minimum_rate = metadata.propose_minimum_rate();
-    let metadata = metadata.metadata().get(0);
-    let epoch_total_block_num = metadata.epoch_len() * metadata.period_len();
-    let validator_num = metadata.validators().len();
-    (epoch_total_block_num * propose_minimum_rate as u32 / validator_num as u32 / 100).into()
+    verify_epoch_leaves(
+        amount_leaves,
+        &claim_ctx.stake_smt_root,
+        batch_reward_infos.amount_epoch_proof(),
+        Error::RewardStakeAmountTopFail,
+    )?;
+    verify_epoch_leaves(
+        count_leaves,
+        &claim_ctx.propose_count_smt_root,
+        batch_reward_infos.count_epoch_proof(),
+        Error::RewardProposeCountTopFail,
+    )?;
+    verify_epoch_leaves(
+        commission_leaves,
+        &claim_ctx.commission_smt_root,
+        batch_reward_infos.commission_epoch_proof(),
+        Error::RewardCommissionTopFail,
+    )?;
+    verify_epoch_leaves(
+        reward_params_leaves,
+        &claim_ctx.reward_params_smt_root,
+        batch_reward_infos.reward_params_proof(),
+        Error::RewardParamsFail,
+    )?;
+
+    for (staker_index, leaves) in delegate_leaves.into_iter().enumerate() {
+        if leaves.is_empty() {
+            continue;
+        }
+        let delegate_epoch_root = get_delegate_smt_root_from_cell_data(
+            &stakers[staker_index],
+            &claim_ctx.delegate_smt_data,
+        )?;
+        verify_epoch_leaves(
+            leaves,
+            &delegate_epoch_root,
+            staker_infos.get(staker_index).delegate_epoch_proof(),
+            Error::RewardWrongDelegateAmount,
+        )?;
+    }
+
+    Ok((reward_amount, all_stakers_listed))
 }
 
 fn calculate_reward(
     miner: &Vec<u8>,
     epoch_reward_obj: &EpochRewardObject,
-    base_reward: u128,
     current_epoch: u64,
-    half_epoch: u64,
-    minimum_normal_propose_count: u64,
-    propose_discount_rate: u8,
+    reward_params: &RewardParamsObject,
 ) -> Result<u128, Error> {
+    let RewardParamsObject {
+        base_reward,
+        half_epoch,
+        minimum_normal_propose_count,
+        propose_discount_rate,
+    } = *reward_params;
     let mut epoch_reward = 0u128;
     for obj in &epoch_reward_obj.reward_objs {
         let propose_count = obj.propose_count;
@@ -363,21 +907,15 @@ fn calculate_reward(
             "miner: {:?},staker: {:?}, commission_rate: {}, reward: {}, base_reward: {}, current_epoch: {}, half_epoch: {}, propse_count: {}",
             miner, obj.staker, obj.commission_rate, reward, base_reward, current_epoch, half_epoch, propose_count
         );
+        // the miner is paid for every staker it stakes as or delegates to in the epoch
         if *miner == obj.staker.to_vec() {
-            epoch_reward = staker_reward;
+            epoch_reward += staker_reward;
             let commission_fee = delegate_reward * obj.commission_rate as u128 / 100;
             epoch_reward += commission_fee;
-        } else {
-            match obj.delegate_amount {
-                // delegator reward, miner can only be staker or delegator
-                Some(amount) => {
-                    epoch_reward = amount * delegate_reward * (100 - obj.commission_rate as u128)
-                        / 100
-                        / obj.total_delegate_amount
-                }
-                // miner not staker or delegator
-                None => epoch_reward = 0,
-            }
+        } else if let Some(amount) = obj.delegate_amount {
+            epoch_reward += amount * delegate_reward * (100 - obj.commission_rate as u128)
+                / 100
+                / obj.total_delegate_amount;
         }
     }
     Ok(epoch_reward)
@@ -411,10 +949,8 @@ fn verify_claim(
 }
 */
 
-fn verify_stake(
-    epoch: u64,
+fn verify_stake_bottom(
     epoch_reward_stake_info_obj: &EpochRewardStakeInfoObject,
-    stake_smt_root: &[u8; 32],
 ) -> Result<(), Error> {
     let mut leaves = Vec::new();
     for stake_info_obj in &epoch_reward_stake_info_obj.stake_infos {
@@ -433,43 +969,12 @@ fn verify_stake(
     if result == false {
         return Err(Error::RewardStakeAmountBottomFail);
     }
-
-    let amount_epoch_proof =
-        CompiledMerkleProof(epoch_reward_stake_info_obj.amount_epoch_proof.clone());
-    // debug!(
-    //     "verify stake smt top proof: {:?}, top root: {:?}, amount_root: {:?}, epoch: {}",
-    //     epoch_reward_stake_info_obj.amount_epoch_proof,
-    //     stake_smt_root,
-    //     epoch_reward_stake_info_obj.amount_root,
-    //     epoch
-    // );
-    let stake_smt_root: H256 = (*stake_smt_root).into();
-    let result = amount_epoch_proof
-        .verify::<Blake2bHasher>(
-            &stake_smt_root,
-            vec![(
-                u64_to_h256(epoch),
-                epoch_reward_stake_info_obj.amount_root.into(),
-            )],
-        )
-        .unwrap();
-    debug!("verify stake smt top result: {}", result);
-    if result == false {
-        return Err(Error::RewardStakeAmountTopFail);
-    }
-
     Ok(())
 }
 
-fn verify_stake_propse(
-    epoch: u64,
+fn verify_propose_bottom(
     epoch_reward_stake_info_obj: &EpochRewardStakeInfoObject,
-    stake_smt_root: &[u8; 32],
-    propose_count_smt_root: &[u8; 32],
 ) -> Result<(), Error> {
-    verify_stake(epoch, epoch_reward_stake_info_obj, stake_smt_root)?;
-
-    // verify propose count
     let mut leaves = Vec::new();
     for stake_info_obj in &epoch_reward_stake_info_obj.stake_infos {
         leaves.push((
@@ -477,12 +982,11 @@ fn verify_stake_propse(
             u64_to_h256(stake_info_obj.propose_count),
         ));
         debug!(
-            "verify propose count smt bottom proof: {:?}, bottom root: {:?}, staker: {:?}, count: {:?}, epoch: {}",
+            "verify propose count smt bottom proof: {:?}, bottom root: {:?}, staker: {:?}, count: {:?}",
             epoch_reward_stake_info_obj.count_proof,
             epoch_reward_stake_info_obj.count_root,
             stake_info_obj.staker,
-            stake_info_obj.propose_count,
-            epoch
+            stake_info_obj.propose_count
         );
     }
 
@@ -495,21 +999,92 @@ fn verify_stake_propse(
     if result == false {
         return Err(Error::RewardProposeCountBottomFail);
     }
+    Ok(())
+}
 
-    let propose_count_smt_root: H256 = (*propose_count_smt_root).into();
-    let propose_count_epoch_proof =
-        CompiledMerkleProof(epoch_reward_stake_info_obj.count_epoch_proof.clone());
-    let leaves = vec![(
-        u64_to_h256(epoch),
-        epoch_reward_stake_info_obj.count_root.into(),
-    )];
-    let result = propose_count_epoch_proof
-        .verify::<Blake2bHasher>(&propose_count_smt_root, leaves)
-        .unwrap();
-    debug!("verify propose count smt top result: {}", result);
+fn verify_commission_bottom(
+    epoch_reward_stake_info_obj: &EpochRewardStakeInfoObject,
+) -> Result<(), Error> {
+    let mut leaves = Vec::new();
+    for stake_info_obj in &epoch_reward_stake_info_obj.stake_infos {
+        leaves.push((
+            addr_to_h256(&stake_info_obj.staker),
+            commission_to_h256(
+                stake_info_obj.commission_rate,
+                stake_info_obj.delegate_threshold,
+            ),
+        ));
+    }
+
+    let result = smt_verify_leaves(
+        leaves,
+        epoch_reward_stake_info_obj.commission_root.into(),
+        CompiledMerkleProof(epoch_reward_stake_info_obj.commission_proof.clone()),
+    )?;
+    debug!("verify commission smt bottom result: {}", result);
     if result == false {
-        return Err(Error::RewardProposeCountTopFail);
+        return Err(Error::RewardCommissionBottomFail);
     }
+    Ok(())
+}
 
+// verify (epoch, bottom root) leaves of one or many epochs against a top smt root
+fn verify_epoch_leaves(
+    leaves: Vec<(H256, H256)>,
+    top_root: &[u8; 32],
+    proof: Vec<u8>,
+    err: Error,
+) -> Result<(), Error> {
+    let result = smt_verify_leaves(leaves, (*top_root).into(), CompiledMerkleProof(proof))?;
+    debug!("verify top smt result: {}", result);
+    if result == false {
+        return Err(err);
+    }
     Ok(())
 }
+
+fn verify_stake_propse(
+    epoch: u64,
+    epoch_reward_stake_info_obj: &EpochRewardStakeInfoObject,
+    stake_smt_root: &[u8; 32],
+    propose_count_smt_root: &[u8; 32],
+) -> Result<(), Error> {
+    verify_stake_bottom(epoch_reward_stake_info_obj)?;
+    verify_epoch_leaves(
+        vec![(
+            u64_to_h256(epoch),
+            epoch_reward_stake_info_obj.amount_root.into(),
+        )],
+        stake_smt_root,
+        epoch_reward_stake_info_obj.amount_epoch_proof.clone(),
+        Error::RewardStakeAmountTopFail,
+    )?;
+
+    verify_propose_bottom(epoch_reward_stake_info_obj)?;
+    verify_epoch_leaves(
+        vec![(
+            u64_to_h256(epoch),
+            epoch_reward_stake_info_obj.count_root.into(),
+        )],
+        propose_count_smt_root,
+        epoch_reward_stake_info_obj.count_epoch_proof.clone(),
+        Error::RewardProposeCountTopFail,
+    )
+}
+
+fn verify_commission(
+    epoch: u64,
+    epoch_reward_stake_info_obj: &EpochRewardStakeInfoObject,
+    commission_smt_root: &[u8; 32],
+) -> Result<(), Error> {
+    verify_commission_bottom(epoch_reward_stake_info_obj)?;
+    verify_epoch_leaves(
+        vec![(
+            u64_to_h256(epoch),
+            epoch_reward_stake_info_obj.commission_root.into(),
+        )],
+        commission_smt_root,
+        epoch_reward_stake_info_obj.commission_epoch_proof.clone(),
+        Error::RewardCommissionTopFail,
+    )
+}
@@ -20,34 +20,40 @@ pub fn main() -> Result<(), Error> {
     // extract omni and reward lock_hash from script_args
     let selection_args: axon::SelectionLockArgs = Cursor::from(args.to_vec()).into();
     let issue_lock_hash = selection_args.issue_lock_hash();
+    let issue_type_hash = selection_args.issue_type_hash();
     let reward_smt_type_id = selection_args.reward_smt_type_id();
 
-    // count issue and reward smt cells count
+    // count issue and reward smt cells count, an issue cell carries the issue type script and only
+    // counts under the issue lock
     let mut issue_cells_count = 0;
+    let mut locked_issue_cells_count = 0;
     let mut reward_cells_count = 0;
 
     // search issue and reward cells via ckb functions
-    QueryIter::new(load_cell_lock_hash, Source::Input).for_each(|lock_hash| {
-        if &lock_hash == issue_lock_hash.as_slice() {
-            issue_cells_count += 1;
-        }
-    });
-
-    QueryIter::new(load_cell_type_hash, Source::Input).for_each(|type_hash| {
-        if let Some(type_hash) = type_hash {
-            if &type_hash == reward_smt_type_id.as_slice() {
-                reward_cells_count += 1;
+    QueryIter::new(load_cell_type_hash, Source::Input)
+        .zip(QueryIter::new(load_cell_lock_hash, Source::Input))
+        .for_each(|(type_hash, lock_hash)| {
+            if let Some(type_hash) = type_hash {
+                if &type_hash == issue_type_hash.as_slice() {
+                    issue_cells_count += 1;
+                    if &lock_hash == issue_lock_hash.as_slice() {
+                        locked_issue_cells_count += 1;
+                    }
+                }
+                if &type_hash == reward_smt_type_id.as_slice() {
+                    reward_cells_count += 1;
+                }
             }
-        }
-    });
+        });
 
     debug!(
         "omni = {}, reward = {}",
         issue_cells_count, reward_cells_count
     );
 
-    // sum of omni and reward must be 1
-    if issue_cells_count + reward_cells_count != 1 {
+    // issue cell must be consumed to track supply, its type script only allows minting along with
+    // reward smt update or by admin
+    if issue_cells_count != 1 || locked_issue_cells_count != 1 || reward_cells_count > 1 {
         return Err(Error::OmniRewardCountError);
     }
 
@@ -24,7 +24,13 @@ use ckb_std::{
 };
 
 use axon_types::{stake_reader, Cursor};
-use util::{error::Error, helper::*};
+use util::{
+    election::*,
+    error::Error,
+    exit::{exited_stakers_of, RELEASE_MODE},
+    helper::*,
+    slash::*,
+};
 
 pub fn main() -> Result<(), Error> {
     debug!("start stake smt type script");
@@ -87,7 +93,15 @@ pub fn main() -> Result<(), Error> {
                     update_stake_smt(&stake_smt_update_infos, &type_ids, &stake_smt_type_id)?;
                 }
                 1 => {
-                    elect_validators(&metadata_type_id)?;
+                    elect_validators(&metadata_type_id, &stake_smt_type_id)?;
+                }
+                2 => {
+                    // slash offending stakers, the rest of their stake is kept in stake smt
+                    slash_stakers(&stake_smt_update_infos, &type_ids, &stake_smt_type_id)?;
+                }
+                3 => {
+                    // drop stakers whose delegators are released by delegate smt
+                    release_stakers(&type_ids, &stake_smt_type_id)?;
                 }
                 _ => {
                     return Err(Error::UnknownMode);
@@ -187,37 +201,13 @@ fn get_selected_unselected_staker(
     new_stake_info_set: &BTreeSet<LockInfo>,
     metadata_type_id: &[u8; 32],
 ) -> Result<(BTreeSet<LockInfo>, BTreeSet<LockInfo>), Error> {
-    // sort stakes by amount
-    let quorum_size = get_quorum_size(
-        metadata_type_id,
-        util::stake::EpochClass::CURRENT,
-        Source::CellDep,
-    )?;
-    let mut top_3quorum = new_stake_info_set.iter().take(3 * quorum_size as usize);
-    let mut select_stake_info_set = BTreeSet::new();
-    while let Some(elem) = top_3quorum.next() {
-        select_stake_info_set.insert((*elem).clone());
-        debug!("select_stake_infos_set : {:x?}", *elem);
-    }
-
-    let mut delete_stake_info_set = BTreeSet::<LockInfo>::new();
-    if new_stake_info_set.len() > select_stake_info_set.len() {
-        let mut iter = new_stake_info_set.iter();
-        let deleted_size = new_stake_info_set.len() - select_stake_info_set.len();
-        for _ in 0..deleted_size {
-            if let Some(elem) = iter.next_back() {
-                delete_stake_info_set.insert(elem.clone());
-                debug!("deleted_stake_infos : {:x?}", *elem);
-            }
-        }
-    }
-
+    // keep the candidate pool of top stakers, others are deleted and withdrawn
+    let quorum_size = get_election_quorum(metadata_type_id, Source::CellDep)?;
+    let (select_stake_info_set, delete_stake_info_set) =
+        select_candidates(new_stake_info_set, quorum_size);
     debug!(
-        "stake_infos_set len: {}, select_stake_infos_set.len():{}, deleted stake infos size: {}, quorum: {}",
-        new_stake_info_set.len(),
-        select_stake_info_set.len(),
-        delete_stake_info_set.len(),
-        quorum_size
+        "select_stake_infos_set : {:x?}, deleted_stake_infos : {:x?}, quorum: {}",
+        select_stake_info_set, delete_stake_info_set, quorum_size
     );
 
     Ok((select_stake_info_set, delete_stake_info_set))
@@ -306,19 +296,23 @@ fn update_stake_smt(
     let new_stake_smt_data = get_stake_smt_data(&cell_type_id, Source::Output)?;
     if old_stake_smt_data.version() != new_stake_smt_data.version()
         || old_stake_smt_data.metadata_type_id() != new_stake_smt_data.metadata_type_id()
+        || old_stake_smt_data.next_liveness_slash_epoch()
+            != new_stake_smt_data.next_liveness_slash_epoch()
     {
         return Err(Error::StakeSmtUpdateDataError);
     }
 
     // construct old stake smt root & verify
     let current_epoch = get_current_epoch(&checkpoint_script_hash.to_vec())?;
-    let min_inguaration_epoch = current_epoch + 2;
+    let min_inguaration_epoch =
+        get_inauguration_epoch(&metadata_type_id, current_epoch, Source::CellDep)?;
     let old_stake_info_set = transform_to_set(&stake_smt_update_infos.all_stake_infos());
     debug!(
         "current epoch:{}, old stake_infos_set len: {}",
         current_epoch,
         old_stake_info_set.len()
     );
+    let old_exited_stakers = exited_stakers_of(&old_stake_smt_data)?;
     verify_old_stake_infos(
         min_inguaration_epoch,
         &stake_smt_update_infos,
@@ -391,6 +385,12 @@ fn update_stake_smt(
         &stake_smt_update_infos,
         min_inguaration_epoch,
     )?;
+    verify_exited_stakers(
+        old_exited_stakers,
+        &new_stake_smt_data,
+        &select_stake_info_set,
+        &delete_stake_info_set,
+    )?;
 
     for select_stake_info in select_stake_info_set {
         if let Some(delta) = stake_deltas
@@ -446,8 +446,44 @@ fn update_stake_smt(
     Ok(())
 }
 
-fn elect_validators(metadata_type_id: &[u8; 32]) -> Result<(), Error> {
+// stakers leaving stake smt are marked exited, a staker elected into the candidate pool again is unmarked
+fn verify_exited_stakers(
+    mut exited_stakers: BTreeSet<[u8; 20]>,
+    new_stake_smt_data: &StakeSmtCellData,
+    select_stake_info_set: &BTreeSet<LockInfo>,
+    delete_stake_info_set: &BTreeSet<LockInfo>,
+) -> Result<(), Error> {
+    for select_stake_info in select_stake_info_set {
+        if select_stake_info.amount == 0 {
+            exited_stakers.insert(select_stake_info.addr);
+        } else {
+            exited_stakers.remove(&select_stake_info.addr);
+        }
+    }
+    for delete_stake_info in delete_stake_info_set {
+        exited_stakers.insert(delete_stake_info.addr);
+    }
+    debug!("exited stakers: {:?}", exited_stakers);
+    if exited_stakers_of(new_stake_smt_data)? != exited_stakers {
+        return Err(Error::ExitedStakersMismatch);
+    }
+
+    Ok(())
+}
+
+fn elect_validators(metadata_type_id: &[u8; 32], cell_type_id: &[u8; 32]) -> Result<(), Error> {
     debug!("smt cell elect validators mode");
+    // metadata withdraws miners not elected together with their delegators, no staker exits here
+    let old_stake_smt_data = get_stake_smt_data(cell_type_id, Source::Input)?;
+    let new_stake_smt_data = get_stake_smt_data(cell_type_id, Source::Output)?;
+    if exited_stakers_of(&old_stake_smt_data)? != exited_stakers_of(&new_stake_smt_data)? {
+        return Err(Error::ExitedStakersMismatch);
+    }
+    if old_stake_smt_data.next_liveness_slash_epoch()
+        != new_stake_smt_data.next_liveness_slash_epoch()
+    {
+        return Err(Error::StakeSmtUpdateDataError);
+    }
     let input_metadata_cell_cnt =
         get_cell_count_by_type_hash(&metadata_type_id.to_vec(), Source::Input);
     if input_metadata_cell_cnt != 1 {
@@ -460,3 +496,161 @@ fn elect_validators(metadata_type_id: &[u8; 32]) -> Result<(), Error> {
     }
     Ok(())
 }
+
+fn release_stakers(
+    type_ids: &metadata_reader::TypeIds,
+    cell_type_id: &[u8; 32],
+) -> Result<(), Error> {
+    debug!("smt cell release stakers mode");
+    let stake_smt_type_id = get_script_hash(
+        &type_ids.stake_smt_code_hash(),
+        &type_ids.stake_smt_type_id(),
+    );
+    let delegate_smt_type_id = get_script_hash(
+        &type_ids.delegate_smt_code_hash(),
+        &type_ids.delegate_smt_type_id(),
+    );
+    if stake_smt_type_id != cell_type_id.as_slice() {
+        return Err(Error::StakeSmtTypeIdMismatch);
+    }
+    let old_stake_smt_data = get_stake_smt_data(&cell_type_id, Source::Input)?;
+    let new_stake_smt_data = get_stake_smt_data(&cell_type_id, Source::Output)?;
+    if old_stake_smt_data.version() != new_stake_smt_data.version()
+        || old_stake_smt_data.metadata_type_id() != new_stake_smt_data.metadata_type_id()
+        || old_stake_smt_data.smt_root() != new_stake_smt_data.smt_root()
+        || old_stake_smt_data.next_liveness_slash_epoch()
+            != new_stake_smt_data.next_liveness_slash_epoch()
+    {
+        return Err(Error::StakeSmtUpdateDataError);
+    }
+
+    // delegate smt verifies that exactly the released stakers are dropped
+    let delegate_smt_witness =
+        get_delegate_smt_witness(&delegate_smt_type_id).map_err(|_| Error::ReleaseModeMismatch)?;
+    if delegate_smt_witness.mode() != RELEASE_MODE {
+        return Err(Error::ReleaseModeMismatch);
+    }
+    Ok(())
+}
+
+fn slash_stakers(
+    stake_smt_update_infos: &StakeSmtUpdateInfo,
+    type_ids: &metadata_reader::TypeIds,
+    cell_type_id: &[u8; 32],
+) -> Result<(), Error> {
+    debug!("smt cell slash stakers mode");
+    let xudt_type_hash = type_ids.xudt_type_hash();
+    let stake_smt_type_id = get_script_hash(
+        &type_ids.stake_smt_code_hash(),
+        &type_ids.stake_smt_type_id(),
+    );
+    let delegate_smt_type_id = get_script_hash(
+        &type_ids.delegate_smt_code_hash(),
+        &type_ids.delegate_smt_type_id(),
+    );
+    let checkpoint_script_hash = get_script_hash(
+        &type_ids.checkpoint_code_hash(),
+        &type_ids.checkpoint_type_id(),
+    );
+    let metadata_type_id =
+        get_script_hash(&type_ids.metadata_code_hash(), &type_ids.metadata_type_id());
+
+    if stake_smt_type_id != cell_type_id.as_slice() {
+        return Err(Error::StakeSmtTypeIdMismatch);
+    }
+    let old_stake_smt_data = get_stake_smt_data(&cell_type_id, Source::Input)?;
+    let new_stake_smt_data = get_stake_smt_data(&cell_type_id, Source::Output)?;
+    if old_stake_smt_data.version() != new_stake_smt_data.version()
+        || old_stake_smt_data.metadata_type_id() != new_stake_smt_data.metadata_type_id()
+    {
+        return Err(Error::StakeSmtUpdateDataError);
+    }
+
+    // delegators of slashed stakers are slashed in the same transaction
+    let delegate_smt_witness = get_delegate_smt_witness(&delegate_smt_type_id)?;
+    if delegate_smt_witness.mode() != SLASH_MODE {
+        return Err(Error::SlashModeMismatch);
+    }
+
+    let slash_info = get_slash_info(&metadata_type_id)?;
+    verify_liveness_slash_epoch(&slash_info, &old_stake_smt_data, &new_stake_smt_data)?;
+    let current_epoch = get_current_epoch(&checkpoint_script_hash.to_vec())?;
+    let min_inguaration_epoch =
+        get_inauguration_epoch(&metadata_type_id, current_epoch, Source::CellDep)?;
+    let old_stake_info_set = transform_to_set(&stake_smt_update_infos.all_stake_infos());
+    let mut exited_stakers = exited_stakers_of(&old_stake_smt_data)?;
+    verify_old_stake_infos(
+        min_inguaration_epoch,
+        &stake_smt_update_infos,
+        old_stake_smt_data,
+        &old_stake_info_set,
+    )?;
+
+    // offenders keep the part not slashed in stake smt, the slashed part leaves their stake AT
+    // cells for treasury, which is verified by delegate smt together with slashed delegations
+    let mut new_stake_info_set = BTreeSet::new();
+    let mut slashed_count = 0;
+    for stake_info in old_stake_info_set {
+        if !slash_info.is_offender(&stake_info.addr) {
+            new_stake_info_set.insert(stake_info);
+            continue;
+        }
+        debug!("slash staker {:?}", stake_info);
+        slashed_count += 1;
+        let (slash_amount, remaining_amount) = slash_info.split_amount(stake_info.amount)?;
+        let stake_at_lock_hash = calc_at_lock_hash(
+            &type_ids.stake_at_code_hash(),
+            &metadata_type_id,
+            &stake_info.addr,
+        );
+        verify_at_released(&xudt_type_hash, &stake_at_lock_hash, slash_amount)?;
+        // a staker slashed to nothing exits, its delegators are released by delegate smt later
+        if remaining_amount == 0 {
+            exited_stakers.insert(stake_info.addr);
+        }
+        new_stake_info_set.insert(LockInfo {
+            addr: stake_info.addr,
+            amount: remaining_amount,
+        });
+    }
+    if slashed_count == 0 {
+        return Err(Error::SlashNoOffender);
+    }
+    if exited_stakers_of(&new_stake_smt_data)? != exited_stakers {
+        return Err(Error::ExitedStakersMismatch);
+    }
+    verify_staker_selection(
+        &new_stake_info_set,
+        &new_stake_smt_data,
+        &stake_smt_update_infos,
+        min_inguaration_epoch,
+    )?;
+
+    Ok(())
+}
+
+// equivocation evidence is consumed by the slash, liveness offenders of an epoch are slashed once
+fn verify_liveness_slash_epoch(
+    slash_info: &SlashInfo,
+    old_stake_smt_data: &StakeSmtCellData,
+    new_stake_smt_data: &StakeSmtCellData,
+) -> Result<(), Error> {
+    let old_epoch = old_stake_smt_data.next_liveness_slash_epoch();
+    let new_epoch = new_stake_smt_data.next_liveness_slash_epoch();
+    match slash_info.liveness_epoch {
+        Some(epoch) => {
+            if epoch < old_epoch {
+                return Err(Error::SlashLivenessRepeated);
+            }
+            if new_epoch != epoch + 1 {
+                return Err(Error::StakeSmtUpdateDataError);
+            }
+        }
+        None => {
+            if new_epoch != old_epoch {
+                return Err(Error::StakeSmtUpdateDataError);
+            }
+        }
+    }
+    Ok(())
+}
@@ -10,13 +10,15 @@ use ckb_std::{
     ckb_constants::Source,
     ckb_types::{bytes::Bytes, prelude::*},
     debug,
-    high_level::{load_script, load_tx_hash, load_witness_args},
+    high_level::{load_script, load_witness_args},
 };
 
 use axon_types::{stake_reader, Cursor};
-use util::{error::Error, helper::*};
-
-use crate::eth::Secp256k1Eth;
+use util::{
+    error::Error,
+    eth::{verify_eth_signature, Operation, TypedOperation},
+    helper::*,
+};
 
 pub fn main() -> Result<(), Error> {
     let script = load_script()?;
@@ -39,7 +41,7 @@ pub fn main() -> Result<(), Error> {
     let witness_args = load_witness_args(0, Source::GroupInput);
     match witness_args {
         Ok(witness) => {
-            let (mode, eth_sig) = {
+            let (mode, sig_mode, eth_sig) = {
                 let witness_lock = witness.lock().to_opt();
                 if witness_lock.is_none() {
                     return Err(Error::WitnessLockError);
@@ -51,11 +53,12 @@ pub fn main() -> Result<(), Error> {
                 let value: stake_reader::StakeAtWitness =
                     Cursor::from(witness_lock.unwrap().raw_data().to_vec()).into();
                 debug!("witness mode: {}", value.mode());
-                (value.mode(), value.eth_sig())
+                (value.mode(), value.sig_mode(), value.eth_sig())
             };
             debug!(
-                "stake at mode: {}, eth_sig: {:?}, len:{}",
+                "stake at mode: {}, sig_mode: {}, eth_sig: {:?}, len:{}",
                 mode,
+                sig_mode,
                 eth_sig,
                 eth_sig.len()
             );
@@ -77,12 +80,17 @@ pub fn main() -> Result<(), Error> {
                         &type_ids.checkpoint_code_hash(),
                         &type_ids.checkpoint_type_id(),
                     );
+                    let reward_smt_type_hash =
+                        get_script_hash(&type_ids.reward_code_hash(), &type_ids.reward_type_id());
                     update_stake_at_cell(
                         &staker_identity,
+                        sig_mode,
                         &eth_sig,
+                        &metadata_type_id,
                         &stake_at_lock_hash,
                         &checkpoint_type_hash.to_vec(),
                         &type_ids.xudt_type_hash(),
+                        &reward_smt_type_hash.to_vec(),
                     )?;
                 }
                 1 => {
@@ -141,27 +149,15 @@ fn check_stake_change(
 
 pub fn update_stake_at_cell(
     staker_identity: &Vec<u8>,
+    sig_mode: u8,
     eth_sig: &Vec<u8>,
+    metadata_type_id: &Vec<u8>,
     stake_at_lock_hash: &[u8; 32],
     checkpoint_type_id: &Vec<u8>,
     xudt_type_hash: &Vec<u8>,
+    reward_smt_type_hash: &Vec<u8>,
 ) -> Result<(), Error> {
     debug!("update stake info in stake at cell");
-    let msg = load_tx_hash()?;
-    let secp256_eth = Secp256k1Eth::default();
-    let result = secp256_eth.verify_alone(
-        staker_identity.as_slice().try_into().unwrap(),
-        eth_sig.as_slice().try_into().unwrap(),
-        msg,
-    )?;
-    debug!(
-        "verify_signature eth_sig: {:?}, msg: {:?}, pubkey: {:?}, result: {}",
-        eth_sig, msg, staker_identity, result
-    );
-    if !result {
-        return Err(Error::SignatureMismatch);
-    }
-
     check_xudt_type_hash(xudt_type_hash)?;
 
     let total_input_at_amount = get_xudt_by_type_hash(xudt_type_hash, Source::Input)?;
@@ -170,9 +166,11 @@ pub fn update_stake_at_cell(
         "input_at_amount:{}, output_at_amount:{}",
         total_input_at_amount, total_output_at_amount
     );
-    if total_input_at_amount != total_output_at_amount {
-        return Err(Error::InputOutputAtAmountNotEqual);
-    }
+    check_at_amount_or_compound(
+        total_input_at_amount,
+        total_output_at_amount,
+        reward_smt_type_hash,
+    )?;
 
     let (input_stake_at_amount, input_stake_at_data) =
         get_stake_at_data_by_lock_hash(&stake_at_lock_hash, Source::Input)?;
@@ -182,8 +180,19 @@ pub fn update_stake_at_cell(
         "input_stake_at_amount:{}, output_stake_at_amount:{}",
         input_stake_at_amount, output_stake_at_amount
     );
+    // requirement info points at the requirement cell, whose commission changes are delayed and
+    // bounded, so it can't be swapped to another requirement cell along with a stake update
+    let (input_requirement, output_requirement) = (
+        input_stake_at_data.requirement_info(),
+        output_stake_at_data.requirement_info(),
+    );
     if input_stake_at_data.version() != output_stake_at_data.version()
         || input_stake_at_data.metadata_type_id() != output_stake_at_data.metadata_type_id()
+        || input_requirement.code_hash() != output_requirement.code_hash()
+        || input_requirement.requirement().metadata_type_id()
+            != output_requirement.requirement().metadata_type_id()
+        || input_requirement.requirement().requirement_type_id()
+            != output_requirement.requirement().requirement_type_id()
     {
         return Err(Error::UpdateDataError);
     }
@@ -197,12 +206,30 @@ pub fn update_stake_at_cell(
     let output_increase = output_stake_info_delta.is_increase() == 1;
     let output_inaugutation_epoch = output_stake_info_delta.inauguration_epoch();
 
+    // staker signs the new stake info delta
+    let operation = TypedOperation {
+        operation: if output_increase {
+            Operation::Stake
+        } else {
+            Operation::Unstake
+        },
+        amount: output_stake_delta,
+        inauguration_epoch: output_inaugutation_epoch,
+        metadata_type_id: metadata_type_id.as_slice().try_into().unwrap(),
+    };
+    verify_eth_signature(sig_mode, staker_identity, eth_sig, &operation)?;
+
     let current_epoch = get_current_epoch(checkpoint_type_id)?;
     debug!(
         "input_stake_delta:{}, output_stake_delta:{}, output_inaugutation_epoch:{}, current_epoch:{}",
         input_stake_delta, output_stake_delta, output_inaugutation_epoch, current_epoch
     );
-    if output_inaugutation_epoch != current_epoch + 2 {
+    let inauguration_epoch = get_inauguration_epoch(
+        &metadata_type_id.as_slice().try_into().unwrap(),
+        current_epoch,
+        Source::CellDep,
+    )?;
+    if output_inaugutation_epoch != inauguration_epoch {
         return Err(Error::BadInaugurationEpoch);
     }
 
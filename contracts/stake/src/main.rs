@@ -12,7 +12,6 @@
 
 // define modules
 mod entry;
-mod eth;
 
 use ckb_std::default_alloc;
 
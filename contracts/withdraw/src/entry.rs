@@ -1,5 +1,5 @@
 // Import from `core` instead of from `std` since we are in no-std mode
-use alloc::vec;
+use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 use core::result::Result;
 
@@ -16,7 +16,11 @@ use axon_types::{
     withdraw_reader::{self, WithdrawInfos},
     Cursor,
 };
-use util::{error::Error, helper::*};
+use util::{
+    error::Error,
+    eth::{verify_eth_signature, Operation, TypedOperation},
+    helper::*,
+};
 
 // #[derive(PartialEq, Eq)]
 #[derive(Debug)]
@@ -31,33 +35,22 @@ impl PartialEq for WithdrawInfo {
 }
 impl Eq for WithdrawInfo {}
 
-fn get_withdraw_infos(
-    current_epoch: u64,
-    unlock_amount: u128,
-    lock1_amount: u128,
-    lock2_amount: u128,
-) -> Vec<WithdrawInfo> {
-    let mut new_withdraw_infos = vec![];
-    if unlock_amount != 0 {
-        new_withdraw_infos.push(WithdrawInfo {
-            unlock_epoch: current_epoch,
-            amount: unlock_amount,
-        });
-    }
-    if lock1_amount != 0 {
-        new_withdraw_infos.push(WithdrawInfo {
-            unlock_epoch: current_epoch + 1,
-            amount: lock1_amount,
-        });
-    }
-    if lock2_amount != 0 {
-        new_withdraw_infos.push(WithdrawInfo {
-            unlock_epoch: current_epoch + 2,
-            amount: lock2_amount,
-        });
-    }
+// amounts of withdraw AT keyed by unlock epoch, amounts already unlocked are keyed by current epoch
+type WithdrawBuckets = BTreeMap<u64, u128>;
+
+fn get_withdraw_infos(buckets: &WithdrawBuckets) -> Vec<WithdrawInfo> {
+    buckets
+        .iter()
+        .filter(|(_, amount)| **amount != 0)
+        .map(|(unlock_epoch, amount)| WithdrawInfo {
+            unlock_epoch: *unlock_epoch,
+            amount: *amount,
+        })
+        .collect()
+}
 
-    new_withdraw_infos
+fn get_total_amount(buckets: &WithdrawBuckets) -> u128 {
+    buckets.values().sum()
 }
 
 pub fn main() -> Result<(), Error> {
@@ -90,9 +83,9 @@ pub fn main() -> Result<(), Error> {
     };
 
     let withdraw_at_lock_hash = { load_cell_lock_hash(0, Source::GroupInput)? };
-    // we may firstly need to check only 1 withdraw at cell exist in input and output
-    let (in_amount, in_data) =
-        get_withdraw_at_data_by_lock_hash(&withdraw_at_lock_hash, Source::Input)?;
+    // the owner may have several withdraw AT cells in input, they are always merged into one
+    let in_cells = get_withdraw_at_datas_by_lock_hash(&withdraw_at_lock_hash, Source::Input)?;
+    let in_amount: u128 = in_cells.iter().map(|(amount, _)| amount).sum();
     let (out_amount, out_data) =
         get_withdraw_at_data_by_lock_hash(&withdraw_at_lock_hash, Source::Output)?;
 
@@ -109,47 +102,44 @@ pub fn main() -> Result<(), Error> {
         epoch, in_amount, out_amount,input_total_amount, output_total_amount
     );
 
+    let unbonding_epochs = get_unbonding_epochs(
+        &metadata_type_id.as_slice().try_into().unwrap(),
+        Source::CellDep,
+    )?;
+    debug!("unbonding_epochs: {}", unbonding_epochs);
+
     if withdraw_witness.is_none() {
         // ACP mode, someone unstake or undelgate
         debug!("acp mode");
+        // only the owner can merge withdraw AT cells
+        if in_cells.len() > 1 {
+            return Err(Error::WithdrawDuplicateCell);
+        }
         if out_amount <= in_amount {
             return Err(Error::OutLessThanIn);
         }
         let increased_amount = out_amount - in_amount;
 
-        let in_data = in_data.lock().withdraw_infos();
-        let mut unlock_amount: u128 = 0u128; // can be withdraw immediately
-        let mut lock1_amount: u128 = 0u128; // can be withdraw in current epoch + 1
-        let mut lock2_amount: u128 = increased_amount; // can be withdraw in current epoch + 2
-        update_withdraw_amounts(
-            epoch,
-            in_data,
-            &mut unlock_amount,
-            &mut lock1_amount,
-            &mut lock2_amount,
-        )?;
-        if in_amount != unlock_amount + lock1_amount + lock2_amount - increased_amount {
+        // increased amount can be withdrawn in current epoch + unbonding_epochs
+        let in_data = in_cells[0].1.lock().withdraw_infos();
+        let mut buckets = WithdrawBuckets::new();
+        let unlock_epoch = epoch
+            .checked_add(unbonding_epochs)
+            .ok_or(Error::WithdrawEpochOverflow)?;
+        buckets.insert(unlock_epoch, increased_amount);
+        update_withdraw_amounts(epoch, unbonding_epochs, in_data, &mut buckets)?;
+        if in_amount != get_total_amount(&buckets) - increased_amount {
             return Err(Error::WithdrawTotalAmount);
         }
-        let new_withdraw_infos =
-            get_withdraw_infos(epoch, unlock_amount, lock1_amount, lock2_amount);
+        let new_withdraw_infos = get_withdraw_infos(&buckets);
 
         let out_data = out_data.lock().withdraw_infos();
-        let mut unlock_amount: u128 = 0u128; // can be withdraw immediately
-        let mut lock1_amount: u128 = 0u128; // can be withdraw in current epoch + 1
-        let mut lock2_amount: u128 = 0u128; // can be withdraw in current epoch + 2
-        update_withdraw_amounts(
-            epoch,
-            out_data,
-            &mut unlock_amount,
-            &mut lock1_amount,
-            &mut lock2_amount,
-        )?;
-        if out_amount != unlock_amount + lock1_amount + lock2_amount {
+        let mut buckets = WithdrawBuckets::new();
+        update_withdraw_amounts(epoch, unbonding_epochs, out_data, &mut buckets)?;
+        if out_amount != get_total_amount(&buckets) {
             return Err(Error::WithdrawTotalAmount);
         }
-        let out_withdraw_infos =
-            get_withdraw_infos(epoch, unlock_amount, lock1_amount, lock2_amount);
+        let out_withdraw_infos = get_withdraw_infos(&buckets);
 
         if new_withdraw_infos != out_withdraw_infos {
             return Err(Error::WrongOutWithdraw);
@@ -159,55 +149,58 @@ pub fn main() -> Result<(), Error> {
             return Err(Error::WithdrawTotalAmount);
         }
     } else {
-        // unlock mode,
-        debug!("unlock mode");
-        let in_data = in_data.lock().withdraw_infos();
-        let mut unlock_amount: u128 = 0u128; // can be withdraw immediately
-        let mut lock1_amount: u128 = 0u128; // can be withdraw in current epoch + 1
-        let mut lock2_amount: u128 = 0u128; // can be withdraw in current epoch + 2
-        update_withdraw_amounts(
-            epoch,
-            in_data,
-            &mut unlock_amount,
-            &mut lock1_amount,
-            &mut lock2_amount,
-        )?;
-        debug!(
-            "unlock_amount: {}, lock1_amount: {}, lock2_amount: {}",
-            unlock_amount, lock1_amount, lock2_amount
-        );
-        if in_amount != unlock_amount + lock1_amount + lock2_amount {
+        // unlock mode, records of all input cells are merged, unlocking nothing is a pure merge
+        debug!("unlock mode, input cells: {}", in_cells.len());
+        let mut buckets = WithdrawBuckets::new();
+        for (_, in_data) in &in_cells {
+            update_withdraw_amounts(
+                epoch,
+                unbonding_epochs,
+                in_data.lock().withdraw_infos(),
+                &mut buckets,
+            )?;
+        }
+        debug!("in buckets: {:?}", buckets);
+        if in_amount != get_total_amount(&buckets) {
             return Err(Error::WithdrawTotalAmount);
         }
-        let new_withdraw_infos = get_withdraw_infos(epoch, 0, lock1_amount, lock2_amount);
+        // can be withdrawn immediately
+        let unlock_amount = buckets.remove(&epoch).unwrap_or(0);
+        let new_withdraw_infos = get_withdraw_infos(&buckets);
 
         if in_amount - out_amount != unlock_amount {
             return Err(Error::WithdrawTotalAmount);
         }
 
+        // only the owner can release unlocked AT
+        let withdraw_witness = withdraw_witness.unwrap();
+        let operation = TypedOperation {
+            operation: Operation::Withdraw,
+            amount: unlock_amount,
+            inauguration_epoch: epoch,
+            metadata_type_id: metadata_type_id.as_slice().try_into().unwrap(),
+        };
+        verify_eth_signature(
+            withdraw_witness.sig_mode(),
+            &withdraw_args.addr(),
+            &withdraw_witness.signature(),
+            &operation,
+        )?;
+
+        // all unlocked amount is released, only pending records are left
         let out_data = out_data.lock().withdraw_infos();
-        if out_data.len() > 2 {
+        if out_data.len() as u64 > unbonding_epochs {
             return Err(Error::WithdrawWrongRecordSize);
         }
 
-        let mut lock1_amount: u128 = 0u128; // can be withdraw in current epoch + 1
-        let mut lock2_amount: u128 = 0u128; // can be withdraw in current epoch + 2
-                                            // unlock_amount is uesless here
-        update_withdraw_amounts(
-            epoch,
-            out_data,
-            &mut unlock_amount,
-            &mut lock1_amount,
-            &mut lock2_amount,
-        )?;
-        debug!(
-            "out_amount: {}, lock1_amount: {}, lock2_amount: {}",
-            out_amount, lock1_amount, lock2_amount
-        );
-        if out_amount != lock1_amount + lock2_amount {
+        let mut buckets = WithdrawBuckets::new();
+        update_withdraw_amounts(epoch, unbonding_epochs, out_data, &mut buckets)?;
+        buckets.remove(&epoch);
+        debug!("out_amount: {}, out buckets: {:?}", out_amount, buckets);
+        if out_amount != get_total_amount(&buckets) {
             return Err(Error::WithdrawTotalAmount);
         }
-        let out_withdraw_infos = get_withdraw_infos(epoch, 0, lock1_amount, lock2_amount);
+        let out_withdraw_infos = get_withdraw_infos(&buckets);
         debug!(
             "new_withdraw_infos: {:?}, out_withdraw_infos: {:?}",
             new_withdraw_infos, out_withdraw_infos
@@ -224,31 +217,38 @@ pub fn main() -> Result<(), Error> {
     Ok(())
 }
 
+// every record is either unlocked or pending for at most unbonding_epochs, so there are at most
+// unbonding_epochs + 1 records
 fn update_withdraw_amounts(
     epoch: u64,
+    unbonding_epochs: u64,
     data: WithdrawInfos,
-    unlock_amount: &mut u128,
-    lock1_amount: &mut u128,
-    lock2_amount: &mut u128,
+    buckets: &mut WithdrawBuckets,
 ) -> Result<(), Error> {
-    if data.len() > 3 {
+    let max_records = unbonding_epochs
+        .checked_add(1)
+        .ok_or(Error::WithdrawEpochOverflow)?;
+    if data.len() as u64 > max_records {
         return Err(Error::WithdrawWrongRecordSize);
     }
+    let last_unlock_epoch = epoch
+        .checked_add(unbonding_epochs)
+        .ok_or(Error::WithdrawEpochOverflow)?;
     for i in 0..data.len() {
         let withdraw_info = data.get(i);
         let amount = bytes_to_u128(&withdraw_info.amount());
         if amount == 0 {
             return Err(Error::WithdrawZeroAmount);
         }
-        if withdraw_info.unlock_epoch() <= epoch {
-            *unlock_amount += bytes_to_u128(&withdraw_info.amount());
-        } else if withdraw_info.unlock_epoch() == epoch + 1 {
-            *lock1_amount += bytes_to_u128(&withdraw_info.amount());
-        } else if withdraw_info.unlock_epoch() == epoch + 2 {
-            *lock2_amount += bytes_to_u128(&withdraw_info.amount());
+        let unlock_epoch = withdraw_info.unlock_epoch();
+        let bucket_epoch = if unlock_epoch <= epoch {
+            epoch
+        } else if unlock_epoch <= last_unlock_epoch {
+            unlock_epoch
         } else {
             return Err(Error::WrongLockEpoch);
-        }
+        };
+        *buckets.entry(bucket_epoch).or_insert(0) += amount;
     }
     Ok(())
 }
@@ -1,3 +1,5 @@
+use std::convert::TryInto;
+
 use crate::helper::axon_byte32;
 
 use super::*;
@@ -15,14 +17,39 @@ use helper::*;
 use molecule::prelude::*;
 use rand::prelude::*;
 use rlp::RlpStream;
-use util::error::Error::CheckpointDataError;
+use util::error::Error::{
+    CheckpointBlockNotContinuous, CheckpointDataError, CheckpointEvidenceCellError,
+    CheckpointEvidenceDuplicated, CheckpointEvidenceNotConflict, CheckpointLackOfQuorum,
+    CheckpointProposalMismatch, CheckpointProposeCountMismatch, MetadataEpochMismatch,
+};
 use util::helper::keccak256;
+use util::smt::{evidence_to_h256, u64_to_h256, TOP_SMT};
 
 fn construct_checkpoint_tx(
     context: &mut Context,
     input_data: TestCheckpointData,
     output_data: TestCheckpointData,
     epoch_len: u32,
+    vote_weights: &[u32],
+) -> TransactionView {
+    construct_checkpoint_tx_with_proposers(
+        context,
+        input_data,
+        output_data,
+        epoch_len,
+        vote_weights,
+        &[PROPOSER],
+    )
+}
+
+// the block at height h is proposed by proposers[h % proposers.len()]
+fn construct_checkpoint_tx_with_proposers(
+    context: &mut Context,
+    input_data: TestCheckpointData,
+    output_data: TestCheckpointData,
+    epoch_len: u32,
+    vote_weights: &[u32],
+    proposers: &[[u8; 20]],
 ) -> TransactionView {
     let contract_bin: Bytes = Loader::default().load_binary("checkpoint");
     let contract_out_point = context.deploy_cell(contract_bin);
@@ -55,12 +82,12 @@ fn construct_checkpoint_tx(
         .version(input_data.version.into())
         .epoch(axon_u64(input_data.epoch))
         .period(axon_u32(input_data.period))
-        // .latest_block_hash(v)
-        .latest_block_height(axon_u64(10))
+        .latest_block_hash(axon_array32_byte32(INPUT_BLOCK_HASH))
+        .latest_block_height(axon_u64(input_data.block_height))
         .metadata_type_id(axon_byte32(&metadata_type_script.calc_script_hash()))
-        // .propose_count(v)
-        .state_root(axon_byte32(&[0u8; 32].pack()))
-        .timestamp(axon_u64(11111))
+        .propose_count(checkpoint_propose_counts(&input_data.propose_count))
+        .state_root(axon_array32_byte32(input_data.state_root))
+        .timestamp(axon_u64(input_data.timestamp))
         .build();
 
     // prepare tx inputs and outputs
@@ -82,44 +109,35 @@ fn construct_checkpoint_tx(
         .type_(Some(checkpoint_type_script.clone()).pack())
         .build()];
 
-    // prepare outputs_data
-    let output_checkpoint_data = CheckpointCellData::new_builder()
-        .version(output_data.version.into())
-        .epoch(axon_u64(output_data.epoch))
-        .period(axon_u32(output_data.period))
-        // .latest_block_hash(v)
-        .latest_block_height(axon_u64(10))
-        .metadata_type_id(axon_byte32(&metadata_type_script.calc_script_hash()))
-        // .propose_count(v)
-        .state_root(axon_byte32(&[0u8; 32].pack()))
-        .timestamp(axon_u64(11111))
-        .build();
-
-    let outputs_data = vec![Bytes::from(output_checkpoint_data.as_bytes())];
-
     // prepare metadata cell_dep
     let bls_keypairs = vec![0; 8]
         .iter()
         .map(|_| random_bls_keypair())
         .collect::<Vec<_>>();
-    let validators = vec![1u64; 8]
-        .into_iter()
+    let validators = vote_weights
+        .iter()
         .enumerate()
-        .map(|(i, _era)| {
+        .map(|(i, vote_weight)| {
             let mut bls_pubkey = [0u8; 48];
             bls_pubkey.copy_from_slice(&bls_keypairs[i].1);
             Validator::new_builder()
                 .bls_pub_key(axon_array48_byte48(bls_pubkey))
+                .vote_weight(axon_u32(*vote_weight))
                 .build()
         })
         .collect::<Vec<_>>();
     let validatorlist = ValidatorList::new_builder().set(validators).build();
     let metadata = Metadata::new_builder()
         .epoch_len(axon_u32(epoch_len))
+        .period_len(axon_u32(PERIOD_LEN))
         .validators(validatorlist)
         .build();
     // let metadata = Metadata::new_builder().epoch_len(axon_u32(100)).build();
-    let metadata_list = MetadataList::new_builder().push(metadata).build();
+    // validators of the next epoch sign the checkpoint crossing the epoch boundary
+    let metadata_list = MetadataList::new_builder()
+        .push(metadata.clone())
+        .push(metadata)
+        .build();
     let metadata_cell_data = axon_metadata_data(
         &metadata_type_script.clone().calc_script_hash(),
         &checkpoint_type_script.calc_script_hash(),
@@ -140,11 +158,49 @@ fn construct_checkpoint_tx(
         )
         .build();
 
-    let (proposal, proof) = mock_witness(&bls_keypairs);
+    // blocks between the input checkpoint and the signed one, each refers to its parent
+    let proposer_of = |height: u64| proposers[height as usize % proposers.len()];
+    let mut prev_hash = INPUT_BLOCK_HASH.to_vec();
+    let mut period_proposals = Vec::new();
+    for height in input_data.block_height + 1..output_data.block_height {
+        let proposal = mock_proposal(
+            &prev_hash,
+            &proposer_of(height),
+            output_data.timestamp,
+            height,
+        );
+        prev_hash = keccak_hash::keccak(proposal.clone()).as_bytes().to_vec();
+        period_proposals.push(axon_bytes(&proposal));
+    }
+    let (proposal, proof) = mock_witness(
+        &bls_keypairs,
+        &output_data,
+        &prev_hash,
+        &proposer_of(output_data.block_height),
+    );
+
+    // prepare outputs_data, block info must be the same as the signed proposal
+    let output_checkpoint_data = CheckpointCellData::new_builder()
+        .version(output_data.version.into())
+        .epoch(axon_u64(output_data.epoch))
+        .period(axon_u32(output_data.period))
+        .latest_block_hash(axon_array32_byte32(
+            keccak_hash::keccak(proposal.clone()).to_fixed_bytes(),
+        ))
+        .latest_block_height(axon_u64(output_data.block_height))
+        .metadata_type_id(axon_byte32(&metadata_type_script.calc_script_hash()))
+        .propose_count(checkpoint_propose_counts(&output_data.propose_count))
+        .state_root(axon_array32_byte32(output_data.state_root))
+        .timestamp(axon_u64(output_data.timestamp))
+        .build();
+
+    let outputs_data = vec![Bytes::from(output_checkpoint_data.as_bytes())];
     // prepare witness
     let witness_input_type = CheckpointWitness::new_builder()
+        .mode(0.into())
         .proposal(axon_bytes(&proposal))
         .proof(axon_bytes(&proof))
+        .period_proposals(ProposalVec::new_builder().set(period_proposals).build())
         .build();
     let witness = WitnessArgs::new_builder()
         .input_type(Some(Bytes::from(witness_input_type.as_bytes())).pack())
@@ -165,10 +221,32 @@ fn construct_checkpoint_tx(
     tx
 }
 
+const PERIOD_LEN: u32 = 10;
+const SIGNED_STATE_ROOT: [u8; 32] = [1u8; 32];
+const PROPOSER: [u8; 20] = [0u8; 20];
+const INPUT_BLOCK_HASH: [u8; 32] = [2u8; 32];
+
+fn checkpoint_propose_counts(propose_counts: &Vec<([u8; 20], u64)>) -> ProposeCounts {
+    let propose_counts = propose_counts
+        .iter()
+        .map(|(address, count)| {
+            ProposeCount::new_builder()
+                .address(axon_byte20(address))
+                .count(axon_u64(*count))
+                .build()
+        })
+        .collect::<Vec<_>>();
+    ProposeCounts::new_builder().set(propose_counts).build()
+}
+
 struct TestCheckpointData {
     version: u8,
     epoch: u64,
     period: u32,
+    block_height: u64,
+    timestamp: u64,
+    state_root: [u8; 32],
+    propose_count: Vec<([u8; 20], u64)>,
 }
 
 #[test]
@@ -180,12 +258,20 @@ fn test_checkpoint_success() {
         version: 0,
         epoch: 1,
         period: 2,
+        block_height: 20,
+        timestamp: 11111,
+        state_root: [0u8; 32],
+        propose_count: vec![(PROPOSER, PERIOD_LEN as u64)],
     };
 
     let output_checkpoint_data = TestCheckpointData {
         version: 0,
         epoch: 1,
         period: 3,
+        block_height: 30,
+        timestamp: 11112,
+        state_root: SIGNED_STATE_ROOT,
+        propose_count: vec![(PROPOSER, 2 * PERIOD_LEN as u64)],
     };
 
     let tx = construct_checkpoint_tx(
@@ -193,6 +279,48 @@ fn test_checkpoint_success() {
         input_checkpoint_data,
         output_checkpoint_data,
         100,
+        &[1; 8],
+    );
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_checkpoint_success_multiple_proposers() {
+    // init context
+    let mut context = Context::default();
+
+    let input_checkpoint_data = TestCheckpointData {
+        version: 0,
+        epoch: 1,
+        period: 2,
+        block_height: 20,
+        timestamp: 11111,
+        state_root: [0u8; 32],
+        propose_count: vec![(PROPOSER, PERIOD_LEN as u64)],
+    };
+
+    // blocks 21 to 30 are proposed by two validators in turn
+    let output_checkpoint_data = TestCheckpointData {
+        version: 0,
+        epoch: 1,
+        period: 3,
+        block_height: 30,
+        timestamp: 11112,
+        state_root: SIGNED_STATE_ROOT,
+        propose_count: vec![(PROPOSER, PERIOD_LEN as u64 + 5), ([1u8; 20], 5)],
+    };
+
+    let tx = construct_checkpoint_tx_with_proposers(
+        &mut context,
+        input_checkpoint_data,
+        output_checkpoint_data,
+        100,
+        &[1; 8],
+        &[PROPOSER, [1u8; 20]],
     );
     // run
     let cycles = context
@@ -211,12 +339,20 @@ fn test_checkpoint_success_period_reset() {
         version: 0,
         epoch: 1,
         period: epoch_len - 1,
+        block_height: 20,
+        timestamp: 11111,
+        state_root: [0u8; 32],
+        propose_count: vec![(PROPOSER, PERIOD_LEN as u64)],
     };
 
     let output_checkpoint_data = TestCheckpointData {
         version: 0,
         epoch: 2,
         period: 0,
+        block_height: 30,
+        timestamp: 11112,
+        state_root: SIGNED_STATE_ROOT,
+        propose_count: vec![(PROPOSER, PERIOD_LEN as u64)],
     };
 
     let tx = construct_checkpoint_tx(
@@ -224,6 +360,7 @@ fn test_checkpoint_success_period_reset() {
         input_checkpoint_data,
         output_checkpoint_data,
         epoch_len,
+        &[1; 8],
     );
     // run
     let cycles = context
@@ -241,12 +378,20 @@ fn test_checkpoint_fail_period_equal() {
         version: 0,
         epoch: 1,
         period: 2,
+        block_height: 20,
+        timestamp: 11111,
+        state_root: [0u8; 32],
+        propose_count: vec![(PROPOSER, PERIOD_LEN as u64)],
     };
 
     let output_checkpoint_data = TestCheckpointData {
         version: 0,
         epoch: 1,
         period: 2,
+        block_height: 30,
+        timestamp: 11112,
+        state_root: SIGNED_STATE_ROOT,
+        propose_count: vec![(PROPOSER, 2 * PERIOD_LEN as u64)],
     };
 
     let tx = construct_checkpoint_tx(
@@ -254,6 +399,7 @@ fn test_checkpoint_fail_period_equal() {
         input_checkpoint_data,
         output_checkpoint_data,
         100,
+        &[1; 8],
     );
     // run
     let err = context
@@ -272,12 +418,20 @@ fn test_checkpoint_fail_period_not_reset() {
         version: 0,
         epoch: 1,
         period: epoch_len - 1,
+        block_height: 20,
+        timestamp: 11111,
+        state_root: [0u8; 32],
+        propose_count: vec![(PROPOSER, PERIOD_LEN as u64)],
     };
 
     let output_checkpoint_data = TestCheckpointData {
         version: 0,
         epoch: 2,
         period: 1,
+        block_height: 30,
+        timestamp: 11112,
+        state_root: SIGNED_STATE_ROOT,
+        propose_count: vec![(PROPOSER, PERIOD_LEN as u64)],
     };
 
     let tx = construct_checkpoint_tx(
@@ -285,6 +439,7 @@ fn test_checkpoint_fail_period_not_reset() {
         input_checkpoint_data,
         output_checkpoint_data,
         epoch_len,
+        &[1; 8],
     );
     // run
     let err = context
@@ -293,6 +448,523 @@ fn test_checkpoint_fail_period_not_reset() {
     assert_script_error(err, CheckpointDataError as i8);
 }
 
+#[test]
+fn test_checkpoint_fail_lack_of_vote_weight() {
+    // init context
+    let mut context = Context::default();
+
+    let input_checkpoint_data = TestCheckpointData {
+        version: 0,
+        epoch: 1,
+        period: 2,
+        block_height: 20,
+        timestamp: 11111,
+        state_root: [0u8; 32],
+        propose_count: vec![(PROPOSER, PERIOD_LEN as u64)],
+    };
+
+    let output_checkpoint_data = TestCheckpointData {
+        version: 0,
+        epoch: 1,
+        period: 3,
+        block_height: 30,
+        timestamp: 11112,
+        state_root: SIGNED_STATE_ROOT,
+        propose_count: vec![(PROPOSER, 2 * PERIOD_LEN as u64)],
+    };
+
+    // 7 of 8 validators signed, but the missing one holds most of the vote weight
+    let tx = construct_checkpoint_tx(
+        &mut context,
+        input_checkpoint_data,
+        output_checkpoint_data,
+        100,
+        &[10, 1, 1, 1, 1, 1, 1, 1],
+    );
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("CheckpointLackOfQuorum");
+    assert_script_error(err, CheckpointLackOfQuorum as i8);
+}
+
+#[test]
+fn test_checkpoint_fail_state_root_not_signed() {
+    // init context
+    let mut context = Context::default();
+
+    let input_checkpoint_data = TestCheckpointData {
+        version: 0,
+        epoch: 1,
+        period: 2,
+        block_height: 20,
+        timestamp: 11111,
+        state_root: [0u8; 32],
+        propose_count: vec![(PROPOSER, PERIOD_LEN as u64)],
+    };
+
+    let output_checkpoint_data = TestCheckpointData {
+        version: 0,
+        epoch: 1,
+        period: 3,
+        block_height: 30,
+        timestamp: 11112,
+        state_root: [3u8; 32],
+        propose_count: vec![(PROPOSER, 2 * PERIOD_LEN as u64)],
+    };
+
+    let tx = construct_checkpoint_tx(
+        &mut context,
+        input_checkpoint_data,
+        output_checkpoint_data,
+        100,
+        &[1; 8],
+    );
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("CheckpointProposalMismatch");
+    assert_script_error(err, CheckpointProposalMismatch as i8);
+}
+
+#[test]
+fn test_checkpoint_fail_block_height_gap() {
+    // init context
+    let mut context = Context::default();
+
+    let input_checkpoint_data = TestCheckpointData {
+        version: 0,
+        epoch: 1,
+        period: 2,
+        block_height: 20,
+        timestamp: 11111,
+        state_root: [0u8; 32],
+        propose_count: vec![(PROPOSER, PERIOD_LEN as u64)],
+    };
+
+    // skip one period of blocks
+    let output_checkpoint_data = TestCheckpointData {
+        version: 0,
+        epoch: 1,
+        period: 3,
+        block_height: 40,
+        timestamp: 11112,
+        state_root: SIGNED_STATE_ROOT,
+        propose_count: vec![(PROPOSER, 2 * PERIOD_LEN as u64)],
+    };
+
+    let tx = construct_checkpoint_tx(
+        &mut context,
+        input_checkpoint_data,
+        output_checkpoint_data,
+        100,
+        &[1; 8],
+    );
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("CheckpointBlockNotContinuous");
+    assert_script_error(err, CheckpointBlockNotContinuous as i8);
+}
+
+#[test]
+fn test_checkpoint_fail_timestamp_backwards() {
+    // init context
+    let mut context = Context::default();
+
+    let input_checkpoint_data = TestCheckpointData {
+        version: 0,
+        epoch: 1,
+        period: 2,
+        block_height: 20,
+        timestamp: 11111,
+        state_root: [0u8; 32],
+        propose_count: vec![(PROPOSER, PERIOD_LEN as u64)],
+    };
+
+    let output_checkpoint_data = TestCheckpointData {
+        version: 0,
+        epoch: 1,
+        period: 3,
+        block_height: 30,
+        timestamp: 11110,
+        state_root: SIGNED_STATE_ROOT,
+        propose_count: vec![(PROPOSER, 2 * PERIOD_LEN as u64)],
+    };
+
+    let tx = construct_checkpoint_tx(
+        &mut context,
+        input_checkpoint_data,
+        output_checkpoint_data,
+        100,
+        &[1; 8],
+    );
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("CheckpointBlockNotContinuous");
+    assert_script_error(err, CheckpointBlockNotContinuous as i8);
+}
+
+#[test]
+fn test_checkpoint_fail_propose_count_not_signed() {
+    // init context
+    let mut context = Context::default();
+
+    let input_checkpoint_data = TestCheckpointData {
+        version: 0,
+        epoch: 1,
+        period: 2,
+        block_height: 20,
+        timestamp: 11111,
+        state_root: [0u8; 32],
+        propose_count: vec![(PROPOSER, PERIOD_LEN as u64)],
+    };
+
+    // credit blocks to a validator which proposed none of the period
+    let output_checkpoint_data = TestCheckpointData {
+        version: 0,
+        epoch: 1,
+        period: 3,
+        block_height: 30,
+        timestamp: 11112,
+        state_root: SIGNED_STATE_ROOT,
+        propose_count: vec![
+            (PROPOSER, PERIOD_LEN as u64),
+            ([1u8; 20], PERIOD_LEN as u64),
+        ],
+    };
+
+    let tx = construct_checkpoint_tx(
+        &mut context,
+        input_checkpoint_data,
+        output_checkpoint_data,
+        100,
+        &[1; 8],
+    );
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("CheckpointProposeCountMismatch");
+    assert_script_error(err, CheckpointProposeCountMismatch as i8);
+}
+
+#[test]
+fn test_checkpoint_fail_stale_metadata() {
+    // init context
+    let mut context = Context::default();
+
+    // metadata cell dep is still in epoch 1
+    let input_checkpoint_data = TestCheckpointData {
+        version: 0,
+        epoch: 3,
+        period: 2,
+        block_height: 20,
+        timestamp: 11111,
+        state_root: [0u8; 32],
+        propose_count: vec![(PROPOSER, PERIOD_LEN as u64)],
+    };
+
+    let output_checkpoint_data = TestCheckpointData {
+        version: 0,
+        epoch: 3,
+        period: 3,
+        block_height: 30,
+        timestamp: 11112,
+        state_root: SIGNED_STATE_ROOT,
+        propose_count: vec![(PROPOSER, 2 * PERIOD_LEN as u64)],
+    };
+
+    let tx = construct_checkpoint_tx(
+        &mut context,
+        input_checkpoint_data,
+        output_checkpoint_data,
+        100,
+        &[1; 8],
+    );
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("MetadataEpochMismatch");
+    assert_script_error(err, MetadataEpochMismatch as i8);
+}
+
+fn construct_evidence_tx(
+    context: &mut Context,
+    block_hashes: ([u8; 32], [u8; 32]),
+    signers: (&[usize], &[usize]),
+    offenders: &[usize],
+    recorded: &[usize],
+) -> TransactionView {
+    let contract_bin: Bytes = Loader::default().load_binary("checkpoint");
+    let contract_out_point = context.deploy_cell(contract_bin);
+    let contract_dep = CellDep::new_builder()
+        .out_point(contract_out_point.clone())
+        .build();
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("always_success script");
+
+    let metadata_type_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![5]))
+        .expect("metadata type script");
+    let always_success_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    let checkpoint_type_script = context
+        .build_script(&contract_out_point, Bytes::from(vec![0u8; 32]))
+        .expect("checkpoint script");
+    let height = 200u64;
+    let round = 100u64;
+    let evidence_args = CheckpointEvidenceArgs::new_builder()
+        .checkpoint_type_id(axon_byte32(&checkpoint_type_script.calc_script_hash()))
+        .height(axon_u64(height))
+        .round(axon_u64(round))
+        .build();
+    let evidence_type_script = context
+        .build_script(&contract_out_point, Bytes::from(evidence_args.as_bytes()))
+        .expect("evidence script");
+
+    // validators signed both proofs are recorded in evidence smt, along with the ones recorded
+    // by evidence submitted before
+    let mut evidence_tree = TOP_SMT::default();
+    for i in recorded {
+        evidence_tree
+            .update(
+                evidence_to_h256(height, round, &[*i as u8; 20]),
+                u64_to_h256(1),
+            )
+            .expect("update evidence smt");
+    }
+    let old_evidence_root: [u8; 32] = evidence_tree.root().as_slice().try_into().unwrap();
+    let evidence_keys = signers
+        .0
+        .iter()
+        .filter(|i| signers.1.contains(i))
+        .map(|i| evidence_to_h256(height, round, &[*i as u8; 20]))
+        .collect::<Vec<_>>();
+    let evidence_proof = evidence_tree
+        .merkle_proof(evidence_keys.clone())
+        .unwrap()
+        .compile(evidence_keys.clone())
+        .unwrap()
+        .0;
+    for key in evidence_keys {
+        evidence_tree
+            .update(key, u64_to_h256(1))
+            .expect("update evidence smt");
+    }
+    let new_evidence_root: [u8; 32] = evidence_tree.root().as_slice().try_into().unwrap();
+
+    // checkpoint cell keeps unchanged except the evidence smt
+    let checkpoint_data = CheckpointCellData::new_builder()
+        .version(0.into())
+        .epoch(axon_u64(1))
+        .period(axon_u32(2))
+        .latest_block_height(axon_u64(20))
+        .metadata_type_id(axon_byte32(&metadata_type_script.calc_script_hash()))
+        .evidence_smt_root(axon_array32_byte32(old_evidence_root))
+        .build();
+    let inputs = vec![CellInput::new_builder()
+        .previous_output(
+            context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000.pack())
+                    .lock(always_success_lock_script.clone())
+                    .type_(Some(checkpoint_type_script.clone()).pack())
+                    .build(),
+                Bytes::from(checkpoint_data.as_bytes()),
+            ),
+        )
+        .build()];
+    let outputs = vec![
+        CellOutput::new_builder()
+            .capacity(1000.pack())
+            .lock(always_success_lock_script.clone())
+            .type_(Some(checkpoint_type_script.clone()).pack())
+            .build(),
+        CellOutput::new_builder()
+            .capacity(1000.pack())
+            .lock(always_success_lock_script.clone())
+            .type_(Some(evidence_type_script.clone()).pack())
+            .build(),
+    ];
+
+    // prepare metadata cell_dep
+    let bls_keypairs = vec![0; 8]
+        .iter()
+        .map(|_| random_bls_keypair())
+        .collect::<Vec<_>>();
+    let validators = bls_keypairs
+        .iter()
+        .enumerate()
+        .map(|(i, (_, pubkey))| {
+            let mut bls_pubkey = [0u8; 48];
+            bls_pubkey.copy_from_slice(pubkey);
+            Validator::new_builder()
+                .bls_pub_key(axon_array48_byte48(bls_pubkey))
+                .address(axon_byte20_identity(&[i as u8; 20]))
+                .vote_weight(axon_u32(1))
+                .build()
+        })
+        .collect::<Vec<_>>();
+    let validatorlist = ValidatorList::new_builder().set(validators).build();
+    let metadata = Metadata::new_builder()
+        .epoch_len(axon_u32(100))
+        .period_len(axon_u32(PERIOD_LEN))
+        .validators(validatorlist)
+        .build();
+    let metadata_list = MetadataList::new_builder().push(metadata).build();
+    let metadata_cell_data = axon_metadata_data(
+        &metadata_type_script.clone().calc_script_hash(),
+        &checkpoint_type_script.calc_script_hash(),
+        &checkpoint_type_script.calc_script_hash(),
+        &checkpoint_type_script.calc_script_hash(), // needless here
+        metadata_list,
+    );
+    let metadata_script_dep = CellDep::new_builder()
+        .out_point(
+            context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000.pack())
+                    .lock(always_success_lock_script.clone())
+                    .type_(Some(metadata_type_script.clone()).pack())
+                    .build(),
+                metadata_cell_data.as_bytes(),
+            ),
+        )
+        .build();
+
+    // prepare evidence cell data
+    let offenders = offenders
+        .iter()
+        .map(|i| axon_byte20(&[*i as u8; 20]))
+        .collect::<Vec<_>>();
+    let evidence_data = CheckpointEvidenceCellData::new_builder()
+        .version(0.into())
+        .metadata_type_id(axon_byte32(&metadata_type_script.calc_script_hash()))
+        .epoch(axon_u64(1))
+        .height(axon_u64(height))
+        .round(axon_u64(round))
+        .offenders(Byte20Vec::new_builder().set(offenders).build())
+        .build();
+    let outputs_data = vec![
+        Bytes::from(
+            checkpoint_data
+                .as_builder()
+                .evidence_smt_root(axon_array32_byte32(new_evidence_root))
+                .build()
+                .as_bytes(),
+        ),
+        Bytes::from(evidence_data.as_bytes()),
+    ];
+
+    // prepare witness with two proofs
+    let proof = mock_proof(&bls_keypairs, height, round, block_hashes.0, signers.0);
+    let conflict_proof = mock_proof(&bls_keypairs, height, round, block_hashes.1, signers.1);
+    let witness_input_type = CheckpointWitness::new_builder()
+        .mode(1.into())
+        .proof(axon_bytes(&proof))
+        .conflict_proof(axon_bytes(&conflict_proof))
+        .evidence_proof(axon_bytes(&evidence_proof))
+        .build();
+    let witness = WitnessArgs::new_builder()
+        .input_type(Some(Bytes::from(witness_input_type.as_bytes())).pack())
+        .build();
+
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .witness(witness.as_bytes().pack())
+        .outputs_data(outputs_data.pack())
+        .cell_dep(contract_dep)
+        .cell_dep(always_success_script_dep)
+        .cell_dep(metadata_script_dep)
+        .build();
+    context.complete_tx(tx)
+}
+
+#[test]
+fn test_checkpoint_evidence_success() {
+    // init context
+    let mut context = Context::default();
+
+    // validators 2, 3 and 4 signed both blocks
+    let tx = construct_evidence_tx(
+        &mut context,
+        ([1u8; 32], [2u8; 32]),
+        (&[0, 1, 2, 3, 4], &[2, 3, 4, 5, 6, 7]),
+        &[2, 3, 4],
+        &[],
+    );
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_checkpoint_evidence_fail_same_block() {
+    // init context
+    let mut context = Context::default();
+
+    let tx = construct_evidence_tx(
+        &mut context,
+        ([1u8; 32], [1u8; 32]),
+        (&[0, 1, 2, 3, 4], &[2, 3, 4, 5, 6, 7]),
+        &[2, 3, 4],
+        &[],
+    );
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("CheckpointEvidenceNotConflict");
+    assert_script_error(err, CheckpointEvidenceNotConflict as i8);
+}
+
+#[test]
+fn test_checkpoint_evidence_fail_duplicated() {
+    // init context
+    let mut context = Context::default();
+
+    // equivocation of validator 3 at the same height and round was submitted before
+    let tx = construct_evidence_tx(
+        &mut context,
+        ([1u8; 32], [2u8; 32]),
+        (&[0, 1, 2, 3, 4], &[2, 3, 4, 5, 6, 7]),
+        &[2, 3, 4],
+        &[3],
+    );
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("CheckpointEvidenceDuplicated");
+    assert_script_error(err, CheckpointEvidenceDuplicated as i8);
+}
+
+#[test]
+fn test_checkpoint_evidence_fail_wrong_offenders() {
+    // init context
+    let mut context = Context::default();
+
+    // validator 1 only signed one of the blocks
+    let tx = construct_evidence_tx(
+        &mut context,
+        ([1u8; 32], [2u8; 32]),
+        (&[0, 1, 2, 3, 4], &[2, 3, 4, 5, 6, 7]),
+        &[1, 2, 3, 4],
+        &[],
+    );
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("CheckpointEvidenceCellError");
+    assert_script_error(err, CheckpointEvidenceCellError as i8);
+}
+
 #[test]
 fn test_checkpoint_create() {
     // init context
@@ -479,29 +1151,60 @@ fn test_checkpoint_axon_blst() {
     assert_eq!(signature, axon_signature);
 }
 
-fn mock_witness(bls_keypairs: &[(SecretKey, Vec<u8>)]) -> (Vec<u8>, Vec<u8>) {
-    // prepare proposal rlp
-    // refer to https://github.com/axonweb3/axon-tools/blob/main/axon-tools-riscv/src/types.rs#L76
-    // only 10 fields are needed here
-    let proposal = {
-        let proposal_field_count = 10;
-        let mut proposal = RlpStream::new_list(proposal_field_count);
+// prepare proposal rlp
+// refer to https://github.com/axonweb3/axon-tools/blob/main/axon-tools-riscv/src/types.rs#L76
+// only 10 fields are needed here
+fn mock_proposal(prev_hash: &Vec<u8>, proposer: &[u8; 20], timestamp: u64, number: u64) -> Vec<u8> {
+    let proposal_field_count = 10;
+    let mut proposal = RlpStream::new_list(proposal_field_count);
+    proposal.append(prev_hash); // prev_hash
+    proposal.append(&proposer.to_vec()); // proposer_address
+    proposal.append(&SIGNED_STATE_ROOT.to_vec()); // prev_state_root
+    proposal.append_empty_data(); // transactions_root
+    proposal.append_empty_data(); // signed_txs_hash
+    proposal.append(&timestamp); // timestamp
+    proposal.append(&number); // number
+    vec![0; 3].iter().for_each(|_| {
         proposal.append_empty_data();
-        proposal.append(&vec![0u8; 20]); // proposer_address
-        vec![0; 8].iter().for_each(|_| {
-            proposal.append_empty_data();
-        });
-        proposal.as_raw().to_vec()
-    };
+    });
+    proposal.as_raw().to_vec()
+}
+
+fn mock_witness(
+    bls_keypairs: &[(SecretKey, Vec<u8>)],
+    block: &TestCheckpointData,
+    prev_hash: &Vec<u8>,
+    proposer: &[u8; 20],
+) -> (Vec<u8>, Vec<u8>) {
+    let proposal = mock_proposal(prev_hash, proposer, block.timestamp, block.block_height);
 
     // prepare proof rlp
-    let proposal_hash = keccak_hash::keccak(proposal.clone());
+    let proposal_hash = keccak_hash::keccak(proposal.clone()).to_fixed_bytes();
+    let signers = (1..bls_keypairs.len()).collect::<Vec<_>>();
+    let proof = mock_proof(
+        bls_keypairs,
+        block.block_height,
+        100,
+        proposal_hash,
+        &signers,
+    );
+    (proposal, proof)
+}
+
+// aggregate signatures of validators at indexes of signers, and mark them in bitmap
+fn mock_proof(
+    bls_keypairs: &[(SecretKey, Vec<u8>)],
+    height: u64,
+    round: u64,
+    block_hash: [u8; 32],
+    signers: &[usize],
+) -> Vec<u8> {
     let message = {
         let mut vote = RlpStream::new_list(4);
-        vote.append(&200u64);
-        vote.append(&100u64);
+        vote.append(&height);
+        vote.append(&round);
         vote.append(&2u8);
-        vote.append(&proposal_hash.as_bytes().to_vec());
+        vote.append(&block_hash.to_vec());
         vote.as_raw().to_vec()
     };
     // println!(
@@ -513,19 +1216,20 @@ fn mock_witness(bls_keypairs: &[(SecretKey, Vec<u8>)]) -> (Vec<u8>, Vec<u8>) {
     //     "generate_bls_signature hash message: {:?}, message len: {}",
     //     message,
     // );
-    let signature = generate_bls_signature(&message, &bls_keypairs[1..]);
-    let mut bitmap = BitVec::from_elem(8, true);
-    bitmap.set(0, false);
-    let proof = {
-        let mut proof = RlpStream::new_list(5);
-        proof.append(&200u64);
-        proof.append(&100u64);
-        proof.append(&proposal_hash.as_bytes().to_vec());
-        proof.append(&signature.to_vec());
-        proof.append(&bitmap.to_bytes());
-        proof.as_raw().to_vec()
-    };
-    (proposal, proof)
+    let signer_keypairs = signers
+        .iter()
+        .map(|i| bls_keypairs[*i].clone())
+        .collect::<Vec<_>>();
+    let signature = generate_bls_signature(&message, &signer_keypairs);
+    let mut bitmap = BitVec::from_elem(bls_keypairs.len(), false);
+    signers.iter().for_each(|i| bitmap.set(*i, true));
+    let mut proof = RlpStream::new_list(5);
+    proof.append(&height);
+    proof.append(&round);
+    proof.append(&block_hash.to_vec());
+    proof.append(&signature.to_vec());
+    proof.append(&bitmap.to_bytes());
+    proof.as_raw().to_vec()
 }
 
 pub fn generate_bls_signature(message: &[u8], bls_keypairs: &[(SecretKey, Vec<u8>)]) -> [u8; 96] {
@@ -1,10 +1,13 @@
 use std::collections::BTreeSet;
+use std::convert::TryInto;
 use std::iter::FromIterator;
 
 use super::*;
 use axon_types::delegate::*;
 use axon_types::metadata::{Metadata, MetadataList};
+use axon_types::stake::StakeSmtWitness;
 use axon_types::withdraw::WithdrawArgs;
+use ckb_system_scripts::BUNDLED_CELL;
 use ckb_testtool::ckb_crypto::secp::{Generator, Privkey, Pubkey};
 use ckb_testtool::ckb_types::core::ScriptHashType;
 use ckb_testtool::ckb_types::{
@@ -15,15 +18,21 @@ use helper::*;
 use molecule::prelude::*;
 use util::{
     error::Error::{
-        DelegateSelf, DelegateSmtVerifySelectionError, InputOutputAtAmountNotEqual,
+        CommissionChangeTooLarge, DelegateSelf, DelegateSmtVerifySelectionError,
+        DelegateToExitedStaker, DelegatorSizeOutOfBounds, ExitedStakersMismatch,
+        InputOutputAtAmountNotEqual, RedelegateInvalid, ReleaseStakerNotExited, SignatureMismatch,
         UnDelegateTooMuch,
     },
+    eth::{Operation, TypedOperation, SIG_MODE_EIP712},
+    exit::RELEASE_MODE,
     smt::LockInfo,
 };
 
 // newly added delegate info
-fn construct_delegate_tx(context: &mut Context) -> TransactionView {
-    let delegator_keypair = Generator::random_keypair();
+fn construct_delegate_tx(
+    context: &mut Context,
+    delegator_keypair: (Privkey, Pubkey),
+) -> TransactionView {
     let staker_keypair = Generator::random_keypair();
     let output_at_amount = 1000;
     let output_delegate_at_amount = 100;
@@ -93,9 +102,17 @@ fn construct_delegate_tx_with_args(
         .out_point(always_success_out_point.clone())
         .build();
 
+    let secp256k1_data_bin = BUNDLED_CELL.get("specs/cells/secp256k1_data").unwrap();
+    let secp256k1_data_out_point = context.deploy_cell(secp256k1_data_bin.to_vec().into());
+    let secp256k1_data_dep = CellDep::new_builder()
+        .out_point(secp256k1_data_out_point)
+        .build();
+
+    // delegator signs the tx with its eth address
+    let delegator_identity = pubkey_to_eth_identity(&delegator_keypair.1);
     let delegate_args = delegate::DelegateArgs::new_builder()
         .metadata_type_id(axon_byte32(&metadata_type_script.calc_script_hash()))
-        .delegator_addr(axon_identity(&delegator_keypair.1.serialize()))
+        .delegator_addr(delegator_identity.clone())
         .build();
 
     let input_delegate_info_deltas =
@@ -110,7 +127,7 @@ fn construct_delegate_tx_with_args(
     let input_delegate_at_data = axon_delegate_at_cell_data_without_amount(
         0,
         &delegator_keypair.1.serialize(),
-        &delegator_keypair.1.serialize(),
+        delegator_identity.clone(),
         &metadata_type_script.calc_script_hash(),
         input_delegate_info_deltas,
     );
@@ -174,7 +191,7 @@ fn construct_delegate_tx_with_args(
     let output_delegate_at_data = axon_delegate_at_cell_data_without_amount(
         0,
         &delegator_keypair.1.serialize(),
-        &delegator_keypair.1.serialize(),
+        delegator_identity.clone(),
         &metadata_type_script.calc_script_hash(),
         output_delegate_info_deltas,
     );
@@ -238,35 +255,39 @@ fn construct_delegate_tx_with_args(
         )
         .build();
 
-    let delegate_at_witness = DelegateAtWitness::new_builder().mode(0.into()).build();
-    println!(
-        "delegate at witness: {:?}",
-        delegate_at_witness.as_bytes().len()
-    );
-    let delegate_at_witness = WitnessArgs::new_builder()
-        .lock(Some(Bytes::from(delegate_at_witness.as_bytes())).pack())
-        .build();
-
     // prepare signed tx
     let tx = TransactionBuilder::default()
         .inputs(inputs)
         .outputs(outputs)
-        .witness(delegate_at_witness.as_bytes().pack())
         .outputs_data(outputs_data.pack())
         .cell_dep(contract_dep)
         .cell_dep(always_success_script_dep)
+        .cell_dep(secp256k1_data_dep)
         .cell_dep(checkpoint_script_dep)
         .cell_dep(metadata_script_dep)
         .build();
     let tx = context.complete_tx(tx);
-    tx
+
+    let signature = sign_eth_tx_hash(&tx, &delegator_keypair.0);
+    let delegate_at_witness = DelegateAtWitness::new_builder()
+        .mode(0.into())
+        .eth_sig(axon_byte65(signature))
+        .build();
+    println!(
+        "delegate at witness: {:?}",
+        delegate_at_witness.as_bytes().len()
+    );
+    let delegate_at_witness = WitnessArgs::new_builder()
+        .lock(Some(Bytes::from(delegate_at_witness.as_bytes())).pack())
+        .build();
+    sign_eth_tx(tx, delegate_at_witness)
 }
 
 #[test]
 fn test_delegate_at_success_add_new() {
     // init context
     let mut context = Context::default();
-    let tx = construct_delegate_tx(&mut context);
+    let tx = construct_delegate_tx(&mut context, Generator::random_keypair());
 
     // run
     let cycles = context
@@ -493,7 +514,7 @@ fn test_delegate_self_fail() {
     let delegator_keypair = Generator::random_keypair();
     let staker_keypair = delegator_keypair.clone();
     let output_delegate_info_delta = delegate::DelegateInfoDelta::new_builder()
-        .staker(axon_identity(&staker_keypair.1.serialize()))
+        .staker(pubkey_to_eth_identity(&staker_keypair.1))
         .build();
     let tx = construct_delegate_tx_with_args(
         &mut context,
@@ -513,6 +534,140 @@ fn test_delegate_self_fail() {
     assert_script_error(err, DelegateSelf as i8);
 }
 
+#[test]
+fn test_delegate_at_fail_wrong_signature() {
+    // init context
+    let mut context = Context::default();
+    let tx = construct_delegate_tx(&mut context, Generator::random_keypair());
+
+    // signed by someone other than the delegator
+    let signature = sign_eth_tx_hash(&tx, &Generator::random_keypair().0);
+    let delegate_at_witness = DelegateAtWitness::new_builder()
+        .mode(0.into())
+        .eth_sig(axon_byte65(signature))
+        .build();
+    let delegate_at_witness = WitnessArgs::new_builder()
+        .lock(Some(Bytes::from(delegate_at_witness.as_bytes())).pack())
+        .build();
+    let tx = sign_eth_tx(tx, delegate_at_witness);
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("SignatureMismatch");
+    assert_script_error(err, SignatureMismatch as i8);
+}
+
+#[test]
+fn test_delegate_at_success_eip712() {
+    // init context
+    let mut context = Context::default();
+    let delegator_keypair = Generator::random_keypair();
+    let tx = construct_delegate_tx(&mut context, delegator_keypair.clone());
+
+    // delegator signs typed data of the delegation instead of raw tx hash
+    let delegate_at_lock = tx.output(0).unwrap().lock();
+    let delegate_args = DelegateArgs::new_unchecked(delegate_at_lock.args().raw_data());
+    let operation = TypedOperation {
+        operation: Operation::Delegate,
+        amount: 100,
+        inauguration_epoch: 3,
+        metadata_type_id: delegate_args
+            .metadata_type_id()
+            .as_slice()
+            .try_into()
+            .unwrap(),
+    };
+    let message = operation.eip712_hash(&tx_hash(&tx));
+    let signature = sign_eth_message(&delegator_keypair.0, message);
+    let delegate_at_witness = DelegateAtWitness::new_builder()
+        .mode(0.into())
+        .eth_sig(axon_byte65(signature))
+        .sig_mode(SIG_MODE_EIP712.into())
+        .build();
+    let delegate_at_witness = WitnessArgs::new_builder()
+        .lock(Some(Bytes::from(delegate_at_witness.as_bytes())).pack())
+        .build();
+    let tx = sign_eth_tx(tx, delegate_at_witness);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_redelegate_at_success() {
+    // init context
+    let mut context = Context::default();
+    let delegator_keypair = Generator::random_keypair();
+    let from_staker_keypair = Generator::random_keypair();
+    let staker_keypair = Generator::random_keypair();
+    let normal_at_amount = 1000;
+    let delegate_at_amount = 100;
+
+    // move delegated AT from one staker to another, AT amounts keep unchanged
+    let output_delegate_info_delta = delegate::DelegateInfoDelta::new_builder()
+        .is_increase(1.into())
+        .amount(axon_u128(delegate_at_amount))
+        .inauguration_epoch(axon_u64(3 as u64))
+        .staker(axon_identity(&staker_keypair.1.serialize()))
+        .from_staker(axon_identity(&from_staker_keypair.1.serialize()))
+        .build();
+
+    let tx = construct_delegate_tx_with_args(
+        &mut context,
+        delegator_keypair,
+        None,
+        output_delegate_info_delta,
+        normal_at_amount,
+        delegate_at_amount,
+        normal_at_amount,
+        delegate_at_amount,
+    );
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_redelegate_at_fail_decrease() {
+    // init context
+    let mut context = Context::default();
+    let delegator_keypair = Generator::random_keypair();
+    let from_staker_keypair = Generator::random_keypair();
+    let staker_keypair = Generator::random_keypair();
+    let normal_at_amount = 1000;
+    let delegate_at_amount = 100;
+
+    let output_delegate_info_delta = delegate::DelegateInfoDelta::new_builder()
+        .is_increase(0.into())
+        .amount(axon_u128(delegate_at_amount))
+        .inauguration_epoch(axon_u64(3 as u64))
+        .staker(axon_identity(&staker_keypair.1.serialize()))
+        .from_staker(axon_identity(&from_staker_keypair.1.serialize()))
+        .build();
+
+    let tx = construct_delegate_tx_with_args(
+        &mut context,
+        delegator_keypair,
+        None,
+        output_delegate_info_delta,
+        normal_at_amount,
+        delegate_at_amount,
+        normal_at_amount,
+        delegate_at_amount,
+    );
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("RedelegateInvalid");
+    assert_script_error(err, RedelegateInvalid as i8);
+}
+
 #[test]
 fn test_undelegate_at_fail_too_much_at() {
     // init context
@@ -827,7 +982,7 @@ fn construct_delegate_smt_undelegate_tx(context: &mut Context) -> TransactionVie
     let input_delegate_at_data = axon_delegate_at_cell_data_without_amount(
         0,
         &delegator_keypair.1.serialize(),
-        &delegator_keypair.1.serialize(),
+        axon_identity(&delegator_keypair.1.serialize()),
         &metadata_type_script.calc_script_hash(),
         input_delegate_info_deltas,
     );
@@ -965,7 +1120,7 @@ fn construct_delegate_smt_undelegate_tx(context: &mut Context) -> TransactionVie
     let output_delegate_at_data = axon_delegate_at_cell_data_without_amount(
         0,
         &delegator_keypair.1.serialize(),
-        &delegator_keypair.1.serialize(),
+        axon_identity(&delegator_keypair.1.serialize()),
         &metadata_type_script.calc_script_hash(),
         output_delegate_info_deltas,
     );
@@ -1012,12 +1167,19 @@ fn construct_delegate_smt_undelegate_tx(context: &mut Context) -> TransactionVie
             .as_bytes()
             .to_vec()
     );
+    let (stake_smt_script_dep, stake_smt_type_script) = axon_stake_smt_cell_dep(
+        &metadata_type_script,
+        &always_success_out_point,
+        &always_success_lock_script,
+        context,
+        &[],
+    );
     let meta_data = axon_metadata_data_by_script(
         &metadata_type_script.clone(),
         &delegate_at_type_script.calc_script_hash(),
         &checkpoint_type_script,
-        &delegate_at_type_script,  // needless here
-        &delegate_smt_type_script, // needless here
+        &stake_smt_type_script,
+        &delegate_smt_type_script,
         metadata_list,
         1,
         100,
@@ -1107,6 +1269,7 @@ fn construct_delegate_smt_undelegate_tx(context: &mut Context) -> TransactionVie
         .cell_dep(metadata_script_dep)
         .cell_dep(delegate_requirement_script_dep)
         .cell_dep(stake_at_script_dep)
+        .cell_dep(stake_smt_script_dep)
         .build();
     let tx = context.complete_tx(tx);
     tx
@@ -1130,6 +1293,22 @@ fn construct_delegate_smt_delegate_tx(
     delegators: Vec<((Privkey, Pubkey), u128)>,
     input_delegate_infos: BTreeSet<LockInfo>,
     intend_fail: bool,
+) -> TransactionView {
+    construct_delegate_smt_delegate_tx_with_args(
+        context,
+        delegators,
+        input_delegate_infos,
+        intend_fail,
+        false,
+    )
+}
+
+fn construct_delegate_smt_delegate_tx_with_args(
+    context: &mut Context,
+    delegators: Vec<((Privkey, Pubkey), u128)>,
+    input_delegate_infos: BTreeSet<LockInfo>,
+    intend_fail: bool,
+    staker_exited: bool,
 ) -> TransactionView {
     let at_contract_bin: Bytes = Loader::default().load_binary("delegate");
     let at_contract_out_point = context.deploy_cell(at_contract_bin);
@@ -1207,7 +1386,7 @@ fn construct_delegate_smt_delegate_tx(
         let input_delegate_at_data = axon_delegate_at_cell_data_without_amount(
             0,
             &delegator_keypair.1.serialize(),
-            &delegator_keypair.1.serialize(),
+            axon_identity(&delegator_keypair.1.serialize()),
             &metadata_type_script.calc_script_hash(),
             input_delegate_info_deltas,
         );
@@ -1253,7 +1432,7 @@ fn construct_delegate_smt_delegate_tx(
             let output_delegate_at_data = axon_delegate_at_cell_data_without_amount(
                 0,
                 &delegator_keypair.1.serialize(),
-                &delegator_keypair.1.serialize(),
+                axon_identity(&delegator_keypair.1.serialize()),
                 &metadata_type_script.calc_script_hash(),
                 output_delegate_info_deltas,
             );
@@ -1434,12 +1613,24 @@ fn construct_delegate_smt_delegate_tx(
     let delegate_at_lock_script = context
         .build_script(&at_contract_out_point, Bytes::from(vec![9u8]))
         .expect("delegate script");
+    let exited_stakers = if staker_exited {
+        vec![staker_addr]
+    } else {
+        vec![]
+    };
+    let (stake_smt_script_dep, stake_smt_type_script) = axon_stake_smt_cell_dep(
+        &metadata_type_script,
+        &always_success_out_point,
+        &always_success_lock_script,
+        context,
+        &exited_stakers,
+    );
     let meta_data = axon_metadata_data_by_script(
         &metadata_type_script.clone(),
         &delegate_at_type_script.calc_script_hash(),
         &checkpoint_type_script,
-        &delegate_at_type_script,  // needless here
-        &delegate_smt_type_script, // needless here
+        &stake_smt_type_script,
+        &delegate_smt_type_script,
         metadata_list,
         current_epoch,
         100,
@@ -1526,6 +1717,7 @@ fn construct_delegate_smt_delegate_tx(
         .cell_dep(metadata_script_dep)
         .cell_dep(delegate_requirement_script_dep)
         .cell_dep(stake_at_script_dep)
+        .cell_dep(stake_smt_script_dep)
         .build();
     let tx = context.complete_tx(tx);
     tx
@@ -1588,6 +1780,28 @@ fn test_delegate_smt_increase_success_toomany_delegator() {
     println!("consume cycles: {}", cycles);
 }
 
+#[test]
+fn test_delegate_smt_fail_exited_staker() {
+    // init context
+    let mut context = Context::default();
+    let delegator_keypair = Generator::random_keypair();
+    let delegate_amount = 2000 as u128;
+    let input_delegate_infos = BTreeSet::new();
+
+    let delegators = vec![(delegator_keypair, delegate_amount)];
+    let tx = construct_delegate_smt_delegate_tx_with_args(
+        &mut context,
+        delegators,
+        input_delegate_infos,
+        false,
+        true,
+    );
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, DelegateToExitedStaker as i8);
+}
+
 fn construct_delegate_smt_delegate_tx_2staker_2delegator(
     context: &mut Context,
     in_smt_delegates: Vec<TestDelegateInfo>,
@@ -1656,7 +1870,7 @@ fn construct_delegate_smt_delegate_tx_2staker_2delegator(
         let input_delegate_at_data = axon_delegate_at_cell_data_without_amount(
             0,
             &delegator_keypair.1.serialize(),
-            &delegator_keypair.1.serialize(),
+            axon_identity(&delegator_keypair.1.serialize()),
             &metadata_type_script.calc_script_hash(),
             input_delegate_info_deltas,
         );
@@ -1702,7 +1916,7 @@ fn construct_delegate_smt_delegate_tx_2staker_2delegator(
         let output_delegate_at_data = axon_delegate_at_cell_data_without_amount(
             0,
             &delegator_keypair.1.serialize(),
-            &delegator_keypair.1.serialize(),
+            axon_identity(&delegator_keypair.1.serialize()),
             &metadata_type_script.calc_script_hash(),
             output_delegate_info_deltas,
         );
@@ -1865,12 +2079,19 @@ fn construct_delegate_smt_delegate_tx_2staker_2delegator(
     let delegate_at_lock_script = context
         .build_script(&at_contract_out_point, Bytes::from(vec![9u8]))
         .expect("delegate script");
+    let (stake_smt_script_dep, stake_smt_type_script) = axon_stake_smt_cell_dep(
+        &metadata_type_script,
+        &always_success_out_point,
+        &always_success_lock_script,
+        context,
+        &[],
+    );
     let meta_data = axon_metadata_data_by_script(
         &metadata_type_script.clone(),
         &delegate_at_type_script.calc_script_hash(),
         &checkpoint_type_script,
-        &delegate_at_type_script,  // needless here
-        &delegate_smt_type_script, // needless here
+        &stake_smt_type_script,
+        &delegate_smt_type_script,
         metadata_list,
         current_epoch,
         100,
@@ -1976,6 +2197,7 @@ fn construct_delegate_smt_delegate_tx_2staker_2delegator(
         .cell_dep(metadata_script_dep)
         .cell_deps(requirement_deps)
         .cell_deps(stake_at_script_deps)
+        .cell_dep(stake_smt_script_dep)
         .build();
     let tx = context.complete_tx(tx);
     tx
@@ -2497,11 +2719,10 @@ fn test_delegate_election_success() {
     println!("consume cycles: {}", cycles);
 }
 
-#[test]
-fn test_delegate_requirement_success() {
-    // init context
-    let mut context = Context::default();
-
+fn construct_delegate_requirement_tx(
+    context: &mut Context,
+    delegate_cell_data: DelegateCellData,
+) -> TransactionView {
     let contract_bin: Bytes = Loader::default().load_binary("requirement");
     let contract_out_point = context.deploy_cell(contract_bin);
     let contract_dep = CellDep::new_builder()
@@ -2516,8 +2737,6 @@ fn test_delegate_requirement_success() {
         .out_point(always_success_out_point.clone())
         .build();
 
-    let delegate_cell_data = axon_delegate_requirement_cell_data(10, 3);
-
     // prepare tx inputs and outputs
     let input = CellInput::new_builder()
         .previous_output(
@@ -2564,6 +2783,501 @@ fn test_delegate_requirement_success() {
 
     // sign tx for delegate at cell (update stake at cell delta mode)
     // let tx = sign_tx(tx, &delegator_keypair.0, 0);
+    tx
+}
+
+#[test]
+fn test_delegate_requirement_success() {
+    // init context
+    let mut context = Context::default();
+    let delegate_cell_data = axon_delegate_requirement_cell_data(10, 3);
+    let tx = construct_delegate_requirement_tx(&mut context, delegate_cell_data);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_delegate_requirement_fail_zero_delegator_size() {
+    // init context
+    let mut context = Context::default();
+    let delegate_cell_data = axon_delegate_requirement_cell_data(10, 0);
+    let tx = construct_delegate_requirement_tx(&mut context, delegate_cell_data);
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("DelegatorSizeOutOfBounds");
+    assert_script_error(err, DelegatorSizeOutOfBounds as i8);
+}
+
+// staker changes commission rate of the requirement cell, checkpoint is at epoch 1 and inauguration delay is 2
+fn construct_update_requirement_tx(
+    context: &mut Context,
+    input_commission_rate: u8,
+    output_commission_rate: u8,
+) -> TransactionView {
+    let secp256k1_data_bin = BUNDLED_CELL.get("specs/cells/secp256k1_data").unwrap();
+    let secp256k1_data_out_point = context.deploy_cell(secp256k1_data_bin.to_vec().into());
+    let secp256k1_data_dep = CellDep::new_builder()
+        .out_point(secp256k1_data_out_point)
+        .build();
+    let contract_bin: Bytes = Loader::default().load_binary("requirement");
+    let contract_out_point = context.deploy_cell(contract_bin);
+    let contract_dep = CellDep::new_builder()
+        .out_point(contract_out_point.clone())
+        .build();
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("always_success script");
+    let always_success_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+    let checkpoint_type_script = context
+        .build_script_with_hash_type(
+            &always_success_out_point,
+            ScriptHashType::Type,
+            Bytes::from(vec![2]),
+        )
+        .expect("checkpoint script");
+    let metadata_type_script = context
+        .build_script_with_hash_type(
+            &always_success_out_point,
+            ScriptHashType::Type,
+            Bytes::from(vec![5]),
+        )
+        .expect("metadata type script");
+
+    let staker_keypair = Generator::random_keypair();
+    let staker_identity = pubkey_to_eth_identity(&staker_keypair.1);
+    let input_data = axon_delegate_requirement_cell_data(input_commission_rate, 3)
+        .as_builder()
+        .l2_address(staker_identity.clone())
+        .build();
+    let commission_change = CommissionChange::new_builder()
+        .previous_rate(input_commission_rate.into())
+        .effective_epoch(axon_u64(3))
+        .build();
+    let output_requirement = input_data
+        .delegate_requirement()
+        .as_builder()
+        .commission_rate(output_commission_rate.into())
+        .build();
+    let output_data = input_data
+        .clone()
+        .as_builder()
+        .delegate_requirement(output_requirement)
+        .commission_change(commission_change)
+        .build();
+
+    let delegate_requirement_args = DelegateRequirementArgs::new_builder()
+        .metadata_type_id(axon_byte32(&metadata_type_script.calc_script_hash()))
+        .requirement_type_id(axon_array32_byte32([1u8; 32]))
+        .build();
+    let delegate_requirement_type_script = context
+        .build_script(&contract_out_point, delegate_requirement_args.as_bytes())
+        .expect("delegate requirement type script");
+
+    let input = CellInput::new_builder()
+        .previous_output(
+            context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000.pack())
+                    .lock(always_success_lock_script.clone())
+                    .type_(Some(delegate_requirement_type_script.clone()).pack())
+                    .build(),
+                input_data.as_bytes(),
+            ),
+        )
+        .build();
+    let outputs = vec![CellOutput::new_builder()
+        .capacity(1000.pack())
+        .lock(always_success_lock_script.clone())
+        .type_(Some(delegate_requirement_type_script).pack())
+        .build()];
+    let outputs_data = vec![output_data.as_bytes()];
+
+    // prepare metadata and checkpoint cell_dep
+    let metadata = Metadata::new_builder().epoch_len(axon_u32(100)).build();
+    let metadata_list = MetadataList::new_builder().push(metadata).build();
+    let meta_data = axon_metadata_data_by_script(
+        &metadata_type_script,
+        &always_success_lock_script.calc_script_hash(),
+        &checkpoint_type_script,
+        &always_success_lock_script,
+        &always_success_lock_script,
+        metadata_list,
+        1,
+        100,
+        100,
+        [0u8; 32],
+        &metadata_type_script.code_hash(),
+        &metadata_type_script.code_hash(),
+        &metadata_type_script.code_hash(),
+    );
+    let metadata_script_dep = CellDep::new_builder()
+        .out_point(
+            context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000.pack())
+                    .lock(always_success_lock_script.clone())
+                    .type_(Some(metadata_type_script.clone()).pack())
+                    .build(),
+                meta_data.as_bytes(),
+            ),
+        )
+        .build();
+    let checkpoint_data = axon_checkpoint_data(&metadata_type_script.calc_script_hash(), 1);
+    let checkpoint_script_dep = CellDep::new_builder()
+        .out_point(
+            context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000.pack())
+                    .lock(always_success_lock_script)
+                    .type_(Some(checkpoint_type_script).pack())
+                    .build(),
+                checkpoint_data.as_bytes(),
+            ),
+        )
+        .build();
+
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(contract_dep)
+        .cell_dep(always_success_script_dep)
+        .cell_dep(secp256k1_data_dep)
+        .cell_dep(metadata_script_dep)
+        .cell_dep(checkpoint_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // sign tx by staker
+    let signature = sign_eth_tx_hash(&tx, &staker_keypair.0);
+    let requirement_witness = RequirementWitness::new_builder()
+        .eth_sig(axon_byte65(signature))
+        .sig_mode(0.into())
+        .build();
+    let witness = WitnessArgs::new_builder()
+        .input_type(Some(requirement_witness.as_bytes()).pack())
+        .build();
+    sign_eth_tx(tx, witness)
+}
+
+#[test]
+fn test_update_requirement_success() {
+    // init context
+    let mut context = Context::default();
+    let tx = construct_update_requirement_tx(&mut context, 10, 15);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_update_requirement_fail_change_too_large() {
+    // init context
+    let mut context = Context::default();
+    let tx = construct_update_requirement_tx(&mut context, 10, 50);
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("CommissionChangeTooLarge");
+    assert_script_error(err, CommissionChangeTooLarge as i8);
+}
+
+// stake smt drops the released staker from `input_exited` to `output_exited` in the same
+// transaction
+fn construct_release_delegators_tx(
+    context: &mut Context,
+    input_exited: &[[u8; 20]],
+    output_exited: &[[u8; 20]],
+) -> TransactionView {
+    let staker = [1u8; 20];
+    let delegator = [3u8; 20];
+    let delegate_amount = 500u128;
+    let epoch = 0;
+    let inauguration_epoch = epoch + 2;
+
+    let smt_contract_bin: Bytes = Loader::default().load_binary("delegate-smt");
+    let smt_contract_out_point = context.deploy_cell(smt_contract_bin);
+    let smt_contract_dep = CellDep::new_builder()
+        .out_point(smt_contract_out_point.clone())
+        .build();
+    let stake_smt_contract_bin: Bytes = Loader::default().load_binary("stake-smt");
+    let stake_smt_contract_out_point = context.deploy_cell(stake_smt_contract_bin);
+    let stake_smt_contract_dep = CellDep::new_builder()
+        .out_point(stake_smt_contract_out_point.clone())
+        .build();
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+    let always_success_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("always_success script");
+    let checkpoint_type_script = context
+        .build_script_with_hash_type(
+            &always_success_out_point,
+            ScriptHashType::Type,
+            Bytes::from(vec![2]),
+        )
+        .expect("checkpoint script");
+    let xudt_type_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![4]))
+        .expect("sudt script");
+    let metadata_type_script = context
+        .build_script_with_hash_type(
+            &always_success_out_point,
+            ScriptHashType::Type,
+            Bytes::from(vec![5]),
+        )
+        .expect("metadata type script");
+    let metadata_type_id = metadata_type_script.calc_script_hash();
+    let delegate_smt_type_script = context
+        .build_script_with_hash_type(
+            &smt_contract_out_point,
+            ScriptHashType::Type,
+            Bytes::from(vec![8u8; 32]),
+        )
+        .expect("delegate smt type script");
+    let stake_smt_type_script = context
+        .build_script_with_hash_type(
+            &stake_smt_contract_out_point,
+            ScriptHashType::Type,
+            Bytes::from(vec![6u8; 32]),
+        )
+        .expect("stake smt type script");
+
+    // delegate and withdraw AT cells are locked by always success to focus on delegate smt cell
+    let delegate_at_lock_script = context
+        .build_script_with_hash_type(
+            &always_success_out_point,
+            ScriptHashType::Type,
+            DelegateArgs::new_builder()
+                .metadata_type_id(axon_byte32(&metadata_type_id))
+                .delegator_addr(axon_byte20_identity(&delegator))
+                .build()
+                .as_bytes(),
+        )
+        .expect("delegate at lock script");
+    let withdraw_lock_script = context
+        .build_script_with_hash_type(
+            &always_success_out_point,
+            ScriptHashType::Type,
+            WithdrawArgs::new_builder()
+                .addr(axon_byte20_identity(&delegator))
+                .metadata_type_id(axon_byte32(&metadata_type_id))
+                .build()
+                .as_bytes(),
+        )
+        .expect("withdraw lock script");
+
+    // delegate smt clears all delegations of the exited staker
+    let old_delegate_infos = vec![LockInfo {
+        addr: delegator,
+        amount: delegate_amount,
+    }]
+    .into_iter()
+    .collect::<BTreeSet<LockInfo>>();
+    let (old_delegate_root, old_delegate_proof) =
+        delegate_2layer_smt_root_proof(inauguration_epoch, &old_delegate_infos);
+    let (new_delegate_root, new_delegate_proof) =
+        delegate_2layer_smt_root_proof(inauguration_epoch, &BTreeSet::new());
+    let delegate_smt_data = |root: sparse_merkle_tree::H256| {
+        let smt_root = StakerSmtRoot::new_builder()
+            .staker(axon_byte20_identity(&staker))
+            .root(axon_array32_byte32(root.as_slice().try_into().unwrap()))
+            .build();
+        DelegateSmtCellData::new_builder()
+            .version(0.into())
+            .smt_roots(StakerSmtRoots::new_builder().push(smt_root).build())
+            .metadata_type_id(axon_byte32(&metadata_type_id))
+            .build()
+    };
+    let stake_group_info = StakeGroupInfo::new_builder()
+        .staker(axon_byte20_identity(&staker))
+        .delegate_infos(
+            DelegateInfos::new_builder()
+                .push(
+                    DelegateInfo::new_builder()
+                        .delegator_addr(axon_byte20_identity(&delegator))
+                        .amount(axon_u128(delegate_amount))
+                        .build(),
+                )
+                .build(),
+        )
+        .delegate_old_epoch_proof(axon_bytes(&old_delegate_proof.0))
+        .delegate_new_epoch_proof(axon_bytes(&new_delegate_proof.0))
+        .build();
+    let delegate_smt_witness = DelegateSmtWitness::new_builder()
+        .mode(RELEASE_MODE.into())
+        .update_info(
+            DelegateSmtUpdateInfo::new_builder()
+                .all_stake_group_infos(
+                    StakeGroupInfos::new_builder()
+                        .push(stake_group_info)
+                        .build(),
+                )
+                .build(),
+        )
+        .build();
+
+    let stake_smt_witness = StakeSmtWitness::new_builder()
+        .mode(RELEASE_MODE.into())
+        .build();
+    let stake_smt_data = |exited_stakers: &[[u8; 20]]| {
+        axon_stake_smt_cell_data(&BTreeSet::new(), &metadata_type_id, 0)
+            .as_builder()
+            .exited_stakers(axon_exited_stakers(exited_stakers))
+            .build()
+    };
+
+    let withdraw_data = |amount: u128| {
+        Bytes::from(axon_withdraw_at_cell_data(
+            amount,
+            axon_withdraw_at_cell_data_without_amount(vec![(inauguration_epoch, amount)]),
+        ))
+    };
+    // (lock, type, input data, output data)
+    let cells = vec![
+        (
+            always_success_lock_script.clone(),
+            delegate_smt_type_script.clone(),
+            delegate_smt_data(old_delegate_root).as_bytes(),
+            delegate_smt_data(new_delegate_root).as_bytes(),
+        ),
+        (
+            delegate_at_lock_script,
+            xudt_type_script.clone(),
+            Bytes::from(axon_delegate_at_cell_data(
+                delegate_amount,
+                DelegateAtCellData::new_builder().build(),
+            )),
+            Bytes::from(axon_delegate_at_cell_data(
+                0,
+                DelegateAtCellData::new_builder().build(),
+            )),
+        ),
+        (
+            withdraw_lock_script.clone(),
+            xudt_type_script.clone(),
+            withdraw_data(0),
+            withdraw_data(delegate_amount),
+        ),
+        (
+            always_success_lock_script.clone(),
+            stake_smt_type_script.clone(),
+            stake_smt_data(input_exited).as_bytes(),
+            stake_smt_data(output_exited).as_bytes(),
+        ),
+    ];
+    let mut inputs = Vec::new();
+    let mut outputs = Vec::new();
+    let mut outputs_data = Vec::new();
+    for (lock, type_, input_data, output_data) in cells {
+        let output = CellOutput::new_builder()
+            .capacity(1000.pack())
+            .lock(lock)
+            .type_(Some(type_).pack())
+            .build();
+        inputs.push(
+            CellInput::new_builder()
+                .previous_output(context.create_cell(output.clone(), input_data))
+                .build(),
+        );
+        outputs.push(output);
+        outputs_data.push(output_data);
+    }
+    // witnesses of delegate smt and stake smt cells, at the first and the last input
+    let witnesses = vec![
+        WitnessArgs::new_builder()
+            .input_type(Some(Bytes::from(delegate_smt_witness.as_bytes())).pack())
+            .build()
+            .as_bytes()
+            .pack(),
+        WitnessArgs::default().as_bytes().pack(),
+        WitnessArgs::default().as_bytes().pack(),
+        WitnessArgs::new_builder()
+            .input_type(Some(Bytes::from(stake_smt_witness.as_bytes())).pack())
+            .build()
+            .as_bytes()
+            .pack(),
+    ];
+    let metadata = Metadata::new_builder().epoch_len(axon_u32(100)).build();
+    let metadata_list = MetadataList::new_builder().push(metadata).build();
+    let at_code_hash = withdraw_lock_script.code_hash();
+    let meta_data = axon_metadata_data_by_script(
+        &metadata_type_script,
+        &xudt_type_script.calc_script_hash(),
+        &checkpoint_type_script,
+        &stake_smt_type_script,
+        &delegate_smt_type_script,
+        metadata_list,
+        epoch,
+        100,
+        100,
+        [0u8; 32],
+        &at_code_hash,
+        &at_code_hash,
+        &at_code_hash,
+    );
+    let metadata_script_dep = CellDep::new_builder()
+        .out_point(
+            context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000.pack())
+                    .lock(always_success_lock_script.clone())
+                    .type_(Some(metadata_type_script.clone()).pack())
+                    .build(),
+                meta_data.as_bytes(),
+            ),
+        )
+        .build();
+    let checkpoint_script_dep = CellDep::new_builder()
+        .out_point(
+            context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000.pack())
+                    .lock(always_success_lock_script.clone())
+                    .type_(Some(checkpoint_type_script).pack())
+                    .build(),
+                axon_checkpoint_data(&metadata_type_id, epoch).as_bytes(),
+            ),
+        )
+        .build();
+
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witnesses(witnesses)
+        .cell_dep(smt_contract_dep)
+        .cell_dep(stake_smt_contract_dep)
+        .cell_dep(always_success_script_dep)
+        .cell_dep(checkpoint_script_dep)
+        .cell_dep(metadata_script_dep)
+        .build();
+    context.complete_tx(tx)
+}
+
+#[test]
+fn test_release_delegators_success() {
+    // init context
+    let mut context = Context::default();
+    let (staker, other_staker) = ([1u8; 20], [2u8; 20]);
+    let tx =
+        construct_release_delegators_tx(&mut context, &[staker, other_staker], &[other_staker]);
 
     // run
     let cycles = context
@@ -2571,3 +3285,30 @@ fn test_delegate_requirement_success() {
         .expect("pass verification");
     println!("consume cycles: {}", cycles);
 }
+
+#[test]
+fn test_release_delegators_fail_not_exited() {
+    // init context
+    let mut context = Context::default();
+    let tx = construct_release_delegators_tx(&mut context, &[], &[]);
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("ReleaseStakerNotExited");
+    assert_script_error(err, ReleaseStakerNotExited as i8);
+}
+
+#[test]
+fn test_release_delegators_fail_staker_kept_exited() {
+    // init context
+    let mut context = Context::default();
+    let staker = [1u8; 20];
+    let tx = construct_release_delegators_tx(&mut context, &[staker], &[staker]);
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("ExitedStakersMismatch");
+    assert_script_error(err, ExitedStakersMismatch as i8);
+}
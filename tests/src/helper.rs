@@ -27,8 +27,8 @@ use molecule::prelude::*;
 use sparse_merkle_tree::CompiledMerkleProof;
 // use tiny_keccak::{Keccak, Hasher};
 use util::{
-    helper::pubkey_to_eth_addr,
-    smt::{u64_to_h256, LockInfo, TOP_SMT},
+    helper::{pubkey_to_eth_addr, RewardParamsObject},
+    smt::{reward_params_to_h256, u64_to_h256, LockInfo, TOP_SMT},
 };
 
 use crate::{
@@ -185,6 +185,28 @@ pub fn eth_addr(pubkey: Vec<u8>) -> basic::Identity {
         .build()
 }
 
+// eth address of a ckb secp256k1 pubkey, which holds x and y without the uncompressed prefix
+pub fn pubkey_to_eth_identity(pubkey: &Pubkey) -> basic::Identity {
+    let mut uncompressed = vec![4u8];
+    uncompressed.extend_from_slice(pubkey.as_bytes());
+    eth_addr(uncompressed)
+}
+
+// recoverable signature which can be verified by Secp256k1Eth::verify_alone
+pub fn sign_eth_message(key: &Privkey, message: [u8; 32]) -> Vec<u8> {
+    key.sign_recoverable(&H256::from(message))
+        .expect("sign")
+        .serialize()
+}
+
+pub fn sign_eth_tx_hash(tx: &TransactionView, key: &Privkey) -> Vec<u8> {
+    sign_eth_message(key, tx_hash(tx))
+}
+
+pub fn tx_hash(tx: &TransactionView) -> [u8; 32] {
+    tx.hash().raw_data().to_vec().try_into().unwrap()
+}
+
 // construct stake_at cell data based on version, l1_address, l2_address, metadata_type_id, delta
 pub fn axon_stake_at_cell_data_without_amount(
     version: u8,
@@ -221,14 +243,14 @@ pub fn axon_stake_at_cell_data(
 pub fn axon_delegate_at_cell_data_without_amount(
     version: u8,
     l1_address: &Vec<u8>,
-    l2_address: &Vec<u8>,
+    l2_address: Identity,
     metadata_type_id: &packed::Byte32,
     delta: axon_types::delegate::DelegateInfoDeltas,
 ) -> axon_types::delegate::DelegateAtCellData {
     let lock_data = axon_types::delegate::DelegateAtCellLockData::new_builder()
         .version(version.into())
         .l1_address(axon_identity(l1_address))
-        .l2_address(axon_identity(l2_address))
+        .l2_address(l2_address)
         .metadata_type_id(axon_byte32(metadata_type_id))
         .delegator_infos(delta)
         .build();
@@ -256,8 +278,12 @@ pub fn axon_delegate_requirement_cell_data(
         .commission_rate(commission_rate.into())
         .max_delegator_size(axon_u32(max_delegator_size))
         .build();
+    let commission_change = axon_types::delegate::CommissionChange::new_builder()
+        .previous_rate(commission_rate.into())
+        .build();
     axon_types::delegate::DelegateCellData::new_builder()
         .delegate_requirement(requirement)
+        .commission_change(commission_change)
         .build()
 }
 
@@ -346,6 +372,44 @@ pub fn axon_delegate_requirement_and_stake_at_cell(
     )
 }
 
+// stake smt cell read by delegate smt to learn which stakers have exited
+pub fn axon_stake_smt_cell_dep(
+    metadata_type_script: &Script,
+    always_success_out_point: &OutPoint,
+    always_success_lock_script: &Script,
+    context: &mut Context,
+    exited_stakers: &[[u8; 20]],
+) -> (CellDep, Script) {
+    let stake_smt_type_script = context
+        .build_script_with_hash_type(
+            &always_success_out_point,
+            ScriptHashType::Type,
+            Bytes::from(vec![6u8; 32]),
+        )
+        .expect("stake smt type script");
+    let stake_smt_data = axon_stake_smt_cell_data(
+        &BTreeSet::new(),
+        &metadata_type_script.calc_script_hash(),
+        0,
+    )
+    .as_builder()
+    .exited_stakers(axon_exited_stakers(exited_stakers))
+    .build();
+    let stake_smt_script_dep = CellDep::new_builder()
+        .out_point(
+            context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000.pack())
+                    .lock(always_success_lock_script.clone())
+                    .type_(Some(stake_smt_type_script.clone()).pack())
+                    .build(),
+                stake_smt_data.as_bytes(),
+            ),
+        )
+        .build();
+    (stake_smt_script_dep, stake_smt_type_script)
+}
+
 // construct stake_at cell data based on version, l1_address, l2_address, metadata_type_id, delta
 pub fn axon_withdraw_at_cell_data_without_amount(
     withdraw_infos: Vec<(u64, u128)>,
@@ -419,6 +483,8 @@ pub fn axon_metadata_data(
         .epoch(axon_u64(1))
         .metadata(metadata_list)
         .type_ids(type_ids)
+        .unbonding_epochs(axon_u64(2))
+        .inauguration_delay(axon_u64(2))
         .build()
 }
 
@@ -462,9 +528,45 @@ pub fn axon_metadata_data_by_script(
         .metadata(metadata_list)
         .type_ids(type_ids)
         .propose_count_smt_root(axon_array32_byte32(propose_count_smt_root))
+        .unbonding_epochs(axon_u64(2))
+        .inauguration_delay(axon_u64(2))
         .build()
 }
 
+pub fn axon_reward_params(
+    reward_params: &RewardParamsObject,
+) -> axon_types::reward::EpochRewardParams {
+    axon_types::reward::EpochRewardParams::new_builder()
+        .base_reward(axon_u128(reward_params.base_reward))
+        .half_epoch(axon_u64(reward_params.half_epoch))
+        .minimum_normal_propose_count(axon_u64(reward_params.minimum_normal_propose_count))
+        .propose_discount_rate(reward_params.propose_discount_rate.into())
+        .build()
+}
+
+// reward params smt recording `reward_params` for all `epochs`, and the proof of these epochs
+pub fn reward_params_smt_root_proof(
+    reward_params: &RewardParamsObject,
+    epochs: &[u64],
+) -> (sparse_merkle_tree::H256, Vec<u8>) {
+    let mut tree = TOP_SMT::default();
+    for epoch in epochs {
+        tree.update(u64_to_h256(*epoch), reward_params_to_h256(reward_params))
+            .expect("update reward params smt");
+    }
+    let keys = epochs
+        .iter()
+        .map(|epoch| u64_to_h256(*epoch))
+        .collect::<Vec<_>>();
+    let proof = tree
+        .merkle_proof(keys.clone())
+        .expect("merkle proof")
+        .compile(keys)
+        .expect("compile proof")
+        .0;
+    (*tree.root(), proof)
+}
+
 pub fn delegate_2layer_smt_root_proof(
     epoch: u64,
     delegate_infos: &BTreeSet<LockInfo>,
@@ -751,6 +853,12 @@ pub fn axon_stake_smt_cell_data(
         .build()
 }
 
+pub fn axon_exited_stakers(stakers: &[[u8; 20]]) -> axon_types::stake::IdentityVec {
+    axon_types::stake::IdentityVec::new_builder()
+        .set(stakers.iter().map(axon_byte20_identity).collect())
+        .build()
+}
+
 pub fn axon_stake_smt_cell_data_for_update_metadata_cell(
     stake_infos: &BTreeSet<LockInfo>,
     metadata_type_id: &packed::Byte32,
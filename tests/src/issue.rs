@@ -0,0 +1,259 @@
+use super::*;
+use axon_types::issue::{IssueArgs, IssueCellData};
+use ckb_testtool::ckb_types::{
+    bytes::Bytes,
+    core::{TransactionBuilder, TransactionView},
+    packed::*,
+    prelude::*,
+};
+use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use helper::*;
+use molecule::prelude::*;
+use util::error::Error::{IssueExceedMaxSupply, IssueSupplyMismatch, IssueUnauthorizedMint};
+
+#[derive(Clone, Copy)]
+enum MintAuthorizer {
+    RewardSmt,
+    Admin,
+    Nobody,
+}
+
+fn axon_issue_cell_data(
+    current_supply: u128,
+    max_supply: u128,
+    xudt_type_script: &Script,
+) -> IssueCellData {
+    IssueCellData::new_builder()
+        .version(0.into())
+        .current_supply(axon_u128(current_supply))
+        .max_suppley(axon_u128(max_supply))
+        .sudt_type_script(axon_byte32(&xudt_type_script.calc_script_hash()))
+        .build()
+}
+
+#[test]
+fn test_issue_creation_success() {
+    // init context
+    let mut context = Context::default();
+
+    let contract_bin: Bytes = Loader::default().load_binary("issue");
+    let contract_out_point = context.deploy_cell(contract_bin);
+    let contract_dep = CellDep::new_builder()
+        .out_point(contract_out_point.clone())
+        .build();
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("always_success script");
+    let always_success_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+    let xudt_type_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![6]))
+        .expect("xudt script");
+
+    // prepare tx inputs and outputs
+    let input = CellInput::new_builder()
+        .previous_output(
+            context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000.pack())
+                    .lock(always_success_lock_script.clone())
+                    .build(),
+                Bytes::new(),
+            ),
+        )
+        .build();
+
+    let issue_args = IssueArgs::new_builder()
+        .type_id(axon_bytes_byte32(&calc_type_id(&input, 0)))
+        .reward_smt_type_id(axon_array32_byte32([3u8; 32]))
+        .admin_lock_hash(axon_array32_byte32([2u8; 32]))
+        .build();
+    let issue_type_script = context
+        .build_script(&contract_out_point, issue_args.as_bytes())
+        .expect("issue script");
+
+    let outputs = vec![
+        // issue cell
+        CellOutput::new_builder()
+            .capacity(1000.pack())
+            .lock(always_success_lock_script.clone())
+            .type_(Some(issue_type_script).pack())
+            .build(),
+    ];
+    let outputs_data = vec![axon_issue_cell_data(0, 1000, &xudt_type_script).as_bytes()];
+
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(contract_dep)
+        .cell_dep(always_success_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+fn construct_issue_tx(
+    context: &mut Context,
+    old_supply: u128,
+    new_supply: u128,
+    max_supply: u128,
+    minted: u128,
+    authorizer: MintAuthorizer,
+) -> TransactionView {
+    let contract_bin: Bytes = Loader::default().load_binary("issue");
+    let contract_out_point = context.deploy_cell(contract_bin);
+    let contract_dep = CellDep::new_builder()
+        .out_point(contract_out_point.clone())
+        .build();
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("always_success script");
+    let always_success_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .expect("admin script");
+    let reward_smt_type_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![3]))
+        .expect("reward smt script");
+    let xudt_type_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![6]))
+        .expect("xudt script");
+
+    let issue_args = IssueArgs::new_builder()
+        .type_id(axon_array32_byte32([1u8; 32]))
+        .reward_smt_type_id(axon_byte32(&reward_smt_type_script.calc_script_hash()))
+        .admin_lock_hash(axon_byte32(&admin_lock_script.calc_script_hash()))
+        .build();
+    let issue_type_script = context
+        .build_script(&contract_out_point, issue_args.as_bytes())
+        .expect("issue script");
+    let issue_output = CellOutput::new_builder()
+        .capacity(1000.pack())
+        .lock(always_success_lock_script.clone())
+        .type_(Some(issue_type_script).pack())
+        .build();
+
+    let mut inputs = vec![CellInput::new_builder()
+        .previous_output(context.create_cell(
+            issue_output.clone(),
+            axon_issue_cell_data(old_supply, max_supply, &xudt_type_script).as_bytes(),
+        ))
+        .build()];
+    let mut outputs = vec![
+        issue_output,
+        // minted xudt cell
+        CellOutput::new_builder()
+            .capacity(1000.pack())
+            .lock(always_success_lock_script.clone())
+            .type_(Some(xudt_type_script.clone()).pack())
+            .build(),
+    ];
+    let mut outputs_data = vec![
+        axon_issue_cell_data(new_supply, max_supply, &xudt_type_script).as_bytes(),
+        Bytes::from(axon_normal_at_cell_data(minted, &[])),
+    ];
+
+    let authorizer_output = match authorizer {
+        MintAuthorizer::RewardSmt => Some(
+            CellOutput::new_builder()
+                .capacity(1000.pack())
+                .lock(always_success_lock_script.clone())
+                .type_(Some(reward_smt_type_script).pack())
+                .build(),
+        ),
+        MintAuthorizer::Admin => Some(
+            CellOutput::new_builder()
+                .capacity(1000.pack())
+                .lock(admin_lock_script)
+                .build(),
+        ),
+        MintAuthorizer::Nobody => None,
+    };
+    if let Some(authorizer_output) = authorizer_output {
+        inputs.push(
+            CellInput::new_builder()
+                .previous_output(context.create_cell(authorizer_output.clone(), Bytes::new()))
+                .build(),
+        );
+        outputs.push(authorizer_output);
+        outputs_data.push(Bytes::new());
+    }
+
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(contract_dep)
+        .cell_dep(always_success_script_dep)
+        .build();
+    context.complete_tx(tx)
+}
+
+#[test]
+fn test_issue_mint_by_reward_smt_success() {
+    let mut context = Context::default();
+    let tx = construct_issue_tx(&mut context, 100, 150, 1000, 50, MintAuthorizer::RewardSmt);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_issue_mint_by_admin_success() {
+    let mut context = Context::default();
+    let tx = construct_issue_tx(&mut context, 100, 1000, 1000, 900, MintAuthorizer::Admin);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_issue_fail_supply_mismatch() {
+    let mut context = Context::default();
+    // 50 xudt is minted but current_supply only increases by 40
+    let tx = construct_issue_tx(&mut context, 100, 140, 1000, 50, MintAuthorizer::RewardSmt);
+
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("IssueSupplyMismatch");
+    assert_script_error(err, IssueSupplyMismatch as i8);
+}
+
+#[test]
+fn test_issue_fail_exceed_max_supply() {
+    let mut context = Context::default();
+    let tx = construct_issue_tx(&mut context, 100, 1001, 1000, 901, MintAuthorizer::Admin);
+
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("IssueExceedMaxSupply");
+    assert_script_error(err, IssueExceedMaxSupply as i8);
+}
+
+#[test]
+fn test_issue_fail_unauthorized_mint() {
+    let mut context = Context::default();
+    let tx = construct_issue_tx(&mut context, 100, 150, 1000, 50, MintAuthorizer::Nobody);
+
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("IssueUnauthorizedMint");
+    assert_script_error(err, IssueUnauthorizedMint as i8);
+}
@@ -11,6 +11,7 @@ mod smt;
 
 mod checkpoint;
 mod delegate;
+mod issue;
 mod metadata;
 mod reward;
 mod selection;
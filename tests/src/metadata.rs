@@ -1,18 +1,22 @@
 use crate::delegate::TestDelegateInfo;
 use crate::smt::{
-    construct_epoch_smt, construct_epoch_smt_for_metadata_update, construct_lock_info_smt,
-    construct_propose_count_smt, TopSmtInfo,
+    construct_empty_epoch_smt_proof, construct_epoch_smt, construct_epoch_smt_for_metadata_update,
+    construct_lock_info_smt, construct_propose_count_smt, TopSmtInfo,
 };
 use std::collections::BTreeSet;
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
 
 use super::*;
 use axon_types::checkpoint::{CheckpointCellData, ProposeCount, ProposeCounts};
 use axon_types::metadata::{
-    DelegateInfo, DelegateProof, DelegateProofs, ElectionSmtProof, Metadata, MetadataArgs,
-    MetadataList, MetadataWitness, MinerGroupInfo, MinerGroupInfos, StakeSmtElectionInfo,
+    BytesVec, DelegateInfo, DelegateProof, DelegateProofs, ElectionSmtProof, GovernanceConfig,
+    GovernanceSignatures, IdentityVec, Metadata, MetadataArgs, MetadataCellData, MetadataList,
+    MetadataWitness, MinerGroupInfo, MinerGroupInfos, StakeSmtElectionInfo, TypeIdsUpgrade,
+    Validator, ValidatorList,
 };
+use axon_types::stake::DelegateRequirementInfo;
 use axon_types::withdraw::WithdrawArgs;
+use ckb_system_scripts::BUNDLED_CELL;
 use ckb_testtool::ckb_crypto::secp::{Generator, Privkey, Pubkey};
 use ckb_testtool::ckb_types::core::ScriptHashType;
 use ckb_testtool::ckb_types::{
@@ -21,15 +25,20 @@ use ckb_testtool::ckb_types::{
 use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
 use helper::*;
 use molecule::prelude::*;
-use util::error::Error::MetadataNotLastCheckpoint;
-use util::helper::ProposeCountObject;
-use util::smt::{u64_to_h256, LockInfo};
-
-#[test]
-fn test_metadata_creation_success() {
-    // init context
-    let mut context = Context::default();
-
+use ophelia::{Crypto, PrivateKey, Signature, ToPublicKey, UncompressedPublicKey};
+use ophelia_secp256k1::{Secp256k1Recoverable, Secp256k1RecoverablePrivateKey};
+use util::error::Error::{
+    GovernanceOutOfBounds, GovernanceUnauthorized, MetadataNotLastCheckpoint, MetadataSizeWrong,
+    TypeIdsUpgradeForbidden, TypeIdsUpgradeTimelocked,
+};
+use util::helper::{CommissionObject, ProposeCountObject, RewardParamsObject};
+use util::smt::{get_bottom_smt_root_commission, u64_to_h256, LockInfo, TOP_SMT};
+
+// `customize` adjusts the metadata cell data created
+fn construct_metadata_creation_tx<F>(context: &mut Context, customize: F) -> TransactionView
+where
+    F: FnOnce(MetadataCellData) -> MetadataCellData,
+{
     let contract_bin: Bytes = Loader::default().load_binary("metadata");
     let contract_out_point = context.deploy_cell(contract_bin);
     let contract_dep = CellDep::new_builder()
@@ -143,6 +152,7 @@ fn test_metadata_creation_success() {
         &metadata_type_script.code_hash(),
         &metadata_type_script.code_hash(),
     );
+    let output_meta_data = customize(output_meta_data);
 
     let outputs_data = vec![output_meta_data.as_bytes()];
 
@@ -155,7 +165,14 @@ fn test_metadata_creation_success() {
         .cell_dep(checkpoint_script_dep)
         .cell_dep(always_success_script_dep)
         .build();
-    let tx = context.complete_tx(tx);
+    context.complete_tx(tx)
+}
+
+#[test]
+fn test_metadata_creation_success() {
+    // init context
+    let mut context = Context::default();
+    let tx = construct_metadata_creation_tx(&mut context, |metadata| metadata);
 
     // run
     let cycles = context
@@ -164,6 +181,92 @@ fn test_metadata_creation_success() {
     println!("consume cycles: {}", cycles);
 }
 
+#[test]
+fn test_metadata_creation_fail_slash_rate_out_of_bounds() {
+    // init context
+    let mut context = Context::default();
+    let tx = construct_metadata_creation_tx(&mut context, |metadata| {
+        metadata.as_builder().slash_rate(101.into()).build()
+    });
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("GovernanceOutOfBounds");
+    assert_script_error(err, GovernanceOutOfBounds as i8);
+}
+
+#[test]
+fn test_metadata_creation_fail_inauguration_delay_out_of_bounds() {
+    // init context
+    let mut context = Context::default();
+    let tx = construct_metadata_creation_tx(&mut context, |metadata| {
+        metadata
+            .as_builder()
+            .inauguration_delay(axon_u64(u64::MAX))
+            .build()
+    });
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("GovernanceOutOfBounds");
+    assert_script_error(err, GovernanceOutOfBounds as i8);
+}
+
+#[test]
+fn test_metadata_creation_fail_zero_unbonding_epochs() {
+    // init context
+    let mut context = Context::default();
+    let tx = construct_metadata_creation_tx(&mut context, |metadata| {
+        metadata.as_builder().unbonding_epochs(axon_u64(0)).build()
+    });
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("GovernanceOutOfBounds");
+    assert_script_error(err, GovernanceOutOfBounds as i8);
+}
+
+#[test]
+fn test_metadata_creation_success_longer_delay() {
+    // init context
+    let mut context = Context::default();
+    let tx = construct_metadata_creation_tx(&mut context, |metadata| {
+        let metadata0 = metadata.metadata().get(0).unwrap();
+        metadata
+            .as_builder()
+            .inauguration_delay(axon_u64(3))
+            .metadata(MetadataList::new_builder().set(vec![metadata0; 3]).build())
+            .build()
+    });
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_metadata_creation_fail_delay_list_size_mismatch() {
+    // init context
+    let mut context = Context::default();
+    let tx = construct_metadata_creation_tx(&mut context, |metadata| {
+        metadata
+            .as_builder()
+            .inauguration_delay(axon_u64(3))
+            .build()
+    });
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("MetadataSizeWrong");
+    assert_script_error(err, MetadataSizeWrong as i8);
+}
+
 #[derive(Clone)]
 struct TestStakeInfo {
     keypair: (Privkey, Pubkey),
@@ -177,6 +280,18 @@ fn construct_metadata_tx(
     stakes: Vec<TestStakeInfo>,
     epoch_len: u32,
     period: u32,
+) -> TransactionView {
+    construct_metadata_tx_with_validator(context, stakes, epoch_len, period, false)
+}
+
+// with `current_validator`, the first staker is a validator of current epoch without requirement
+// cell, whose commission snapshot is the default one
+fn construct_metadata_tx_with_validator(
+    context: &mut Context,
+    stakes: Vec<TestStakeInfo>,
+    epoch_len: u32,
+    period: u32,
+    current_validator: bool,
 ) -> TransactionView {
     let current_epoch = 0;
     // let epoch_len = 100;
@@ -363,11 +478,29 @@ fn construct_metadata_tx(
     );
 
     // prepare metadata
-    let input_metadata0 = Metadata::new_builder()
+    let input_metadata1 = Metadata::new_builder()
         .epoch_len(axon_u32(epoch_len))
         .quorum(axon_u16(2))
         .build();
-    let input_metadata1 = input_metadata0.clone();
+    let validator_addr = pubkey_to_addr(&special_keypair.1.serialize());
+    let (input_metadata0, commissions) = if current_validator {
+        let validator = Validator::new_builder()
+            .address(axon_byte20_identity(&validator_addr))
+            .build();
+        let metadata = input_metadata1
+            .clone()
+            .as_builder()
+            .validators(ValidatorList::new_builder().push(validator).build())
+            .build();
+        let commission = CommissionObject {
+            addr: validator_addr,
+            commission_rate: 0,
+            delegate_threshold: 0,
+        };
+        (metadata, vec![commission])
+    } else {
+        (input_metadata1.clone(), vec![])
+    };
     let input_metadata_list = MetadataList::new_builder()
         .push(input_metadata0)
         .push(input_metadata1.clone())
@@ -567,6 +700,28 @@ fn construct_metadata_tx(
         .push(output_metadata0)
         .push(output_metadata1)
         .build();
+    let mut commission_top_tree = TOP_SMT::default();
+    commission_top_tree
+        .update(
+            u64_to_h256(current_epoch),
+            get_bottom_smt_root_commission(&commissions),
+        )
+        .expect("update");
+    let commission_proof = commission_top_tree
+        .merkle_proof(vec![u64_to_h256(current_epoch)])
+        .expect("merkle proof")
+        .compile(vec![u64_to_h256(current_epoch)])
+        .expect("compile proof")
+        .0;
+    // reward params in force during the ending epoch, period_len is unset so anyone proposes enough
+    let reward_params = RewardParamsObject {
+        base_reward: 100,
+        half_epoch: 100,
+        minimum_normal_propose_count: 0,
+        propose_discount_rate: 95,
+    };
+    let (reward_params_root, reward_params_proof) =
+        reward_params_smt_root_proof(&reward_params, &[current_epoch]);
     let output_meta_data = axon_metadata_data_by_script(
         &metadata_type_script.clone(),
         &stake_smt_type_script.calc_script_hash(),
@@ -581,7 +736,15 @@ fn construct_metadata_tx(
         &metadata_type_script.code_hash(),
         &metadata_type_script.code_hash(),
         &withdraw_lock_script.code_hash(),
-    );
+    )
+    .as_builder()
+    .commission_smt_root(axon_array32_byte32(
+        commission_top_tree.root().as_slice().try_into().unwrap(),
+    ))
+    .reward_params_smt_root(axon_array32_byte32(
+        reward_params_root.as_slice().try_into().unwrap(),
+    ))
+    .build();
 
     // assume only 1 staker has delegator
     let output_delegate_infos = stake0.delegators.clone();
@@ -665,6 +828,8 @@ fn construct_metadata_tx(
         .build();
     let metadata_witness = MetadataWitness::new_builder()
         .new_propose_proof(axon_bytes(&propose_count_proof))
+        .new_commission_proof(axon_bytes(&commission_proof))
+        .new_reward_params_proof(axon_bytes(&reward_params_proof))
         .smt_election_info(stake_smt_election_info)
         .build();
     let metadata_witness = WitnessArgs::new_builder()
@@ -685,10 +850,66 @@ fn construct_metadata_tx(
         .cell_dep(checkpoint_script_dep)
         .cell_dep(always_success_script_dep)
         .build();
+    let tx = if current_validator {
+        // stake AT cell of the validator leaves requirement info empty
+        let metadata_type_id = metadata_type_script.calc_script_hash();
+        let stake_at_lock_script = Script::new_builder()
+            .code_hash(metadata_type_script.code_hash())
+            .hash_type(ScriptHashType::Type.into())
+            .args(
+                [metadata_type_id.as_slice(), validator_addr.as_slice()]
+                    .concat()
+                    .pack(),
+            )
+            .build();
+        let stake_at_data = axon_stake_at_cell_data_without_amount(
+            0,
+            &special_keypair.1.serialize(),
+            axon_byte20_identity(&validator_addr),
+            &metadata_type_id,
+            axon_types::stake::StakeInfoDelta::default(),
+            DelegateRequirementInfo::default(),
+        );
+        let stake_at_dep = CellDep::new_builder()
+            .out_point(
+                context.create_cell(
+                    CellOutput::new_builder()
+                        .capacity(1000.pack())
+                        .lock(stake_at_lock_script)
+                        .type_(Some(stake_at_type_script).pack())
+                        .build(),
+                    Bytes::from(axon_stake_at_cell_data(1000, stake_at_data)),
+                ),
+            )
+            .build();
+        tx.as_advanced_builder().cell_dep(stake_at_dep).build()
+    } else {
+        tx
+    };
     let tx = context.complete_tx(tx);
     tx
 }
 
+#[test]
+fn test_metadata_success_validator_without_requirement() {
+    // init context
+    let mut context = Context::default();
+
+    let stake1 = TestStakeInfo {
+        keypair: Generator::random_keypair(),
+        propose_count: 100,
+        amount: 2000,
+        delegators: BTreeSet::new(),
+    };
+
+    let tx = construct_metadata_tx_with_validator(&mut context, vec![stake1], 100, 99, true);
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
 #[test]
 fn test_metadata_success_1staker_0delegator() {
     // init context
@@ -769,6 +990,28 @@ fn test_metadata_success_2stakers() {
     println!("consume cycles: {}", cycles);
 }
 
+#[test]
+fn test_metadata_success_2stakers_equal_amount() {
+    // miners with equal total amount are ordered by address, both are elected
+    // init context
+    let mut context = Context::default();
+
+    let stakes = (0..2)
+        .map(|_| TestStakeInfo {
+            keypair: Generator::random_keypair(),
+            propose_count: 100,
+            amount: 2000,
+            delegators: BTreeSet::<LockInfo>::new(),
+        })
+        .collect::<Vec<_>>();
+    let tx = construct_metadata_tx(&mut context, stakes, 100, 99);
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
 #[test]
 fn test_metadata_success_3stakers() {
     // only 2 will be selected
@@ -1205,6 +1448,14 @@ fn construct_metadata_tx_3stakers_1delegator_1validaor(
         .push(output_metadata0)
         .push(output_metadata1)
         .build();
+    let reward_params = RewardParamsObject {
+        base_reward: 100,
+        half_epoch: 100,
+        minimum_normal_propose_count: 0,
+        propose_discount_rate: 95,
+    };
+    let (reward_params_root, reward_params_proof) =
+        reward_params_smt_root_proof(&reward_params, &[current_epoch]);
     let output_meta_data = axon_metadata_data_by_script(
         &metadata_type_script.clone(),
         &stake_smt_type_script.calc_script_hash(),
@@ -1219,7 +1470,12 @@ fn construct_metadata_tx_3stakers_1delegator_1validaor(
         &metadata_type_script.code_hash(),
         &metadata_type_script.code_hash(),
         &withdraw_lock_script.code_hash(),
-    );
+    )
+    .as_builder()
+    .reward_params_smt_root(axon_array32_byte32(
+        reward_params_root.as_slice().try_into().unwrap(),
+    ))
+    .build();
 
     let output_delegate_smt_cell_data = axon_delegate_smt_cell_data_multiple_for_metadata_update(
         &out_test_delegate_infos,
@@ -1340,6 +1596,9 @@ fn construct_metadata_tx_3stakers_1delegator_1validaor(
         .build();
     let metadata_witness = MetadataWitness::new_builder()
         .new_propose_proof(axon_bytes(&propose_count_proof))
+        // no validator in current epoch, commission smt records nothing
+        .new_commission_proof(axon_bytes(&construct_empty_epoch_smt_proof(current_epoch)))
+        .new_reward_params_proof(axon_bytes(&reward_params_proof))
         .smt_election_info(stake_smt_election_info)
         .build();
     let metadata_witness = WitnessArgs::new_builder()
@@ -1363,3 +1622,382 @@ fn construct_metadata_tx_3stakers_1delegator_1validaor(
     let tx = context.complete_tx(tx);
     tx
 }
+
+fn governance_metadata(epoch_len: u32) -> Metadata {
+    Metadata::new_builder()
+        .epoch_len(axon_u32(epoch_len))
+        .period_len(axon_u32(100))
+        .quorum(axon_u16(2))
+        .gas_limit(axon_u64(30_000_000))
+        .gas_price(axon_u64(1))
+        .interval(axon_u32(3_000))
+        .propose_ratio(axon_u32(15))
+        .prevote_ratio(axon_u32(10))
+        .precommit_ratio(axon_u32(10))
+        .brake_ratio(axon_u32(10))
+        .tx_num_limit(axon_u32(20_000))
+        .max_tx_size(axon_u32(1_024 * 1_024))
+        .build()
+}
+
+fn governance_metadata_list(next_epoch_len: u32) -> MetadataList {
+    MetadataList::new_builder()
+        .push(governance_metadata(100))
+        .push(governance_metadata(next_epoch_len))
+        .build()
+}
+
+// update builds input and output metadata cell data from the default one
+fn construct_governance_tx(
+    context: &mut Context,
+    mode: u8,
+    signer_count: usize,
+    update: impl Fn(MetadataCellData) -> (MetadataCellData, MetadataCellData),
+) -> TransactionView {
+    let contract_bin: Bytes = Loader::default().load_binary("metadata");
+    let contract_out_point = context.deploy_cell(contract_bin);
+    let contract_dep = CellDep::new_builder()
+        .out_point(contract_out_point.clone())
+        .build();
+    let secp256k1_data_bin = BUNDLED_CELL.get("specs/cells/secp256k1_data").unwrap();
+    let secp256k1_data_out_point = context.deploy_cell(secp256k1_data_bin.to_vec().into());
+    let secp256k1_data_dep = CellDep::new_builder()
+        .out_point(secp256k1_data_out_point)
+        .build();
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("always_success script");
+    let always_success_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    let metadata_args = MetadataArgs::new_builder()
+        .metadata_type_id(axon_byte32(&[1u8; 32].pack()))
+        .build();
+    let metadata_type_script = context
+        .build_script_with_hash_type(
+            &contract_out_point,
+            ScriptHashType::Type,
+            Bytes::from(metadata_args.as_bytes()),
+        )
+        .expect("metadata type script");
+
+    // 2 of 3 multisig members
+    let priv_keys = [[0x11u8; 32], [0x22u8; 32], [0x33u8; 32]]
+        .iter()
+        .map(|key| Secp256k1RecoverablePrivateKey::try_from(key.as_slice()).unwrap())
+        .collect::<Vec<_>>();
+    let members = priv_keys
+        .iter()
+        .map(|key| eth_addr(key.pub_key().to_uncompressed_bytes().to_vec()))
+        .collect::<Vec<_>>();
+    let governance = GovernanceConfig::new_builder()
+        .mode(0.into())
+        .threshold(2.into())
+        .members(IdentityVec::new_builder().set(members).build())
+        .upgrade_delay(axon_u64(2))
+        .build();
+
+    let metadata_data = axon_metadata_data_by_script(
+        &metadata_type_script,
+        &metadata_type_script.calc_script_hash(),
+        &metadata_type_script,
+        &metadata_type_script,
+        &metadata_type_script,
+        governance_metadata_list(100),
+        1,
+        100,
+        100,
+        [0u8; 32],
+        &metadata_type_script.code_hash(),
+        &metadata_type_script.code_hash(),
+        &metadata_type_script.code_hash(),
+    )
+    .as_builder()
+    .governance(governance)
+    .build();
+    let (input_metadata_data, output_metadata_data) = update(metadata_data);
+
+    let metadata_cell = CellOutput::new_builder()
+        .capacity(1000.pack())
+        .lock(always_success_lock_script.clone())
+        .type_(Some(metadata_type_script.clone()).pack())
+        .build();
+    let input = CellInput::new_builder()
+        .previous_output(context.create_cell(metadata_cell.clone(), input_metadata_data.as_bytes()))
+        .build();
+
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .output(metadata_cell)
+        .output_data(output_metadata_data.as_bytes().pack())
+        .cell_dep(contract_dep)
+        .cell_dep(secp256k1_data_dep)
+        .cell_dep(always_success_script_dep)
+        .build();
+
+    // members sign the tx hash, which excludes witnesses
+    let msg = tx.hash();
+    let signatures = priv_keys[..signer_count]
+        .iter()
+        .map(|key| {
+            let signature = Secp256k1Recoverable::sign_message(&msg.as_bytes(), &key.to_bytes())
+                .unwrap()
+                .to_bytes()
+                .to_vec();
+            axon_bytes(&signature)
+        })
+        .collect::<Vec<_>>();
+    let governance_sigs = GovernanceSignatures::new_builder()
+        .signatures(BytesVec::new_builder().set(signatures).build())
+        .build();
+    let metadata_witness = MetadataWitness::new_builder()
+        .mode(mode.into())
+        .governance_sigs(governance_sigs)
+        .build();
+    let metadata_witness = WitnessArgs::new_builder()
+        .input_type(Some(Bytes::from(metadata_witness.as_bytes())).pack())
+        .build();
+
+    let tx = tx
+        .as_advanced_builder()
+        .witness(metadata_witness.as_bytes().pack())
+        .build();
+    context.complete_tx(tx)
+}
+
+#[test]
+fn test_metadata_governance_success() {
+    // init context
+    let mut context = Context::default();
+
+    let tx = construct_governance_tx(&mut context, 1, 2, |data| {
+        let output = data
+            .clone()
+            .as_builder()
+            .metadata(governance_metadata_list(200))
+            .build();
+        (data, output)
+    });
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_metadata_governance_success_longer_delay() {
+    // init context
+    let mut context = Context::default();
+
+    // with a 3-epoch delay governance schedules parameters of the last epoch of the list
+    let tx = construct_governance_tx(&mut context, 1, 2, |data| {
+        let list = |last_epoch_len| {
+            MetadataList::new_builder()
+                .push(governance_metadata(100))
+                .push(governance_metadata(100))
+                .push(governance_metadata(last_epoch_len))
+                .build()
+        };
+        let input = data
+            .as_builder()
+            .inauguration_delay(axon_u64(3))
+            .metadata(list(100))
+            .build();
+        let output = input.clone().as_builder().metadata(list(200)).build();
+        (input, output)
+    });
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_metadata_governance_fail_out_of_bounds() {
+    // init context
+    let mut context = Context::default();
+
+    let tx = construct_governance_tx(&mut context, 1, 2, |data| {
+        let output = data
+            .clone()
+            .as_builder()
+            .metadata(governance_metadata_list(0))
+            .build();
+        (data, output)
+    });
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("GovernanceOutOfBounds");
+    assert_script_error(err, GovernanceOutOfBounds as i8);
+}
+
+#[test]
+fn test_metadata_governance_reward_params_success() {
+    // init context
+    let mut context = Context::default();
+
+    let tx = construct_governance_tx(&mut context, 1, 2, |data| {
+        let output = data
+            .clone()
+            .as_builder()
+            .base_reward(axon_u128(200))
+            .half_epoch(axon_u64(50))
+            .propose_minimum_rate(90.into())
+            .propose_discount_rate(80.into())
+            .build();
+        (data, output)
+    });
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_metadata_governance_fail_zero_half_epoch() {
+    // init context
+    let mut context = Context::default();
+
+    let tx = construct_governance_tx(&mut context, 1, 2, |data| {
+        let output = data.clone().as_builder().half_epoch(axon_u64(0)).build();
+        (data, output)
+    });
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("GovernanceOutOfBounds");
+    assert_script_error(err, GovernanceOutOfBounds as i8);
+}
+
+#[test]
+fn test_metadata_governance_fail_below_threshold() {
+    // init context
+    let mut context = Context::default();
+
+    let tx = construct_governance_tx(&mut context, 1, 1, |data| {
+        let output = data
+            .clone()
+            .as_builder()
+            .metadata(governance_metadata_list(200))
+            .build();
+        (data, output)
+    });
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("GovernanceUnauthorized");
+    assert_script_error(err, GovernanceUnauthorized as i8);
+}
+
+// reward code hash is swapped to a new one
+fn upgrade_type_ids(data: &MetadataCellData, effective_epoch: u64) -> TypeIdsUpgrade {
+    let type_ids = data
+        .type_ids()
+        .as_builder()
+        .reward_code_hash(axon_byte32(&[9u8; 32].pack()))
+        .build();
+    TypeIdsUpgrade::new_builder()
+        .effective_epoch(axon_u64(effective_epoch))
+        .type_ids(type_ids)
+        .build()
+}
+
+#[test]
+fn test_metadata_type_ids_upgrade_proposal_success() {
+    // init context
+    let mut context = Context::default();
+
+    // metadata epoch is 1 and upgrade delay is 2
+    let tx = construct_governance_tx(&mut context, 2, 2, |data| {
+        let output = data
+            .clone()
+            .as_builder()
+            .type_ids_upgrade(upgrade_type_ids(&data, 3))
+            .build();
+        (data, output)
+    });
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_metadata_type_ids_upgrade_proposal_fail_lock_code_hash() {
+    // init context
+    let mut context = Context::default();
+
+    let tx = construct_governance_tx(&mut context, 2, 2, |data| {
+        let upgrade = upgrade_type_ids(&data, 3);
+        let type_ids = upgrade
+            .type_ids()
+            .as_builder()
+            .stake_at_code_hash(axon_byte32(&[9u8; 32].pack()))
+            .build();
+        let upgrade = upgrade.as_builder().type_ids(type_ids).build();
+        let output = data.clone().as_builder().type_ids_upgrade(upgrade).build();
+        (data, output)
+    });
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("TypeIdsUpgradeForbidden");
+    assert_script_error(err, TypeIdsUpgradeForbidden as i8);
+}
+
+#[test]
+fn test_metadata_type_ids_upgrade_application_success() {
+    // init context
+    let mut context = Context::default();
+
+    let tx = construct_governance_tx(&mut context, 3, 0, |data| {
+        let upgrade = upgrade_type_ids(&data, 3);
+        let input = data
+            .as_builder()
+            .epoch(axon_u64(3))
+            .type_ids_upgrade(upgrade.clone())
+            .build();
+        let output = input
+            .clone()
+            .as_builder()
+            .type_ids(upgrade.type_ids())
+            .type_ids_upgrade(TypeIdsUpgrade::default())
+            .build();
+        (input, output)
+    });
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_metadata_type_ids_upgrade_application_fail_timelocked() {
+    // init context
+    let mut context = Context::default();
+
+    let tx = construct_governance_tx(&mut context, 3, 0, |data| {
+        let upgrade = upgrade_type_ids(&data, 3);
+        let input = data.as_builder().type_ids_upgrade(upgrade.clone()).build();
+        let output = input
+            .clone()
+            .as_builder()
+            .type_ids(upgrade.type_ids())
+            .type_ids_upgrade(TypeIdsUpgrade::default())
+            .build();
+        (input, output)
+    });
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("TypeIdsUpgradeTimelocked");
+    assert_script_error(err, TypeIdsUpgradeTimelocked as i8);
+}
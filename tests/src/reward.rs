@@ -6,9 +6,12 @@ use super::*;
 use axon_types::checkpoint::CheckpointCellData;
 use axon_types::metadata::{Metadata, MetadataList, Validator, ValidatorList};
 use axon_types::reward::{
-    EpochRewardStakeInfo, EpochRewardStakeInfos, NotClaimInfo, RewardDelegateInfo,
+    BatchEpochRewardInfo, BatchEpochRewardInfos, BatchRewardInfos, BatchRewardStakeInfo,
+    BatchRewardStakeInfos, BatchStakerInfo, BatchStakerInfos, EpochRewardStakeInfo,
+    EpochRewardStakeInfos, NotClaimInfo, RewardBeneficiary, RewardDelegateInfo,
     RewardDelegateInfos, RewardSmtCellData, RewardStakeInfo, RewardStakeInfos, RewardWitness,
 };
+use axon_types::stake::DelegateRequirementInfo;
 use ckb_system_scripts::BUNDLED_CELL;
 use ckb_testtool::ckb_crypto::secp::{Generator, Privkey, Pubkey};
 use ckb_testtool::ckb_types::{
@@ -21,10 +24,15 @@ use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
 use helper::*;
 use molecule::prelude::*;
 use sparse_merkle_tree::{blake2b::Blake2bHasher, CompiledMerkleProof, H256};
-use util::error::Error::{RewardWrongAmount, RewardWrongOwner};
+use util::error::Error::{
+    RewardBatchMismatch, RewardClaimEpochMismatch, RewardClaimProofFail, RewardClaimUnauthorized,
+    RewardCommissionBottomFail, RewardCompoundMismatch, RewardParamsFail, RewardWrongAmount,
+    RewardWrongOwner, SignatureMismatch,
+};
+use util::helper::RewardParamsObject;
 use util::smt::{
-    addr_to_h256, u128_to_h256, u64_to_h256, BottomValue, EpochValue, LockInfo, ProposeBottomValue,
-    BOTTOM_SMT, CLAIM_SMT, PROPOSE_BOTTOM_SMT, TOP_SMT,
+    addr_to_h256, commission_to_h256, u128_to_h256, u64_to_h256, BottomValue, EpochValue, LockInfo,
+    ProposeBottomValue, BOTTOM_SMT, CLAIM_SMT, COMMISSION_BOTTOM_SMT, PROPOSE_BOTTOM_SMT, TOP_SMT,
 };
 
 #[test]
@@ -118,6 +126,66 @@ fn construct_reward_tx_with_reward_amount(
     delegator_keypair: (Privkey, Pubkey),
     miner_keypair: (Privkey, Pubkey),
     reward_keypair: (Privkey, Pubkey),
+) -> TransactionView {
+    construct_reward_tx_with_commission_rate(
+        context,
+        reward_amount,
+        staker_keypair,
+        delegator_keypair,
+        miner_keypair,
+        reward_keypair,
+        10,
+    )
+}
+
+// commission rate recorded by metadata is always 10%, the witness claims `commission_rate`
+fn construct_reward_tx_with_commission_rate(
+    context: &mut Context,
+    reward_amount: u128,
+    staker_keypair: (Privkey, Pubkey),
+    delegator_keypair: (Privkey, Pubkey),
+    miner_keypair: (Privkey, Pubkey),
+    reward_keypair: (Privkey, Pubkey),
+    commission_rate: u8,
+) -> TransactionView {
+    construct_reward_tx_with_claim_format(
+        context,
+        reward_amount,
+        staker_keypair,
+        delegator_keypair,
+        miner_keypair,
+        reward_keypair,
+        commission_rate,
+        ClaimFormat::PerEpoch,
+        Payout::Owner,
+    )
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ClaimFormat {
+    PerEpoch,
+    Batched,
+    Both, // reward_infos and batch_reward_infos are both set
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Payout {
+    Owner,
+    Beneficiary,
+    CompoundStake(u128, u128), // pending unstake of the input stake at cell, stake delta of the output one
+    CompoundDelegate(u128),    // delegate delta to the staker in the output delegate at cell
+}
+
+fn construct_reward_tx_with_claim_format(
+    context: &mut Context,
+    reward_amount: u128,
+    staker_keypair: (Privkey, Pubkey),
+    delegator_keypair: (Privkey, Pubkey),
+    miner_keypair: (Privkey, Pubkey),
+    reward_keypair: (Privkey, Pubkey),
+    commission_rate: u8,
+    claim_format: ClaimFormat,
+    payout: Payout,
 ) -> TransactionView {
     let contract_bin: Bytes = Loader::default().load_binary("reward");
     let contract_out_point = context.deploy_cell(contract_bin);
@@ -322,6 +390,38 @@ fn construct_reward_tx_with_reward_amount(
         .0;
     let propose_count_smt_top_tree_root = propose_count_smt_top_tree.root();
 
+    let mut commission_smt_bottom_tree = COMMISSION_BOTTOM_SMT::default();
+    commission_smt_bottom_tree
+        .update(addr_to_h256(&staker_addr), commission_to_h256(10, 0))
+        .expect("update commission smt bottom tree");
+    let commission_smt_bottom_proof = commission_smt_bottom_tree
+        .merkle_proof(vec![addr_to_h256(&staker_addr)])
+        .unwrap()
+        .compile(vec![addr_to_h256(&staker_addr)])
+        .unwrap()
+        .0;
+    let commission_smt_bottom_tree_root = *commission_smt_bottom_tree.root();
+    let mut commission_smt_top_tree = TOP_SMT::default();
+    commission_smt_top_tree
+        .update(u64_to_h256(claim_epoch), commission_smt_bottom_tree_root)
+        .expect("update commission smt top tree");
+    let commission_smt_top_proof = commission_smt_top_tree
+        .merkle_proof(vec![u64_to_h256(claim_epoch)])
+        .unwrap()
+        .compile(vec![u64_to_h256(claim_epoch)])
+        .unwrap()
+        .0;
+
+    // reward params recorded for the claimed epoch, governance has doubled base reward since then
+    let reward_params = RewardParamsObject {
+        base_reward: 1000,
+        half_epoch: 100,
+        minimum_normal_propose_count: (epoch_len * period_len) as u64 * 95 / 100,
+        propose_discount_rate: 95,
+    };
+    let (reward_params_root, reward_params_proof) =
+        reward_params_smt_root_proof(&reward_params, &[claim_epoch]);
+
     let meta_data = axon_metadata_data_by_script(
         &metadata_type_script.clone(),
         &at_type_script.calc_script_hash(),
@@ -330,16 +430,28 @@ fn construct_reward_tx_with_reward_amount(
         &delegate_smt_type_script,
         metadata_list.clone(),
         current_epoch,
-        1000,
+        2000,
         100,
         propose_count_smt_top_tree_root
             .as_slice()
             .try_into()
             .unwrap(),
         &stake_at_lock_script.code_hash(),
+        &stake_at_lock_script.code_hash(),
         &metadata_type_script.code_hash(),
-        &metadata_type_script.code_hash(),
-    );
+    )
+    .as_builder()
+    .commission_smt_root(axon_array32_byte32(
+        commission_smt_top_tree
+            .root()
+            .as_slice()
+            .try_into()
+            .unwrap(),
+    ))
+    .reward_params_smt_root(axon_array32_byte32(
+        reward_params_root.as_slice().try_into().unwrap(),
+    ))
+    .build();
 
     let metadata_script_dep = CellDep::new_builder()
         .out_point(
@@ -354,12 +466,13 @@ fn construct_reward_tx_with_reward_amount(
         )
         .build();
 
+    let miner_addr = pubkey_to_addr(&miner_keypair.1.serialize());
     let old_claim_tree = CLAIM_SMT::default();
     let old_claim_proof = old_claim_tree
-        .merkle_proof(vec![addr_to_h256(&staker_addr)])
+        .merkle_proof(vec![addr_to_h256(&miner_addr)])
         .unwrap();
     let old_claim_proof = old_claim_proof
-        .compile(vec![addr_to_h256(&staker_addr)])
+        .compile(vec![addr_to_h256(&miner_addr)])
         .unwrap()
         .0;
     let old_not_claim_info = NotClaimInfo::new_builder()
@@ -371,6 +484,35 @@ fn construct_reward_tx_with_reward_amount(
         old_not_claim_info,
         old_claim_tree.root()
     );
+    let secp256k1_data_bin = BUNDLED_CELL.get("specs/cells/secp256k1_data").unwrap();
+    let secp256k1_data_out_point = context.deploy_cell(secp256k1_data_bin.to_vec().into());
+    let reward_addr = pubkey_to_addr(&reward_keypair.1.serialize());
+    let secp256k1_blake2b_lock_script = context
+        .build_script(&secp256k1_data_out_point, Bytes::from(reward_addr.to_vec()))
+        .expect("always_success script");
+
+    // the normal at cell of reward_keypair is registered as the miner's beneficiary
+    let beneficiary_lock_hash: [u8; 32] = if payout == Payout::Beneficiary {
+        secp256k1_blake2b_lock_script
+            .calc_script_hash()
+            .as_slice()
+            .try_into()
+            .unwrap()
+    } else {
+        [0u8; 32]
+    };
+    let mut beneficiary_tree = TOP_SMT::default();
+    beneficiary_tree
+        .update(addr_to_h256(&miner_addr), beneficiary_lock_hash.into())
+        .expect("update beneficiary smt");
+    let beneficiary_proof = beneficiary_tree
+        .merkle_proof(vec![addr_to_h256(&miner_addr)])
+        .unwrap()
+        .compile(vec![addr_to_h256(&miner_addr)])
+        .unwrap()
+        .0;
+    let beneficiary_smt_root: [u8; 32] = beneficiary_tree.root().as_slice().try_into().unwrap();
+
     let input_reward_smt_data = axon_reward_smt_data(
         metadata_type_script
             .calc_script_hash()
@@ -378,8 +520,11 @@ fn construct_reward_tx_with_reward_amount(
             .try_into()
             .unwrap(),
         old_claim_tree.root().as_slice().try_into().unwrap(),
-    );
-    let inputs = vec![
+    )
+    .as_builder()
+    .beneficiary_smt_root(axon_array32_byte32(beneficiary_smt_root))
+    .build();
+    let mut inputs = vec![
         input0,
         // reward smt cell
         CellInput::new_builder()
@@ -396,25 +541,13 @@ fn construct_reward_tx_with_reward_amount(
             .build(),
     ];
 
-    let secp256k1_data_bin = BUNDLED_CELL.get("specs/cells/secp256k1_data").unwrap();
-    let secp256k1_data_out_point = context.deploy_cell(secp256k1_data_bin.to_vec().into());
-    let reward_addr = pubkey_to_addr(&reward_keypair.1.serialize());
-    let secp256k1_blake2b_lock_script = context
-        .build_script(&secp256k1_data_out_point, Bytes::from(reward_addr.to_vec()))
-        .expect("always_success script");
-    let outputs = vec![
+    let mut outputs = vec![
         // reward smt cell
         CellOutput::new_builder()
             .capacity(1000.pack())
             .lock(always_success_lock_script.clone())
             .type_(Some(reward_type_script.clone()).pack())
             .build(),
-        // normal at cell
-        CellOutput::new_builder()
-            .capacity(1000.pack())
-            .lock(secp256k1_blake2b_lock_script.clone())
-            .type_(Some(at_type_script.clone()).pack())
-            .build(),
     ];
 
     let mut new_claim_tree = CLAIM_SMT::default();
@@ -423,15 +556,15 @@ fn construct_reward_tx_with_reward_amount(
     let minimum_not_claim_epoch = claim_epoch + 1;
     new_claim_tree
         .update(
-            addr_to_h256(&staker_addr),
+            addr_to_h256(&miner_addr),
             EpochValue(minimum_not_claim_epoch),
         )
         .expect("update");
     let new_claim_proof = new_claim_tree
-        .merkle_proof(vec![addr_to_h256(&staker_addr)])
+        .merkle_proof(vec![addr_to_h256(&miner_addr)])
         .unwrap();
     let new_claim_proof = new_claim_proof
-        .compile(vec![addr_to_h256(&staker_addr)])
+        .compile(vec![addr_to_h256(&miner_addr)])
         .unwrap()
         .0;
     let new_not_claim_info = NotClaimInfo::new_builder()
@@ -446,11 +579,121 @@ fn construct_reward_tx_with_reward_amount(
             .try_into()
             .unwrap(),
         new_claim_tree.root().as_slice().try_into().unwrap(),
-    );
-    let outputs_data = vec![
-        output_reward_smt_data.as_bytes(),
-        Bytes::from(axon_normal_at_cell_data(reward_amount, &[])),
-    ];
+    )
+    .as_builder()
+    .beneficiary_smt_root(axon_array32_byte32(beneficiary_smt_root))
+    .build();
+    let mut outputs_data = vec![output_reward_smt_data.as_bytes()];
+    if let Payout::CompoundStake(input_unstake, output_stake_delta) = payout {
+        // the reward is added to the staker's stake at cell, which has 100 at before
+        let stake_at_data = |amount: u128, delta: axon_types::stake::StakeInfoDelta| {
+            Bytes::from(axon_stake_at_cell_data(
+                amount,
+                axon_stake_at_cell_data_without_amount(
+                    0,
+                    &staker_keypair.1.serialize(),
+                    axon_byte20_identity(&staker_addr),
+                    &metadata_type_script.calc_script_hash(),
+                    delta,
+                    DelegateRequirementInfo::new_builder().build(),
+                ),
+            ))
+        };
+        let stake_at_output = CellOutput::new_builder()
+            .capacity(1000.pack())
+            .lock(stake_at_lock_script.clone())
+            .type_(Some(at_type_script.clone()).pack())
+            .build();
+        inputs.push(
+            CellInput::new_builder()
+                .previous_output(
+                    context.create_cell(
+                        stake_at_output.clone(),
+                        stake_at_data(
+                            100,
+                            axon_types::stake::StakeInfoDelta::new_builder()
+                                .amount(axon_u128(input_unstake))
+                                .inauguration_epoch(axon_u64(current_epoch + 2))
+                                .build(),
+                        ),
+                    ),
+                )
+                .build(),
+        );
+        outputs.push(stake_at_output);
+        outputs_data.push(stake_at_data(
+            100 + reward_amount,
+            axon_types::stake::StakeInfoDelta::new_builder()
+                .is_increase(1.into())
+                .amount(axon_u128(output_stake_delta))
+                .inauguration_epoch(axon_u64(current_epoch + 2))
+                .build(),
+        ));
+    } else if let Payout::CompoundDelegate(output_delegate_delta) = payout {
+        // the reward is delegated to the staker from the miner's delegate at cell, which has 100 at
+        // and no delta before
+        let mut delegate_at_args = metadata_type_script.calc_script_hash().as_slice().to_vec();
+        delegate_at_args.extend_from_slice(&miner_addr);
+        let delegate_at_lock_script = context
+            .build_script_with_hash_type(
+                &always_success_out_point,
+                ScriptHashType::Type,
+                Bytes::from(delegate_at_args),
+            )
+            .expect("delegate at script");
+        let delegate_at_data = |amount: u128, delta: axon_types::delegate::DelegateInfoDeltas| {
+            Bytes::from(axon_delegate_at_cell_data(
+                amount,
+                axon_delegate_at_cell_data_without_amount(
+                    0,
+                    &miner_keypair.1.serialize(),
+                    axon_byte20_identity(&miner_addr),
+                    &metadata_type_script.calc_script_hash(),
+                    delta,
+                ),
+            ))
+        };
+        let delegate_at_output = CellOutput::new_builder()
+            .capacity(1000.pack())
+            .lock(delegate_at_lock_script)
+            .type_(Some(at_type_script.clone()).pack())
+            .build();
+        inputs.push(
+            CellInput::new_builder()
+                .previous_output(context.create_cell(
+                    delegate_at_output.clone(),
+                    delegate_at_data(
+                        100,
+                        axon_types::delegate::DelegateInfoDeltas::new_builder().build(),
+                    ),
+                ))
+                .build(),
+        );
+        outputs.push(delegate_at_output);
+        outputs_data.push(delegate_at_data(
+            100 + reward_amount,
+            axon_types::delegate::DelegateInfoDeltas::new_builder()
+                .push(
+                    axon_types::delegate::DelegateInfoDelta::new_builder()
+                        .is_increase(1.into())
+                        .staker(axon_byte20_identity(&staker_addr))
+                        .amount(axon_u128(output_delegate_delta))
+                        .inauguration_epoch(axon_u64(current_epoch + 2))
+                        .build(),
+                )
+                .build(),
+        ));
+    } else {
+        // normal at cell
+        outputs.push(
+            CellOutput::new_builder()
+                .capacity(1000.pack())
+                .lock(secp256k1_blake2b_lock_script.clone())
+                .type_(Some(at_type_script.clone()).pack())
+                .build(),
+        );
+        outputs_data.push(Bytes::from(axon_normal_at_cell_data(reward_amount, &[])));
+    }
 
     let reward_delegate_info = RewardDelegateInfo::new_builder()
         .delegator_addr(axon_identity(&delegator_keypair.1.serialize()))
@@ -463,8 +706,10 @@ fn construct_reward_tx_with_reward_amount(
         .validator(axon_identity(&staker_keypair.1.serialize()))
         .staker_amount(axon_u128(stake_amount))
         .propose_count(axon_u64(propose_count))
-        .delegate_infos(delegate_infos)
+        .delegate_infos(delegate_infos.clone())
         .delegate_epoch_proof(axon_bytes(&delegate_epoch_proof.0.to_vec()))
+        .commission_rate(commission_rate.into())
+        .delegate_threshold(axon_u128(0))
         .build();
     let reward_stake_infos = RewardStakeInfos::new_builder()
         .push(reward_stake_info)
@@ -519,17 +764,93 @@ fn construct_reward_tx_with_reward_amount(
         .count_root(axon_bytes(
             &propose_count_smt_bottom_tree_root.as_slice().to_vec(),
         ))
+        .commission_epoch_proof(axon_bytes(&commission_smt_top_proof))
+        .commission_proof(axon_bytes(&commission_smt_bottom_proof))
+        .commission_root(axon_bytes(
+            &commission_smt_bottom_tree_root.as_slice().to_vec(),
+        ))
+        .reward_params(axon_reward_params(&reward_params))
+        .reward_params_proof(axon_bytes(&reward_params_proof))
         .reward_stake_infos(reward_stake_infos)
         .build();
     let epoch_reward_stake_infos = EpochRewardStakeInfos::new_builder()
         .push(epoch_reward_stake_info)
         .build();
-    let reward_witness = RewardWitness::new_builder()
+
+    // only one epoch is claimed, so the batched top proofs are the same as the per epoch ones
+    let batch_stake_info = BatchRewardStakeInfo::new_builder()
+        .staker_index(axon_u32(0))
+        .staker_amount(axon_u128(stake_amount))
+        .propose_count(axon_u64(propose_count))
+        .delegate_infos(delegate_infos)
+        .commission_rate(commission_rate.into())
+        .delegate_threshold(axon_u128(0))
+        .build();
+    let batch_epoch_info = BatchEpochRewardInfo::new_builder()
+        .reward_stake_infos(
+            BatchRewardStakeInfos::new_builder()
+                .push(batch_stake_info)
+                .build(),
+        )
+        .amount_proof(axon_bytes(&stake_smt_bottom_proof))
+        .amount_root(axon_bytes(&stake_smt_bottom_tree_root.as_slice().to_vec()))
+        .count_proof(axon_bytes(&propose_count_smt_bottom_proof))
+        .count_root(axon_bytes(
+            &propose_count_smt_bottom_tree_root.as_slice().to_vec(),
+        ))
+        .commission_proof(axon_bytes(&commission_smt_bottom_proof))
+        .commission_root(axon_bytes(
+            &commission_smt_bottom_tree_root.as_slice().to_vec(),
+        ))
+        .reward_params(axon_reward_params(&reward_params))
+        .build();
+    let batch_staker_info = BatchStakerInfo::new_builder()
+        .validator(axon_identity(&staker_keypair.1.serialize()))
+        .delegate_epoch_proof(axon_bytes(&delegate_epoch_proof.0.to_vec()))
+        .build();
+    let batch_reward_infos = BatchRewardInfos::new_builder()
+        .staker_infos(
+            BatchStakerInfos::new_builder()
+                .push(batch_staker_info)
+                .build(),
+        )
+        .epoch_infos(
+            BatchEpochRewardInfos::new_builder()
+                .push(batch_epoch_info)
+                .build(),
+        )
+        .amount_epoch_proof(axon_bytes(&stake_smt_top_proof))
+        .count_epoch_proof(axon_bytes(&propose_count_smt_top_proof))
+        .commission_epoch_proof(axon_bytes(&commission_smt_top_proof))
+        .reward_params_proof(axon_bytes(&reward_params_proof))
+        .build();
+
+    let mut reward_witness = RewardWitness::new_builder()
         .miner(axon_identity(&miner_keypair.1.serialize()))
         .old_not_claim_info(old_not_claim_info)
-        .reward_infos(epoch_reward_stake_infos)
         .new_not_claim_info(new_not_claim_info)
-        .build();
+        .beneficiary(
+            RewardBeneficiary::new_builder()
+                .lock_hash(axon_array32_byte32(beneficiary_lock_hash))
+                .proof(axon_bytes(&beneficiary_proof))
+                .build(),
+        );
+    match payout {
+        Payout::CompoundStake(..) => reward_witness = reward_witness.mode(2.into()),
+        Payout::CompoundDelegate(_) => {
+            reward_witness = reward_witness
+                .mode(2.into())
+                .compound_staker(axon_byte20_identity(&staker_addr))
+        }
+        _ => {}
+    }
+    if claim_format != ClaimFormat::Batched {
+        reward_witness = reward_witness.reward_infos(epoch_reward_stake_infos);
+    }
+    if claim_format != ClaimFormat::PerEpoch {
+        reward_witness = reward_witness.batch_reward_infos(batch_reward_infos);
+    }
+    let reward_witness = reward_witness.build();
     let reward_witness = WitnessArgs::new_builder()
         .input_type(Some(Bytes::from(reward_witness.as_bytes())).pack())
         .build();
@@ -706,6 +1027,29 @@ fn test_reward_fail_more_amount() {
     assert_script_error(err, RewardWrongAmount as i8);
 }
 
+#[test]
+fn test_reward_fail_wrong_commission_rate() {
+    // init context
+    let mut context = Context::default();
+    // the staker claims with the current commission rate rather than the recorded 10%
+    let reward_amount = 600;
+    let staker_keypair = Generator::random_keypair();
+    let delegator_keypair = Generator::random_keypair();
+    let tx = construct_reward_tx_with_commission_rate(
+        &mut context,
+        reward_amount,
+        staker_keypair.clone(),
+        delegator_keypair,
+        staker_keypair.clone(),
+        staker_keypair,
+        20,
+    );
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, RewardCommissionBottomFail as i8);
+}
+
 #[test]
 fn test_smt_not_exist() {
     // let old_epoch = 5;
@@ -763,3 +1107,899 @@ fn test_smt_not_exist() {
         println!("result: {}", result);
     }
 }
+
+#[test]
+fn test_reward_batch_success() {
+    // init context
+    let mut context = Context::default();
+    let staker_keypair = Generator::random_keypair();
+    let delegator_keypair = Generator::random_keypair();
+    let tx = construct_reward_tx_with_claim_format(
+        &mut context,
+        550,
+        staker_keypair.clone(),
+        delegator_keypair,
+        staker_keypair.clone(),
+        staker_keypair,
+        10,
+        ClaimFormat::Batched,
+        Payout::Owner,
+    );
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_reward_fail_batch_with_reward_infos() {
+    // init context
+    let mut context = Context::default();
+    let staker_keypair = Generator::random_keypair();
+    let delegator_keypair = Generator::random_keypair();
+    let tx = construct_reward_tx_with_claim_format(
+        &mut context,
+        550,
+        staker_keypair.clone(),
+        delegator_keypair,
+        staker_keypair.clone(),
+        staker_keypair,
+        10,
+        ClaimFormat::Both,
+        Payout::Owner,
+    );
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("RewardBatchMismatch");
+    assert_script_error(err, RewardBatchMismatch as i8);
+}
+
+// the miner delegates to two stakers in epoch 0 and claims it in batched format, each staker has
+// 1000 staked and the miner's 1000 delegated, with 10% commission, only `listed_stakers` of them
+// are listed in the witness
+fn construct_reward_tx_delegating_to_two_stakers(
+    context: &mut Context,
+    reward_amount: u128,
+    listed_stakers: usize,
+) -> TransactionView {
+    let contract_bin: Bytes = Loader::default().load_binary("reward");
+    let contract_out_point = context.deploy_cell(contract_bin);
+    let contract_dep = CellDep::new_builder()
+        .out_point(contract_out_point.clone())
+        .build();
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("always_success script");
+    let always_success_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+    let always_success_type_script = |context: &mut Context, args: u8| {
+        context
+            .build_script_with_hash_type(
+                &always_success_out_point,
+                ScriptHashType::Type,
+                Bytes::from(vec![args]),
+            )
+            .expect("always_success type script")
+    };
+    let metadata_type_script = context
+        .build_script_with_hash_type(
+            &contract_out_point,
+            ScriptHashType::Type,
+            Bytes::from(vec![2]),
+        )
+        .expect("metadata type script");
+    let checkpoint_type_script = always_success_type_script(context, 3);
+    let stake_smt_type_script = always_success_type_script(context, 4);
+    let delegate_smt_type_script = always_success_type_script(context, 5);
+    let at_type_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![6]))
+        .expect("sudt script");
+
+    let current_epoch = 3;
+    let claim_epoch = 0;
+    let amount = 1000;
+    let commission_rate = 10;
+    let propose_count = 1000;
+    let miner_keypair = Generator::random_keypair();
+    let miner_addr = pubkey_to_addr(&miner_keypair.1.serialize());
+    let mut stakers = (0..2)
+        .map(|_| pubkey_to_addr(&Generator::random_keypair().1.serialize()))
+        .collect::<Vec<[u8; 20]>>();
+    stakers.sort();
+    let staker_keys = stakers
+        .iter()
+        .map(|staker| addr_to_h256(staker))
+        .collect::<Vec<H256>>();
+
+    // bottom smts of epoch 0 hold both stakers, and each staker has the miner's delegation
+    let mut stake_bottom_tree = BOTTOM_SMT::default();
+    let mut count_bottom_tree = PROPOSE_BOTTOM_SMT::default();
+    let mut commission_bottom_tree = COMMISSION_BOTTOM_SMT::default();
+    for key in &staker_keys {
+        stake_bottom_tree
+            .update(*key, BottomValue(amount))
+            .expect("update stake smt bottom tree");
+        count_bottom_tree
+            .update(*key, ProposeBottomValue(propose_count))
+            .expect("update propose count smt bottom tree");
+        commission_bottom_tree
+            .update(*key, commission_to_h256(commission_rate, 0))
+            .expect("update commission smt bottom tree");
+    }
+    let listed_keys = staker_keys[..listed_stakers].to_vec();
+    let bottom_proof =
+        |proof: sparse_merkle_tree::MerkleProof| proof.compile(listed_keys.clone()).unwrap().0;
+    let stake_bottom_proof =
+        bottom_proof(stake_bottom_tree.merkle_proof(listed_keys.clone()).unwrap());
+    let count_bottom_proof =
+        bottom_proof(count_bottom_tree.merkle_proof(listed_keys.clone()).unwrap());
+    let commission_bottom_proof = bottom_proof(
+        commission_bottom_tree
+            .merkle_proof(listed_keys.clone())
+            .unwrap(),
+    );
+
+    let epoch_key = u64_to_h256(claim_epoch);
+    let top_tree = |bottom_root: &H256| {
+        let mut tree = TOP_SMT::default();
+        tree.update(epoch_key, *bottom_root)
+            .expect("update top tree");
+        let proof = tree
+            .merkle_proof(vec![epoch_key])
+            .unwrap()
+            .compile(vec![epoch_key])
+            .unwrap()
+            .0;
+        (*tree.root(), proof)
+    };
+    let (stake_top_root, stake_top_proof) = top_tree(stake_bottom_tree.root());
+    let (count_top_root, count_top_proof) = top_tree(count_bottom_tree.root());
+    let (commission_top_root, commission_top_proof) = top_tree(commission_bottom_tree.root());
+    let delegate_infos = BTreeSet::from_iter(vec![LockInfo {
+        addr: miner_addr,
+        amount,
+    }]);
+    let (delegate_top_root, delegate_top_proof) =
+        delegate_2layer_smt_root_proof(claim_epoch, &delegate_infos);
+
+    let stake_smt_data = axon_types::stake::StakeSmtCellData::new_builder()
+        .smt_root(axon_array32_byte32(
+            stake_top_root.as_slice().try_into().unwrap(),
+        ))
+        .metadata_type_id(axon_byte32(&metadata_type_script.calc_script_hash()))
+        .build();
+    let delegate_smt_data = axon_types::delegate::DelegateSmtCellData::new_builder()
+        .smt_roots(
+            axon_types::delegate::StakerSmtRoots::new_builder()
+                .set(
+                    stakers
+                        .iter()
+                        .map(|staker| {
+                            axon_types::delegate::StakerSmtRoot::new_builder()
+                                .staker(axon_byte20_identity(staker))
+                                .root(axon_array32_byte32(
+                                    delegate_top_root.as_slice().try_into().unwrap(),
+                                ))
+                                .build()
+                        })
+                        .collect(),
+                )
+                .build(),
+        )
+        .metadata_type_id(axon_byte32(&metadata_type_script.calc_script_hash()))
+        .build();
+    let reward_params = RewardParamsObject {
+        base_reward: 1000,
+        half_epoch: 100,
+        minimum_normal_propose_count: 100 * 10 * 95 / 100,
+        propose_discount_rate: 95,
+    };
+    let (reward_params_root, reward_params_proof) =
+        reward_params_smt_root_proof(&reward_params, &[claim_epoch]);
+    let metadata = Metadata::new_builder()
+        .epoch_len(axon_u32(100))
+        .period_len(axon_u32(10))
+        .validators(
+            ValidatorList::new_builder()
+                .push(Validator::new_builder().build())
+                .build(),
+        )
+        .build();
+    let meta_data = axon_metadata_data_by_script(
+        &metadata_type_script,
+        &at_type_script.calc_script_hash(),
+        &checkpoint_type_script,
+        &stake_smt_type_script,
+        &delegate_smt_type_script,
+        MetadataList::new_builder()
+            .push(metadata.clone())
+            .push(metadata)
+            .build(),
+        current_epoch,
+        1000,
+        100,
+        count_top_root.as_slice().try_into().unwrap(),
+        &metadata_type_script.code_hash(),
+        &metadata_type_script.code_hash(),
+        &metadata_type_script.code_hash(),
+    )
+    .as_builder()
+    .commission_smt_root(axon_array32_byte32(
+        commission_top_root.as_slice().try_into().unwrap(),
+    ))
+    .reward_params_smt_root(axon_array32_byte32(
+        reward_params_root.as_slice().try_into().unwrap(),
+    ))
+    .build();
+    let mut cell_deps = vec![contract_dep, always_success_script_dep];
+    for (type_script, data) in [
+        (metadata_type_script.clone(), meta_data.as_bytes()),
+        (stake_smt_type_script, stake_smt_data.as_bytes()),
+        (delegate_smt_type_script, delegate_smt_data.as_bytes()),
+    ] {
+        cell_deps.push(
+            CellDep::new_builder()
+                .out_point(
+                    context.create_cell(
+                        CellOutput::new_builder()
+                            .capacity(1000.pack())
+                            .lock(always_success_lock_script.clone())
+                            .type_(Some(type_script).pack())
+                            .build(),
+                        data,
+                    ),
+                )
+                .build(),
+        );
+    }
+
+    // the miner claims epoch 0 and has no beneficiary
+    let miner_key = addr_to_h256(&miner_addr);
+    let claim_proof = |tree: &CLAIM_SMT| {
+        tree.merkle_proof(vec![miner_key])
+            .unwrap()
+            .compile(vec![miner_key])
+            .unwrap()
+            .0
+    };
+    let old_claim_tree = CLAIM_SMT::default();
+    let mut new_claim_tree = CLAIM_SMT::default();
+    new_claim_tree
+        .update(miner_key, EpochValue(claim_epoch + 1))
+        .expect("update claim smt");
+    let beneficiary_proof = TOP_SMT::default()
+        .merkle_proof(vec![miner_key])
+        .unwrap()
+        .compile(vec![miner_key])
+        .unwrap()
+        .0;
+    let reward_type_script = context
+        .build_script(&contract_out_point, Bytes::from(vec![7u8; 32]))
+        .expect("reward type script");
+    let reward_smt_output = CellOutput::new_builder()
+        .capacity(1000.pack())
+        .lock(always_success_lock_script.clone())
+        .type_(Some(reward_type_script).pack())
+        .build();
+    let reward_smt_data = |claim_tree: &CLAIM_SMT| {
+        axon_reward_smt_data(
+            metadata_type_script
+                .calc_script_hash()
+                .as_slice()
+                .try_into()
+                .unwrap(),
+            claim_tree.root().as_slice().try_into().unwrap(),
+        )
+        .as_bytes()
+    };
+    let input = CellInput::new_builder()
+        .previous_output(
+            context.create_cell(reward_smt_output.clone(), reward_smt_data(&old_claim_tree)),
+        )
+        .build();
+    let secp256k1_data_bin = BUNDLED_CELL.get("specs/cells/secp256k1_data").unwrap();
+    let secp256k1_data_out_point = context.deploy_cell(secp256k1_data_bin.to_vec().into());
+    let miner_lock_script = context
+        .build_script(&secp256k1_data_out_point, Bytes::from(miner_addr.to_vec()))
+        .expect("miner lock script");
+    let outputs = vec![
+        reward_smt_output,
+        CellOutput::new_builder()
+            .capacity(1000.pack())
+            .lock(miner_lock_script)
+            .type_(Some(at_type_script).pack())
+            .build(),
+    ];
+    let outputs_data = vec![
+        reward_smt_data(&new_claim_tree),
+        Bytes::from(axon_normal_at_cell_data(reward_amount, &[])),
+    ];
+
+    let mut stake_infos = BatchRewardStakeInfos::new_builder();
+    let mut staker_infos = BatchStakerInfos::new_builder();
+    for staker_index in 0..listed_stakers {
+        stake_infos = stake_infos.push(
+            BatchRewardStakeInfo::new_builder()
+                .staker_index(axon_u32(staker_index as u32))
+                .staker_amount(axon_u128(amount))
+                .propose_count(axon_u64(propose_count))
+                .delegate_infos(
+                    RewardDelegateInfos::new_builder()
+                        .push(
+                            RewardDelegateInfo::new_builder()
+                                .delegator_addr(axon_identity(&miner_keypair.1.serialize()))
+                                .amount(axon_u128(amount))
+                                .build(),
+                        )
+                        .build(),
+                )
+                .commission_rate(commission_rate.into())
+                .delegate_threshold(axon_u128(0))
+                .build(),
+        );
+        staker_infos = staker_infos.push(
+            BatchStakerInfo::new_builder()
+                .validator(axon_byte20_identity(&stakers[staker_index]))
+                .delegate_epoch_proof(axon_bytes(&delegate_top_proof.0.to_vec()))
+                .build(),
+        );
+    }
+    let epoch_info = BatchEpochRewardInfo::new_builder()
+        .reward_stake_infos(stake_infos.build())
+        .amount_proof(axon_bytes(&stake_bottom_proof))
+        .amount_root(axon_bytes(&stake_bottom_tree.root().as_slice().to_vec()))
+        .count_proof(axon_bytes(&count_bottom_proof))
+        .count_root(axon_bytes(&count_bottom_tree.root().as_slice().to_vec()))
+        .commission_proof(axon_bytes(&commission_bottom_proof))
+        .commission_root(axon_bytes(
+            &commission_bottom_tree.root().as_slice().to_vec(),
+        ))
+        .reward_params(axon_reward_params(&reward_params))
+        .build();
+    let batch_reward_infos = BatchRewardInfos::new_builder()
+        .staker_infos(staker_infos.build())
+        .epoch_infos(
+            BatchEpochRewardInfos::new_builder()
+                .push(epoch_info)
+                .build(),
+        )
+        .amount_epoch_proof(axon_bytes(&stake_top_proof))
+        .count_epoch_proof(axon_bytes(&count_top_proof))
+        .commission_epoch_proof(axon_bytes(&commission_top_proof))
+        .reward_params_proof(axon_bytes(&reward_params_proof))
+        .build();
+    let reward_witness = RewardWitness::new_builder()
+        .miner(axon_identity(&miner_keypair.1.serialize()))
+        .old_not_claim_info(
+            NotClaimInfo::new_builder()
+                .epoch(axon_u64(claim_epoch))
+                .proof(axon_bytes(&claim_proof(&old_claim_tree)))
+                .build(),
+        )
+        .new_not_claim_info(
+            NotClaimInfo::new_builder()
+                .epoch(axon_u64(claim_epoch + 1))
+                .proof(axon_bytes(&claim_proof(&new_claim_tree)))
+                .build(),
+        )
+        .batch_reward_infos(batch_reward_infos)
+        .beneficiary(
+            RewardBeneficiary::new_builder()
+                .proof(axon_bytes(&beneficiary_proof))
+                .build(),
+        )
+        .build();
+    let witness = WitnessArgs::new_builder()
+        .input_type(Some(Bytes::from(reward_witness.as_bytes())).pack())
+        .build();
+
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witness(witness.as_bytes().pack())
+        .cell_deps(cell_deps)
+        .build();
+    context.complete_tx(tx)
+}
+
+#[test]
+fn test_reward_batch_delegator_of_two_stakers_success() {
+    // init context
+    let mut context = Context::default();
+    // 500 of each staker's reward goes to delegation, 450 of it after commission
+    let tx = construct_reward_tx_delegating_to_two_stakers(&mut context, 900, 2);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_reward_fail_batch_delegator_of_two_stakers_one_share() {
+    // init context
+    let mut context = Context::default();
+    let tx = construct_reward_tx_delegating_to_two_stakers(&mut context, 450, 2);
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("RewardWrongAmount");
+    assert_script_error(err, RewardWrongAmount as i8);
+}
+
+#[test]
+fn test_reward_fail_batch_staker_omitted_unsigned() {
+    // init context
+    let mut context = Context::default();
+    // a keeper leaves the other staker out, dropping the miner's delegation to it
+    let tx = construct_reward_tx_delegating_to_two_stakers(&mut context, 450, 1);
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("RewardClaimUnauthorized");
+    assert_script_error(err, RewardClaimUnauthorized as i8);
+}
+
+// rebuild the reward witness of a claim tx, the claim needs no signature so the tx stays valid
+fn update_reward_witness<F>(tx: TransactionView, update: F) -> TransactionView
+where
+    F: FnOnce(RewardWitness) -> RewardWitness,
+{
+    let witness = WitnessArgs::from_slice(&tx.witnesses().get(1).unwrap().raw_data()).unwrap();
+    let reward_witness =
+        RewardWitness::from_slice(&witness.input_type().to_opt().unwrap().raw_data()).unwrap();
+    let witness = witness
+        .as_builder()
+        .input_type(Some(update(reward_witness).as_bytes()).pack())
+        .build();
+    tx.as_advanced_builder()
+        .set_witnesses(vec![Bytes::new().pack(), witness.as_bytes().pack()])
+        .build()
+}
+
+#[test]
+fn test_reward_fail_wrong_claim_proof() {
+    // init context
+    let mut context = Context::default();
+    let tx = construct_reward_tx(&mut context);
+    // the new claim smt proof is for epoch 1, not epoch 2
+    let tx = update_reward_witness(tx, |reward_witness| {
+        let new_not_claim_info = reward_witness
+            .new_not_claim_info()
+            .as_builder()
+            .epoch(axon_u64(2))
+            .build();
+        reward_witness
+            .as_builder()
+            .new_not_claim_info(new_not_claim_info)
+            .build()
+    });
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("RewardClaimProofFail");
+    assert_script_error(err, RewardClaimProofFail as i8);
+}
+
+#[test]
+fn test_reward_fail_claim_epoch_backwards() {
+    // init context
+    let mut context = Context::default();
+    let tx = construct_reward_tx(&mut context);
+    // claim from epoch 2 back to epoch 1
+    let tx = update_reward_witness(tx, |reward_witness| {
+        let old_not_claim_info = reward_witness
+            .old_not_claim_info()
+            .as_builder()
+            .epoch(axon_u64(2))
+            .build();
+        reward_witness
+            .as_builder()
+            .old_not_claim_info(old_not_claim_info)
+            .build()
+    });
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("RewardClaimEpochMismatch");
+    assert_script_error(err, RewardClaimEpochMismatch as i8);
+}
+
+#[test]
+fn test_reward_fail_current_reward_params() {
+    // init context
+    let mut context = Context::default();
+    let staker_keypair = Generator::random_keypair();
+    let delegator_keypair = Generator::random_keypair();
+    // 1100 is the reward with base reward of current metadata, not the recorded one
+    let tx = construct_reward_tx_with_reward_amount(
+        &mut context,
+        1100,
+        staker_keypair.clone(),
+        delegator_keypair,
+        staker_keypair.clone(),
+        staker_keypair,
+    );
+    let tx = update_reward_witness(tx, |reward_witness| {
+        let reward_info = reward_witness.reward_infos().get(0).unwrap();
+        let reward_params = reward_info
+            .reward_params()
+            .as_builder()
+            .base_reward(axon_u128(2000))
+            .build();
+        let reward_info = reward_info
+            .as_builder()
+            .reward_params(reward_params)
+            .build();
+        reward_witness
+            .as_builder()
+            .reward_infos(
+                EpochRewardStakeInfos::new_builder()
+                    .push(reward_info)
+                    .build(),
+            )
+            .build()
+    });
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("RewardParamsFail");
+    assert_script_error(err, RewardParamsFail as i8);
+}
+
+#[test]
+fn test_reward_fail_batch_current_reward_params() {
+    // init context
+    let mut context = Context::default();
+    let staker_keypair = Generator::random_keypair();
+    let delegator_keypair = Generator::random_keypair();
+    let tx = construct_reward_tx_with_claim_format(
+        &mut context,
+        1100,
+        staker_keypair.clone(),
+        delegator_keypair,
+        staker_keypair.clone(),
+        staker_keypair,
+        10,
+        ClaimFormat::Batched,
+        Payout::Owner,
+    );
+    let tx = update_reward_witness(tx, |reward_witness| {
+        let batch_reward_infos = reward_witness.batch_reward_infos();
+        let epoch_info = batch_reward_infos.epoch_infos().get(0).unwrap();
+        let reward_params = epoch_info
+            .reward_params()
+            .as_builder()
+            .base_reward(axon_u128(2000))
+            .build();
+        let epoch_info = epoch_info.as_builder().reward_params(reward_params).build();
+        let batch_reward_infos = batch_reward_infos
+            .as_builder()
+            .epoch_infos(
+                BatchEpochRewardInfos::new_builder()
+                    .push(epoch_info)
+                    .build(),
+            )
+            .build();
+        reward_witness
+            .as_builder()
+            .batch_reward_infos(batch_reward_infos)
+            .build()
+    });
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("RewardParamsFail");
+    assert_script_error(err, RewardParamsFail as i8);
+}
+
+#[test]
+fn test_reward_beneficiary_success() {
+    // init context
+    let mut context = Context::default();
+    let staker_keypair = Generator::random_keypair();
+    let delegator_keypair = Generator::random_keypair();
+    // rewards of the staker go to the normal at cell of its registered cold wallet
+    let cold_wallet_keypair = Generator::random_keypair();
+    let tx = construct_reward_tx_with_claim_format(
+        &mut context,
+        550,
+        staker_keypair.clone(),
+        delegator_keypair,
+        staker_keypair,
+        cold_wallet_keypair,
+        10,
+        ClaimFormat::PerEpoch,
+        Payout::Beneficiary,
+    );
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_reward_compound_stake_success() {
+    // init context
+    let mut context = Context::default();
+    let staker_keypair = Generator::random_keypair();
+    let delegator_keypair = Generator::random_keypair();
+    let tx = construct_reward_tx_with_claim_format(
+        &mut context,
+        550,
+        staker_keypair.clone(),
+        delegator_keypair,
+        staker_keypair.clone(),
+        staker_keypair,
+        10,
+        ClaimFormat::PerEpoch,
+        Payout::CompoundStake(0, 550),
+    );
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_reward_fail_compound_wrong_delta() {
+    // init context
+    let mut context = Context::default();
+    let staker_keypair = Generator::random_keypair();
+    let delegator_keypair = Generator::random_keypair();
+    // the whole reward goes into the stake at cell, but only part of it is staked
+    let tx = construct_reward_tx_with_claim_format(
+        &mut context,
+        550,
+        staker_keypair.clone(),
+        delegator_keypair,
+        staker_keypair.clone(),
+        staker_keypair,
+        10,
+        ClaimFormat::PerEpoch,
+        Payout::CompoundStake(0, 500),
+    );
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("RewardCompoundMismatch");
+    assert_script_error(err, RewardCompoundMismatch as i8);
+}
+
+#[test]
+fn test_reward_fail_compound_over_unstake() {
+    // init context
+    let mut context = Context::default();
+    let staker_keypair = Generator::random_keypair();
+    let delegator_keypair = Generator::random_keypair();
+    // the increase delta would replace the pending unstake of the stake at cell
+    let tx = construct_reward_tx_with_claim_format(
+        &mut context,
+        550,
+        staker_keypair.clone(),
+        delegator_keypair,
+        staker_keypair.clone(),
+        staker_keypair,
+        10,
+        ClaimFormat::PerEpoch,
+        Payout::CompoundStake(50, 550),
+    );
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("RewardCompoundMismatch");
+    assert_script_error(err, RewardCompoundMismatch as i8);
+}
+
+#[test]
+fn test_reward_compound_delegate_success() {
+    // init context
+    let mut context = Context::default();
+    let staker_keypair = Generator::random_keypair();
+    let delegator_keypair = Generator::random_keypair();
+    let tx = construct_reward_tx_with_claim_format(
+        &mut context,
+        450, // 500 - 50 (commission)
+        staker_keypair,
+        delegator_keypair.clone(),
+        delegator_keypair.clone(),
+        delegator_keypair,
+        10,
+        ClaimFormat::PerEpoch,
+        Payout::CompoundDelegate(450),
+    );
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+fn construct_register_beneficiary_tx(
+    context: &mut Context,
+    signer_is_miner: bool,
+) -> TransactionView {
+    let contract_bin: Bytes = Loader::default().load_binary("reward");
+    let contract_out_point = context.deploy_cell(contract_bin);
+    let contract_dep = CellDep::new_builder()
+        .out_point(contract_out_point.clone())
+        .build();
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("always_success script");
+    let always_success_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+    let secp256k1_data_bin = BUNDLED_CELL.get("specs/cells/secp256k1_data").unwrap();
+    let secp256k1_data_out_point = context.deploy_cell(secp256k1_data_bin.to_vec().into());
+    let secp256k1_data_dep = CellDep::new_builder()
+        .out_point(secp256k1_data_out_point)
+        .build();
+
+    let metadata_type_script = context
+        .build_script_with_hash_type(
+            &always_success_out_point,
+            ScriptHashType::Type,
+            Bytes::from(vec![2]),
+        )
+        .expect("metadata type script");
+    let at_type_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![6]))
+        .expect("sudt script");
+    let meta_data = axon_metadata_data(
+        &metadata_type_script.calc_script_hash(),
+        &at_type_script.calc_script_hash(),
+        &metadata_type_script.calc_script_hash(),
+        &metadata_type_script.calc_script_hash(),
+        MetadataList::new_builder().build(),
+    );
+    let metadata_script_dep = CellDep::new_builder()
+        .out_point(
+            context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000.pack())
+                    .lock(always_success_lock_script.clone())
+                    .type_(Some(metadata_type_script.clone()).pack())
+                    .build(),
+                meta_data.as_bytes(),
+            ),
+        )
+        .build();
+
+    // the miner registers another lock as its beneficiary
+    let miner_keypair = Generator::random_keypair();
+    let miner_identity = pubkey_to_eth_identity(&miner_keypair.1);
+    let miner_addr: [u8; 20] = miner_identity.as_slice().try_into().unwrap();
+    let beneficiary_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![9]))
+        .expect("beneficiary script");
+    let beneficiary_lock_hash: [u8; 32] = beneficiary_lock_script
+        .calc_script_hash()
+        .as_slice()
+        .try_into()
+        .unwrap();
+    let old_beneficiary_tree = TOP_SMT::default();
+    let mut new_beneficiary_tree = TOP_SMT::default();
+    new_beneficiary_tree
+        .update(addr_to_h256(&miner_addr), beneficiary_lock_hash.into())
+        .expect("update beneficiary smt");
+    let beneficiary_proof = new_beneficiary_tree
+        .merkle_proof(vec![addr_to_h256(&miner_addr)])
+        .unwrap()
+        .compile(vec![addr_to_h256(&miner_addr)])
+        .unwrap()
+        .0;
+
+    let reward_type_script = context
+        .build_script(&contract_out_point, Bytes::from(vec![7u8; 32]))
+        .expect("reward type script");
+    let reward_smt_data = |beneficiary_smt_root: &H256| {
+        axon_reward_smt_data(
+            metadata_type_script
+                .calc_script_hash()
+                .as_slice()
+                .try_into()
+                .unwrap(),
+            [0u8; 32],
+        )
+        .as_builder()
+        .beneficiary_smt_root(axon_array32_byte32(
+            beneficiary_smt_root.as_slice().try_into().unwrap(),
+        ))
+        .build()
+    };
+    let reward_smt_output = CellOutput::new_builder()
+        .capacity(1000.pack())
+        .lock(always_success_lock_script.clone())
+        .type_(Some(reward_type_script.clone()).pack())
+        .build();
+    let input = CellInput::new_builder()
+        .previous_output(context.create_cell(
+            reward_smt_output.clone(),
+            reward_smt_data(old_beneficiary_tree.root()).as_bytes(),
+        ))
+        .build();
+    let outputs_data = vec![reward_smt_data(new_beneficiary_tree.root()).as_bytes()];
+
+    let tx = TransactionBuilder::default()
+        .input(input)
+        .output(reward_smt_output)
+        .outputs_data(outputs_data.pack())
+        .cell_dep(contract_dep)
+        .cell_dep(always_success_script_dep)
+        .cell_dep(secp256k1_data_dep)
+        .cell_dep(metadata_script_dep)
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // sign tx by miner
+    let signature = if signer_is_miner {
+        sign_eth_tx_hash(&tx, &miner_keypair.0)
+    } else {
+        sign_eth_tx_hash(&tx, &Generator::random_keypair().0)
+    };
+    let beneficiary = RewardBeneficiary::new_builder()
+        .lock_hash(axon_array32_byte32([0u8; 32]))
+        .proof(axon_bytes(&beneficiary_proof))
+        .new_lock_hash(axon_array32_byte32(beneficiary_lock_hash))
+        .eth_sig(axon_byte65(signature))
+        .sig_mode(0.into())
+        .build();
+    let reward_witness = RewardWitness::new_builder()
+        .miner(miner_identity)
+        .mode(1.into())
+        .beneficiary(beneficiary)
+        .build();
+    let witness = WitnessArgs::new_builder()
+        .input_type(Some(reward_witness.as_bytes()).pack())
+        .build();
+    sign_eth_tx(tx, witness)
+}
+
+#[test]
+fn test_reward_register_beneficiary_success() {
+    // init context
+    let mut context = Context::default();
+    let tx = construct_register_beneficiary_tx(&mut context, true);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_reward_register_beneficiary_fail_wrong_signer() {
+    // init context
+    let mut context = Context::default();
+    let tx = construct_register_beneficiary_tx(&mut context, false);
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("SignatureMismatch");
+    assert_script_error(err, SignatureMismatch as i8);
+}
@@ -1,15 +1,16 @@
 use super::*;
 use axon_types::selection::SelectionLockArgs;
-use ckb_testtool::ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*};
+use ckb_testtool::ckb_types::{
+    bytes::Bytes, core::TransactionBuilder, core::TransactionView, packed::*, prelude::*,
+};
 use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
 use helper::*;
 use molecule::prelude::*;
 use util::error::Error::OmniRewardCountError;
 
-#[test]
-fn test_selection_success() {
+// the omni cell under issue lock carries the issue type script only if `with_issue_type`
+fn construct_selection_tx(context: &mut Context, with_issue_type: bool) -> TransactionView {
     // deploy contract
-    let mut context = Context::default();
     let contract_bin: Bytes = Loader::default().load_binary("selection");
     let out_point = context.deploy_cell(contract_bin);
     let reward_contract_bin: Bytes = Loader::default().load_binary("reward");
@@ -20,10 +21,13 @@ fn test_selection_success() {
     let always_success_lock_script = context
         .build_script(&always_success_out_point, Bytes::new())
         .expect("always_success script");
+    let issue_lock_hash = always_success_lock_script.calc_script_hash();
+    let issue_type_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .expect("issue type script");
     let always_success_script_dep = CellDep::new_builder()
         .out_point(always_success_out_point)
         .build();
-    let issue_lock_hash = always_success_lock_script.calc_script_hash();
     // prepare args for reward contract
     let reward_type_script = context
         .build_script(&reward_out_point, Bytes::new())
@@ -32,6 +36,7 @@ fn test_selection_success() {
     let reward_smt_type_id = reward_type_script.calc_script_hash();
     let selection_args = SelectionLockArgs::new_builder()
         .issue_lock_hash(axon_byte32(&issue_lock_hash))
+        .issue_type_hash(axon_byte32(&issue_type_script.calc_script_hash()))
         .reward_smt_type_id(axon_byte32(&reward_smt_type_id))
         .build();
 
@@ -42,18 +47,20 @@ fn test_selection_success() {
     let lock_script_dep = CellDep::new_builder().out_point(out_point).build();
 
     // prepare inputs and outputs
+    let omni_type = if with_issue_type {
+        Some(issue_type_script)
+    } else {
+        None
+    };
+    let omni_cell = CellOutput::new_builder()
+        .capacity(500.pack())
+        .lock(always_success_lock_script)
+        .type_(omni_type.pack())
+        .build();
     let inputs = vec![
         // omni cell
         CellInput::new_builder()
-            .previous_output(
-                context.create_cell(
-                    CellOutput::new_builder()
-                        .capacity(500.pack())
-                        .lock(always_success_lock_script.clone())
-                        .build(),
-                    Bytes::new(),
-                ),
-            )
+            .previous_output(context.create_cell(omni_cell.clone(), Bytes::new()))
             .build(),
         // selection cell
         CellInput::new_builder()
@@ -70,10 +77,7 @@ fn test_selection_success() {
     ];
     let outputs = vec![
         // omni cell
-        CellOutput::new_builder()
-            .capacity(500u64.pack())
-            .lock(always_success_lock_script)
-            .build(),
+        omni_cell,
         // selection cell
         CellOutput::new_builder()
             .capacity(500u64.pack())
@@ -93,7 +97,13 @@ fn test_selection_success() {
         .cell_dep(always_success_script_dep)
         .cell_dep(reward_script_dep)
         .build();
-    let tx = context.complete_tx(tx);
+    context.complete_tx(tx)
+}
+
+#[test]
+fn test_selection_success() {
+    let mut context = Context::default();
+    let tx = construct_selection_tx(&mut context, true);
 
     // run
     let cycles = context
@@ -102,6 +112,18 @@ fn test_selection_success() {
     println!("consume cycles: {}", cycles);
 }
 
+#[test]
+fn test_selection_fail_issue_lock_without_issue_type() {
+    let mut context = Context::default();
+    let tx = construct_selection_tx(&mut context, false);
+
+    // verify_tx return error OmniRewardCountError
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("OmniRewardCountError");
+    assert_script_error(err, OmniRewardCountError as i8);
+}
+
 #[test]
 fn test_selection_fail() {
     // deploy contract
@@ -134,20 +156,8 @@ fn test_selection_fail() {
         .expect("selection script");
     let lock_script_dep = CellDep::new_builder().out_point(out_point).build();
 
-    // prepare inputs and outputs
+    // prepare inputs and outputs, reward cell is consumed without issue cell
     let inputs = vec![
-        // omni cell
-        CellInput::new_builder()
-            .previous_output(
-                context.create_cell(
-                    CellOutput::new_builder()
-                        .capacity(500.pack())
-                        .lock(always_success_lock_script.clone())
-                        .build(),
-                    Bytes::new(),
-                ),
-            )
-            .build(),
         // reward cell
         CellInput::new_builder()
             .previous_output(
@@ -175,10 +185,11 @@ fn test_selection_fail() {
             .build(),
     ];
     let outputs = vec![
-        // omni cell
+        // reward cell
         CellOutput::new_builder()
             .capacity(500u64.pack())
-            .lock(always_success_lock_script)
+            .lock(reward_type_script.clone())
+            .type_(Some(reward_type_script).pack())
             .build(),
         // selection cell
         CellOutput::new_builder()
@@ -171,6 +171,16 @@ pub fn construct_epoch_smt(top_smt_infos: &Vec<TopSmtInfo>) -> (H256, MerkleProo
     )
 }
 
+// proof of an epoch absent from an empty top smt tree
+pub fn construct_empty_epoch_smt_proof(epoch: u64) -> Vec<u8> {
+    TOP_SMT::default()
+        .merkle_proof(vec![u64_to_h256(epoch)])
+        .expect("merkle proof")
+        .compile(vec![u64_to_h256(epoch)])
+        .expect("compile proof")
+        .0
+}
+
 // top smt tree, only suitable for metadata update and top_smt_infos.len() == 1
 pub fn construct_epoch_smt_for_metadata_update(
     top_smt_infos: &Vec<TopSmtInfo>,
@@ -4,7 +4,7 @@ use std::convert::{TryFrom, TryInto};
 use crate::smt::{construct_epoch_smt, construct_lock_info_smt, TopSmtInfo};
 
 use super::*;
-use axon_types::metadata::{Metadata, MetadataList};
+use axon_types::metadata::{Metadata, MetadataList, Validator, ValidatorList};
 use axon_types::stake::*;
 use axon_types::withdraw::WithdrawArgs;
 // use bit_vec::BitVec;
@@ -21,7 +21,7 @@ use ophelia::{Crypto, PrivateKey, Signature, ToPublicKey, UncompressedPublicKey}
 use ophelia_secp256k1::{Secp256k1Recoverable, Secp256k1RecoverablePrivateKey};
 use util::error::Error::{
     BadInaugurationEpoch, BadStakeChange, BadStakeUnstakeChange, InputOutputAtAmountNotEqual,
-    UnstakeTooMuch,
+    SlashLivenessRepeated, SlashTreasuryAmountMismatch, UnstakeTooMuch, UpdateDataError,
 };
 use util::smt::{u64_to_h256, LockInfo, BOTTOM_SMT};
 // use util::helper::pubkey_to_eth_addr;
@@ -34,6 +34,31 @@ fn construct_stake_at_tx(
     input_normal_at_amount: u128,
     output_stake_at_amount: u128,
     output_normal_at_amount: u128,
+) -> TransactionView {
+    construct_stake_at_tx_with_delay(
+        context,
+        input_stake_info_delta,
+        output_stake_info_delta,
+        input_stake_at_amount,
+        input_normal_at_amount,
+        output_stake_at_amount,
+        output_normal_at_amount,
+        2,
+        DelegateRequirementInfo::default(),
+    )
+}
+
+// output stake AT cell carries `output_requirement_info`, the input one carries the default
+fn construct_stake_at_tx_with_delay(
+    context: &mut Context,
+    input_stake_info_delta: StakeInfoDelta,
+    output_stake_info_delta: StakeInfoDelta,
+    input_stake_at_amount: u128,
+    input_normal_at_amount: u128,
+    output_stake_at_amount: u128,
+    output_normal_at_amount: u128,
+    inauguration_delay: u64,
+    output_requirement_info: DelegateRequirementInfo,
 ) -> TransactionView {
     let secp256k1_data_bin = BUNDLED_CELL.get("specs/cells/secp256k1_data").unwrap();
     let secp256k1_data_out_point = context.deploy_cell(secp256k1_data_bin.to_vec().into());
@@ -161,7 +186,7 @@ fn construct_stake_at_tx(
         l2_addr.clone(),
         &metadata_type_script.calc_script_hash(),
         output_stake_info_delta,
-        DelegateRequirementInfo::default(),
+        output_requirement_info,
     );
     let outputs_data = vec![
         Bytes::from(axon_stake_at_cell_data(
@@ -171,9 +196,11 @@ fn construct_stake_at_tx(
         Bytes::from(output_normal_at_amount.to_le_bytes().to_vec()), // normal at cell
     ];
 
-    // prepare metadata cell_dep
+    // prepare metadata cell_dep, which keeps one metadata for each epoch until inauguration
     let metadata = Metadata::new_builder().epoch_len(axon_u32(100)).build();
-    let metadata_list = MetadataList::new_builder().push(metadata).build();
+    let metadata_list = MetadataList::new_builder()
+        .set(vec![metadata; inauguration_delay as usize])
+        .build();
     let meta_data = axon_metadata_data_by_script(
         &metadata_type_script.clone(),
         &stake_at_type_script.calc_script_hash(),
@@ -188,7 +215,10 @@ fn construct_stake_at_tx(
         &metadata_type_script.code_hash(),
         &metadata_type_script.code_hash(),
         &metadata_type_script.code_hash(),
-    );
+    )
+    .as_builder()
+    .inauguration_delay(axon_u64(inauguration_delay))
+    .build();
     let metadata_script_dep = CellDep::new_builder()
         .out_point(
             context.create_cell(
@@ -406,6 +436,129 @@ fn test_stake_at_fail_wrong_epoch() {
     assert_script_error(err, BadInaugurationEpoch as i8);
 }
 
+#[test]
+fn test_stake_at_success_longer_delay() {
+    // init context
+    let mut context = Context::default();
+    let input_stake_at_amount = 100;
+    let input_normal_at_amount = 1000;
+    let output_stake_at_amount = 200;
+    let output_normal_at_amount = 900;
+
+    let input_stake_info_delta = stake::StakeInfoDelta::new_builder()
+        .is_increase(1.into())
+        .amount(axon_u128(input_stake_at_amount))
+        .inauguration_epoch(axon_u64(4 as u64))
+        .build();
+    let output_stake_info_delta = stake::StakeInfoDelta::new_builder()
+        .is_increase(1.into())
+        .amount(axon_u128(output_stake_at_amount))
+        .inauguration_epoch(axon_u64(4 as u64))
+        .build();
+
+    // delta of epoch 1 takes effect in epoch 4 with a 3-epoch delay
+    let tx = construct_stake_at_tx_with_delay(
+        &mut context,
+        input_stake_info_delta,
+        output_stake_info_delta,
+        input_stake_at_amount,
+        input_normal_at_amount,
+        output_stake_at_amount,
+        output_normal_at_amount,
+        3,
+        DelegateRequirementInfo::default(),
+    );
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_stake_at_success_shorter_delay() {
+    // init context
+    let mut context = Context::default();
+    let input_stake_at_amount = 100;
+    let input_normal_at_amount = 1000;
+    let output_stake_at_amount = 200;
+    let output_normal_at_amount = 900;
+
+    let input_stake_info_delta = stake::StakeInfoDelta::new_builder()
+        .is_increase(1.into())
+        .amount(axon_u128(input_stake_at_amount))
+        .inauguration_epoch(axon_u64(2 as u64))
+        .build();
+    let output_stake_info_delta = stake::StakeInfoDelta::new_builder()
+        .is_increase(1.into())
+        .amount(axon_u128(output_stake_at_amount))
+        .inauguration_epoch(axon_u64(2 as u64))
+        .build();
+
+    // delta of epoch 1 takes effect in epoch 2 with a 1-epoch delay
+    let tx = construct_stake_at_tx_with_delay(
+        &mut context,
+        input_stake_info_delta,
+        output_stake_info_delta,
+        input_stake_at_amount,
+        input_normal_at_amount,
+        output_stake_at_amount,
+        output_normal_at_amount,
+        1,
+        DelegateRequirementInfo::default(),
+    );
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_stake_at_fail_requirement_swapped() {
+    // init context
+    let mut context = Context::default();
+    let input_stake_at_amount = 100;
+    let input_normal_at_amount = 1000;
+    let output_stake_at_amount = 200;
+    let output_normal_at_amount = 900;
+
+    let input_stake_info_delta = stake::StakeInfoDelta::new_builder()
+        .is_increase(1.into())
+        .amount(axon_u128(input_stake_at_amount))
+        .inauguration_epoch(axon_u64(3 as u64))
+        .build();
+    let output_stake_info_delta = stake::StakeInfoDelta::new_builder()
+        .is_increase(1.into())
+        .amount(axon_u128(output_stake_at_amount))
+        .inauguration_epoch(axon_u64(3 as u64))
+        .build();
+
+    // requirement info points at another requirement cell
+    let requirement_info = DelegateRequirementInfo::new_builder()
+        .code_hash(axon_byte32(&[7u8; 32].pack()))
+        .build();
+    let tx = construct_stake_at_tx_with_delay(
+        &mut context,
+        input_stake_info_delta,
+        output_stake_info_delta,
+        input_stake_at_amount,
+        input_normal_at_amount,
+        output_stake_at_amount,
+        output_normal_at_amount,
+        2,
+        requirement_info,
+    );
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("UpdateDataError");
+    assert_script_error(err, UpdateDataError as i8);
+}
+
 #[test]
 fn test_stake_at_fail_more_at() {
     // init context
@@ -1220,11 +1373,19 @@ fn construct_unstake_smt_tx(
     //     amount: input_stake_smt_amount.saturating_sub(input_unstake_amount),
     // };
     let output_stake_infos = new_lock_infos.into_iter().collect::<BTreeSet<LockInfo>>();
+    // stakers[0] exits when it is deleted or unstakes all
+    let mut exited_stakers = Vec::new();
+    if is_too_many_staker || input_stake_smt_amount == input_unstake_amount {
+        exited_stakers.push(l2_addr.as_slice().try_into().unwrap());
+    }
     let output_stake_smt_data = axon_stake_smt_cell_data(
         &output_stake_infos,
         &metadata_type_script.calc_script_hash(),
         inauguration_epoch,
-    );
+    )
+    .as_builder()
+    .exited_stakers(axon_exited_stakers(&exited_stakers))
+    .build();
     println!(
         "output stake smt data: {:?}",
         output_stake_smt_data.as_bytes().len()
@@ -1617,7 +1778,13 @@ fn construct_unstake_smt_tx_old3_new4(
         &output_stake_infos,
         &metadata_type_script.calc_script_hash(),
         inauguration_epoch,
-    );
+    )
+    .as_builder()
+    .exited_stakers(axon_exited_stakers(&[l2_addr
+        .as_slice()
+        .try_into()
+        .unwrap()]))
+    .build();
     println!(
         "output stake smt data: {:?}",
         output_stake_smt_data.as_bytes().len()
@@ -1960,6 +2127,43 @@ fn test_unstake_smt_success() {
     println!("consume cycles: {}", cycles);
 }
 
+// staker has staked 100 at, and updated to stake smt cell
+// staker then redeems all of it and exits
+#[test]
+fn test_unstake_smt_success_full_exit() {
+    // init context
+    let mut context = Context::default();
+    let input_unstake_amount = 100;
+    let input_stake_smt_amount = 100;
+    let input_stake_at_amount = 100;
+
+    let mut stakers = Vec::new();
+    let staker0 = TestStakeInfos {
+        staker: Generator::random_keypair(),
+        input_stake_info_delta: stake::StakeInfoDelta::new_builder()
+            .is_increase(0.into())
+            .amount(axon_u128(input_unstake_amount))
+            .inauguration_epoch(axon_u64(2))
+            .build(),
+        input_stake_at_amount: input_stake_at_amount,
+        output_stake_info_delta: stake::StakeInfoDelta::new_builder().build(),
+        output_stake_at_amount: 0,
+    };
+    stakers.push(staker0);
+
+    let tx = construct_unstake_smt_tx(
+        &mut context,
+        stakers,
+        input_unstake_amount,
+        input_stake_smt_amount,
+    );
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
 // staker has staked 90 at, and updated to stake smt cell
 // staker then want to redeem 100 at
 #[test]
@@ -2139,7 +2343,11 @@ fn test_stake_election_success() {
 
     // prepare metadata cell_dep
     let metadata = Metadata::new_builder().epoch_len(axon_u32(100)).build();
-    let metadata_list = MetadataList::new_builder().push(metadata).build();
+    // election quorum comes from metadata of the next epoch
+    let metadata_list = MetadataList::new_builder()
+        .push(metadata.clone())
+        .push(metadata)
+        .build();
     let meta_data = axon_metadata_data(
         &metadata_type_script.clone().calc_script_hash(),
         &stake_smt_type_script.calc_script_hash(),
@@ -2288,3 +2496,434 @@ fn test_lock_info_sort_success() {
         println!("LockInfo: {:?}", lock_info);
     }
 }
+
+const SLASH_RATE: u8 = 10;
+const LIVENESS_SLASH_RATE: u8 = 50;
+
+// staker [1u8; 20] didn't propose any block in this epoch and is slashed together with its
+// delegator [3u8; 20], staker [2u8; 20] proposed enough blocks and is kept in stake smt
+fn construct_slash_smt_tx(
+    context: &mut Context,
+    treasury_amount: u128,
+    next_liveness_slash_epoch: u64,
+) -> TransactionView {
+    let offender = [1u8; 20];
+    let staker = [2u8; 20];
+    let delegator = [3u8; 20];
+    let offender_stake_amount = 1000u128;
+    let staker_stake_amount = 2000u128;
+    let delegate_amount = 500u128;
+    let epoch = 0;
+    let inauguration_epoch = epoch + 2;
+
+    let stake_smt_contract_bin: Bytes = Loader::default().load_binary("stake-smt");
+    let stake_smt_contract_out_point = context.deploy_cell(stake_smt_contract_bin);
+    let stake_smt_contract_dep = CellDep::new_builder()
+        .out_point(stake_smt_contract_out_point.clone())
+        .build();
+    let delegate_smt_contract_bin: Bytes = Loader::default().load_binary("delegate-smt");
+    let delegate_smt_contract_out_point = context.deploy_cell(delegate_smt_contract_bin);
+    let delegate_smt_contract_dep = CellDep::new_builder()
+        .out_point(delegate_smt_contract_out_point.clone())
+        .build();
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_script_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+    let always_success_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .expect("always_success script");
+    let checkpoint_type_script = context
+        .build_script_with_hash_type(
+            &always_success_out_point,
+            ScriptHashType::Type,
+            Bytes::from(vec![2]),
+        )
+        .expect("checkpoint script");
+    let xudt_type_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![4]))
+        .expect("sudt script");
+    let metadata_type_script = context
+        .build_script_with_hash_type(
+            &always_success_out_point,
+            ScriptHashType::Type,
+            Bytes::from(vec![5]),
+        )
+        .expect("metadata type script");
+    let metadata_type_id = metadata_type_script.calc_script_hash();
+    let treasury_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![7]))
+        .expect("treasury lock script");
+    let stake_smt_type_script = context
+        .build_script_with_hash_type(
+            &stake_smt_contract_out_point,
+            ScriptHashType::Type,
+            Bytes::from(vec![6u8; 32]),
+        )
+        .expect("stake smt type script");
+    let delegate_smt_type_script = context
+        .build_script_with_hash_type(
+            &delegate_smt_contract_out_point,
+            ScriptHashType::Type,
+            Bytes::from(vec![8u8; 32]),
+        )
+        .expect("delegate smt type script");
+
+    // stake and delegate AT cells are locked by always success to focus on smt cells
+    let at_lock_script = |context: &mut Context, args: Bytes| {
+        context
+            .build_script_with_hash_type(&always_success_out_point, ScriptHashType::Type, args)
+            .expect("at lock script")
+    };
+    let offender_stake_at_lock_script = at_lock_script(
+        context,
+        stake::StakeArgs::new_builder()
+            .metadata_type_id(axon_byte32(&metadata_type_id))
+            .stake_addr(axon_byte20_identity(&offender))
+            .build()
+            .as_bytes(),
+    );
+    let delegate_at_lock_script = at_lock_script(
+        context,
+        axon_types::delegate::DelegateArgs::new_builder()
+            .metadata_type_id(axon_byte32(&metadata_type_id))
+            .delegator_addr(axon_byte20_identity(&delegator))
+            .build()
+            .as_bytes(),
+    );
+    let offender_slash_amount = offender_stake_amount * SLASH_RATE as u128 / 100;
+    let delegator_slash_amount = delegate_amount * SLASH_RATE as u128 / 100;
+
+    // stake smt keeps the part of offender's stake not slashed
+    let old_stake_infos = vec![
+        LockInfo {
+            addr: offender,
+            amount: offender_stake_amount,
+        },
+        LockInfo {
+            addr: staker,
+            amount: staker_stake_amount,
+        },
+    ]
+    .into_iter()
+    .collect::<BTreeSet<LockInfo>>();
+    let new_stake_infos = vec![
+        LockInfo {
+            addr: offender,
+            amount: offender_stake_amount - offender_slash_amount,
+        },
+        LockInfo {
+            addr: staker,
+            amount: staker_stake_amount,
+        },
+    ]
+    .into_iter()
+    .collect::<BTreeSet<LockInfo>>();
+    let stake_smt_proof = |stake_infos: &BTreeSet<LockInfo>| {
+        let (bottom_root, _) = construct_lock_info_smt(stake_infos);
+        let (_, proof) = construct_epoch_smt(&vec![TopSmtInfo {
+            epoch: inauguration_epoch,
+            smt_root: bottom_root,
+        }]);
+        proof
+            .compile(vec![u64_to_h256(inauguration_epoch)])
+            .unwrap()
+            .0
+    };
+    let all_stake_infos = old_stake_infos
+        .iter()
+        .map(|info| {
+            stake::StakeInfo::new_builder()
+                .addr(axon_byte20_identity(&info.addr))
+                .amount(axon_u128(info.amount))
+                .build()
+        })
+        .collect::<Vec<_>>();
+    let stake_smt_update_info = stake::StakeSmtUpdateInfo::new_builder()
+        .all_stake_infos(
+            stake::StakeInfos::new_builder()
+                .set(all_stake_infos)
+                .build(),
+        )
+        .old_epoch_proof(axon_bytes(&stake_smt_proof(&old_stake_infos)))
+        .new_epoch_proof(axon_bytes(&stake_smt_proof(&new_stake_infos)))
+        .build();
+    let stake_smt_witness = StakeSmtWitness::new_builder()
+        .mode(2.into())
+        .update_info(stake_smt_update_info)
+        .build();
+
+    // delegate smt reduces delegations of the offender by the same rate
+    let old_delegate_infos = vec![LockInfo {
+        addr: delegator,
+        amount: delegate_amount,
+    }]
+    .into_iter()
+    .collect::<BTreeSet<LockInfo>>();
+    let new_delegate_infos = vec![LockInfo {
+        addr: delegator,
+        amount: delegate_amount - delegator_slash_amount,
+    }]
+    .into_iter()
+    .collect::<BTreeSet<LockInfo>>();
+    let (old_delegate_root, old_delegate_proof) =
+        delegate_2layer_smt_root_proof(inauguration_epoch, &old_delegate_infos);
+    let (new_delegate_root, new_delegate_proof) =
+        delegate_2layer_smt_root_proof(inauguration_epoch, &new_delegate_infos);
+    let delegate_smt_data = |root: sparse_merkle_tree::H256| {
+        let smt_root = axon_types::delegate::StakerSmtRoot::new_builder()
+            .staker(axon_byte20_identity(&offender))
+            .root(axon_array32_byte32(root.as_slice().try_into().unwrap()))
+            .build();
+        axon_types::delegate::DelegateSmtCellData::new_builder()
+            .version(0.into())
+            .smt_roots(
+                axon_types::delegate::StakerSmtRoots::new_builder()
+                    .push(smt_root)
+                    .build(),
+            )
+            .metadata_type_id(axon_byte32(&metadata_type_id))
+            .build()
+    };
+    let delegate_info = axon_types::delegate::DelegateInfo::new_builder()
+        .delegator_addr(axon_byte20_identity(&delegator))
+        .amount(axon_u128(delegate_amount))
+        .build();
+    let stake_group_info = axon_types::delegate::StakeGroupInfo::new_builder()
+        .staker(axon_byte20_identity(&offender))
+        .delegate_infos(
+            axon_types::delegate::DelegateInfos::new_builder()
+                .push(delegate_info)
+                .build(),
+        )
+        .delegate_old_epoch_proof(axon_bytes(&old_delegate_proof.0))
+        .delegate_new_epoch_proof(axon_bytes(&new_delegate_proof.0))
+        .build();
+    let delegate_smt_witness = axon_types::delegate::DelegateSmtWitness::new_builder()
+        .mode(2.into())
+        .update_info(
+            axon_types::delegate::DelegateSmtUpdateInfo::new_builder()
+                .all_stake_group_infos(
+                    axon_types::delegate::StakeGroupInfos::new_builder()
+                        .push(stake_group_info)
+                        .build(),
+                )
+                .build(),
+        )
+        .build();
+
+    // (lock, type, input data, output data)
+    let cells = vec![
+        (
+            always_success_lock_script.clone(),
+            stake_smt_type_script.clone(),
+            axon_stake_smt_cell_data(&old_stake_infos, &metadata_type_id, inauguration_epoch)
+                .as_builder()
+                .next_liveness_slash_epoch(axon_u64(next_liveness_slash_epoch))
+                .build()
+                .as_bytes(),
+            axon_stake_smt_cell_data(&new_stake_infos, &metadata_type_id, inauguration_epoch)
+                .as_builder()
+                .next_liveness_slash_epoch(axon_u64(epoch + 1))
+                .build()
+                .as_bytes(),
+        ),
+        (
+            always_success_lock_script.clone(),
+            delegate_smt_type_script.clone(),
+            delegate_smt_data(old_delegate_root).as_bytes(),
+            delegate_smt_data(new_delegate_root).as_bytes(),
+        ),
+        (
+            offender_stake_at_lock_script.clone(),
+            xudt_type_script.clone(),
+            Bytes::from(axon_stake_at_cell_data(
+                offender_stake_amount,
+                StakeAtCellData::new_builder().build(),
+            )),
+            Bytes::from(axon_stake_at_cell_data(
+                offender_stake_amount - offender_slash_amount,
+                StakeAtCellData::new_builder().build(),
+            )),
+        ),
+        (
+            delegate_at_lock_script,
+            xudt_type_script.clone(),
+            Bytes::from(axon_delegate_at_cell_data(
+                delegate_amount,
+                axon_types::delegate::DelegateAtCellData::new_builder().build(),
+            )),
+            Bytes::from(axon_delegate_at_cell_data(
+                delegate_amount - delegator_slash_amount,
+                axon_types::delegate::DelegateAtCellData::new_builder().build(),
+            )),
+        ),
+        (
+            treasury_lock_script.clone(),
+            xudt_type_script.clone(),
+            Bytes::from(0u128.to_le_bytes().to_vec()),
+            Bytes::from(treasury_amount.to_le_bytes().to_vec()),
+        ),
+    ];
+    let mut inputs = Vec::new();
+    let mut outputs = Vec::new();
+    let mut outputs_data = Vec::new();
+    for (lock, type_, input_data, output_data) in cells {
+        let output = CellOutput::new_builder()
+            .capacity(1000.pack())
+            .lock(lock)
+            .type_(Some(type_).pack())
+            .build();
+        inputs.push(
+            CellInput::new_builder()
+                .previous_output(context.create_cell(output.clone(), input_data))
+                .build(),
+        );
+        outputs.push(output);
+        outputs_data.push(output_data);
+    }
+    let witnesses = vec![
+        WitnessArgs::new_builder()
+            .input_type(Some(Bytes::from(stake_smt_witness.as_bytes())).pack())
+            .build()
+            .as_bytes()
+            .pack(),
+        WitnessArgs::new_builder()
+            .input_type(Some(Bytes::from(delegate_smt_witness.as_bytes())).pack())
+            .build()
+            .as_bytes()
+            .pack(),
+    ];
+
+    // both stakers are validators of this epoch with the same propose_weight, epoch_len *
+    // period_len / 2 blocks are expected to be proposed by each of them
+    let validators = [offender, staker]
+        .iter()
+        .map(|addr| {
+            Validator::new_builder()
+                .address(axon_byte20_identity(addr))
+                .propose_weight(axon_u32(1))
+                .build()
+        })
+        .collect::<Vec<_>>();
+    let metadata = Metadata::new_builder()
+        .epoch_len(axon_u32(10))
+        .period_len(axon_u32(10))
+        .quorum(axon_u16(1))
+        .validators(ValidatorList::new_builder().set(validators).build())
+        .build();
+    let metadata_list = MetadataList::new_builder().push(metadata).build();
+    let at_code_hash = offender_stake_at_lock_script.code_hash();
+    let meta_data = axon_metadata_data_by_script(
+        &metadata_type_script,
+        &xudt_type_script.calc_script_hash(),
+        &checkpoint_type_script,
+        &stake_smt_type_script,
+        &delegate_smt_type_script,
+        metadata_list,
+        epoch,
+        100,
+        100,
+        [0u8; 32],
+        &at_code_hash,
+        &at_code_hash,
+        &at_code_hash,
+    )
+    .as_builder()
+    .slash_rate(SLASH_RATE.into())
+    .liveness_slash_rate(LIVENESS_SLASH_RATE.into())
+    .treasury_lock_hash(axon_byte32(&treasury_lock_script.calc_script_hash()))
+    .build();
+    let metadata_script_dep = CellDep::new_builder()
+        .out_point(
+            context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000.pack())
+                    .lock(always_success_lock_script.clone())
+                    .type_(Some(metadata_type_script.clone()).pack())
+                    .build(),
+                meta_data.as_bytes(),
+            ),
+        )
+        .build();
+
+    // last checkpoint of the epoch, the offender proposed nothing
+    let propose_count = axon_types::checkpoint::ProposeCount::new_builder()
+        .address(axon_byte20(&staker))
+        .count(axon_u64(100))
+        .build();
+    let checkpoint_data = axon_checkpoint_data(&metadata_type_id, epoch)
+        .as_builder()
+        .period(axon_u32(9))
+        .propose_count(
+            axon_types::checkpoint::ProposeCounts::new_builder()
+                .push(propose_count)
+                .build(),
+        )
+        .build();
+    let checkpoint_script_dep = CellDep::new_builder()
+        .out_point(
+            context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000.pack())
+                    .lock(always_success_lock_script.clone())
+                    .type_(Some(checkpoint_type_script).pack())
+                    .build(),
+                checkpoint_data.as_bytes(),
+            ),
+        )
+        .build();
+
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .witnesses(witnesses)
+        .cell_dep(stake_smt_contract_dep)
+        .cell_dep(delegate_smt_contract_dep)
+        .cell_dep(always_success_script_dep)
+        .cell_dep(checkpoint_script_dep)
+        .cell_dep(metadata_script_dep)
+        .build();
+    context.complete_tx(tx)
+}
+
+#[test]
+fn test_slash_smt_success() {
+    // init context
+    let mut context = Context::default();
+    // 10% of offender's 1000 stake and its delegator's 500 delegation
+    let tx = construct_slash_smt_tx(&mut context, 150, 0);
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_slash_smt_fail_treasury_amount() {
+    // init context
+    let mut context = Context::default();
+    // delegator's slashed part is not sent to treasury
+    let tx = construct_slash_smt_tx(&mut context, 100, 0);
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("SlashTreasuryAmountMismatch");
+    assert_script_error(err, SlashTreasuryAmountMismatch as i8);
+}
+
+#[test]
+fn test_slash_smt_fail_liveness_repeated() {
+    // init context
+    let mut context = Context::default();
+    // liveness offenders of this epoch are already slashed
+    let tx = construct_slash_smt_tx(&mut context, 150, 1);
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("SlashLivenessRepeated");
+    assert_script_error(err, SlashLivenessRepeated as i8);
+}
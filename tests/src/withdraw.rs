@@ -5,6 +5,8 @@ use axon_types::checkpoint::CheckpointCellData;
 use axon_types::metadata::MetadataList;
 use axon_types::withdraw::{WithdrawArgs, WithdrawWitness};
 // use bit_vec::BitVec;
+use ckb_system_scripts::BUNDLED_CELL;
+use ckb_testtool::ckb_crypto::secp::{Generator, Privkey};
 use ckb_testtool::ckb_types::core::ScriptHashType;
 use ckb_testtool::ckb_types::{
     bytes::Bytes, core::TransactionBuilder, core::TransactionView, packed::*, prelude::*,
@@ -13,9 +15,21 @@ use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
 use helper::*;
 use molecule::prelude::*;
 use util::error::Error::{
-    WithdrawTotalAmount, WithdrawWrongRecordSize, WithdrawZeroAmount, WrongLockEpoch,
+    SignatureMismatch, WithdrawEpochOverflow, WithdrawTotalAmount, WithdrawWrongRecordSize,
+    WithdrawZeroAmount, WrongLockEpoch, WrongOutWithdraw,
 };
 
+fn sign_withdraw_tx(tx: TransactionView, key: &Privkey) -> TransactionView {
+    let signature = sign_eth_tx_hash(&tx, key);
+    let withdraw_witness = WithdrawWitness::new_builder()
+        .signature(axon_bytes(&signature))
+        .build();
+    let withdraw_witness = WitnessArgs::new_builder()
+        .lock(Some(withdraw_witness.as_bytes()).pack())
+        .build();
+    sign_eth_tx(tx, withdraw_witness)
+}
+
 fn construct_withdraw_tx_with_amount(
     context: &mut Context,
     input_withdraw_infos: Vec<(u64, u128)>,
@@ -23,6 +37,23 @@ fn construct_withdraw_tx_with_amount(
     input_withdraw_amount: u128,
     output_withdraw_amount: u128,
     output_normal_at_amount: u128,
+) -> TransactionView {
+    construct_withdraw_tx_with_inputs(
+        context,
+        vec![(input_withdraw_infos, input_withdraw_amount)],
+        output_withdraw_infos,
+        output_withdraw_amount,
+        output_normal_at_amount,
+    )
+}
+
+// every input withdraw AT cell is (withdraw infos, amount), all of them belong to the same owner
+fn construct_withdraw_tx_with_inputs(
+    context: &mut Context,
+    input_withdraw_cells: Vec<(Vec<(u64, u128)>, u128)>,
+    output_withdraw_infos: Vec<(u64, u128)>,
+    output_withdraw_amount: u128,
+    output_normal_at_amount: u128,
 ) -> TransactionView {
     let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
     let always_success_lock_script = context
@@ -46,8 +77,9 @@ fn construct_withdraw_tx_with_amount(
         )
         .expect("metadata type script");
 
+    let withdrawer_keypair = Generator::random_keypair();
     let withdraw_lock_args = WithdrawArgs::new_builder()
-        .addr(axon_identity(&[0u8; 20].to_vec()))
+        .addr(pubkey_to_eth_identity(&withdrawer_keypair.1))
         .metadata_type_id(axon_byte32(&metadata_type_script.calc_script_hash()))
         .build();
     let withdraw_lock_script = context
@@ -58,23 +90,28 @@ fn construct_withdraw_tx_with_amount(
         .build_script(&always_success_out_point, Bytes::from(vec![6]))
         .expect("sudt script");
 
-    let input_withdraw_at_cell_data =
-        axon_withdraw_at_cell_data_without_amount(input_withdraw_infos);
-    let input = CellInput::new_builder()
-        .previous_output(
-            context.create_cell(
-                CellOutput::new_builder()
-                    .capacity(2000.pack())
-                    .lock(withdraw_lock_script.clone())
-                    .type_(Some(at_type_script.clone()).pack())
-                    .build(),
-                Bytes::from(axon_withdraw_at_cell_data(
-                    input_withdraw_amount,
-                    input_withdraw_at_cell_data,
-                )), // delegate at cell
-            ),
-        )
-        .build();
+    let inputs = input_withdraw_cells
+        .into_iter()
+        .map(|(input_withdraw_infos, input_withdraw_amount)| {
+            let input_withdraw_at_cell_data =
+                axon_withdraw_at_cell_data_without_amount(input_withdraw_infos);
+            CellInput::new_builder()
+                .previous_output(
+                    context.create_cell(
+                        CellOutput::new_builder()
+                            .capacity(2000.pack())
+                            .lock(withdraw_lock_script.clone())
+                            .type_(Some(at_type_script.clone()).pack())
+                            .build(),
+                        Bytes::from(axon_withdraw_at_cell_data(
+                            input_withdraw_amount,
+                            input_withdraw_at_cell_data,
+                        )), // withdraw at cell
+                    ),
+                )
+                .build()
+        })
+        .collect::<Vec<_>>();
 
     let output_withdraw_at_cell_data =
         axon_withdraw_at_cell_data_without_amount(output_withdraw_infos);
@@ -152,26 +189,25 @@ fn construct_withdraw_tx_with_amount(
         )
         .build();
 
-    let withdraw_witness = WithdrawWitness::new_builder()
-        .signature(axon_bytes(&[0u8; 65].to_vec()))
-        .build();
-    let withdraw_witness = WitnessArgs::new_builder()
-        .lock(Some(withdraw_witness.as_bytes()).pack())
+    let secp256k1_data_bin = BUNDLED_CELL.get("specs/cells/secp256k1_data").unwrap();
+    let secp256k1_data_out_point = context.deploy_cell(secp256k1_data_bin.to_vec().into());
+    let secp256k1_data_dep = CellDep::new_builder()
+        .out_point(secp256k1_data_out_point)
         .build();
 
     // prepare signed tx
     let tx = TransactionBuilder::default()
-        .input(input)
+        .inputs(inputs)
         .outputs(outputs)
         .outputs_data(outputs_data.pack())
-        .witness(withdraw_witness.as_bytes().pack())
         .cell_dep(contract_dep)
         .cell_dep(metadata_script_dep)
         .cell_dep(checkpoint_script_dep)
         .cell_dep(always_success_script_dep)
+        .cell_dep(secp256k1_data_dep)
         .build();
     let tx = context.complete_tx(tx);
-    tx
+    sign_withdraw_tx(tx, &withdrawer_keypair.0)
 }
 
 fn construct_withdraw_tx(
@@ -196,6 +232,20 @@ fn construct_withdraw_tx_increase(
     context: &mut Context,
     input_withdraw_infos: Vec<(u64, u128)>,
     output_withdraw_infos: Vec<(u64, u128)>,
+) -> TransactionView {
+    construct_withdraw_tx_increase_with_unbonding(
+        context,
+        input_withdraw_infos,
+        output_withdraw_infos,
+        2,
+    )
+}
+
+fn construct_withdraw_tx_increase_with_unbonding(
+    context: &mut Context,
+    input_withdraw_infos: Vec<(u64, u128)>,
+    output_withdraw_infos: Vec<(u64, u128)>,
+    unbonding_epochs: u64,
 ) -> TransactionView {
     let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
     let always_success_lock_script = context
@@ -300,7 +350,10 @@ fn construct_withdraw_tx_increase(
         &metadata_type_script.code_hash(),
         &metadata_type_script.code_hash(),
         &metadata_type_script.code_hash(),
-    );
+    )
+    .as_builder()
+    .unbonding_epochs(axon_u64(unbonding_epochs))
+    .build();
 
     let metadata_script_dep = CellDep::new_builder()
         .out_point(
@@ -347,6 +400,24 @@ fn test_withdraw_success() {
     println!("consume cycles: {}", cycles);
 }
 
+#[test]
+fn test_withdraw_fail_wrong_signature() {
+    // init context
+    let mut context = Context::default();
+    let input_withdraw_infos = vec![(3 as u64, 1000 as u128), (4, 2000), (5, 3000)];
+    let output_withdraw_infos = vec![(4 as u64, 2000 as u128), (5, 3000)];
+    let tx = construct_withdraw_tx(&mut context, input_withdraw_infos, output_withdraw_infos);
+
+    // signed by someone other than the owner
+    let tx = sign_withdraw_tx(tx, &Generator::random_keypair().0);
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("SignatureMismatch");
+    assert_script_error(err, SignatureMismatch as i8);
+}
+
 #[test]
 fn test_withdraw_fail_too_much() {
     // init context
@@ -459,6 +530,53 @@ fn test_withdraw_fail_epoch_n3() {
     assert_script_error(err, WrongLockEpoch as i8);
 }
 
+#[test]
+fn test_withdraw_merge_success() {
+    // init context
+    let mut context = Context::default();
+    let input_withdraw_cells = vec![
+        (vec![(3 as u64, 1000 as u128), (4, 2000)], 3000 as u128),
+        (vec![(4, 1000), (5, 2000)], 3000),
+    ];
+    let output_withdraw_infos = vec![(4 as u64, 3000 as u128), (5, 2000)];
+    let tx = construct_withdraw_tx_with_inputs(
+        &mut context,
+        input_withdraw_cells,
+        output_withdraw_infos,
+        5000,
+        1000,
+    );
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_withdraw_merge_fail_wrong_records() {
+    // init context
+    let mut context = Context::default();
+    let input_withdraw_cells = vec![
+        (vec![(3 as u64, 1000 as u128), (4, 2000)], 3000 as u128),
+        (vec![(4, 1000), (5, 2000)], 3000),
+    ];
+    // records of the second cell are dropped
+    let output_withdraw_infos = vec![(4 as u64, 2000 as u128), (5, 3000)];
+    let tx = construct_withdraw_tx_with_inputs(
+        &mut context,
+        input_withdraw_cells,
+        output_withdraw_infos,
+        5000,
+        1000,
+    );
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_script_error(err, WrongOutWithdraw as i8);
+}
+
 #[test]
 fn test_increase_withdraw_success() {
     // init context
@@ -498,3 +616,71 @@ fn test_increase_withdraw_fail_wrong_epoch() {
         .expect_err("WrongLockEpoch");
     assert_script_error(err, WrongLockEpoch as i8);
 }
+
+#[test]
+fn test_increase_withdraw_success_long_unbonding() {
+    // init context
+    let mut context = Context::default();
+    let input_withdraw_infos = vec![(3 as u64, 1000 as u128), (5, 2000)];
+    let output_withdraw_infos = vec![
+        (3 as u64, 1000 as u128),
+        (5 as u64, 2000 as u128),
+        (7, 3000),
+    ];
+    let tx = construct_withdraw_tx_increase_with_unbonding(
+        &mut context,
+        input_withdraw_infos,
+        output_withdraw_infos,
+        4,
+    );
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+    println!("consume cycles: {}", cycles);
+}
+
+#[test]
+fn test_increase_withdraw_fail_beyond_unbonding() {
+    // init context
+    let mut context = Context::default();
+    let input_withdraw_infos = vec![(3 as u64, 1000 as u128), (4, 2000)];
+    let output_withdraw_infos = vec![
+        (3 as u64, 1000 as u128),
+        (4 as u64, 2000 as u128),
+        (7, 3000),
+    ];
+    let tx = construct_withdraw_tx_increase_with_unbonding(
+        &mut context,
+        input_withdraw_infos,
+        output_withdraw_infos,
+        3,
+    );
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("WrongLockEpoch");
+    assert_script_error(err, WrongLockEpoch as i8);
+}
+
+#[test]
+fn test_increase_withdraw_fail_unbonding_overflow() {
+    // init context
+    let mut context = Context::default();
+    let input_withdraw_infos = vec![(3 as u64, 1000 as u128)];
+    let output_withdraw_infos = vec![(3 as u64, 1000 as u128), (u64::MAX, 3000)];
+    let tx = construct_withdraw_tx_increase_with_unbonding(
+        &mut context,
+        input_withdraw_infos,
+        output_withdraw_infos,
+        u64::MAX,
+    );
+
+    // run
+    let err = context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect_err("WithdrawEpochOverflow");
+    assert_script_error(err, WithdrawEpochOverflow as i8);
+}